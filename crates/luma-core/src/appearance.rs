@@ -0,0 +1,56 @@
+/// Rounding applied to window corners by the system compositor
+/// 
+/// Only honored where the platform supports it (Windows 11 and later);
+/// elsewhere it is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CornerPreference {
+    /// Let the system decide
+    #[default]
+    Default,
+    /// Never round corners
+    DoNotRound,
+    /// Round corners
+    Round,
+    /// Round corners with a small radius
+    RoundSmall,
+}
+
+/// System-drawn material behind the window client area
+/// 
+/// Only available on Windows 11 22H2 and later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backdrop {
+    /// Plain window background
+    #[default]
+    None,
+    /// Mica, tinted with the desktop wallpaper (main windows)
+    Mica,
+    /// Alternate, more strongly tinted Mica (tabbed windows)
+    MicaAlt,
+    /// Translucent blurred acrylic (transient windows, popups)
+    Acrylic,
+}
+
+/// Light or dark application theme, as chosen in the system settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Dark text on light backgrounds
+    #[default]
+    Light,
+    /// Light text on dark backgrounds
+    Dark,
+}
+
+/// How text too long for its widget is shortened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EllipsisMode {
+    /// Clip the text at the widget's edge
+    #[default]
+    None,
+    /// Cut the end of the text and put "…" in its place
+    End,
+    /// Cut the middle of a file path, keeping the file name visible
+    Path,
+    /// Like `End`, but only break between words
+    Word,
+}
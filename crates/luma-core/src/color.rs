@@ -0,0 +1,119 @@
+/// An RGBA color with 8 bits per channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// Create a color from red, green, blue and alpha components
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+    
+    /// Create an opaque color from red, green and blue components
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+    
+    // Named colors, with the same values as in XAML
+    pub const TRANSPARENT: Self = Self { r: 0, g: 0, b: 0, a: 0 };
+    pub const BLACK: Self = Self { r: 0, g: 0, b: 0, a: 255 };
+    pub const WHITE: Self = Self { r: 255, g: 255, b: 255, a: 255 };
+    pub const GRAY: Self = Self { r: 128, g: 128, b: 128, a: 255 };
+    pub const RED: Self = Self { r: 255, g: 0, b: 0, a: 255 };
+    pub const GREEN: Self = Self { r: 0, g: 128, b: 0, a: 255 };
+    pub const BLUE: Self = Self { r: 0, g: 0, b: 255, a: 255 };
+    pub const YELLOW: Self = Self { r: 255, g: 255, b: 0, a: 255 };
+    pub const ORANGE: Self = Self { r: 255, g: 165, b: 0, a: 255 };
+    pub const PURPLE: Self = Self { r: 128, g: 0, b: 128, a: 255 };
+    
+    /// Parse a hex color: `#RGB`, `#ARGB`, `#RRGGBB` or `#AARRGGBB`
+    /// 
+    /// Alpha comes first, as in XAML. The `#` is optional. Returns `None`
+    /// for any other length or a non-hex digit.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        
+        // Short forms repeat each digit, so #F00 is #FF0000
+        let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok().map(|d| d * 17);
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        match hex.len() {
+            3 => Some(Self::rgb(digit(0)?, digit(1)?, digit(2)?)),
+            4 => Some(Self::new(digit(1)?, digit(2)?, digit(3)?, digit(0)?)),
+            6 => Some(Self::rgb(byte(0)?, byte(2)?, byte(4)?)),
+            8 => Some(Self::new(byte(2)?, byte(4)?, byte(6)?, byte(0)?)),
+            _ => None,
+        }
+    }
+    
+    /// Look up one of the named color constants, ignoring case
+    /// 
+    /// Covers the names of the constants above, such as `Red` or `Transparent`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        const NAMED: [(&str, Color); 10] = [
+            ("Transparent", Color::TRANSPARENT),
+            ("Black", Color::BLACK),
+            ("White", Color::WHITE),
+            ("Gray", Color::GRAY),
+            ("Red", Color::RED),
+            ("Green", Color::GREEN),
+            ("Blue", Color::BLUE),
+            ("Yellow", Color::YELLOW),
+            ("Orange", Color::ORANGE),
+            ("Purple", Color::PURPLE),
+        ];
+        let name = name.trim();
+        NAMED
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|(_, color)| *color)
+    }
+    
+    /// Pack the color as a Win32 `COLORREF` (0x00BBGGRR), dropping alpha
+    pub fn to_win32_colorref(&self) -> u32 {
+        self.r as u32 | (self.g as u32) << 8 | (self.b as u32) << 16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_color_rgb_is_opaque() {
+        let color = Color::rgb(10, 20, 30);
+        assert_eq!(color, Color::new(10, 20, 30, 255));
+    }
+    
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(Color::from_hex("#FF8000"), Some(Color::rgb(255, 128, 0)));
+        assert_eq!(Color::from_hex("80ff8000"), Some(Color::new(255, 128, 0, 128)));
+        assert_eq!(Color::from_hex("#F80"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::from_hex("#8F80"), Some(Color::new(255, 136, 0, 136)));
+        assert_eq!(Color::from_hex("#FF80"), Some(Color::new(255, 136, 0, 255)));
+        assert_eq!(Color::from_hex("#FF800"), None);
+        assert_eq!(Color::from_hex("#GG8000"), None);
+        assert_eq!(Color::from_hex("#"), None);
+    }
+    
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Color::from_name("Red"), Some(Color::RED));
+        assert_eq!(Color::from_name("transparent"), Some(Color::TRANSPARENT));
+        assert_eq!(Color::from_name("Chartreuse"), None);
+    }
+    
+    #[test]
+    fn test_colorref_is_bgr() {
+        assert_eq!(Color::new(0x11, 0x22, 0x33, 0x44).to_win32_colorref(), 0x0033_2211);
+        assert_eq!(Color::RED.to_win32_colorref(), 0x0000_00FF);
+    }
+}
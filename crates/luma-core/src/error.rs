@@ -15,6 +15,9 @@ pub enum Error {
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
     
+    #[error("Index {index} out of range for {len} items")]
+    IndexOutOfRange { index: usize, len: usize },
+    
     #[error("Platform error: {0}")]
     Platform(String),
     
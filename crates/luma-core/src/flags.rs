@@ -16,6 +16,8 @@ bitflags! {
         const TITLED = 0b0001_0000;
         /// Window stays on top of other windows
         const ALWAYS_ON_TOP = 0b0010_0000;
+        /// Window is a tool window (small caption, no taskbar button)
+        const TOOL_WINDOW = 0b0100_0000;
     }
 }
 
@@ -26,6 +28,28 @@ impl Default for WindowFlags {
     }
 }
 
+impl WindowFlags {
+    /// Standard top-level window (same as `WindowFlags::default()`)
+    pub fn standard() -> Self {
+        Self::default()
+    }
+    
+    /// Fixed-size dialog: title bar and close button only
+    pub fn dialog() -> Self {
+        Self::TITLED | Self::CLOSABLE
+    }
+    
+    /// Borderless window with no title bar or system buttons
+    pub fn borderless() -> Self {
+        Self::empty()
+    }
+    
+    /// Resizable tool window with a small caption and close button
+    pub fn tool_window() -> Self {
+        Self::TITLED | Self::CLOSABLE | Self::RESIZABLE | Self::TOOL_WINDOW
+    }
+}
+
 bitflags! {
     /// Button style flags
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -75,6 +99,22 @@ mod tests {
         assert!(flags.contains(WindowFlags::TITLED));
     }
     
+    #[test]
+    fn test_window_flags_presets() {
+        let dialog = WindowFlags::dialog();
+        assert!(dialog.contains(WindowFlags::TITLED | WindowFlags::CLOSABLE));
+        assert!(!dialog.contains(WindowFlags::RESIZABLE));
+        assert!(!dialog.contains(WindowFlags::MAXIMIZABLE));
+        
+        assert!(WindowFlags::borderless().is_empty());
+        
+        let tool = WindowFlags::tool_window();
+        assert!(tool.contains(WindowFlags::TOOL_WINDOW));
+        assert!(!tool.contains(WindowFlags::MINIMIZABLE));
+        
+        assert_eq!(WindowFlags::standard(), WindowFlags::default());
+    }
+    
     #[test]
     fn test_button_flags() {
         let mut flags = ButtonFlags::empty();
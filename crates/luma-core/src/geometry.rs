@@ -1,5 +1,6 @@
 /// A 2D point with integer coordinates
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -17,6 +18,7 @@ impl Point {
 
 /// A 2D size with unsigned dimensions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     pub width: u32,
     pub height: u32,
@@ -34,6 +36,7 @@ impl Size {
 
 /// A rectangle defined by position and size
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub x: i32,
     pub y: i32,
@@ -62,6 +65,59 @@ impl Rect {
     pub fn size(&self) -> Size {
         Size { width: self.width, height: self.height }
     }
+    
+    /// Split the rectangle into its top-left corner and size
+    pub fn into_point_size(self) -> (Point, Size) {
+        (self.point(), self.size())
+    }
+    
+    /// Get the bottom-left corner, where a drop-down popup is usually placed
+    pub fn bottom_left(&self) -> Point {
+        Point { x: self.x, y: self.y.saturating_add_unsigned(self.height) }
+    }
+    
+    /// Check if a point lies inside the rectangle (right/bottom edges exclusive)
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.y >= self.y
+            && (point.x as i64) < self.x as i64 + self.width as i64
+            && (point.y as i64) < self.y as i64 + self.height as i64
+    }
+}
+
+/// DPI at which one device-independent pixel is one physical pixel
+pub const BASE_DPI: u32 = 96;
+
+/// Get the scale factor for a DPI, such as 1.5 for 144 DPI (150%)
+pub fn scale_for_dpi(dpi: u32) -> f32 {
+    dpi as f32 / BASE_DPI as f32
+}
+
+/// A length in device-independent pixels (1/96 inch), the unit XAML uses
+/// 
+/// Convert to [`Px`] with the scale factor of the window the length is used
+/// in before handing it to the platform.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dip(pub f32);
+
+impl Dip {
+    /// Convert to physical pixels at a scale factor, rounding to the nearest pixel
+    pub fn to_px(self, scale: f32) -> Px {
+        Px((self.0 * scale).round() as i32)
+    }
+}
+
+/// A length in physical pixels, the unit Win32 uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Px(pub i32);
+
+impl Px {
+    /// Convert to device-independent pixels at a scale factor
+    pub fn to_dip(self, scale: f32) -> Dip {
+        Dip(self.0 as f32 / scale)
+    }
 }
 
 #[cfg(test)]
@@ -93,4 +149,40 @@ mod tests {
         assert_eq!(r.width, 50);
         assert_eq!(r.height, 100);
     }
+    
+    #[test]
+    fn test_rect_into_point_size() {
+        let r = Rect::new(-5, 10, 50, 100);
+        assert_eq!(r.into_point_size(), (Point::new(-5, 10), Size::new(50, 100)));
+        assert_eq!(Rect::from_point_size(r.point(), r.size()), r);
+    }
+    
+    #[test]
+    fn test_rect_bottom_left() {
+        assert_eq!(Rect::new(10, 20, 30, 40).bottom_left(), Point::new(10, 60));
+    }
+    
+    #[test]
+    fn test_rect_contains() {
+        let r = Rect::new(10, 10, 20, 20);
+        assert!(r.contains(Point::new(10, 10)));
+        assert!(r.contains(Point::new(29, 29)));
+        assert!(!r.contains(Point::new(30, 10)));
+        assert!(!r.contains(Point::new(9, 15)));
+    }
+    
+    #[test]
+    fn test_dip_to_px_at_150_percent() {
+        let scale = scale_for_dpi(144);
+        assert_eq!(scale, 1.5);
+        assert_eq!(Dip(100.0).to_px(scale), Px(150));
+        assert_eq!(Px(150).to_dip(scale), Dip(100.0));
+    }
+    
+    #[test]
+    fn test_dip_to_px_rounds() {
+        assert_eq!(Dip(100.0).to_px(1.0), Px(100));
+        assert_eq!(Dip(1.0).to_px(1.25), Px(1));
+        assert_eq!(Dip(3.0).to_px(1.25), Px(4));
+    }
 }
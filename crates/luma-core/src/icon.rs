@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+/// An icon image, either a stock system icon or loaded from a file
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Icon {
+    /// The default application icon
+    #[default]
+    Application,
+    /// Stock information icon
+    Information,
+    /// Stock warning icon
+    Warning,
+    /// Stock error icon
+    Error,
+    /// Icon loaded from a file (`.ico` on Windows)
+    File(PathBuf),
+}
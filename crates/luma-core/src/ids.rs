@@ -1,21 +1,52 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
+static WIDGET_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+static WINDOW_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+
 /// Unique identifier for a widget
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// 
+/// IDs are handed out from a process-wide monotonic counter, so every call to
+/// [`WidgetId::new`] returns a value that has never been returned before, even when
+/// called from several threads at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WidgetId(u64);
 
 impl WidgetId {
     /// Generate a new unique widget ID
+    /// 
+    /// The counter starts at 1 and wraps around after `u64::MAX` IDs, at which
+    /// point uniqueness is no longer guaranteed. Even at one ID per nanosecond
+    /// that takes centuries, so in practice IDs never repeat.
     pub fn new() -> Self {
-        static COUNTER: AtomicU64 = AtomicU64::new(1);
-        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+        Self(WIDGET_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+    
+    /// Recreate an ID from its raw value
+    /// 
+    /// This does not allocate a new ID; it is meant for round-tripping a value
+    /// previously obtained from [`WidgetId::as_u64`] (e.g. a map key).
+    pub fn from_raw(raw: u64) -> Self {
+        Self(raw)
     }
     
+    /// Get the raw integer value of this ID
     pub fn as_u64(&self) -> u64 {
         self.0
     }
 }
 
+impl From<WidgetId> for u64 {
+    fn from(id: WidgetId) -> Self {
+        id.as_u64()
+    }
+}
+
+impl From<u64> for WidgetId {
+    fn from(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
 impl Default for WidgetId {
     fn default() -> Self {
         Self::new()
@@ -23,21 +54,49 @@ impl Default for WidgetId {
 }
 
 /// Unique identifier for a window
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// 
+/// IDs are handed out from a process-wide monotonic counter, so every call to
+/// [`WindowId::new`] returns a value that has never been returned before, even when
+/// called from several threads at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WindowId(u64);
 
 impl WindowId {
     /// Generate a new unique window ID
+    /// 
+    /// The counter starts at 1 and wraps around after `u64::MAX` IDs, at which
+    /// point uniqueness is no longer guaranteed. Even at one ID per nanosecond
+    /// that takes centuries, so in practice IDs never repeat.
     pub fn new() -> Self {
-        static COUNTER: AtomicU64 = AtomicU64::new(1);
-        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+        Self(WINDOW_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+    
+    /// Recreate an ID from its raw value
+    /// 
+    /// This does not allocate a new ID; it is meant for round-tripping a value
+    /// previously obtained from [`WindowId::as_u64`] (e.g. a map key).
+    pub fn from_raw(raw: u64) -> Self {
+        Self(raw)
     }
     
+    /// Get the raw integer value of this ID
     pub fn as_u64(&self) -> u64 {
         self.0
     }
 }
 
+impl From<WindowId> for u64 {
+    fn from(id: WindowId) -> Self {
+        id.as_u64()
+    }
+}
+
+impl From<u64> for WindowId {
+    fn from(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
 impl Default for WindowId {
     fn default() -> Self {
         Self::new()
@@ -61,4 +120,36 @@ mod tests {
         let id2 = WindowId::new();
         assert_ne!(id1, id2);
     }
+    
+    #[test]
+    fn test_widget_id_raw_round_trip() {
+        let id = WidgetId::new();
+        let raw: u64 = id.into();
+        assert_eq!(raw, id.as_u64());
+        assert_eq!(WidgetId::from_raw(raw), id);
+        assert_eq!(WidgetId::from(raw), id);
+    }
+    
+    #[test]
+    fn test_window_id_raw_round_trip() {
+        let id = WindowId::new();
+        assert_eq!(WindowId::from(u64::from(id)), id);
+    }
+    
+    #[test]
+    fn test_widget_id_unique_across_threads() {
+        use std::collections::HashSet;
+        
+        let handles: Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(|| (0..1000).map(|_| WidgetId::new()).collect::<Vec<_>>()))
+            .collect();
+        
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for id in handle.join().unwrap() {
+                assert!(seen.insert(id), "duplicate id {:?}", id);
+            }
+        }
+        assert_eq!(seen.len(), 8 * 1000);
+    }
 }
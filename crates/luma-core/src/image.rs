@@ -0,0 +1,64 @@
+use crate::{Rect, Size};
+
+/// How an image is scaled to fill the area it is displayed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Stretch {
+    /// Draw at the image's own size, anchored top-left
+    None,
+    /// Scale each axis independently to exactly fill the area
+    Fill,
+    /// Scale to fit inside the area, keeping the aspect ratio (centered)
+    #[default]
+    Uniform,
+    /// Scale to cover the area, keeping the aspect ratio (centered, cropped)
+    UniformToFill,
+}
+
+/// Compute where an image is drawn within an area
+/// 
+/// The returned rectangle is relative to the area's top-left corner. For
+/// [`Stretch::UniformToFill`] and [`Stretch::None`] it can extend beyond the
+/// area, which the caller clips.
+pub fn stretch_rect(image: Size, area: Size, stretch: Stretch) -> Rect {
+    if image.width == 0 || image.height == 0 {
+        return Rect::new(0, 0, 0, 0);
+    }
+    
+    let scale_x = area.width as f64 / image.width as f64;
+    let scale_y = area.height as f64 / image.height as f64;
+    let scale = match stretch {
+        Stretch::None => return Rect::new(0, 0, image.width, image.height),
+        Stretch::Fill => return Rect::new(0, 0, area.width, area.height),
+        Stretch::Uniform => scale_x.min(scale_y),
+        Stretch::UniformToFill => scale_x.max(scale_y),
+    };
+    
+    let width = (image.width as f64 * scale).round() as u32;
+    let height = (image.height as f64 * scale).round() as u32;
+    let x = (area.width as i32 - width as i32) / 2;
+    let y = (area.height as i32 - height as i32) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_stretch_rect() {
+        let image = Size::new(200, 100);
+        let area = Size::new(100, 100);
+        
+        assert_eq!(stretch_rect(image, area, Stretch::None), Rect::new(0, 0, 200, 100));
+        assert_eq!(stretch_rect(image, area, Stretch::Fill), Rect::new(0, 0, 100, 100));
+        assert_eq!(stretch_rect(image, area, Stretch::Uniform), Rect::new(0, 25, 100, 50));
+        assert_eq!(stretch_rect(image, area, Stretch::UniformToFill), Rect::new(-50, 0, 200, 100));
+    }
+    
+    #[test]
+    fn test_stretch_rect_empty_image() {
+        let rect = stretch_rect(Size::zero(), Size::new(100, 100), Stretch::Uniform);
+        assert_eq!(rect.width, 0);
+        assert_eq!(rect.height, 0);
+    }
+}
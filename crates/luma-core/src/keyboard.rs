@@ -0,0 +1,148 @@
+use bitflags::bitflags;
+
+/// A key that can be part of a keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A letter or digit key (case-insensitive)
+    Char(char),
+    /// A function key, `F(1)` through `F(24)`
+    F(u8),
+    /// Enter / Return
+    Enter,
+    /// Escape
+    Escape,
+    /// Tab
+    Tab,
+    /// Space bar
+    Space,
+    /// Backspace
+    Backspace,
+    /// Delete
+    Delete,
+    /// Insert
+    Insert,
+    /// Home
+    Home,
+    /// End
+    End,
+    /// Page Up
+    PageUp,
+    /// Page Down
+    PageDown,
+    /// Left arrow
+    Left,
+    /// Right arrow
+    Right,
+    /// Up arrow
+    Up,
+    /// Down arrow
+    Down,
+}
+
+bitflags! {
+    /// Modifier keys held together with a shortcut key
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Modifiers: u32 {
+        /// Ctrl key
+        const CONTROL = 0b0001;
+        /// Shift key
+        const SHIFT = 0b0010;
+        /// Alt key
+        const ALT = 0b0100;
+    }
+}
+
+/// A keyboard shortcut that triggers an application command
+/// 
+/// When the key combination is pressed in any window of the application,
+/// the command registered under `command_id` is invoked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    /// The key that triggers the command
+    pub key: Key,
+    /// Modifier keys that must be held
+    pub modifiers: Modifiers,
+    /// Identifier of the command to invoke
+    pub command_id: u16,
+}
+
+impl Accelerator {
+    /// Create a new accelerator
+    pub fn new(key: Key, modifiers: Modifiers, command_id: u16) -> Self {
+        Self { key, modifiers, command_id }
+    }
+    
+    /// Shortcut for a Ctrl+key accelerator
+    pub fn ctrl(key: Key, command_id: u16) -> Self {
+        Self::new(key, Modifiers::CONTROL, command_id)
+    }
+}
+
+/// Get the mnemonic character of a label, if it has one
+/// 
+/// The mnemonic is the character following the first single `&`, so `"&File"`
+/// has the mnemonic `f` and `"Save &As"` has `a`. A doubled `&&` is a literal
+/// ampersand. The result is lowercase so it can be compared directly against
+/// a typed character.
+pub fn mnemonic(label: &str) -> Option<char> {
+    let mut chars = label.chars();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => continue,
+                Some(m) => return m.to_lowercase().next(),
+                None => return None,
+            }
+        }
+    }
+    None
+}
+
+/// Get the text a label is displayed with, without its mnemonic marker
+/// 
+/// `"&File"` is shown as `File` and `"Tom && Jerry"` as `Tom & Jerry`.
+pub fn display_text(label: &str) -> String {
+    let mut text = String::with_capacity(label.len());
+    let mut chars = label.chars();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some(next) => text.push(next),
+                None => break,
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_mnemonic() {
+        assert_eq!(mnemonic("&File"), Some('f'));
+        assert_eq!(mnemonic("Save &As"), Some('a'));
+        assert_eq!(mnemonic("Tom && &Jerry"), Some('j'));
+        assert_eq!(mnemonic("Plain"), None);
+        assert_eq!(mnemonic("A && B"), None);
+        assert_eq!(mnemonic("Trailing&"), None);
+    }
+    
+    #[test]
+    fn test_display_text() {
+        assert_eq!(display_text("&File"), "File");
+        assert_eq!(display_text("Tom && &Jerry"), "Tom & Jerry");
+        assert_eq!(display_text("Plain"), "Plain");
+    }
+    
+    #[test]
+    fn test_accelerator_ctrl() {
+        let accel = Accelerator::ctrl(Key::Char('S'), 1);
+        assert_eq!(accel.modifiers, Modifiers::CONTROL);
+        assert_eq!(accel.key, Key::Char('S'));
+        assert_eq!(accel.command_id, 1);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Result, Size, Point};
+use crate::ids::WidgetId;
+use super::Container;
+
+/// A container that switches between layouts depending on the width it is given
+/// 
+/// Each layout has a minimum width breakpoint; a layout pass uses the layout
+/// with the largest breakpoint that fits, like WinUI's adaptive triggers. This
+/// lets a window show a sidebar when wide and stack its content when narrow.
+/// 
+/// Only the chosen layout is laid out. The other layouts' widgets keep
+/// their last bounds, so give each layout its own widgets and hide the ones
+/// not in use (see [`AdaptiveLayout::active_breakpoint`]).
+#[derive(Default)]
+pub struct AdaptiveLayout {
+    /// Layouts sorted by breakpoint
+    layouts: Vec<(u32, Box<dyn Container>)>,
+    /// Index of the layout used by the last layout pass
+    active: Option<usize>,
+}
+
+impl AdaptiveLayout {
+    /// Create an adaptive layout with no layouts
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add a layout used from a minimum width upward, replacing any with the same breakpoint
+    pub fn add(&mut self, min_width: u32, layout: Box<dyn Container>) {
+        match self.layouts.binary_search_by_key(&min_width, |(width, _)| *width) {
+            Ok(index) => self.layouts[index].1 = layout,
+            Err(index) => self.layouts.insert(index, (min_width, layout)),
+        }
+        self.active = None;
+    }
+    
+    /// Get the number of layouts
+    pub fn layout_count(&self) -> usize {
+        self.layouts.len()
+    }
+    
+    /// Get the breakpoint of the layout used by the last layout pass
+    pub fn active_breakpoint(&self) -> Option<u32> {
+        self.active.map(|index| self.layouts[index].0)
+    }
+    
+    /// Find the layout for a width: the largest breakpoint at most the width
+    /// 
+    /// Widths below every breakpoint use the narrowest layout.
+    fn select(&self, width: u32) -> Option<usize> {
+        if self.layouts.is_empty() {
+            return None;
+        }
+        let fitting = self.layouts.partition_point(|(min_width, _)| *min_width <= width);
+        Some(fitting.saturating_sub(1))
+    }
+}
+
+impl Container for AdaptiveLayout {
+    fn layout(&mut self, available_space: Size) -> Result<()> {
+        let Some(index) = self.select(available_space.width) else {
+            return Ok(());
+        };
+        if self.active != Some(index) {
+            tracing::debug!(
+                "AdaptiveLayout: switching to breakpoint {} for width {}",
+                self.layouts[index].0,
+                available_space.width
+            );
+        }
+        
+        self.active = Some(index);
+        self.layouts[index].1.layout(available_space)
+    }
+    
+    fn natural_size(&self, available_space: Size) -> Size {
+        match self.select(available_space.width) {
+            Some(index) => self.layouts[index].1.natural_size(available_space),
+            None => available_space,
+        }
+    }
+    
+    fn widget_at(&self, point: Point) -> Option<WidgetId> {
+        self.layouts[self.active?].1.widget_at(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rect, layout::{BoxLayout, LayoutConstraints, Widget}};
+    use crate::layout::test_widget::TestWidget;
+    
+    fn layout_with(mut layout: BoxLayout, widget: &TestWidget) -> Box<dyn Container> {
+        layout.add(
+            Box::new(widget.clone()),
+            LayoutConstraints::default().preferred_width(100).preferred_height(40),
+        );
+        Box::new(layout)
+    }
+    
+    #[test]
+    fn test_switches_layout_at_breakpoint() {
+        let narrow = TestWidget::new();
+        let wide = TestWidget::new();
+        let mut adaptive = AdaptiveLayout::new();
+        adaptive.add(600, layout_with(BoxLayout::horizontal(), &wide));
+        adaptive.add(0, layout_with(BoxLayout::vertical(), &narrow));
+        assert_eq!(adaptive.layout_count(), 2);
+        assert_eq!(adaptive.active_breakpoint(), None);
+        
+        adaptive.layout(Size::new(599, 300)).unwrap();
+        assert_eq!(adaptive.active_breakpoint(), Some(0));
+        assert_eq!(narrow.get_bounds(), Rect::new(0, 0, 100, 40));
+        assert_eq!(wide.get_bounds(), Rect::default());
+        
+        adaptive.layout(Size::new(600, 300)).unwrap();
+        assert_eq!(adaptive.active_breakpoint(), Some(600));
+        assert_eq!(wide.get_bounds().width, 100);
+        assert!(adaptive.widget_at(Point::new(10, 10)).is_some());
+    }
+    
+    #[test]
+    fn test_narrower_than_every_breakpoint() {
+        let widget = TestWidget::new();
+        let mut adaptive = AdaptiveLayout::new();
+        adaptive.add(400, layout_with(BoxLayout::vertical(), &widget));
+        
+        adaptive.layout(Size::new(200, 100)).unwrap();
+        assert_eq!(adaptive.active_breakpoint(), Some(400));
+        assert_eq!(widget.get_bounds().height, 40);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{Result, Size, Rect};
-use super::{Container, LayoutConstraints, Widget};
+use crate::{Result, Size, Rect, Point};
+use crate::ids::WidgetId;
+use super::{Container, LayoutConstraints, Widget, WidgetHandle};
 
 /// Layout direction for BoxLayout
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,6 +18,12 @@ pub struct BoxLayout {
     direction: LayoutDirection,
     gap: u32,
     children: Vec<(Box<dyn Widget>, LayoutConstraints)>,
+    /// Space given to the last successful layout pass and the children's
+    /// preferred sizes at the time; while both are unchanged the children
+    /// are already in place and the pass is skipped
+    laid_out_for: Option<(Size, Vec<Option<Size>>)>,
+    /// Scale factor for children whose constraints are in DIPs
+    scale: f32,
 }
 
 impl BoxLayout {
@@ -26,6 +33,8 @@ impl BoxLayout {
             direction: LayoutDirection::Horizontal,
             gap: 0,
             children: Vec::new(),
+            laid_out_for: None,
+            scale: 1.0,
         }
     }
     
@@ -35,18 +44,92 @@ impl BoxLayout {
             direction: LayoutDirection::Vertical,
             gap: 0,
             children: Vec::new(),
+            laid_out_for: None,
+            scale: 1.0,
         }
     }
     
     /// Set the gap between children
+    /// 
+    /// Like XAML's `StackPanel.Spacing`, the gap goes only between adjacent
+    /// children, not before the first or after the last.
     pub fn with_gap(mut self, gap: u32) -> Self {
         self.gap = gap;
         self
     }
     
+    /// Get the direction children are arranged in
+    pub fn direction(&self) -> LayoutDirection {
+        self.direction
+    }
+    
+    /// Change the direction children are arranged in
+    /// 
+    /// Children and their constraints are kept, so a layout can switch
+    /// between a row and a column as its window is resized. Takes effect on
+    /// the next layout pass.
+    pub fn set_direction(&mut self, direction: LayoutDirection) {
+        self.direction = direction;
+        self.invalidate();
+    }
+    
+    /// Get the gap between children
+    pub fn gap(&self) -> u32 {
+        self.gap
+    }
+    
+    /// Change the gap between children
+    /// 
+    /// Takes effect on the next layout pass, even if the space is unchanged.
+    pub fn set_gap(&mut self, gap: u32) {
+        self.gap = gap;
+        self.invalidate();
+    }
+    
+    /// Get the scale factor DIP constraints are converted with
+    pub fn scale_factor(&self) -> f32 {
+        self.scale
+    }
+    
     /// Add a child widget with constraints
     pub fn add(&mut self, widget: Box<dyn Widget>, constraints: LayoutConstraints) {
         self.children.push((widget, constraints));
+        self.invalidate();
+    }
+    
+    /// Add a child widget with constraints, keeping a handle to it
+    /// 
+    /// The handle reaches the widget by its concrete type after the layout
+    /// has taken it, for example to change a label's text.
+    pub fn add_with_handle<W: Widget + 'static>(&mut self, widget: W, constraints: LayoutConstraints) -> WidgetHandle<W> {
+        let handle = WidgetHandle::new(widget);
+        self.add(Box::new(handle.clone()), constraints);
+        handle
+    }
+    
+    /// Remove the child with an ID and return it
+    /// 
+    /// Returns `None` if no direct child has the ID. The remaining children
+    /// close the gap on the next layout pass.
+    pub fn remove(&mut self, id: WidgetId) -> Option<Box<dyn Widget>> {
+        let index = self.children.iter().position(|(widget, _)| widget.id() == id)?;
+        self.invalidate();
+        Some(self.children.remove(index).0)
+    }
+    
+    /// Remove all children
+    pub fn clear(&mut self) {
+        self.children.clear();
+        self.invalidate();
+    }
+    
+    /// Force the next layout pass to reposition every child
+    /// 
+    /// Passes are normally skipped while the space and the children's
+    /// preferred sizes are the same as in the previous one. Call this after
+    /// changing anything else a child's bounds depend on.
+    pub fn invalidate(&mut self) {
+        self.laid_out_for = None;
     }
     
     /// Get the number of children
@@ -61,9 +144,75 @@ impl Container for BoxLayout {
             return Ok(());
         }
         
-        match self.direction {
+        // A child whose content changed, such as a label given longer text,
+        // reports a new preferred size and has to be laid out again
+        let preferred_sizes: Vec<_> = self.children.iter().map(|(widget, _)| widget.preferred_size()).collect();
+        if self.laid_out_for.as_ref().is_some_and(|(space, sizes)| *space == available_space && *sizes == preferred_sizes) {
+            return Ok(());
+        }
+        
+        // Nested layouts get nested spans, so a resize can be followed down the tree
+        let _span = tracing::debug_span!(
+            "box_layout",
+            direction = ?self.direction,
+            children = self.children.len(),
+            width = available_space.width,
+            height = available_space.height,
+        ).entered();
+        
+        #[cfg(feature = "layout-metrics")]
+        let start = std::time::Instant::now();
+        
+        let result = match self.direction {
             LayoutDirection::Horizontal => self.layout_horizontal(available_space),
             LayoutDirection::Vertical => self.layout_vertical(available_space),
+        };
+        
+        #[cfg(feature = "layout-metrics")]
+        super::metrics::record_layout(start.elapsed());
+        
+        self.laid_out_for = result.is_ok().then_some((available_space, preferred_sizes));
+        result
+    }
+    
+    fn set_scale_factor(&mut self, scale: f32) {
+        if self.scale != scale {
+            self.scale = scale;
+            self.invalidate();
+        }
+    }
+    
+    fn widget_at(&self, point: Point) -> Option<WidgetId> {
+        // Children added later are drawn over earlier ones
+        self.children
+            .iter()
+            .rev()
+            .map(|(widget, _)| widget)
+            .find(|widget| widget.get_bounds().contains(point))
+            .map(|widget| widget.id())
+    }
+    
+    fn natural_size(&self, available_space: Size) -> Size {
+        let total_gaps = self.gap * (self.children.len().saturating_sub(1) as u32);
+        
+        // Expanding children count at their preferred (or minimum) size here
+        match self.direction {
+            LayoutDirection::Vertical => {
+                let height: u32 = self.children
+                    .iter()
+                    .map(|(w, c)| (w, c.to_physical(self.scale)))
+                    .map(|(w, c)| natural_height(w.as_ref(), &c).or(c.min_height).unwrap_or(30) + c.padding.vertical())
+                    .sum();
+                Size::new(available_space.width, height + total_gaps)
+            }
+            LayoutDirection::Horizontal => {
+                let width: u32 = self.children
+                    .iter()
+                    .map(|(w, c)| (w, c.to_physical(self.scale)))
+                    .map(|(w, c)| natural_width(w.as_ref(), &c).or(c.min_width).unwrap_or(100) + c.padding.horizontal())
+                    .sum();
+                Size::new(width + total_gaps, available_space.height)
+            }
         }
     }
 }
@@ -81,11 +230,12 @@ impl BoxLayout {
         let mut total_fixed_height = 0u32;
         let mut expand_count = 0u32;
         
-        for (_, constraints) in &self.children {
+        for (widget, constraints) in &self.children {
+            let constraints = &constraints.to_physical(self.scale);
             if constraints.expand_vertical {
                 expand_count += 1;
             } else {
-                let height = constraints.preferred_height.unwrap_or(30);
+                let height = natural_height(widget.as_ref(), constraints).unwrap_or(30);
                 total_fixed_height += height + constraints.padding.vertical();
             }
         }
@@ -111,11 +261,12 @@ impl BoxLayout {
         let mut y = 0i32;
         
         for (widget, constraints) in &mut self.children {
+            let constraints = &constraints.to_physical(self.scale);
             // Calculate widget height
             let widget_height = if constraints.expand_vertical {
                 expand_height.saturating_sub(constraints.padding.vertical())
             } else {
-                constraints.preferred_height.unwrap_or(30)
+                natural_height(widget.as_ref(), constraints).unwrap_or(30)
             };
             
             // Calculate widget width
@@ -162,11 +313,12 @@ impl BoxLayout {
         let mut total_fixed_width = 0u32;
         let mut expand_count = 0u32;
         
-        for (_, constraints) in &self.children {
+        for (widget, constraints) in &self.children {
+            let constraints = &constraints.to_physical(self.scale);
             if constraints.expand_horizontal {
                 expand_count += 1;
             } else {
-                let width = constraints.preferred_width.unwrap_or(100);
+                let width = natural_width(widget.as_ref(), constraints).unwrap_or(100);
                 total_fixed_width += width + constraints.padding.horizontal();
             }
         }
@@ -185,11 +337,12 @@ impl BoxLayout {
         let mut x = 0i32;
         
         for (widget, constraints) in &mut self.children {
+            let constraints = &constraints.to_physical(self.scale);
             // Calculate widget width
             let widget_width = if constraints.expand_horizontal {
                 expand_width.saturating_sub(constraints.padding.horizontal())
             } else {
-                constraints.preferred_width.unwrap_or(100)
+                natural_width(widget.as_ref(), constraints).unwrap_or(100)
             };
             
             // Calculate widget height
@@ -217,10 +370,22 @@ impl BoxLayout {
     }
 }
 
+/// Width of a child along a horizontal layout: its constraint, else what the widget asks for
+fn natural_width(widget: &dyn Widget, constraints: &LayoutConstraints) -> Option<u32> {
+    constraints.preferred_width.or_else(|| widget.preferred_size().map(|size| size.width))
+}
+
+/// Height of a child along a vertical layout: its constraint, else what the widget asks for
+fn natural_height(widget: &dyn Widget, constraints: &LayoutConstraints) -> Option<u32> {
+    constraints.preferred_height.or_else(|| widget.preferred_size().map(|size| size.height))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ids::WidgetId;
+    use crate::layout::Padding;
+    use crate::layout::test_widget::TestWidget;
     
     // Mock widget for testing
     struct MockWidget {
@@ -264,4 +429,264 @@ mod tests {
         
         assert_eq!(layout.child_count(), 2);
     }
+    
+    #[test]
+    fn test_natural_size() {
+        let mut layout = BoxLayout::vertical().with_gap(10);
+        
+        for height in [50, 40] {
+            layout.add(
+                Box::new(TestWidget::new()),
+                LayoutConstraints::default().preferred_height(height).padding(Padding::all(5)),
+            );
+        }
+        layout.add(
+            Box::new(TestWidget::new()),
+            LayoutConstraints::default().expand_vertical(true),
+        );
+        
+        // 60 + 50 + 30 (default height) + 2 gaps, regardless of the space offered
+        assert_eq!(layout.natural_size(Size::new(200, 100)), Size::new(200, 160));
+        assert_eq!(layout.natural_size(Size::new(300, 1000)), Size::new(300, 160));
+    }
+    
+    #[test]
+    fn test_widget_preferred_size() {
+        let mut layout = BoxLayout::horizontal();
+        for width in [60, 80] {
+            layout.add(
+                Box::new(TestWidget::new().with_preferred_size(Size::new(width, 20))),
+                LayoutConstraints::default(),
+            );
+        }
+        // A constraint still wins over the widget's own size
+        layout.add(
+            Box::new(TestWidget::new().with_preferred_size(Size::new(60, 20))),
+            LayoutConstraints::default().preferred_width(40),
+        );
+        layout.add(
+            Box::new(TestWidget::new()),
+            LayoutConstraints::default(),
+        );
+        
+        // 60 + 80 + 40 + 100 (default width)
+        assert_eq!(layout.natural_size(Size::new(500, 30)), Size::new(280, 30));
+    }
+    
+    #[test]
+    fn test_widget_at_finds_topmost_child() {
+        let mut layout = BoxLayout::vertical();
+        let bottom = TestWidget::new().with_bounds(Rect::new(0, 0, 100, 100));
+        let top = TestWidget::new().with_bounds(Rect::new(50, 50, 100, 100));
+        let (bottom_id, top_id) = (bottom.id(), top.id());
+        layout.add(Box::new(bottom), LayoutConstraints::default());
+        layout.add(Box::new(top), LayoutConstraints::default());
+        
+        assert_eq!(layout.widget_at(Point::new(10, 10)), Some(bottom_id));
+        assert_eq!(layout.widget_at(Point::new(75, 75)), Some(top_id));
+        assert_eq!(layout.widget_at(Point::new(149, 149)), Some(top_id));
+        assert_eq!(layout.widget_at(Point::new(150, 150)), None);
+        assert_eq!(layout.widget_at(Point::new(-1, 10)), None);
+    }
+    
+    #[test]
+    fn test_gap_only_between_children() {
+        let mut layout = BoxLayout::vertical().with_gap(10);
+        let widgets: Vec<_> = (0..3).map(|_| TestWidget::new()).collect();
+        for widget in &widgets {
+            layout.add(Box::new(widget.clone()), LayoutConstraints::default().preferred_height(50));
+        }
+        let tops = || widgets.iter().map(|widget| widget.get_bounds().y).collect::<Vec<_>>();
+        
+        layout.layout(Size::new(100, 200)).unwrap();
+        assert_eq!(tops(), [0, 60, 120]);
+        assert_eq!(layout.natural_size(Size::new(100, 200)).height, 170);
+        
+        // Changing the gap lays the children out again in the same space
+        layout.set_gap(20);
+        assert_eq!(layout.gap(), 20);
+        layout.layout(Size::new(100, 200)).unwrap();
+        assert_eq!(tops(), [0, 70, 140]);
+        assert_eq!(layout.natural_size(Size::new(100, 200)).height, 190);
+        
+        layout.set_gap(0);
+        assert_eq!(layout.natural_size(Size::new(100, 200)).height, 150);
+    }
+    
+    #[test]
+    fn test_flipped_direction_repositions_children() {
+        let mut layout = BoxLayout::vertical();
+        let widgets: Vec<_> = (0..3).map(|_| TestWidget::new()).collect();
+        for widget in &widgets {
+            layout.add(
+                Box::new(widget.clone()),
+                LayoutConstraints::default().preferred_width(40).preferred_height(20),
+            );
+        }
+        let origins = || widgets.iter().map(|widget| (widget.get_bounds().x, widget.get_bounds().y)).collect::<Vec<_>>();
+        
+        assert_eq!(layout.direction(), LayoutDirection::Vertical);
+        layout.layout(Size::new(200, 200)).unwrap();
+        assert_eq!(origins(), [(0, 0), (0, 20), (0, 40)]);
+        
+        layout.set_direction(LayoutDirection::Horizontal);
+        assert_eq!(layout.direction(), LayoutDirection::Horizontal);
+        layout.layout(Size::new(200, 200)).unwrap();
+        assert_eq!(origins(), [(0, 0), (40, 0), (80, 0)]);
+        assert_eq!(layout.child_count(), 3);
+        assert!(widgets.iter().all(|widget| widget.get_bounds().width == 40 && widget.get_bounds().height == 20));
+    }
+    
+    #[test]
+    fn test_unchanged_size_skips_layout() {
+        let first = TestWidget::new();
+        let mut layout = BoxLayout::vertical();
+        layout.add(Box::new(first.clone()), LayoutConstraints::default());
+        
+        layout.layout(Size::new(200, 100)).unwrap();
+        layout.layout(Size::new(200, 100)).unwrap();
+        assert_eq!(first.set_bounds_calls(), 1);
+        
+        layout.layout(Size::new(201, 100)).unwrap();
+        assert_eq!(first.set_bounds_calls(), 2);
+        
+        // Adding a child invalidates the cache, so existing children move again
+        let second = TestWidget::new();
+        layout.add(Box::new(second.clone()), LayoutConstraints::default());
+        layout.layout(Size::new(201, 100)).unwrap();
+        assert_eq!((first.set_bounds_calls(), second.set_bounds_calls()), (3, 1));
+        
+        layout.invalidate();
+        layout.layout(Size::new(201, 100)).unwrap();
+        assert_eq!((first.set_bounds_calls(), second.set_bounds_calls()), (4, 2));
+    }
+    
+    #[test]
+    fn test_preferred_size_change_skips_cache() {
+        let mut layout = BoxLayout::horizontal();
+        let label = layout.add_with_handle(
+            TestWidget::new().with_preferred_size(Size::new(60, 20)),
+            LayoutConstraints::default(),
+        );
+        let next = TestWidget::new().with_preferred_size(Size::new(40, 20));
+        layout.add(Box::new(next.clone()), LayoutConstraints::default());
+        
+        layout.layout(Size::new(300, 100)).unwrap();
+        assert_eq!(next.get_bounds().x, 60);
+        
+        // The widget grows, like a label given longer text, in unchanged space
+        label.borrow_mut().set_preferred_size(Some(Size::new(90, 20)));
+        layout.layout(Size::new(300, 100)).unwrap();
+        assert_eq!(label.borrow().get_bounds().width, 90);
+        assert_eq!(next.get_bounds().x, 90);
+        
+        layout.layout(Size::new(300, 100)).unwrap();
+        assert_eq!(next.set_bounds_calls(), 2);
+    }
+    
+    #[test]
+    fn test_remove_closes_gap() {
+        let mut layout = BoxLayout::vertical();
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let widget = TestWidget::new();
+            ids.push(widget.id());
+            layout.add(Box::new(widget), LayoutConstraints::default().preferred_height(20));
+        }
+        layout.layout(Size::new(100, 100)).unwrap();
+        
+        let removed = layout.remove(ids[0]).unwrap();
+        assert_eq!(removed.id(), ids[0]);
+        assert!(layout.remove(ids[0]).is_none());
+        assert_eq!(layout.child_count(), 2);
+        
+        // Same space as before, but the removal forces the survivors to move up
+        layout.layout(Size::new(100, 100)).unwrap();
+        assert_eq!(layout.widget_at(Point::new(5, 5)), Some(ids[1]));
+        assert_eq!(layout.widget_at(Point::new(5, 25)), Some(ids[2]));
+    }
+    
+    #[test]
+    fn test_dip_constraints_scale_with_window() {
+        let mut layout = BoxLayout::vertical();
+        let dips = TestWidget::new();
+        let pixels = TestWidget::new();
+        layout.add(
+            Box::new(dips.clone()),
+            LayoutConstraints::default().preferred_width(100).preferred_height(20).in_dips(),
+        );
+        layout.add(
+            Box::new(pixels.clone()),
+            LayoutConstraints::default().preferred_width(100).preferred_height(20),
+        );
+        
+        layout.layout(Size::new(400, 400)).unwrap();
+        assert_eq!(dips.get_bounds(), Rect::new(0, 0, 100, 20));
+        
+        // A new scale lays the children out again in the same space
+        layout.set_scale_factor(1.5);
+        layout.layout(Size::new(400, 400)).unwrap();
+        assert_eq!(dips.get_bounds(), Rect::new(0, 0, 150, 30));
+        assert_eq!(pixels.get_bounds(), Rect::new(0, 30, 100, 20));
+        assert_eq!(layout.natural_size(Size::new(400, 400)).height, 50);
+    }
+    
+    /// Subscriber that records the names and fields of created spans
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        spans: std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>,
+    }
+    
+    struct FieldRecorder<'a>(&'a mut String);
+    
+    impl tracing::field::Visit for FieldRecorder<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.push_str(&format!("{}={:?} ", field.name(), value));
+        }
+    }
+    
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+        
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut fields = String::new();
+            span.record(&mut FieldRecorder(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span.metadata().name().to_string(), fields.trim_end().to_string()));
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+        
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+        
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+        
+        fn event(&self, _event: &tracing::Event<'_>) {}
+        
+        fn enter(&self, _span: &tracing::span::Id) {}
+        
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+    
+    #[test]
+    fn test_layout_emits_spans() {
+        let recorder = SpanRecorder::default();
+        
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut inner = BoxLayout::horizontal();
+            inner.add(Box::new(TestWidget::new()), LayoutConstraints::default());
+            
+            let mut outer = BoxLayout::vertical();
+            outer.add(Box::new(TestWidget::new()), LayoutConstraints::default());
+            outer.layout(Size::new(300, 200)).unwrap();
+            inner.layout(Size::new(120, 40)).unwrap();
+        });
+        
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(*spans, [
+            ("box_layout".to_string(), "direction=Vertical children=1 width=300 height=200".to_string()),
+            ("box_layout".to_string(), "direction=Horizontal children=1 width=120 height=40".to_string()),
+        ]);
+    }
 }
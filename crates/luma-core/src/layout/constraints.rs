@@ -1,3 +1,5 @@
+use crate::geometry::Dip;
+
 /// Alignment options for widgets within their allocated space
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Alignment {
@@ -70,6 +72,10 @@ impl Default for Padding {
 }
 
 /// Layout constraints for a widget within a container
+/// 
+/// Sizes and padding are in physical pixels unless the constraints are
+/// marked with [`LayoutConstraints::in_dips`], in which case the layout
+/// scales them by the window's DPI when it positions the widget.
 #[derive(Debug, Clone, Copy)]
 pub struct LayoutConstraints {
     pub min_width: Option<u32>,
@@ -82,6 +88,8 @@ pub struct LayoutConstraints {
     pub expand_vertical: bool,
     pub alignment: Alignment,
     pub padding: Padding,
+    /// Whether the sizes and padding are device-independent pixels
+    pub in_dips: bool,
 }
 
 impl Default for LayoutConstraints {
@@ -97,6 +105,7 @@ impl Default for LayoutConstraints {
             expand_vertical: false,
             alignment: Alignment::Fill,
             padding: Padding::zero(),
+            in_dips: false,
         }
     }
 }
@@ -173,6 +182,40 @@ impl LayoutConstraints {
         self.max_height = Some(height);
         self
     }
+    
+    /// Treat the sizes and padding as device-independent pixels
+    /// 
+    /// At 150% scaling a preferred width of 100 then lays out 150 pixels wide.
+    pub fn in_dips(mut self) -> Self {
+        self.in_dips = true;
+        self
+    }
+    
+    /// Get the constraints in physical pixels at a scale factor
+    /// 
+    /// Constraints already in physical pixels are returned unchanged.
+    pub fn to_physical(&self, scale: f32) -> Self {
+        if !self.in_dips {
+            return *self;
+        }
+        let px = |value: u32| Dip(value as f32).to_px(scale).0.max(0) as u32;
+        Self {
+            min_width: self.min_width.map(px),
+            max_width: self.max_width.map(px),
+            min_height: self.min_height.map(px),
+            max_height: self.max_height.map(px),
+            preferred_width: self.preferred_width.map(px),
+            preferred_height: self.preferred_height.map(px),
+            padding: Padding {
+                top: px(self.padding.top),
+                right: px(self.padding.right),
+                bottom: px(self.padding.bottom),
+                left: px(self.padding.left),
+            },
+            in_dips: false,
+            ..*self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -198,4 +241,22 @@ mod tests {
         assert!(constraints.expand_horizontal);
         assert!(!constraints.expand_vertical);
     }
+    
+    #[test]
+    fn test_dip_constraints_to_physical() {
+        let constraints = LayoutConstraints::new()
+            .preferred_width(100)
+            .min_height(20)
+            .padding(Padding::all(4))
+            .in_dips();
+        
+        let physical = constraints.to_physical(1.5);
+        assert_eq!(physical.preferred_width, Some(150));
+        assert_eq!(physical.min_height, Some(30));
+        assert_eq!(physical.padding, Padding::all(6));
+        assert!(!physical.in_dips);
+        
+        let plain = LayoutConstraints::new().preferred_width(100);
+        assert_eq!(plain.to_physical(1.5).preferred_width, Some(100));
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{Result, Size, Rect};
+use crate::{Result, Error, Size, Rect, Point};
+use crate::ids::WidgetId;
 
 /// A widget that can be positioned and sized
 pub trait Widget {
@@ -6,10 +7,28 @@ pub trait Widget {
     fn set_bounds(&mut self, bounds: Rect) -> Result<()>;
     
     /// Get the current bounds of the widget
+    /// 
+    /// These are relative to the parent's client area, as passed to `set_bounds`.
     fn get_bounds(&self) -> Rect;
     
+    /// Get the widget's bounds in screen coordinates
+    /// 
+    /// Used to place popups such as context menus and tooltips next to the
+    /// widget. Widgets without a native window return an error.
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        Err(Error::OperationFailed("widget has no screen bounds".into()))
+    }
+    
     /// Get the widget's ID
     fn id(&self) -> crate::ids::WidgetId;
+    
+    /// Get the size the widget would like, such as a button's size to fit its label
+    /// 
+    /// Layouts use this for children whose constraints give no preferred size.
+    /// Widgets without a natural size return `None` and get the layout's default.
+    fn preferred_size(&self) -> Option<Size> {
+        None
+    }
 }
 
 /// A container that can hold and layout child widgets
@@ -18,4 +37,26 @@ pub trait Container {
     /// 
     /// This is called when the container is resized or children are added/removed
     fn layout(&mut self, available_space: Size) -> Result<()>;
+    
+    /// Measure the space the children need when not stretched to fill
+    /// 
+    /// Used by scrolling containers to size their content. The default
+    /// assumes the container always fits the space it is given.
+    fn natural_size(&self, available_space: Size) -> Size {
+        available_space
+    }
+    
+    /// Set the scale factor for children whose constraints are in DIPs
+    /// 
+    /// Windows call this with their DPI scale, such as 1.5 at 150%, before
+    /// laying out. Takes effect on the next layout pass. The default ignores it.
+    fn set_scale_factor(&mut self, _scale: f32) {}
+    
+    /// Find the topmost child whose bounds contain a point
+    /// 
+    /// The point is in the same coordinates as the children's bounds. Where
+    /// children overlap, the one drawn last wins. The default finds nothing.
+    fn widget_at(&self, _point: Point) -> Option<WidgetId> {
+        None
+    }
 }
@@ -0,0 +1,92 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds (in microseconds) of the histogram buckets; the last bucket
+/// counts everything slower
+pub const BUCKET_BOUNDS_MICROS: [u64; 8] = [50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+/// Histogram of layout pass durations
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutHistogram {
+    /// Pass counts per bucket; `buckets[i]` counts passes up to
+    /// `BUCKET_BOUNDS_MICROS[i]`, and the last entry counts the rest
+    pub buckets: [u64; BUCKET_BOUNDS_MICROS.len() + 1],
+    /// Number of recorded passes
+    pub count: u64,
+    /// Sum of all recorded durations
+    pub total: Duration,
+    /// Slowest recorded pass
+    pub max: Duration,
+}
+
+impl LayoutHistogram {
+    /// Add one layout pass
+    pub fn record(&mut self, duration: Duration) {
+        let micros = duration.as_micros();
+        let bucket = BUCKET_BOUNDS_MICROS
+            .iter()
+            .position(|&bound| micros <= bound as u128)
+            .unwrap_or(BUCKET_BOUNDS_MICROS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+    
+    /// Average pass duration, or zero if nothing was recorded
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+}
+
+/// Durations of top-level layout passes across the process
+static HISTOGRAM: Mutex<LayoutHistogram> = Mutex::new(LayoutHistogram {
+    buckets: [0; BUCKET_BOUNDS_MICROS.len() + 1],
+    count: 0,
+    total: Duration::ZERO,
+    max: Duration::ZERO,
+});
+
+/// Record the duration of a layout pass
+pub fn record_layout(duration: Duration) {
+    if let Ok(mut histogram) = HISTOGRAM.lock() {
+        histogram.record(duration);
+    }
+}
+
+/// Get a snapshot of the layout duration histogram
+pub fn layout_histogram() -> LayoutHistogram {
+    HISTOGRAM.lock().map(|h| h.clone()).unwrap_or_default()
+}
+
+/// Clear the layout duration histogram
+pub fn reset_layout_histogram() {
+    if let Ok(mut histogram) = HISTOGRAM.lock() {
+        *histogram = LayoutHistogram::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_histogram_buckets() {
+        let mut histogram = LayoutHistogram::default();
+        histogram.record(Duration::from_micros(10));
+        histogram.record(Duration::from_micros(50));
+        histogram.record(Duration::from_micros(300));
+        histogram.record(Duration::from_millis(20));
+        
+        assert_eq!(histogram.buckets[0], 2);
+        assert_eq!(histogram.buckets[3], 1);
+        assert_eq!(histogram.buckets[BUCKET_BOUNDS_MICROS.len()], 1);
+        assert_eq!(histogram.count, 4);
+        assert_eq!(histogram.max, Duration::from_millis(20));
+        assert_eq!(histogram.mean(), Duration::from_micros(5090));
+    }
+}
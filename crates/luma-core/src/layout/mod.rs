@@ -1,7 +1,15 @@
 pub mod constraints;
 pub mod container;
 pub mod box_layout;
+pub mod adaptive;
+pub mod widget_handle;
+#[cfg(feature = "layout-metrics")]
+pub mod metrics;
+#[cfg(test)]
+mod test_widget;
 
 pub use constraints::{Alignment, Padding, LayoutConstraints};
 pub use container::{Container, Widget};
 pub use box_layout::{BoxLayout, LayoutDirection};
+pub use adaptive::AdaptiveLayout;
+pub use widget_handle::WidgetHandle;
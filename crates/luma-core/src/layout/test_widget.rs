@@ -0,0 +1,72 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use crate::{Result, Size, Rect};
+use crate::ids::WidgetId;
+use super::Widget;
+
+/// Widget for layout tests
+/// 
+/// Clones share the widget's bounds and its count of `set_bounds` calls, so
+/// a test can keep a clone to inspect the widget once a layout owns it. The
+/// preferred size is per clone; change it through a `WidgetHandle`.
+#[derive(Clone)]
+pub(crate) struct TestWidget {
+    id: WidgetId,
+    bounds: Rc<Cell<Rect>>,
+    set_bounds_calls: Rc<Cell<usize>>,
+    preferred_size: Option<Size>,
+}
+
+impl TestWidget {
+    /// Create a widget with empty bounds and no preferred size
+    pub(crate) fn new() -> Self {
+        Self {
+            id: WidgetId::new(),
+            bounds: Rc::new(Cell::new(Rect::default())),
+            set_bounds_calls: Rc::new(Cell::new(0)),
+            preferred_size: None,
+        }
+    }
+    
+    /// Start with bounds, as if a layout had already placed the widget
+    pub(crate) fn with_bounds(self, bounds: Rect) -> Self {
+        self.bounds.set(bounds);
+        self
+    }
+    
+    /// Report a size of its own, like a button sized to its label
+    pub(crate) fn with_preferred_size(mut self, size: Size) -> Self {
+        self.preferred_size = Some(size);
+        self
+    }
+    
+    /// Change the size the widget reports, like a label given new text
+    pub(crate) fn set_preferred_size(&mut self, size: Option<Size>) {
+        self.preferred_size = size;
+    }
+    
+    /// Get how often any clone of the widget has been positioned
+    pub(crate) fn set_bounds_calls(&self) -> usize {
+        self.set_bounds_calls.get()
+    }
+}
+
+impl Widget for TestWidget {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds.set(bounds);
+        self.set_bounds_calls.set(self.set_bounds_calls.get() + 1);
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds.get()
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+    
+    fn preferred_size(&self) -> Option<Size> {
+        self.preferred_size
+    }
+}
@@ -0,0 +1,97 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+use crate::{Result, Size, Rect};
+use crate::ids::WidgetId;
+use super::container::Widget;
+
+/// Shared handle to a widget that has been added to a layout
+/// 
+/// Layouts own their children as `Box<dyn Widget>`, so a widget moved into
+/// one can no longer be reached by its concrete type. A handle shares the
+/// widget between the layout and the application instead: the layout holds
+/// one clone and positions the widget through it, while clones kept by the
+/// application (or moved into callbacks) can still call the widget's own
+/// methods, such as changing a label's text.
+/// 
+/// Don't hold a [`borrow_mut`](WidgetHandle::borrow_mut) across a layout
+/// pass; the layout needs to borrow the widget to move it.
+pub struct WidgetHandle<W: Widget> {
+    widget: Rc<RefCell<W>>,
+    id: WidgetId,
+}
+
+impl<W: Widget> WidgetHandle<W> {
+    /// Wrap a widget in a new handle
+    pub fn new(widget: W) -> Self {
+        let id = widget.id();
+        Self { widget: Rc::new(RefCell::new(widget)), id }
+    }
+    
+    /// Borrow the widget
+    /// 
+    /// Panics if it is mutably borrowed.
+    pub fn borrow(&self) -> Ref<'_, W> {
+        self.widget.borrow()
+    }
+    
+    /// Borrow the widget mutably
+    /// 
+    /// Panics if it is already borrowed.
+    pub fn borrow_mut(&self) -> RefMut<'_, W> {
+        self.widget.borrow_mut()
+    }
+}
+
+impl<W: Widget> Clone for WidgetHandle<W> {
+    fn clone(&self) -> Self {
+        Self { widget: Rc::clone(&self.widget), id: self.id }
+    }
+}
+
+impl<W: Widget> Widget for WidgetHandle<W> {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.widget.borrow_mut().set_bounds(bounds)
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.widget.borrow().get_bounds()
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        self.widget.borrow().get_screen_bounds()
+    }
+    
+    // Cached so layouts can find the child while the widget is borrowed
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+    
+    fn preferred_size(&self) -> Option<Size> {
+        self.widget.borrow().preferred_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{BoxLayout, Container, LayoutConstraints};
+    use crate::layout::test_widget::TestWidget;
+    
+    #[test]
+    fn test_handle_reaches_widget_in_layout() {
+        let mut layout = BoxLayout::vertical();
+        let status = layout.add_with_handle(
+            TestWidget::new(),
+            LayoutConstraints::default().preferred_height(20),
+        );
+        layout.layout(Size::new(100, 100)).unwrap();
+        
+        assert_eq!(status.borrow().get_bounds(), Rect::new(0, 0, 100, 20));
+        status.borrow_mut().set_preferred_size(Some(Size::new(80, 20)));
+        assert_eq!(status.clone().borrow().preferred_size(), Some(Size::new(80, 20)));
+        
+        let id = status.borrow().id();
+        assert!(layout.remove(id).is_some());
+        assert_eq!(status.borrow().preferred_size(), Some(Size::new(80, 20)));
+    }
+}
@@ -7,15 +7,35 @@ pub mod handle;
 pub mod traits;
 pub mod flags;
 pub mod layout;
+pub mod color;
+pub mod appearance;
+pub mod icon;
+pub mod keyboard;
+pub mod date;
+pub mod image;
+pub mod panic_guard;
+pub mod widget_tree;
+pub mod observable;
+pub mod window_state;
 
 // Re-export commonly used types
 pub use error::{Error, Result};
-pub use geometry::{Point, Size, Rect};
+pub use geometry::{Point, Size, Rect, Dip, Px};
 pub use ids::{WidgetId, WindowId};
 pub use handle::Handle;
 pub use flags::{WindowFlags, ButtonFlags, ListBoxFlags};
+pub use color::Color;
+pub use appearance::{CornerPreference, Backdrop, Theme, EllipsisMode};
+pub use icon::Icon;
+pub use keyboard::{Key, Modifiers, Accelerator};
+pub use date::Date;
+pub use image::Stretch;
+pub use widget_tree::{WidgetTree, WidgetKind};
+pub use observable::{Observable, SubscriptionId};
+pub use window_state::{WindowState, WindowPlacement, TaskbarProgressState, FlashMode};
 pub use layout::{
     Alignment, Padding, LayoutConstraints,
-    Container, Widget,
+    Container, Widget, WidgetHandle,
     BoxLayout, LayoutDirection,
+    AdaptiveLayout,
 };
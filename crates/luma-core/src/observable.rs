@@ -0,0 +1,207 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Identifier of a subscription, for [`Observable::unsubscribe`]
+/// 
+/// Only unique within the observable that returned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(u64);
+
+type Subscriber<T> = Rc<RefCell<dyn FnMut(&T)>>;
+
+/// Shared value that notifies subscribers when it is set
+/// 
+/// Clones share the same value, so one can be handed to a widget binding
+/// while another is kept to update it from code. The UI is single-threaded,
+/// so it is neither `Send` nor `Sync`.
+pub struct Observable<T> {
+    inner: Rc<Inner<T>>,
+}
+
+struct Inner<T> {
+    value: RefCell<T>,
+    subscribers: RefCell<Vec<(SubscriptionId, Subscriber<T>)>>,
+    next_id: Cell<u64>,
+    /// Set while subscribers are being called
+    notifying: Cell<bool>,
+    /// Set when the value changes during a notification
+    pending: Cell<bool>,
+}
+
+impl<T: Clone + 'static> Observable<T> {
+    /// Create an observable holding an initial value
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                value: RefCell::new(value),
+                subscribers: RefCell::new(Vec::new()),
+                next_id: Cell::new(1),
+                notifying: Cell::new(false),
+                pending: Cell::new(false),
+            }),
+        }
+    }
+    
+    /// Get a copy of the current value
+    pub fn get(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+    
+    /// Replace the value and notify every subscriber
+    /// 
+    /// A subscriber that sets the value does not re-enter the others: the
+    /// new value is stored at once, and subscribers are called with it again
+    /// once the current round of notifications has finished.
+    pub fn set(&self, value: T) {
+        *self.inner.value.borrow_mut() = value;
+        if self.inner.notifying.get() {
+            self.inner.pending.set(true);
+            return;
+        }
+        
+        let _notifying = NotifyingGuard::enter(&self.inner.notifying);
+        loop {
+            self.inner.pending.set(false);
+            self.notify();
+            if !self.inner.pending.get() {
+                break;
+            }
+        }
+    }
+    
+    /// Call each subscriber with the current value
+    fn notify(&self) {
+        let value = self.get();
+        // Snapshot the list so subscribers can subscribe and unsubscribe
+        let subscribers = self.inner.subscribers.borrow().clone();
+        for (id, subscriber) in subscribers {
+            // Skip subscriptions removed by an earlier subscriber in this round
+            let subscribed = self.inner.subscribers.borrow().iter().any(|(other, _)| *other == id);
+            if subscribed {
+                (subscriber.borrow_mut())(&value);
+            }
+        }
+    }
+    
+    /// Call `callback` with the new value whenever it is set
+    pub fn subscribe<F>(&self, callback: F) -> SubscriptionId
+    where
+        F: FnMut(&T) + 'static,
+    {
+        let id = SubscriptionId(self.inner.next_id.replace(self.inner.next_id.get() + 1));
+        self.inner.subscribers.borrow_mut().push((id, Rc::new(RefCell::new(callback))));
+        id
+    }
+    
+    /// Stop calling a subscriber, returning whether it was subscribed
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut subscribers = self.inner.subscribers.borrow_mut();
+        let count = subscribers.len();
+        subscribers.retain(|(other, _)| *other != id);
+        subscribers.len() != count
+    }
+}
+
+/// Marks an observable as notifying until dropped
+/// 
+/// Clearing the flag on drop rather than after the loop means a subscriber
+/// that panics does not leave later `set` calls queued forever.
+struct NotifyingGuard<'a>(&'a Cell<bool>);
+
+impl<'a> NotifyingGuard<'a> {
+    fn enter(notifying: &'a Cell<bool>) -> Self {
+        notifying.set(true);
+        Self(notifying)
+    }
+}
+
+impl Drop for NotifyingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+impl<T> Clone for Observable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T: Default + Clone + 'static> Default for Observable<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_subscriber_gets_new_value() {
+        let count = Observable::new(1);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+        count.subscribe(move |value| sink.borrow_mut().push(*value));
+        
+        count.set(2);
+        count.clone().set(3);
+        assert_eq!(*seen.borrow(), [2, 3]);
+        assert_eq!(count.get(), 3);
+    }
+    
+    #[test]
+    fn test_unsubscribe_stops_notifications() {
+        let name = Observable::new(String::new());
+        let calls = Rc::new(Cell::new(0));
+        let sink = Rc::clone(&calls);
+        let id = name.subscribe(move |_| sink.set(sink.get() + 1));
+        
+        name.set("a".into());
+        assert!(name.unsubscribe(id));
+        assert!(!name.unsubscribe(id));
+        name.set("b".into());
+        assert_eq!(calls.get(), 1);
+    }
+    
+    #[test]
+    fn test_set_from_subscriber_does_not_reenter() {
+        let value = Observable::new(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        
+        // Clamp to 10, setting the value again from inside a notification
+        let clamp = value.clone();
+        value.subscribe(move |v| {
+            if *v > 10 {
+                clamp.set(10);
+            }
+        });
+        let sink = Rc::clone(&seen);
+        value.subscribe(move |v| sink.borrow_mut().push(*v));
+        
+        value.set(15);
+        assert_eq!(value.get(), 10);
+        assert_eq!(*seen.borrow(), [15, 10]);
+    }
+    
+    #[test]
+    fn test_panicking_subscriber_does_not_block_later_sets() {
+        let value = Observable::new(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        value.subscribe(|v| {
+            if *v == 1 {
+                panic!("subscriber failed");
+            }
+        });
+        let sink = Rc::clone(&seen);
+        value.subscribe(move |v| sink.borrow_mut().push(*v));
+        
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| value.set(1)));
+        assert!(result.is_err());
+        
+        value.set(2);
+        assert_eq!(*seen.borrow(), [2]);
+    }
+}
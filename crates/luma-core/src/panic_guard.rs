@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Handler invoked with the message of a caught panic
+pub type PanicHandler = Box<dyn FnMut(&str)>;
+
+thread_local! {
+    /// Handler for panics caught on this (UI) thread
+    static PANIC_HANDLER: RefCell<Option<PanicHandler>> = RefCell::new(None);
+}
+
+/// Set the handler invoked with the message of each caught callback panic
+/// 
+/// The handler is per thread; set it on the thread that runs the event loop.
+/// Pass `None` to remove it.
+pub fn set_panic_handler(handler: Option<PanicHandler>) {
+    PANIC_HANDLER.with(|slot| *slot.borrow_mut() = handler);
+}
+
+/// Run code that may call into user callbacks, catching any panic
+/// 
+/// Backends call this at every FFI boundary (window procedures and other
+/// `extern "system"` functions), since unwinding out of them is undefined
+/// behavior. A caught panic is logged and passed to the panic handler, and
+/// `None` is returned.
+pub fn catch_callback_panic<R>(f: impl FnOnce() -> R) -> Option<R> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            tracing::error!("Panic in event callback: {}", message);
+            report_panic(message);
+            None
+        }
+    }
+}
+
+/// Get the message of a panic payload
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Pass a panic message to the handler, if one is set
+fn report_panic(message: &str) {
+    // Take the handler out while it runs so it can replace itself, and so a
+    // panic inside it cannot re-enter
+    let handler = PANIC_HANDLER.with(|slot| slot.borrow_mut().take());
+    if let Some(mut handler) = handler {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| handler(message)));
+        if result.is_err() {
+            tracing::error!("Panic handler panicked");
+        }
+        PANIC_HANDLER.with(|slot| {
+            let mut slot = slot.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(handler);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    
+    #[test]
+    fn test_panicking_button_callback_is_caught() {
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let messages_for_handler = Rc::clone(&messages);
+        set_panic_handler(Some(Box::new(move |message| {
+            messages_for_handler.borrow_mut().push(message.to_string());
+        })));
+        
+        // Backends invoke widget callbacks through raw `FnMut` pointers
+        let mut on_click: Box<dyn FnMut()> = Box::new(|| panic!("click failed: {}", 42));
+        assert_eq!(catch_callback_panic(&mut *on_click), None);
+        assert_eq!(catch_callback_panic(|| 7), Some(7));
+        
+        assert_eq!(*messages.borrow(), ["click failed: 42"]);
+        set_panic_handler(None);
+    }
+    
+    #[test]
+    fn test_panic_without_handler() {
+        assert_eq!(catch_callback_panic(|| -> u32 { panic!("no handler") }), None);
+    }
+    
+    #[test]
+    fn test_panic_message() {
+        let payload: Box<dyn Any + Send> = Box::new("static");
+        assert_eq!(panic_message(payload.as_ref()), "static");
+        let payload: Box<dyn Any + Send> = Box::new(String::from("owned"));
+        assert_eq!(panic_message(payload.as_ref()), "owned");
+        let payload: Box<dyn Any + Send> = Box::new(5);
+        assert_eq!(panic_message(payload.as_ref()), "unknown panic");
+    }
+}
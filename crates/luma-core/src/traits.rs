@@ -1,4 +1,5 @@
-use crate::{Result, Point, Size, WindowFlags, ButtonFlags, ListBoxFlags};
+use crate::{Result, Point, Size, WindowFlags, ButtonFlags, ListBoxFlags, Accelerator, Date, Stretch};
+use std::time::Duration;
 
 /// Platform-specific application backend
 pub trait ApplicationBackend {
@@ -12,6 +13,9 @@ pub trait ApplicationBackend {
     
     /// Quit the application
     fn quit(&mut self) -> Result<()>;
+    
+    /// Replace the set of keyboard accelerators handled by the event loop
+    fn set_accelerators(&mut self, accelerators: &[Accelerator]) -> Result<()>;
 }
 
 /// Platform-specific window backend
@@ -23,6 +27,9 @@ pub trait WindowBackend {
     /// Set the window title
     fn set_title(&mut self, title: &str) -> Result<()>;
     
+    /// Get the window title
+    fn get_title(&self) -> Result<String>;
+    
     /// Set the window size
     fn set_size(&mut self, width: u32, height: u32) -> Result<()>;
     
@@ -39,8 +46,28 @@ pub trait WindowBackend {
     fn get_client_size(&self) -> Result<Size>;
 }
 
+/// Operations shared by every widget backend
+pub trait WidgetBackend {
+    /// Set the widget bounds (position and size) within its parent
+    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
+    
+    /// Enable or disable the widget
+    /// 
+    /// A disabled widget is drawn grayed and ignores keyboard and mouse input.
+    fn set_enabled(&mut self, enabled: bool) -> Result<()>;
+    
+    /// Show or hide the widget
+    fn set_visible(&mut self, visible: bool) -> Result<()>;
+    
+    /// Get the raw widget handle
+    /// 
+    /// For containers this is the handle child widgets are created in, which
+    /// for a scroll viewer is its content area.
+    fn raw_handle(&self) -> *mut std::ffi::c_void;
+}
+
 /// Platform-specific button backend
-pub trait ButtonBackend {
+pub trait ButtonBackend: WidgetBackend {
     /// Create a new button
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -52,16 +79,10 @@ pub trait ButtonBackend {
     
     /// Set the button label
     fn set_label(&mut self, label: &str) -> Result<()>;
-    
-    /// Enable or disable the button
-    fn set_enabled(&mut self, enabled: bool) -> Result<()>;
-    
-    /// Set the button bounds (position and size)
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
 }
 
 /// Platform-specific panel (container) backend
-pub trait PanelBackend {
+pub trait PanelBackend: WidgetBackend {
     /// Create a new panel
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -69,15 +90,40 @@ pub trait PanelBackend {
         size: Size,
     ) -> Result<Self> where Self: Sized;
     
-    /// Get the raw panel handle (for creating child widgets)
-    fn raw_handle(&self) -> *mut std::ffi::c_void;
+    /// Get the number of direct child widgets
+    fn child_count(&self) -> usize;
     
-    /// Set the panel bounds
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
+    /// Destroy all child widgets
+    fn clear_children(&mut self) -> Result<()>;
+}
+
+/// Platform-specific scroll viewer backend
+/// 
+/// A scroll viewer shows a window onto content that may be larger than it,
+/// with scroll bars to move the content.
+pub trait ScrollViewerBackend: WidgetBackend {
+    /// Create a new scroll viewer
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Get the size of the visible area, excluding scroll bars
+    fn viewport_size(&self) -> Result<Size>;
+    
+    /// Set the size of the content, updating the scroll bars
+    fn set_content_size(&mut self, size: Size) -> Result<()>;
+    
+    /// Get the current scroll offset of the content
+    fn scroll_offset(&self) -> Point;
+    
+    /// Scroll so the given content position is at the top-left of the viewport
+    fn scroll_to(&mut self, offset: Point) -> Result<()>;
 }
 
 /// Platform-specific label backend
-pub trait LabelBackend {
+pub trait LabelBackend: WidgetBackend {
     /// Create a new label
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -86,15 +132,15 @@ pub trait LabelBackend {
         size: Size,
     ) -> Result<Self> where Self: Sized;
     
+    /// Get the label text
+    fn get_text(&self) -> Result<String>;
+    
     /// Set the label text
     fn set_text(&mut self, text: &str) -> Result<()>;
-    
-    /// Set the label bounds (position and size)
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
 }
 
 /// Platform-specific text input backend
-pub trait TextInputBackend {
+pub trait TextInputBackend: WidgetBackend {
     /// Create a new text input
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -112,12 +158,51 @@ pub trait TextInputBackend {
     /// Set read-only mode
     fn set_read_only(&mut self, read_only: bool) -> Result<()>;
     
-    /// Set the text input bounds (position and size)
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
+    /// Limit how many characters the user can enter, or `None` for no limit
+    /// 
+    /// Text set in code is not cut short. Fails for a limit of zero.
+    fn set_max_length(&mut self, max_length: Option<u32>) -> Result<()>;
+    
+    /// Set the grey hint shown while the input is empty, or `""` to remove it
+    /// 
+    /// The hint is hidden while the input has focus unless `show_when_focused` is set.
+    fn set_placeholder(&mut self, text: &str, show_when_focused: bool) -> Result<()>;
+    
+    /// Get the selected range as character offsets, start first
+    /// 
+    /// An empty range is the caret position.
+    fn get_selection(&self) -> Result<(usize, usize)>;
+    
+    /// Select a range of characters, clamped to the text
+    /// 
+    /// The caret goes to `end`, so a range with `end` before `start` selects backwards.
+    fn set_selection(&mut self, start: usize, end: usize) -> Result<()>;
+    
+    /// Replace the selection with text, or insert it at the caret if nothing is selected
+    /// 
+    /// The caret ends up after the inserted text.
+    fn replace_selection(&mut self, text: &str) -> Result<()>;
+    
+    /// Check whether there is an edit to undo
+    fn can_undo(&self) -> Result<bool>;
+    
+    /// Undo the last edit
+    /// 
+    /// Only one edit is kept, and undoing again redoes it.
+    fn undo(&mut self) -> Result<()>;
+    
+    /// Move the selected text to the clipboard
+    fn cut(&mut self) -> Result<()>;
+    
+    /// Copy the selected text to the clipboard
+    fn copy(&self) -> Result<()>;
+    
+    /// Replace the selection with the text on the clipboard
+    fn paste(&mut self) -> Result<()>;
 }
 
 /// Platform-specific checkbox backend
-pub trait CheckBoxBackend {
+pub trait CheckBoxBackend: WidgetBackend {
     /// Create a new checkbox
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -135,13 +220,10 @@ pub trait CheckBoxBackend {
     
     /// Set the label text
     fn set_label(&mut self, label: &str) -> Result<()>;
-    
-    /// Set the checkbox bounds (position and size)
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
 }
 
 /// Platform-specific listbox backend
-pub trait ListBoxBackend {
+pub trait ListBoxBackend: WidgetBackend {
     /// Create a new listbox
     fn new(
         parent_hwnd: *mut std::ffi::c_void,
@@ -171,6 +253,160 @@ pub trait ListBoxBackend {
     /// Set selected index (for single-select)
     fn set_selected_index(&mut self, index: Option<usize>) -> Result<()>;
     
-    /// Set the listbox bounds (position and size)
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()>;
+    /// Set selected indices (for multi-select), replacing the selection
+    fn set_selected_indices(&mut self, indices: &[usize]) -> Result<()>;
+    
+    /// Select or deselect every item (for multi-select)
+    fn set_all_selected(&mut self, selected: bool) -> Result<()>;
+}
+
+/// Platform-specific list view (multi-column table with headers) backend
+pub trait ListViewBackend: WidgetBackend {
+    /// Create a new, empty list view
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Add a column at the right, with a header title and a width in pixels
+    fn add_column(&mut self, title: &str, width: u32) -> Result<()>;
+    
+    /// Get the number of columns
+    fn column_count(&self) -> usize;
+    
+    /// Add a row at the bottom, one cell per column
+    /// 
+    /// Missing trailing cells are left empty. Fails if there are more cells than columns.
+    fn add_row(&mut self, cells: &[&str]) -> Result<()>;
+    
+    /// Remove a row by index
+    fn remove_row(&mut self, index: usize) -> Result<()>;
+    
+    /// Remove all rows, keeping the columns
+    fn clear_rows(&mut self) -> Result<()>;
+    
+    /// Get the number of rows
+    fn row_count(&self) -> Result<usize>;
+    
+    /// Get the cells of a row, one per column
+    fn get_row(&self, index: usize) -> Result<Vec<String>>;
+    
+    /// Get the selected row
+    fn get_selected_index(&self) -> Result<Option<usize>>;
+    
+    /// Select a row, or clear the selection with `None`
+    fn set_selected_index(&mut self, index: Option<usize>) -> Result<()>;
+    
+    /// Reorder the rows by the text of one column, keeping the selected row selected
+    /// 
+    /// `compare` orders two cells; it is reversed for a descending sort. The
+    /// column header shows the sort direction.
+    fn sort_rows(
+        &mut self,
+        column: usize,
+        ascending: bool,
+        compare: &dyn Fn(&str, &str) -> std::cmp::Ordering,
+    ) -> Result<()>;
+}
+
+/// Platform-specific number box (numeric input with up/down buttons) backend
+pub trait NumberBoxBackend: WidgetBackend {
+    /// Create a new number box
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Get the current value
+    fn value(&self) -> f64;
+    
+    /// Set the value, clamped to the range
+    fn set_value(&mut self, value: f64) -> Result<()>;
+    
+    /// Set the allowed range, clamping the current value into it
+    fn set_range(&mut self, min: f64, max: f64) -> Result<()>;
+    
+    /// Set the amount added or removed by the up/down buttons
+    fn set_step(&mut self, step: f64) -> Result<()>;
+    
+    /// Set the number of decimal places shown
+    fn set_decimals(&mut self, decimals: usize) -> Result<()>;
+}
+
+/// Platform-specific date picker backend
+pub trait DatePickerBackend: WidgetBackend {
+    /// Create a new date picker showing today's date
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Get the selected date
+    fn get_date(&self) -> Result<Date>;
+    
+    /// Set the selected date
+    fn set_date(&mut self, date: Date) -> Result<()>;
+    
+    /// Set the display format (e.g. `"dd MMMM yyyy"`), or `None` for the system short date
+    fn set_format(&mut self, format: Option<&str>) -> Result<()>;
+}
+
+/// Platform-specific image view backend
+pub trait ImageViewBackend: WidgetBackend {
+    /// Create a new, empty image view
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Load and display an image file, returning its size in pixels
+    fn load_file(&mut self, path: &std::path::Path) -> Result<Size>;
+    
+    /// Remove the displayed image
+    fn clear(&mut self);
+    
+    /// Set how the image is scaled to the view
+    fn set_stretch(&mut self, stretch: Stretch);
+}
+
+/// Platform-specific progress bar backend
+pub trait ProgressBarBackend: WidgetBackend {
+    /// Create a new progress bar at 0 in a range of 0 to 100
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Set the values at the empty and full ends of the bar
+    fn set_range(&mut self, min: u32, max: u32) -> Result<()>;
+    
+    /// Get the current value
+    fn get_value(&self) -> Result<u32>;
+    
+    /// Set the current value, clamped to the range
+    fn set_value(&mut self, value: u32) -> Result<()>;
+    
+    /// Switch between showing the value and an animation with no end
+    /// 
+    /// `interval` is the time between animation steps.
+    fn set_indeterminate(&mut self, indeterminate: bool, interval: Duration) -> Result<()>;
+}
+
+/// Platform-specific hyperlink backend
+pub trait HyperlinkBackend: WidgetBackend {
+    /// Create a new hyperlink showing text with `<a>` markup for its links
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        markup: &str,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> where Self: Sized;
+    
+    /// Replace the text and links
+    fn set_markup(&mut self, markup: &str) -> Result<()>;
 }
@@ -0,0 +1,118 @@
+use std::fmt;
+use crate::geometry::Rect;
+
+/// Kind of native control a node in a [`WidgetTree`] is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WidgetKind {
+    /// Top-level window
+    Window,
+    Button,
+    CheckBox,
+    Label,
+    TextInput,
+    ListBox,
+    ListView,
+    Panel,
+    ScrollViewer,
+    NumberBox,
+    DatePicker,
+    ImageView,
+    ProgressBar,
+    Hyperlink,
+    /// Control not created by Luma, with its platform class name
+    Other(String),
+}
+
+impl fmt::Display for WidgetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Other(class) => f.write_str(class),
+            kind => fmt::Debug::fmt(kind, f),
+        }
+    }
+}
+
+/// Snapshot of a window or widget and everything inside it
+/// 
+/// Built from the native controls, so it describes what is on screen
+/// rather than which Rust values exist. Formats as an indented outline,
+/// one line per widget, for comparing in tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WidgetTree {
+    pub kind: WidgetKind,
+    /// Window title, label or current text (empty if the control has none)
+    pub text: String,
+    /// Bounds in screen coordinates
+    pub bounds: Rect,
+    pub children: Vec<WidgetTree>,
+}
+
+impl WidgetTree {
+    /// Iterate over this node and all its descendants, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &WidgetTree> {
+        let mut pending = vec![self];
+        std::iter::from_fn(move || {
+            let node = pending.pop()?;
+            pending.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+    
+    /// Find every node of a kind, in depth-first order
+    pub fn find_all(&self, kind: &WidgetKind) -> Vec<&WidgetTree> {
+        self.iter().filter(|node| node.kind == *kind).collect()
+    }
+    
+    /// Get the text of every node of a kind, in depth-first order
+    pub fn texts(&self, kind: &WidgetKind) -> Vec<&str> {
+        self.iter().filter(|node| node.kind == *kind).map(|node| node.text.as_str()).collect()
+    }
+    
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let Rect { x, y, width, height } = self.bounds;
+        write!(f, "{:indent$}{}", "", self.kind, indent = depth * 2)?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        writeln!(f, " at ({}, {}) {}x{}", x, y, width, height)?;
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for WidgetTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn node(kind: WidgetKind, text: &str, bounds: Rect, children: Vec<WidgetTree>) -> WidgetTree {
+        WidgetTree { kind, text: text.to_string(), bounds, children }
+    }
+    
+    #[test]
+    fn test_widget_tree_outline() {
+        let tree = node(WidgetKind::Window, "Form", Rect::new(0, 0, 200, 100), vec![
+            node(WidgetKind::Panel, "", Rect::new(0, 0, 200, 50), vec![
+                node(WidgetKind::Label, "Name:", Rect::new(5, 5, 50, 20), vec![]),
+            ]),
+            node(WidgetKind::Other("msctls_trackbar32".into()), "", Rect::new(0, 50, 200, 20), vec![]),
+        ]);
+        
+        assert_eq!(
+            tree.to_string(),
+            "Window \"Form\" at (0, 0) 200x100\n\
+             \x20 Panel at (0, 0) 200x50\n\
+             \x20   Label \"Name:\" at (5, 5) 50x20\n\
+             \x20 msctls_trackbar32 at (0, 50) 200x20\n"
+        );
+        assert_eq!(tree.iter().count(), 4);
+        assert_eq!(tree.texts(&WidgetKind::Label), ["Name:"]);
+    }
+}
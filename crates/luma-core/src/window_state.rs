@@ -0,0 +1,87 @@
+use crate::Rect;
+
+/// Whether a window fills the screen, sits in the taskbar or has its own size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowState {
+    /// Shown at its own position and size
+    #[default]
+    Normal,
+    /// Filling the work area of its monitor
+    Maximized,
+    /// Hidden in the taskbar
+    Minimized,
+}
+
+/// What a window flashes to get the user's attention
+/// 
+/// The count is how many times to flash; `None` flashes until
+/// [`FlashMode::Stop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlashMode {
+    /// Flash the title bar
+    Caption(Option<u32>),
+    /// Flash the taskbar button
+    Tray(Option<u32>),
+    /// Flash both the title bar and the taskbar button
+    All(Option<u32>),
+    /// Stop flashing and restore the normal look
+    Stop,
+}
+
+/// How a window's taskbar button shows progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskbarProgressState {
+    /// No progress shown
+    #[default]
+    NoProgress,
+    /// Pulsing green, for work of unknown length
+    Indeterminate,
+    /// Green bar showing the progress value
+    Normal,
+    /// Red bar, for work that failed
+    Error,
+    /// Yellow bar, for work that is on hold
+    Paused,
+}
+
+/// Where a window is and how it is shown, for restoring it in a later session
+/// 
+/// Serializable with the `serde` feature, so it can be saved to a settings
+/// file when the window closes and reapplied the next time it opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowPlacement {
+    /// Whether the window is maximized, minimized or neither
+    pub state: WindowState,
+    /// Outer bounds of the window when it is neither maximized nor minimized
+    /// 
+    /// Kept while the window is maximized or minimized, so a window saved
+    /// maximized still restores to its last normal size. Measured from the
+    /// top-left of the work area, which differs from screen coordinates
+    /// when the taskbar is at the top or left of the screen.
+    pub normal_bounds: Rect,
+}
+
+impl WindowPlacement {
+    /// Create a placement from a state and normal bounds
+    pub fn new(state: WindowState, normal_bounds: Rect) -> Self {
+        Self { state, normal_bounds }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_placement_round_trips_through_json() {
+        let placement = WindowPlacement::new(WindowState::Maximized, Rect::new(-8, 40, 1024, 768));
+        let json = serde_json::to_string(&placement).unwrap();
+        assert_eq!(
+            json,
+            r#"{"state":"Maximized","normal_bounds":{"x":-8,"y":40,"width":1024,"height":768}}"#
+        );
+        assert_eq!(serde_json::from_str::<WindowPlacement>(&json).unwrap(), placement);
+    }
+}
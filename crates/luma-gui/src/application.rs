@@ -1,11 +1,14 @@
-use luma_core::{Result, traits::ApplicationBackend};
+use luma_core::{Result, Theme, Accelerator, traits::ApplicationBackend};
 use crate::Win32Application;
+use std::collections::HashMap;
 
 /// Cross-platform application instance
 /// 
 /// This is the entry point for all Luma applications.
 pub struct Application {
     backend: Win32Application,
+    accelerators: Vec<Accelerator>,
+    commands: HashMap<u16, Box<dyn FnMut()>>,
 }
 
 impl Application {
@@ -22,6 +25,8 @@ impl Application {
     pub fn new() -> Result<Self> {
         Ok(Self {
             backend: Win32Application::new()?,
+            accelerators: Vec::new(),
+            commands: HashMap::new(),
         })
     }
     
@@ -43,7 +48,92 @@ impl Application {
     }
     
     /// Quit the application
+    /// 
+    /// [`run`](Application::run) returns once the events already queued have
+    /// been handled. From a widget callback, where `run` is borrowing the
+    /// application, use [`Application::request_quit`] instead.
     pub fn quit(&mut self) -> Result<()> {
         self.backend.quit()
     }
+    
+    /// Ask the running event loop to exit, without needing the application
+    /// 
+    /// For widget and window callbacks, such as a "Quit" button or menu item.
+    /// Must be called on the thread running the event loop.
+    /// 
+    /// # Example
+    /// 
+    /// ```no_run
+    /// use luma_gui::prelude::*;
+    /// 
+    /// let mut app = Application::new()?;
+    /// let window = Window::builder().build()?;
+    /// let _quit = Button::builder()
+    ///     .label("&Quit")
+    ///     .on_click(Application::request_quit)
+    ///     .build(&window)?;
+    /// app.run()?;
+    /// # Ok::<(), luma_gui::Error>(())
+    /// ```
+    pub fn request_quit() {
+        Win32Application::post_quit();
+    }
+    
+    /// Register a keyboard shortcut and the command it triggers
+    /// 
+    /// Accelerators sharing a `command_id` share one callback; registering
+    /// again with the same ID replaces it.
+    /// 
+    /// # Example
+    /// 
+    /// ```no_run
+    /// use luma_gui::{Application, Accelerator, Key};
+    /// 
+    /// let mut app = Application::new()?;
+    /// app.register_accelerator(Accelerator::ctrl(Key::Char('S'), 1), || println!("Save"))?;
+    /// # Ok::<(), luma_gui::Error>(())
+    /// ```
+    pub fn register_accelerator<F>(&mut self, accelerator: Accelerator, callback: F) -> Result<()>
+    where
+        F: FnMut() + 'static,
+    {
+        let mut accelerators = self.accelerators.clone();
+        accelerators.push(accelerator);
+        self.backend.set_accelerators(&accelerators)?;
+        self.accelerators = accelerators;
+        
+        let mut callback: Box<dyn FnMut()> = Box::new(callback);
+        crate::register_command_callback(accelerator.command_id, callback.as_mut() as *mut dyn FnMut());
+        // Dropped only after the backend points at the new callback
+        self.commands.insert(accelerator.command_id, callback);
+        
+        Ok(())
+    }
+    
+    /// Set the handler invoked when a widget or window callback panics
+    /// 
+    /// Panics in callbacks are always caught and logged so they cannot unwind
+    /// through the platform event loop; the handler lets the application
+    /// react, for example by showing a message and calling
+    /// [`Application::request_quit`]. It receives the panic message.
+    pub fn on_panic<F>(&mut self, handler: F)
+    where
+        F: FnMut(&str) + 'static,
+    {
+        luma_core::panic_guard::set_panic_handler(Some(Box::new(handler)));
+    }
+    
+    /// Get the light/dark theme currently selected in the system settings
+    pub fn system_theme() -> Theme {
+        crate::system_theme()
+    }
+}
+
+impl Drop for Application {
+    fn drop(&mut self) {
+        luma_core::panic_guard::set_panic_handler(None);
+        for command_id in self.commands.keys() {
+            crate::unregister_command_callback(*command_id);
+        }
+    }
 }
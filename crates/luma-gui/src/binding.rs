@@ -0,0 +1,323 @@
+// Data binding between widget properties and observable values
+//
+// Enabled with the `binding` feature. A `DataContext` holds named
+// observables, and `bind` connects a widget property to one of them using
+// the path and mode of a XAML `{Binding}` markup extension.
+
+use luma_core::{Result, Error};
+use crate::Observable;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+pub use luma_xaml::BindingExtension;
+
+/// Value an observable can hold to be bound to a widget property
+/// 
+/// Properties are exchanged as text, so a number can be shown in a label
+/// or edited in a text input.
+pub trait BindingValue: Clone + PartialEq + 'static {
+    /// Format the value for a widget property
+    fn to_text(&self) -> String;
+    
+    /// Parse a widget property, or `None` if the text is not a valid value
+    fn from_text(text: &str) -> Option<Self>;
+}
+
+impl<T> BindingValue for T
+where
+    T: Display + FromStr + Clone + PartialEq + 'static,
+{
+    fn to_text(&self) -> String {
+        self.to_string()
+    }
+    
+    fn from_text(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+}
+
+/// Function that sets a widget property from text
+pub type PropertyWriter = Box<dyn Fn(&str) -> Result<()>>;
+
+/// Widget with properties that can be bound to a [`DataContext`]
+pub trait Bindable {
+    /// Get a function that sets a property, or `None` if the widget has no such property
+    /// 
+    /// The function must not borrow the widget, since bindings update it
+    /// after it has been moved into a layout.
+    fn property_writer(&self, property: &str) -> Option<PropertyWriter>;
+    
+    /// Call `callback` with the new value of a property whenever the user changes it
+    /// 
+    /// Fails if the user cannot change the property, so it cannot be bound `TwoWay`.
+    fn watch_property(&mut self, property: &str, callback: Box<dyn FnMut(String)>) -> Result<()>;
+}
+
+/// How changes flow between a widget property and its source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BindingMode {
+    /// Set the property once, when the binding is made
+    OneTime,
+    /// Update the property whenever the source changes
+    #[default]
+    OneWay,
+    /// Also write changes made by the user back to the source
+    TwoWay,
+}
+
+impl BindingMode {
+    /// Parse the `Mode` of a `{Binding}`, defaulting to `OneWay` like WinUI
+    pub fn parse(mode: Option<&str>) -> Result<Self> {
+        match mode {
+            None => Ok(Self::default()),
+            Some("OneTime") => Ok(Self::OneTime),
+            Some("OneWay") => Ok(Self::OneWay),
+            Some("TwoWay") => Ok(Self::TwoWay),
+            Some(other) => Err(Error::InvalidParameter(format!("Unsupported binding mode: {}", other))),
+        }
+    }
+}
+
+/// Observable seen through its text representation
+trait Source {
+    fn text(&self) -> String;
+    
+    /// Store parsed text if it is valid and differs from the current value
+    fn set_text(&self, text: &str);
+    
+    fn subscribe(&self, callback: Box<dyn FnMut(String)>);
+}
+
+impl<T: BindingValue> Source for Observable<T> {
+    fn text(&self) -> String {
+        self.get().to_text()
+    }
+    
+    fn set_text(&self, text: &str) {
+        // Skipping unchanged values stops a TwoWay binding echoing its own update
+        if let Some(value) = T::from_text(text) {
+            if value != self.get() {
+                self.set(value);
+            }
+        }
+    }
+    
+    fn subscribe(&self, mut callback: Box<dyn FnMut(String)>) {
+        Observable::subscribe(self, move |value: &T| callback(value.to_text()));
+    }
+}
+
+/// A named observable, both typed and type-erased
+struct Entry {
+    value: Box<dyn Any>,
+    source: Rc<dyn Source>,
+}
+
+/// Named observable values that widget properties can be bound to
+#[derive(Default)]
+pub struct DataContext {
+    entries: HashMap<String, Entry>,
+}
+
+impl DataContext {
+    /// Create an empty data context
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add an observable under a name, replacing any with the same name
+    pub fn insert<T: BindingValue>(&mut self, name: impl Into<String>, value: Observable<T>) {
+        let entry = Entry {
+            source: Rc::new(value.clone()),
+            value: Box::new(value),
+        };
+        self.entries.insert(name.into(), entry);
+    }
+    
+    /// Get the observable with a name, or `None` if there is none holding a `T`
+    pub fn get<T: BindingValue>(&self, name: &str) -> Option<Observable<T>> {
+        self.entries.get(name)?.value.downcast_ref::<Observable<T>>().cloned()
+    }
+}
+
+/// Bind a widget property to the observable named by a binding's path
+/// 
+/// Sets the property now, then keeps it up to date as the observable changes
+/// (unless the mode is `OneTime`). A `TwoWay` binding also writes changes the
+/// user makes back to the observable; text that does not parse as the
+/// observable's type is ignored. The binding lasts as long as the observable,
+/// and updates after the widget is dropped have no effect.
+/// 
+/// Fails if the binding has a `Source` (only the data context is supported),
+/// if its path or the property is unknown, or if the mode is not supported
+/// by the property.
+/// 
+/// ```no_run
+/// use luma_gui::prelude::*;
+/// use luma_gui::binding::{bind, BindingExtension, DataContext};
+/// 
+/// # fn main() -> Result<()> {
+/// let window = Window::builder().build()?;
+/// let mut label = Label::builder().build(&window)?;
+/// 
+/// let mut context = DataContext::new();
+/// let greeting = Observable::new(String::from("Hello"));
+/// context.insert("Greeting", greeting.clone());
+/// 
+/// let binding = BindingExtension { path: "Greeting".into(), mode: None, source: None };
+/// bind(&mut label, "Text", &binding, &context)?;
+/// greeting.set("Goodbye".into());
+/// # Ok(())
+/// # }
+/// ```
+pub fn bind(
+    widget: &mut impl Bindable,
+    property: &str,
+    binding: &BindingExtension,
+    context: &DataContext,
+) -> Result<()> {
+    if let Some(source) = &binding.source {
+        return Err(Error::InvalidParameter(format!("Unsupported binding source: {}", source)));
+    }
+    let mode = BindingMode::parse(binding.mode.as_deref())?;
+    let source = context
+        .entries
+        .get(&binding.path)
+        .map(|entry| Rc::clone(&entry.source))
+        .ok_or_else(|| Error::InvalidParameter(format!("No value named '{}' to bind to", binding.path)))?;
+    let write = widget
+        .property_writer(property)
+        .ok_or_else(|| Error::InvalidParameter(format!("Unknown bindable property: {}", property)))?;
+    
+    if mode == BindingMode::TwoWay {
+        let source = Rc::clone(&source);
+        widget.watch_property(property, Box::new(move |text| source.set_text(&text)))?;
+    }
+    
+    write(&source.text())?;
+    if mode != BindingMode::OneTime {
+        source.subscribe(Box::new(move |text| {
+            let _ = write(&text);
+        }));
+    }
+    
+    Ok(())
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use crate::widgets::{CheckBox, Label, TextInput};
+    use crate::Window;
+    
+    fn binding(path: &str, mode: Option<&str>) -> BindingExtension {
+        BindingExtension {
+            path: path.to_string(),
+            mode: mode.map(str::to_string),
+            source: None,
+        }
+    }
+    
+    #[test]
+    fn test_one_way_follows_observable() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder().build(&window).unwrap();
+        let count = Observable::new(1);
+        let mut context = DataContext::new();
+        context.insert("Count", count.clone());
+        
+        bind(&mut label, "Text", &binding("Count", None), &context).unwrap();
+        count.set(2);
+        
+        let handle = mock::children(&window)[0];
+        assert_eq!(mock::calls(handle)[1..], ["set_text(\"1\")", "set_text(\"2\")"]);
+        assert_eq!(context.get::<i32>("Count").unwrap().get(), 2);
+        assert!(context.get::<String>("Count").is_none());
+    }
+    
+    #[test]
+    fn test_bound_label_fits_new_text() {
+        use crate::{Application, LayoutConstraints, Widget};
+        
+        // The stub font is 7 pixels per character
+        let mut app = Application::new().unwrap();
+        let mut window = Window::builder().size(400, 100).build().unwrap();
+        let mut label = Label::builder().build(&window).unwrap();
+        let name = Observable::new(String::from("Ada"));
+        let mut context = DataContext::new();
+        context.insert("Name", name.clone());
+        bind(&mut label, "Text", &binding("Name", None), &context).unwrap();
+        
+        window.set_layout(crate::BoxLayout::horizontal()).unwrap();
+        let label = window.add_widget_with_handle(label, LayoutConstraints::default()).unwrap();
+        assert_eq!(label.borrow().get_bounds().width, 21);
+        
+        // The layout pass runs from the event loop, as after a message
+        name.set("Grace Hopper".into());
+        app.run().unwrap();
+        assert_eq!(label.borrow().get_bounds().width, 84);
+    }
+    
+    #[test]
+    fn test_two_way_writes_back() {
+        let window = Window::builder().build().unwrap();
+        let mut input = TextInput::builder().build(&window).unwrap();
+        let mut checkbox = CheckBox::builder().build(&window).unwrap();
+        let name = Observable::new(String::from("Ada"));
+        let agreed = Observable::new(false);
+        let mut context = DataContext::new();
+        context.insert("Name", name.clone());
+        context.insert("Agreed", agreed.clone());
+        
+        bind(&mut input, "Text", &binding("Name", Some("TwoWay")), &context).unwrap();
+        bind(&mut checkbox, "IsChecked", &binding("Agreed", Some("TwoWay")), &context).unwrap();
+        assert_eq!(input.get_text().unwrap(), "Ada");
+        
+        let widgets = mock::children(&window);
+        mock::type_text(widgets[0], "Grace").unwrap();
+        assert_eq!(name.get(), "Grace");
+        mock::click(widgets[1]);
+        assert!(agreed.get());
+        
+        name.set("Linus".into());
+        assert_eq!(input.get_text().unwrap(), "Linus");
+        agreed.set(false);
+        assert!(!checkbox.is_checked().unwrap());
+    }
+    
+    #[test]
+    fn test_placeholder_text_binds_one_way() {
+        let window = Window::builder().build().unwrap();
+        let mut input = TextInput::builder().show_placeholder_when_focused(true).build(&window).unwrap();
+        let hint = Observable::new(String::from("Your name here..."));
+        let mut context = DataContext::new();
+        context.insert("Hint", hint.clone());
+        
+        bind(&mut input, "PlaceholderText", &binding("Hint", None), &context).unwrap();
+        hint.set("Nickname".into());
+        
+        let handle = mock::children(&window)[0];
+        assert_eq!(
+            mock::calls(handle)[1..],
+            ["set_placeholder(\"Your name here...\", true)", "set_placeholder(\"Nickname\", true)"]
+        );
+        assert!(bind(&mut input, "PlaceholderText", &binding("Hint", Some("TwoWay")), &context).is_err());
+    }
+    
+    #[test]
+    fn test_invalid_bindings_fail() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder().build(&window).unwrap();
+        let mut context = DataContext::new();
+        context.insert("Name", Observable::new(String::new()));
+        
+        assert!(bind(&mut label, "Text", &binding("Missing", None), &context).is_err());
+        assert!(bind(&mut label, "Width", &binding("Name", None), &context).is_err());
+        assert!(bind(&mut label, "Text", &binding("Name", Some("TwoWay")), &context).is_err());
+        assert!(bind(&mut label, "Text", &binding("Name", Some("OneWayToSource")), &context).is_err());
+    }
+}
@@ -3,7 +3,36 @@
 use cfg_if::cfg_if;
 
 cfg_if! {
-    if #[cfg(windows)] {
+    if #[cfg(feature = "stub-backend")] {
+        // Headless stand-ins under the names the widgets expect; checked
+        // first so the stub (and `mock`) replaces Win32 on Windows too
+        mod stub;
+        pub(crate) use stub::{
+            StubApplication as Win32Application,
+            StubWindow as Win32Window,
+            StubButton as Win32Button,
+            StubLabel as Win32Label,
+            StubTextInput as Win32TextInput,
+            StubCheckBox as Win32CheckBox,
+            StubListBox as Win32ListBox,
+            StubListView as Win32ListView,
+            StubPanel as Win32Panel,
+            StubScrollViewer as Win32ScrollViewer,
+            StubNumberBox as Win32NumberBox,
+            StubDatePicker as Win32DatePicker,
+            StubImageView as Win32ImageView,
+            StubProgressBar as Win32ProgressBar,
+            StubHyperlink as Win32Hyperlink,
+            StubTrayIcon as Win32TrayIcon,
+            screen_bounds, with_deferred_positioning, system_theme, shell_open,
+            register_command_callback, unregister_command_callback,
+            register_widget_callback, unregister_widget_callback,
+            assign_control_id, release_control_id, request_layout,
+        };
+        
+        #[cfg(feature = "mock")]
+        pub use stub::mock;
+    } else if #[cfg(windows)] {
         pub(crate) use luma_windows::*;
     } else if #[cfg(target_os = "macos")] {
         compile_error!("macOS support not yet implemented");
@@ -16,19 +45,31 @@ cfg_if! {
 
 pub mod application;
 pub mod window;
+pub mod parent;
 pub mod widgets;
 pub mod prelude;
+pub mod tray;
+pub mod shell;
+#[cfg(feature = "binding")]
+pub mod binding;
 
 // Re-export main types at crate root for convenience
 pub use application::Application;
 pub use window::{Window, WindowBuilder};
+pub use parent::Parent;
+pub use tray::TrayIcon;
 
 // Re-export core types for convenience
 pub use luma_core::{
     Error, Result,
-    Point, Size, Rect,
-    WidgetId, WindowId,
-    WindowFlags, ButtonFlags, ListBoxFlags,
+    Point, Size, Rect, Dip, Px,
+    Color, CornerPreference, Backdrop, Theme, EllipsisMode, Icon,
+    Key, Modifiers, Accelerator,
+    Date, Stretch,
+    Widget, WidgetHandle, WidgetId, WindowId,
+    WidgetTree, WidgetKind,
+    Observable, SubscriptionId,
+    WindowFlags, WindowState, WindowPlacement, TaskbarProgressState, FlashMode, ButtonFlags, ListBoxFlags,
     Alignment, Padding, LayoutConstraints,
     BoxLayout, LayoutDirection,
 };
@@ -0,0 +1,14 @@
+use crate::window::Window;
+
+/// Something child widgets can be created in: a window or a container such as a panel
+pub trait Parent {
+    /// Get the raw handle that child widgets are created under
+    #[doc(hidden)]
+    fn raw_handle(&self) -> *mut std::ffi::c_void;
+}
+
+impl Parent for Window {
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        Window::raw_handle(self)
+    }
+}
@@ -3,9 +3,16 @@
 pub use crate::{
     Application,
     Window, WindowBuilder,
+    Parent, Widget, WidgetHandle,
+    WidgetTree, WidgetKind,
+    Observable,
+    TrayIcon,
     Error, Result,
     Point, Size, Rect,
-    WindowFlags, ButtonFlags, ListBoxFlags,
+    Color, CornerPreference, Backdrop, Theme, EllipsisMode, Icon,
+    Key, Modifiers, Accelerator,
+    Date, Stretch,
+    WindowFlags, WindowState, TaskbarProgressState, FlashMode, ButtonFlags, ListBoxFlags,
     Alignment, Padding, LayoutConstraints,
     BoxLayout, LayoutDirection,
 };
@@ -16,4 +23,12 @@ pub use crate::widgets::{
     TextInput, TextInputBuilder,
     CheckBox, CheckBoxBuilder,
     ListBox, ListBoxBuilder,
+    ListView, ListViewBuilder,
+    Panel, PanelBuilder,
+    ScrollViewer, ScrollViewerBuilder,
+    NumberBox, NumberBoxBuilder,
+    DatePicker, DatePickerBuilder,
+    ImageView, ImageViewBuilder,
+    ProgressBar, ProgressBarBuilder,
+    Hyperlink, HyperlinkBuilder,
 };
@@ -0,0 +1,20 @@
+use luma_core::{Result, Error};
+
+/// Open a file, folder or URL with its default handler
+/// 
+/// A URL opens in the default browser, a folder in the file manager and a
+/// file in the application associated with its type. Fails if there is no
+/// such handler or the target does not exist.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// luma_gui::shell::open("https://github.com/DaCodeChick/Luma")?;
+/// # Ok::<(), luma_gui::Error>(())
+/// ```
+pub fn open(path_or_url: &str) -> Result<()> {
+    if path_or_url.trim().is_empty() {
+        return Err(Error::InvalidParameter("Nothing to open".into()));
+    }
+    crate::shell_open(path_or_url)
+}
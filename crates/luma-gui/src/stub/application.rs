@@ -0,0 +1,48 @@
+use luma_core::{Result, Accelerator, traits::ApplicationBackend};
+use std::cell::Cell;
+
+thread_local! {
+    /// Whether a quit was requested and not yet consumed by `run`
+    static QUIT_POSTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Check whether a quit was requested since the last run
+#[cfg(any(test, feature = "mock"))]
+pub(super) fn quit_posted() -> bool {
+    QUIT_POSTED.with(Cell::get)
+}
+
+/// Stub application backend
+/// 
+/// There are no events to wait for, so [`run`](ApplicationBackend::run)
+/// only runs requested layout passes and returns, consuming any pending quit
+/// request.
+pub struct StubApplication;
+
+impl StubApplication {
+    /// Ask the event loop to exit
+    pub fn post_quit() {
+        QUIT_POSTED.with(|posted| posted.set(true));
+    }
+}
+
+impl ApplicationBackend for StubApplication {
+    fn new() -> Result<Self> {
+        Ok(Self)
+    }
+    
+    fn run(&mut self) -> Result<()> {
+        super::window::run_pending_layouts();
+        QUIT_POSTED.with(|posted| posted.set(false));
+        Ok(())
+    }
+    
+    fn quit(&mut self) -> Result<()> {
+        Self::post_quit();
+        Ok(())
+    }
+    
+    fn set_accelerators(&mut self, _accelerators: &[Accelerator]) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,800 @@
+// Call recording and simulated input for the stub backend
+//
+// Enabled with the `mock` feature, for testing code built on luma-gui
+// without a display. Every window and widget logs the backend calls made on
+// it, and tests can click buttons and checkboxes, type into text inputs,
+// select listbox items and list view rows, click column headers and links,
+// and trigger accelerators as a user would.
+
+use luma_core::Result;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use crate::parent::Parent;
+use luma_core::{Error, WidgetKind};
+use super::StubHandle;
+
+pub use super::StubHandle as MockHandle;
+
+thread_local! {
+    static CALLS: RefCell<HashMap<StubHandle, Vec<String>>> = RefCell::new(HashMap::new());
+}
+
+/// Append a call to a handle's log
+pub(super) fn record(handle: StubHandle, call: String) {
+    CALLS.with(|calls| calls.borrow_mut().entry(handle).or_default().push(call));
+}
+
+/// Get the handle of a window or container
+pub fn handle_of(parent: &impl Parent) -> MockHandle {
+    StubHandle::from_raw(parent.raw_handle())
+}
+
+/// Get the widgets created directly in a window or container, oldest first
+pub fn children(parent: &impl Parent) -> Vec<MockHandle> {
+    super::children(handle_of(parent))
+}
+
+/// Get the backend calls made on a window or widget, such as `set_text("Hi")`
+/// 
+/// The log outlives the widget, so calls made while it was dropped can be checked.
+pub fn calls(handle: MockHandle) -> Vec<String> {
+    CALLS.with(|calls| calls.borrow().get(&handle).cloned().unwrap_or_default())
+}
+
+/// Forget the calls recorded for a window or widget
+pub fn clear_calls(handle: MockHandle) {
+    CALLS.with(|calls| calls.borrow_mut().remove(&handle));
+}
+
+/// Click a button or checkbox, returning whether it has a click callback
+/// 
+/// A checkbox is toggled before its callback runs.
+pub fn click(handle: MockHandle) -> bool {
+    super::click(handle.0)
+}
+
+/// Replace the text of a text input as the user would, running its change callback
+/// 
+/// Characters the input's filter rejects are dropped, and the text is cut
+/// short at its maximum length. Fails if the handle is not a text input.
+pub fn type_text(handle: MockHandle, text: &str) -> Result<()> {
+    if super::node_kind(handle) != Some(WidgetKind::TextInput) {
+        return Err(Error::InvalidParameter(format!("Not a text input: {}", handle.0)));
+    }
+    let text = super::widgets::filter_input(handle, text);
+    super::widgets::set_input_text(handle, &super::widgets::truncate_input(handle, &text, 0));
+    Ok(())
+}
+
+/// Get the text last cut or copied from a text input
+pub fn clipboard() -> String {
+    super::widgets::clipboard_text()
+}
+
+/// Get the control ID of a widget, if it was given one for command dispatch
+pub fn control_id(handle: MockHandle) -> Option<u16> {
+    super::node_control_id(handle)
+}
+
+/// Send a command from a control ID as its control would, returning whether a widget handles it
+pub fn send_command(control_id: u16) -> bool {
+    super::send_command(control_id)
+}
+
+/// Get the files, folders and URLs opened with [`crate::shell::open`], oldest first
+pub fn opened() -> Vec<String> {
+    super::opened()
+}
+
+/// Check whether the application was asked to quit since it last ran
+pub fn quit_requested() -> bool {
+    super::application::quit_posted()
+}
+
+/// Trigger the accelerator command with an ID, returning whether it has a callback
+pub fn run_command(command_id: u16) -> bool {
+    super::run_command(command_id)
+}
+
+/// Select listbox items as the user would, running its selection callback
+/// 
+/// Pass a single index for a single-select listbox, or none to clear the
+/// selection. Fails if the handle is not a listbox or an index is out of range.
+pub fn select(handle: MockHandle, indices: &[usize]) -> Result<()> {
+    super::widgets::select_items(handle, indices)?;
+    super::notify(handle);
+    Ok(())
+}
+
+/// Double-click a listbox item as the user would, returning whether it had an activate callback
+/// 
+/// The first click selects the item, running the selection callback. Fails if
+/// the handle is not a listbox or the index is out of range.
+pub fn double_click(handle: MockHandle, index: usize) -> Result<bool> {
+    select(handle, &[index])?;
+    Ok(super::invoke(super::widgets::activate_callback(handle)?))
+}
+
+/// Select a list view row as the user would, running its selection callback
+/// 
+/// Pass `None` to clear the selection. Fails if the handle is not a list
+/// view or the index is out of range.
+pub fn select_row(handle: MockHandle, index: Option<usize>) -> Result<()> {
+    super::widgets::select_row(handle, index)?;
+    super::notify(handle);
+    Ok(())
+}
+
+/// Click a list view column header, returning whether it had a column click callback
+/// 
+/// Fails if the handle is not a list view or the column does not exist.
+pub fn click_column(handle: MockHandle, column: usize) -> Result<bool> {
+    let Some(callback) = super::widgets::column_click_callback(handle, column)? else {
+        return Ok(false);
+    };
+    // Safety: Callback owners clear them before they are dropped
+    luma_core::panic_guard::catch_callback_panic(|| unsafe { (*callback)(column) });
+    Ok(true)
+}
+
+/// Click a link of a hyperlink, returning whether it had a click callback
+/// 
+/// Links are numbered in the order they appear in the markup. Fails if the
+/// handle is not a hyperlink or the link does not exist.
+pub fn click_link(handle: MockHandle, index: usize) -> Result<bool> {
+    let Some(callback) = super::widgets::link_click_callback(handle, index)? else {
+        return Ok(false);
+    };
+    // Safety: Callback owners clear them before they are dropped
+    luma_core::panic_guard::catch_callback_panic(|| unsafe { (*callback)(index) });
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::{Button, Hyperlink, Label, ListBox, ListView, ProgressBar, compare_numeric};
+    use crate::Window;
+    use luma_core::{BoxLayout, Color, EllipsisMode, FlashMode, LayoutConstraints, Rect, Widget, WidgetKind, TaskbarProgressState, WindowPlacement, WindowState};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+    
+    #[test]
+    fn test_listbox_items_are_recorded() {
+        let window = Window::builder().title("Mock").size(300, 200).build().unwrap();
+        let _listbox = ListBox::builder().items(["one", "two"]).build(&window).unwrap();
+        
+        let widgets = children(&window);
+        assert_eq!(widgets.len(), 1);
+        assert_eq!(calls(widgets[0])[1..], ["add_item(\"one\")", "add_item(\"two\")"]);
+        assert!(calls(handle_of(&window))[0].starts_with("new(\"Mock\", 300, 200"));
+    }
+    
+    #[test]
+    fn test_select_fires_on_select_single() {
+        let window = Window::builder().build().unwrap();
+        let selected = Rc::new(Cell::new(None));
+        let sink = Rc::clone(&selected);
+        let listbox = ListBox::builder()
+            .items(["one", "two", "three"])
+            .on_select_single(move |index| sink.set(Some(index)))
+            .build(&window)
+            .unwrap();
+        let handle = children(&window)[0];
+        
+        select(handle, &[2]).unwrap();
+        assert_eq!(selected.get(), Some(Some(2)));
+        assert_eq!(listbox.get_selected_index().unwrap(), Some(2));
+        
+        select(handle, &[]).unwrap();
+        assert_eq!(selected.get(), Some(None));
+        
+        assert!(matches!(select(handle, &[3]), Err(Error::IndexOutOfRange { index: 3, len: 3 })));
+    }
+    
+    #[test]
+    fn test_widget_backends_share_surface() {
+        use crate::{Win32Button, Win32Label, Win32ListBox, Win32Panel, Win32ProgressBar};
+        use luma_core::traits::{
+            ButtonBackend, LabelBackend, ListBoxBackend, PanelBackend, ProgressBarBackend, WidgetBackend,
+        };
+        use luma_core::{ButtonFlags, ListBoxFlags, Point, Size};
+        
+        fn exercise(backend: &mut impl WidgetBackend) {
+            let handle = StubHandle::from_raw(backend.raw_handle());
+            backend.set_bounds(5, 10, 60, 30).unwrap();
+            backend.set_enabled(false).unwrap();
+            backend.set_visible(false).unwrap();
+            assert_eq!(super::super::node_bounds(handle), Some(Rect::new(5, 10, 60, 30)));
+            assert!(calls(handle).ends_with(&["set_enabled(false)".to_string(), "set_visible(false)".to_string()]));
+        }
+        
+        let window = Window::builder().build().unwrap();
+        let parent = handle_of(&window).as_raw();
+        let (pos, size) = (Point::new(0, 0), Size::new(10, 10));
+        exercise(&mut Win32Button::new(parent, "OK", pos, size, ButtonFlags::default()).unwrap());
+        exercise(&mut Win32Label::new(parent, "Name", pos, size).unwrap());
+        exercise(&mut Win32ListBox::new(parent, pos, size, ListBoxFlags::default()).unwrap());
+        exercise(&mut Win32Panel::new(parent, pos, size).unwrap());
+        exercise(&mut Win32ProgressBar::new(parent, pos, size).unwrap());
+    }
+    
+    #[test]
+    fn test_quit_button_ends_run() {
+        let mut app = crate::Application::new().unwrap();
+        let window = Window::builder().build().unwrap();
+        let _quit = Button::builder()
+            .label("&Quit")
+            .on_click(crate::Application::request_quit)
+            .build(&window)
+            .unwrap();
+        assert!(!quit_requested());
+        
+        assert!(click(children(&window)[0]));
+        assert!(quit_requested());
+        app.run().unwrap();
+        assert!(!quit_requested());
+    }
+    
+    #[test]
+    fn test_label_text_reads_back() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder().text("Clicked 0 times").build(&window).unwrap();
+        assert_eq!(label.get_text().unwrap(), "Clicked 0 times");
+        
+        label.set_text("Clicked 1 time").unwrap();
+        assert_eq!(label.get_text().unwrap(), "Clicked 1 time");
+        assert_eq!(calls(children(&window)[0]).last().unwrap(), "set_text(\"Clicked 1 time\")");
+    }
+    
+    #[test]
+    fn test_set_selected_indices_reads_back() {
+        let window = Window::builder().build().unwrap();
+        let mut listbox = ListBox::builder()
+            .items(["one", "two", "three"])
+            .multi_select(true)
+            .build(&window)
+            .unwrap();
+        
+        listbox.set_selected_indices(&[2, 0]).unwrap();
+        assert_eq!(listbox.get_selected_indices().unwrap(), [0, 2]);
+        assert!(matches!(
+            listbox.set_selected_indices(&[1, 3]),
+            Err(Error::IndexOutOfRange { index: 3, len: 3 })
+        ));
+        assert_eq!(listbox.get_selected_indices().unwrap(), [0, 2]);
+        listbox.set_selected_indices(&[]).unwrap();
+        assert!(listbox.get_selected_indices().unwrap().is_empty());
+        
+        let mut single = ListBox::builder().items(["one"]).build(&window).unwrap();
+        assert!(single.set_selected_indices(&[0]).is_err());
+    }
+    
+    #[test]
+    fn test_select_all_and_deselect_all() {
+        let window = Window::builder().build().unwrap();
+        let mut listbox = ListBox::builder()
+            .items(["one", "two", "three"])
+            .multi_select(true)
+            .build(&window)
+            .unwrap();
+        
+        listbox.select_all().unwrap();
+        assert_eq!(listbox.get_selected_indices().unwrap(), [0, 1, 2]);
+        listbox.deselect_all().unwrap();
+        assert!(listbox.get_selected_indices().unwrap().is_empty());
+        
+        let mut single = ListBox::builder().items(["one"]).build(&window).unwrap();
+        assert!(single.select_all().is_err());
+        assert!(single.deselect_all().is_err());
+    }
+    
+    #[test]
+    fn test_double_click_activates_item() {
+        let window = Window::builder().build().unwrap();
+        let activated = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&activated);
+        let _listbox = ListBox::builder()
+            .items(["one", "two", "three"])
+            .on_select_single(|_| {})
+            .on_activate(move |index| sink.borrow_mut().push(index))
+            .build(&window)
+            .unwrap();
+        let handle = children(&window)[0];
+        
+        assert!(double_click(handle, 1).unwrap());
+        assert!(double_click(handle, 2).unwrap());
+        assert_eq!(*activated.borrow(), [1, 2]);
+        assert!(double_click(handle, 3).is_err());
+        
+        let _plain = ListBox::builder().items(["one"]).build(&window).unwrap();
+        assert!(!double_click(children(&window)[1], 0).unwrap());
+    }
+    
+    #[test]
+    fn test_listbox_index_errors_are_structured() {
+        let window = Window::builder().build().unwrap();
+        let mut listbox = ListBox::builder().items(["one", "two"]).build(&window).unwrap();
+        
+        let error = listbox.remove_item(5).unwrap_err();
+        assert!(matches!(error, Error::IndexOutOfRange { index: 5, len: 2 }));
+        assert_eq!(error.to_string(), "Index 5 out of range for 2 items");
+        assert!(matches!(
+            listbox.set_selected_index(Some(2)),
+            Err(Error::IndexOutOfRange { index: 2, len: 2 })
+        ));
+        
+        listbox.remove_item(1).unwrap();
+        assert!(matches!(listbox.remove_item(1), Err(Error::IndexOutOfRange { index: 1, len: 1 })));
+    }
+    
+    #[test]
+    fn test_command_reaches_widget_by_control_id() {
+        let window = Window::builder().build().unwrap();
+        let clicked = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&clicked);
+        let _plain = Button::builder().label("Plain").build(&window).unwrap();
+        let button = Button::builder()
+            .label("Counted")
+            .on_click(move || counter.set(counter.get() + 1))
+            .build(&window)
+            .unwrap();
+        
+        let widgets = children(&window);
+        let (plain_id, button_id) = (control_id(widgets[0]).unwrap(), control_id(widgets[1]).unwrap());
+        assert_ne!(plain_id, button_id);
+        
+        assert!(send_command(button_id));
+        assert!(!send_command(plain_id));
+        assert_eq!(clicked.get(), 1);
+        
+        drop(button);
+        assert!(!send_command(button_id));
+        assert_eq!(clicked.get(), 1);
+    }
+    
+    #[test]
+    fn test_setter_calls_are_recorded() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder().text("Before").build(&window).unwrap();
+        let mut button = Button::builder().label("OK").build(&window).unwrap();
+        label.set_text("After").unwrap();
+        button.set_enabled(false).unwrap();
+        
+        let widgets = children(&window);
+        assert_eq!(calls(widgets[0]), ["new(\"Before\")", "set_text(\"After\")"]);
+        assert_eq!(calls(widgets[1])[1..], ["set_enabled(false)"]);
+        
+        clear_calls(widgets[0]);
+        assert!(calls(widgets[0]).is_empty());
+        assert!(!click(widgets[1]));
+    }
+    
+    #[test]
+    fn test_widget_colors_are_recorded() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder().text("Dark").text_color(Color::WHITE).build(&window).unwrap();
+        label.set_background(Some(Color::BLACK)).unwrap();
+        label.set_text_color(None);
+        
+        let handle = children(&window)[0];
+        assert_eq!(calls(handle)[1..], [
+            format!("set_text_color({:?})", Some(Color::WHITE)),
+            format!("set_background({:?})", Some(Color::BLACK)),
+            "set_text_color(None)".to_string(),
+        ]);
+    }
+    
+    #[test]
+    fn test_label_ellipsis_is_recorded() {
+        let window = Window::builder().build().unwrap();
+        let mut label = Label::builder()
+            .text(r"C:\Users\Ada\Documents\Reports\2024\summary.docx")
+            .ellipsize(EllipsisMode::Path)
+            .build(&window)
+            .unwrap();
+        let _plain = Label::builder().build(&window).unwrap();
+        label.set_ellipsis(EllipsisMode::None);
+        
+        let widgets = children(&window);
+        assert_eq!(calls(widgets[0])[1..], ["set_ellipsis(Path)", "set_ellipsis(None)"]);
+        assert_eq!(calls(widgets[1]).len(), 1);
+    }
+    
+    #[test]
+    fn test_progress_bar_keeps_value_through_marquee() {
+        let window = Window::builder().build().unwrap();
+        let mut bar = ProgressBar::builder().range(0, 10).value(4).build(&window).unwrap();
+        
+        bar.set_indeterminate(true).unwrap();
+        bar.set_marquee_interval(Duration::from_millis(50)).unwrap();
+        bar.set_value(25).unwrap();
+        bar.set_indeterminate(false).unwrap();
+        assert!(!bar.is_indeterminate());
+        assert_eq!(bar.value().unwrap(), 10);
+        assert!(bar.set_range(5, 1).is_err());
+        
+        let widgets = children(&window);
+        assert_eq!(calls(widgets[0]), [
+            "set_range(0, 10)",
+            "set_value(4)",
+            "set_indeterminate(true, 30ms)",
+            "set_indeterminate(true, 50ms)",
+            "set_value(25)",
+            "set_indeterminate(false, 50ms)",
+        ]);
+    }
+    
+    #[test]
+    fn test_link_clicks_report_urls() {
+        let window = Window::builder().build().unwrap();
+        let clicked = Rc::new(RefCell::new(Vec::new()));
+        let for_click = Rc::clone(&clicked);
+        let mut link = Hyperlink::builder()
+            .text(r#"Read the <a id="p" HREF='https://example.com/privacy'>privacy policy</a> or <A>help</A>"#)
+            .on_click(move |url| for_click.borrow_mut().push(url.to_string()))
+            .build(&window)
+            .unwrap();
+        let handle = children(&window)[0];
+        
+        assert!(click_link(handle, 1).unwrap());
+        assert!(click_link(handle, 0).unwrap());
+        assert!(click_link(handle, 2).is_err());
+        link.set_text(r#"<abbr>FAQ</abbr> <a href="https://example.com/faq">FAQ</a>"#).unwrap();
+        assert!(click_link(handle, 0).unwrap());
+        assert_eq!(*clicked.borrow(), ["help", "https://example.com/privacy", "https://example.com/faq"]);
+    }
+    
+    #[test]
+    fn test_shell_open_records_targets() {
+        crate::shell::open("https://example.com").unwrap();
+        crate::shell::open(r"C:\Users\Public").unwrap();
+        assert!(crate::shell::open("  ").is_err());
+        assert_eq!(opened(), ["https://example.com", r"C:\Users\Public"]);
+    }
+    
+    #[test]
+    fn test_centered_window_starts_maximized() {
+        let mut window = Window::builder()
+            .size(400, 300)
+            .position(5, 5)
+            .centered(true)
+            .start_state(WindowState::Maximized)
+            .build()
+            .unwrap();
+        assert_eq!(window.get_screen_bounds().unwrap(), Rect::new(760, 370, 400, 300));
+        
+        window.show().unwrap();
+        window.hide().unwrap();
+        window.show().unwrap();
+        window.set_state(WindowState::Normal).unwrap();
+        let calls = calls(handle_of(&window));
+        assert_eq!(calls[calls.len() - 4..], ["show(Maximized)", "hide()", "show()", "set_state(Normal)"]);
+    }
+    
+    #[test]
+    fn test_click_through_overlay() {
+        let mut window = Window::builder().size(200, 80).build().unwrap();
+        window.set_click_through(true).unwrap();
+        window.set_interactive_region(Some(&[Rect::new(150, 0, 50, 20)])).unwrap();
+        window.set_interactive_region(None).unwrap();
+        
+        let calls = calls(handle_of(&window));
+        assert_eq!(calls[calls.len() - 3..], [
+            "set_click_through(true)",
+            "set_interactive_region(Some([Rect { x: 150, y: 0, width: 50, height: 20 }]))",
+            "set_interactive_region(None)",
+        ]);
+    }
+    
+    #[test]
+    fn test_state_changes_are_reported_once() {
+        let states = Rc::new(RefCell::new(Vec::new()));
+        let for_change = Rc::clone(&states);
+        let mut window = Window::builder()
+            .start_state(WindowState::Minimized)
+            .on_state_changed(move |state| for_change.borrow_mut().push(state))
+            .build()
+            .unwrap();
+        
+        window.show().unwrap();
+        assert!(window.is_minimized());
+        window.set_state(WindowState::Maximized).unwrap();
+        window.set_state(WindowState::Maximized).unwrap();
+        assert!(window.is_maximized() && !window.is_minimized());
+        window.set_state(WindowState::Normal).unwrap();
+        assert!(!window.is_maximized());
+        assert_eq!(*states.borrow(), [WindowState::Minimized, WindowState::Maximized, WindowState::Normal]);
+    }
+    
+    #[test]
+    fn test_placement_reapplies_to_new_window() {
+        let mut window = Window::builder().size(500, 400).position(30, 40).build().unwrap();
+        window.show().unwrap();
+        window.set_state(WindowState::Maximized).unwrap();
+        let saved = window.get_placement().unwrap();
+        assert_eq!(saved, WindowPlacement::new(WindowState::Maximized, Rect::new(30, 40, 500, 400)));
+        drop(window);
+        
+        let mut window = Window::builder().build().unwrap();
+        window.set_placement(saved).unwrap();
+        assert!(!window.is_maximized());
+        window.show().unwrap();
+        assert!(window.is_maximized());
+        assert_eq!(window.get_placement().unwrap(), saved);
+    }
+    
+    #[test]
+    fn test_buttons_size_to_their_labels() {
+        let mut window = Window::builder().size(400, 100).build().unwrap();
+        let mut layout = BoxLayout::horizontal();
+        for label in ["OK", "&Cancel"] {
+            let button = Button::builder().label(label).build(&window).unwrap();
+            layout.add(Box::new(button), LayoutConstraints::default().preferred_height(25));
+        }
+        window.set_layout(layout).unwrap();
+        
+        let tree = window.widget_tree().unwrap();
+        let widths: Vec<u32> = tree.find_all(&WidgetKind::Button).iter().map(|b| b.bounds.width).collect();
+        // Two and six characters of the stub font, plus padding; the mnemonic marker takes no space
+        assert_eq!(widths, [2 * 7 + 24, 6 * 7 + 24]);
+    }
+    
+    #[test]
+    fn test_list_view_rows_and_callbacks() {
+        let window = Window::builder().build().unwrap();
+        let selected = Rc::new(Cell::new(None));
+        let clicked = Rc::new(Cell::new(None));
+        let (select_sink, click_sink) = (Rc::clone(&selected), Rc::clone(&clicked));
+        let mut table = ListView::builder()
+            .column("Name", 120)
+            .column("Email", 160)
+            .row(["Ada", "ada@example.com"])
+            .on_select(move |index| select_sink.set(Some(index)))
+            .on_column_click(move |column| click_sink.set(Some(column)))
+            .build(&window)
+            .unwrap();
+        table.add_row(&["Grace"]).unwrap();
+        assert!(table.add_row(&["Alan", "alan@example.com", "UK"]).is_err());
+        
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(table.row_count().unwrap(), 2);
+        assert_eq!(table.get_row(0).unwrap(), ["Ada", "ada@example.com"]);
+        assert_eq!(table.get_row(1).unwrap(), ["Grace", ""]);
+        
+        let handle = children(&window)[0];
+        select_row(handle, Some(1)).unwrap();
+        assert_eq!(selected.get(), Some(Some(1)));
+        assert_eq!(table.get_selected_index().unwrap(), Some(1));
+        assert!(select_row(handle, Some(2)).is_err());
+        
+        assert!(click_column(handle, 1).unwrap());
+        assert_eq!(clicked.get(), Some(1));
+        assert!(click_column(handle, 2).is_err());
+        
+        table.remove_row(0).unwrap();
+        assert_eq!(table.get_selected_index().unwrap(), Some(0));
+    }
+    
+    #[test]
+    fn test_list_view_sorts_by_clicked_column() {
+        let window = Window::builder().build().unwrap();
+        let mut table = ListView::builder()
+            .column("Name", 120)
+            .column("Age", 60)
+            .row(["Ada", "36"])
+            .row(["Grace", "9"])
+            .row(["Alan", "10"])
+            .sortable(true)
+            .comparator(1, compare_numeric)
+            .build(&window)
+            .unwrap();
+        table.set_selected_index(Some(0)).unwrap();
+        let names = |table: &ListView| -> Vec<String> {
+            (0..table.row_count().unwrap()).map(|i| table.get_row(i).unwrap()[0].clone()).collect()
+        };
+        
+        let handle = children(&window)[0];
+        click_column(handle, 1).unwrap();
+        assert_eq!(names(&table), ["Grace", "Alan", "Ada"]);
+        assert_eq!(table.sorted_by(), Some((1, true)));
+        assert_eq!(table.get_selected_index().unwrap(), Some(2));
+        
+        click_column(handle, 1).unwrap();
+        assert_eq!(names(&table), ["Ada", "Alan", "Grace"]);
+        assert_eq!(table.sorted_by(), Some((1, false)));
+        
+        // Without a comparator, cells compare as text
+        table.sort_by_column(0, true).unwrap();
+        assert_eq!(names(&table), ["Ada", "Alan", "Grace"]);
+        table.set_column_comparator(0, |a, b| b.cmp(a));
+        table.sort_by_column(0, true).unwrap();
+        assert_eq!(names(&table), ["Grace", "Alan", "Ada"]);
+        assert!(table.sort_by_column(2, true).is_err());
+    }
+    
+    #[test]
+    fn test_text_input_selection_and_insert() {
+        use crate::widgets::TextInput;
+        
+        let window = Window::builder().build().unwrap();
+        let changes = Rc::new(Cell::new(0));
+        let sink = Rc::clone(&changes);
+        let mut input = TextInput::builder()
+            .text("Hello world")
+            .on_text_changed(move |_| sink.set(sink.get() + 1))
+            .build(&window)
+            .unwrap();
+        
+        input.select_all().unwrap();
+        assert_eq!(input.get_selection().unwrap(), (0, 11));
+        
+        // Backwards selections read back start first
+        input.set_selection(11, 6).unwrap();
+        assert_eq!(input.get_selection().unwrap(), (6, 11));
+        input.insert_text_at_caret("there").unwrap();
+        assert_eq!(input.get_text().unwrap(), "Hello there");
+        assert_eq!(input.get_selection().unwrap(), (11, 11));
+        
+        input.set_caret(5).unwrap();
+        input.insert_text_at_caret(",").unwrap();
+        assert_eq!(input.get_text().unwrap(), "Hello, there");
+        assert_eq!(input.get_selection().unwrap(), (6, 6));
+        assert_eq!(changes.get(), 2);
+        
+        let handle = children(&window)[0];
+        assert_eq!(calls(handle)[3], "set_selection(11, 6)");
+        
+        // Offsets count characters, not bytes
+        type_text(handle, "héllo").unwrap();
+        input.set_caret(usize::MAX).unwrap();
+        input.insert_text_at_caret("!").unwrap();
+        assert_eq!(input.get_text().unwrap(), "héllo!");
+    }
+    
+    #[test]
+    fn test_text_input_undo_and_clipboard() {
+        use crate::widgets::TextInput;
+        
+        let window = Window::builder().build().unwrap();
+        let mut input = TextInput::builder().text("copy me").build(&window).unwrap();
+        assert!(!input.can_undo().unwrap());
+        
+        input.set_selection(0, 4).unwrap();
+        input.cut().unwrap();
+        assert_eq!(clipboard(), "copy");
+        assert_eq!(input.get_text().unwrap(), " me");
+        assert!(input.can_undo().unwrap());
+        
+        // Undoing twice redoes, like the native control
+        input.undo().unwrap();
+        assert_eq!(input.get_text().unwrap(), "copy me");
+        input.undo().unwrap();
+        assert_eq!(input.get_text().unwrap(), " me");
+        
+        input.set_caret(usize::MAX).unwrap();
+        input.paste().unwrap();
+        assert_eq!(input.get_text().unwrap(), " mecopy");
+        
+        // Copying with nothing selected leaves the clipboard alone
+        input.copy().unwrap();
+        assert_eq!(clipboard(), "copy");
+        
+        input.set_text("fresh").unwrap();
+        assert!(!input.can_undo().unwrap());
+        assert!(!input.has_focus());
+        assert_eq!(calls(children(&window)[0])[3..6], ["cut()", "undo()", "undo()"]);
+    }
+    
+    #[test]
+    fn test_char_filter_drops_typed_and_pasted_characters() {
+        use crate::widgets::TextInput;
+        
+        let window = Window::builder().build().unwrap();
+        let mut source = TextInput::builder().text("(555) 123-4567\nsecond line").build(&window).unwrap();
+        let mut phone = TextInput::builder()
+            .char_filter(|c| c.is_ascii_digit() || c == '-')
+            .build(&window)
+            .unwrap();
+        let age = TextInput::builder().numeric().text("12a").build(&window).unwrap();
+        let handles = children(&window);
+        
+        type_text(handles[1], "555-12x3").unwrap();
+        assert_eq!(phone.get_text().unwrap(), "555-123");
+        
+        // Pasting keeps the allowed characters of the first line
+        source.select_all().unwrap();
+        source.copy().unwrap();
+        phone.set_text("").unwrap();
+        phone.paste().unwrap();
+        assert_eq!(phone.get_text().unwrap(), "555123-4567");
+        
+        // Text set in code is not filtered
+        assert_eq!(age.get_text().unwrap(), "12a");
+        type_text(handles[2], "4 2").unwrap();
+        assert_eq!(age.get_text().unwrap(), "42");
+        assert_eq!(calls(handles[1])[1], "set_char_filter(Some(..))");
+    }
+    
+    #[test]
+    fn test_max_length_caps_user_input() {
+        use crate::widgets::TextInput;
+        
+        let window = Window::builder().build().unwrap();
+        let mut code = TextInput::builder().max_length(5).build(&window).unwrap();
+        let handle = children(&window)[0];
+        
+        type_text(handle, "ABCDEFG").unwrap();
+        assert_eq!(code.get_text().unwrap(), "ABCDE");
+        
+        // Replacing a selection only has room for what it frees up
+        code.set_selection(1, 3).unwrap();
+        code.insert_text_at_caret("xyz").unwrap();
+        assert_eq!(code.get_text().unwrap(), "AxyDE");
+        
+        // Text set in code is not cut short, and the limit can be lifted
+        code.set_text("longer than five").unwrap();
+        assert_eq!(code.get_text().unwrap(), "longer than five");
+        code.set_max_length(None).unwrap();
+        type_text(handle, "ABCDEFG").unwrap();
+        assert_eq!(code.get_text().unwrap(), "ABCDEFG");
+        
+        assert!(code.set_max_length(Some(0)).is_err());
+        assert!(TextInput::builder().max_length(0).build(&window).is_err());
+    }
+    
+    #[test]
+    fn test_placeholder_is_recorded() {
+        use crate::widgets::TextInput;
+        
+        let window = Window::builder().build().unwrap();
+        let mut input = TextInput::builder().placeholder("Your name here...").build(&window).unwrap();
+        input.set_placeholder("", true).unwrap();
+        
+        let handle = children(&window)[0];
+        assert_eq!(calls(handle)[1..], ["set_placeholder(\"Your name here...\", false)", "set_placeholder(\"\", true)"]);
+    }
+    
+    #[test]
+    fn test_window_title_round_trips() {
+        let mut window = Window::builder().title("First").build().unwrap();
+        assert_eq!(window.title().unwrap(), "First");
+        
+        window.set_title("Grüße – 2").unwrap();
+        assert_eq!(window.title().unwrap(), "Grüße – 2");
+        window.set_title("").unwrap();
+        assert_eq!(window.title().unwrap(), "");
+    }
+    
+    #[test]
+    fn test_taskbar_progress_is_recorded() {
+        let mut window = Window::builder().build().unwrap();
+        window.set_taskbar_progress(Some((30, 100))).unwrap();
+        window.set_taskbar_state(TaskbarProgressState::Paused).unwrap();
+        window.set_taskbar_progress(None).unwrap();
+        
+        let calls = calls(handle_of(&window));
+        assert!(calls.contains(&"set_taskbar_progress(Some((30, 100)))".to_string()));
+        assert!(calls.contains(&"set_taskbar_state(Paused)".to_string()));
+        assert!(calls.contains(&"set_taskbar_progress(None)".to_string()));
+    }
+    
+    #[test]
+    fn test_flash_is_recorded_and_checks_count() {
+        let mut window = Window::builder().build().unwrap();
+        window.flash(FlashMode::Tray(Some(3))).unwrap();
+        window.flash(FlashMode::All(None)).unwrap();
+        window.flash(FlashMode::Stop).unwrap();
+        window.flash_until_focus().unwrap();
+        assert!(matches!(window.flash(FlashMode::Caption(Some(0))), Err(Error::InvalidParameter(_))));
+        
+        let calls = calls(handle_of(&window));
+        assert!(calls.contains(&"flash(Tray(Some(3)))".to_string()));
+        assert!(calls.contains(&"flash(All(None))".to_string()));
+        assert!(calls.contains(&"flash(Stop)".to_string()));
+        assert!(calls.contains(&"flash_until_focus()".to_string()));
+        assert!(!calls.contains(&"flash(Caption(Some(0)))".to_string()));
+        assert!(!window.is_active());
+    }
+}
@@ -0,0 +1,576 @@
+// Headless stub backend for platforms without a native backend
+//
+// Enabled with the `stub-backend` feature, which takes the place of the
+// native backend where there is one. Windows are never displayed and widgets
+// keep their state in memory, so code built on luma-gui compiles and runs
+// (for tests and examples) on any platform.
+
+mod application;
+mod window;
+mod widgets;
+mod tray;
+#[cfg(feature = "mock")]
+pub mod mock;
+
+pub use application::StubApplication;
+pub use window::{StubWindow, request_layout};
+pub use widgets::{
+    StubButton, StubLabel, StubTextInput, StubCheckBox, StubListBox, StubListView,
+    StubPanel, StubScrollViewer, StubNumberBox, StubDatePicker, StubImageView, StubProgressBar,
+    StubHyperlink,
+};
+pub use tray::StubTrayIcon;
+
+use luma_core::{Result, Error, Rect, Theme, WidgetTree, WidgetKind, WidgetId};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// Handle of a stub window or widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StubHandle(pub isize);
+
+impl StubHandle {
+    /// Get the handle as the raw pointer passed to child widgets
+    pub fn as_raw(self) -> *mut std::ffi::c_void {
+        self.0 as *mut std::ffi::c_void
+    }
+    
+    fn from_raw(raw: *mut std::ffi::c_void) -> Self {
+        Self(raw as isize)
+    }
+}
+
+/// A window or widget in the stub tree
+struct Node {
+    parent: Option<StubHandle>,
+    kind: WidgetKind,
+    text: String,
+    /// Bounds relative to the parent (screen coordinates for top-level windows)
+    bounds: Rect,
+    /// Control ID identifying the widget in command dispatch
+    control_id: Option<u16>,
+}
+
+/// First control ID handed out, as in the Win32 backend
+const FIRST_CONTROL_ID: u16 = 0x0100;
+
+thread_local! {
+    static NODES: RefCell<HashMap<StubHandle, Node>> = RefCell::new(HashMap::new());
+    static NEXT_HANDLE: Cell<isize> = const { Cell::new(1) };
+    static CALLBACKS: RefCell<HashMap<isize, *mut dyn FnMut()>> = RefCell::new(HashMap::new());
+    static COMMAND_CALLBACKS: RefCell<HashMap<u16, *mut dyn FnMut()>> = RefCell::new(HashMap::new());
+    static CONTROL_IDS: RefCell<HashMap<WidgetId, u16>> = RefCell::new(HashMap::new());
+    static NEXT_CONTROL_ID: Cell<u16> = const { Cell::new(FIRST_CONTROL_ID) };
+    static WIDGET_CALLBACKS: RefCell<HashMap<WidgetId, *mut dyn FnMut()>> = RefCell::new(HashMap::new());
+}
+
+/// Add a node under `parent` (null for a top-level window)
+fn create_node(parent: *mut std::ffi::c_void, kind: WidgetKind, bounds: Rect) -> StubHandle {
+    let handle = StubHandle(NEXT_HANDLE.with(|next| next.replace(next.get() + 1)));
+    let parent = (!parent.is_null()).then(|| StubHandle::from_raw(parent));
+    let node = Node { parent, kind, text: String::new(), bounds, control_id: None };
+    NODES.with(|nodes| nodes.borrow_mut().insert(handle, node));
+    handle
+}
+
+/// Set the text a node shows in its widget tree
+fn set_node_text(handle: StubHandle, text: impl Into<String>) {
+    NODES.with(|nodes| {
+        if let Some(node) = nodes.borrow_mut().get_mut(&handle) {
+            node.text = text.into();
+        }
+    });
+}
+
+/// Get the text of a node
+fn node_text(handle: StubHandle) -> String {
+    NODES.with(|nodes| nodes.borrow().get(&handle).map(|node| node.text.clone()).unwrap_or_default())
+}
+
+/// Get the kind of widget a node is, or `None` if it has been destroyed
+#[cfg(feature = "mock")]
+fn node_kind(handle: StubHandle) -> Option<WidgetKind> {
+    NODES.with(|nodes| nodes.borrow().get(&handle).map(|node| node.kind.clone()))
+}
+
+/// Move a node within its parent
+fn set_node_bounds(handle: StubHandle, bounds: Rect) {
+    NODES.with(|nodes| {
+        if let Some(node) = nodes.borrow_mut().get_mut(&handle) {
+            node.bounds = bounds;
+        }
+    });
+}
+
+/// Get the bounds of a node relative to its parent
+fn node_bounds(handle: StubHandle) -> Option<Rect> {
+    NODES.with(|nodes| nodes.borrow().get(&handle).map(|node| node.bounds))
+}
+
+/// Get the top-level window a node is in (the node itself for a window)
+fn root_node(handle: StubHandle) -> StubHandle {
+    NODES.with(|nodes| {
+        let nodes = nodes.borrow();
+        let mut root = handle;
+        while let Some(parent) = nodes.get(&root).and_then(|node| node.parent) {
+            root = parent;
+        }
+        root
+    })
+}
+
+/// Get the direct children of a node, oldest first
+fn children(handle: StubHandle) -> Vec<StubHandle> {
+    let mut children: Vec<StubHandle> = NODES.with(|nodes| {
+        nodes
+            .borrow()
+            .iter()
+            .filter(|(_, node)| node.parent == Some(handle))
+            .map(|(child, _)| *child)
+            .collect()
+    });
+    children.sort_by_key(|child| child.0);
+    children
+}
+
+/// Remove the descendants of a node and their callbacks, leaving the node itself
+fn destroy_children(handle: StubHandle) {
+    let removed = NODES.with(|nodes| {
+        let mut nodes = nodes.borrow_mut();
+        let mut removed = Vec::new();
+        let mut pending = vec![handle];
+        while let Some(parent) = pending.pop() {
+            let children: Vec<StubHandle> = nodes
+                .iter()
+                .filter(|(_, node)| node.parent == Some(parent))
+                .map(|(child, _)| *child)
+                .collect();
+            for child in children {
+                nodes.remove(&child);
+                pending.push(child);
+                removed.push(child);
+            }
+        }
+        removed
+    });
+    
+    for child in removed {
+        unregister_callback(child.0);
+    }
+}
+
+/// Remove a node and its descendants
+fn destroy_node(handle: StubHandle) {
+    destroy_children(handle);
+    NODES.with(|nodes| nodes.borrow_mut().remove(&handle));
+}
+
+/// Get the bounds of a window or widget in screen coordinates
+pub fn screen_bounds(handle: StubHandle) -> Result<Rect> {
+    NODES.with(|nodes| {
+        let nodes = nodes.borrow();
+        let node = nodes
+            .get(&handle)
+            .ok_or_else(|| Error::InvalidParameter(format!("Unknown handle: {}", handle.0)))?;
+        
+        let mut bounds = node.bounds;
+        let mut parent = node.parent;
+        while let Some(ancestor) = parent.and_then(|handle| nodes.get(&handle)) {
+            bounds.x += ancestor.bounds.x;
+            bounds.y += ancestor.bounds.y;
+            parent = ancestor.parent;
+        }
+        Ok(bounds)
+    })
+}
+
+/// Describe a window or widget and everything inside it
+pub fn widget_tree(handle: StubHandle) -> Result<WidgetTree> {
+    let bounds = screen_bounds(handle)?;
+    let (kind, text) = NODES.with(|nodes| {
+        let node = &nodes.borrow()[&handle];
+        (node.kind.clone(), node.text.clone())
+    });
+    
+    // A scroll viewer's only child is the canvas holding its content
+    let children = if kind == WidgetKind::ScrollViewer {
+        children(handle).into_iter().flat_map(children).collect()
+    } else {
+        children(handle)
+    };
+    
+    Ok(WidgetTree {
+        kind,
+        text,
+        bounds,
+        children: children.into_iter().map(widget_tree).collect::<Result<_>>()?,
+    })
+}
+
+/// Run `f`; widgets move immediately, so there is nothing to batch
+pub fn with_deferred_positioning<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Get the system theme; there is none, so this is always light
+pub fn system_theme() -> Theme {
+    Theme::Light
+}
+
+thread_local! {
+    /// Everything passed to `shell_open`, oldest first
+    static OPENED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Record the target instead of launching its handler
+pub fn shell_open(target: &str) -> Result<()> {
+    OPENED.with(|opened| opened.borrow_mut().push(target.to_string()));
+    Ok(())
+}
+
+/// Get the targets opened with `shell_open`, oldest first
+#[cfg(any(test, feature = "mock"))]
+pub fn opened() -> Vec<String> {
+    OPENED.with(|opened| opened.borrow().clone())
+}
+
+/// Register a click callback for a widget
+pub fn register_callback(hwnd: isize, callback: *mut dyn FnMut()) {
+    CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert(hwnd, callback));
+}
+
+/// Unregister a widget's click callback
+pub fn unregister_callback(hwnd: isize) {
+    CALLBACKS.with(|callbacks| callbacks.borrow_mut().remove(&hwnd));
+}
+
+/// Register the callback for an accelerator command ID
+pub fn register_command_callback(command_id: u16, callback: *mut dyn FnMut()) {
+    COMMAND_CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert(command_id, callback));
+}
+
+/// Unregister the callback for an accelerator command ID
+pub fn unregister_command_callback(command_id: u16) {
+    COMMAND_CALLBACKS.with(|callbacks| callbacks.borrow_mut().remove(&command_id));
+}
+
+/// Give a widget node the widget's control ID, allocating one if the widget has none
+/// 
+/// IDs are not recycled once released; the stub never runs out in practice.
+pub fn assign_control_id(hwnd: isize, widget: WidgetId) -> Result<u16> {
+    let id = CONTROL_IDS.with(|ids| {
+        *ids.borrow_mut()
+            .entry(widget)
+            .or_insert_with(|| NEXT_CONTROL_ID.with(|next| next.replace(next.get().wrapping_add(1))))
+    });
+    NODES.with(|nodes| {
+        if let Some(node) = nodes.borrow_mut().get_mut(&StubHandle(hwnd)) {
+            node.control_id = Some(id);
+        }
+    });
+    Ok(id)
+}
+
+/// Free a widget's control ID
+pub fn release_control_id(widget: WidgetId) {
+    CONTROL_IDS.with(|ids| ids.borrow_mut().remove(&widget));
+}
+
+/// Register a callback for a widget, reached through its control ID
+/// 
+/// Stub controls send no notification codes, so `notification` is ignored.
+pub fn register_widget_callback(widget: WidgetId, _notification: Option<u32>, callback: *mut dyn FnMut()) {
+    WIDGET_CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert(widget, callback));
+}
+
+/// Unregister the callback for a widget
+pub fn unregister_widget_callback(widget: WidgetId) {
+    WIDGET_CALLBACKS.with(|callbacks| callbacks.borrow_mut().remove(&widget));
+}
+
+/// Get the control ID assigned to a node
+fn node_control_id(handle: StubHandle) -> Option<u16> {
+    NODES.with(|nodes| nodes.borrow().get(&handle).and_then(|node| node.control_id))
+}
+
+/// Find the callback for a control ID: control ID, then widget ID, then handler
+fn widget_command_callback(control_id: u16) -> Option<*mut dyn FnMut()> {
+    let widget = CONTROL_IDS.with(|ids| {
+        ids.borrow().iter().find(|(_, &id)| id == control_id).map(|(widget, _)| *widget)
+    })?;
+    WIDGET_CALLBACKS.with(|callbacks| callbacks.borrow().get(&widget).copied())
+}
+
+/// Add a call to the mock call log of a window or widget
+#[cfg_attr(not(feature = "mock"), allow(unused_variables))]
+fn record(handle: StubHandle, call: impl FnOnce() -> String) {
+    #[cfg(feature = "mock")]
+    mock::record(handle, call());
+}
+
+/// Click a widget as the user would, returning whether it had a click callback
+/// 
+/// Checkboxes toggle before their callback runs, like `BS_AUTOCHECKBOX`.
+#[cfg(any(test, feature = "mock"))]
+pub fn click(hwnd: isize) -> bool {
+    widgets::toggle_checkbox(StubHandle(hwnd));
+    notify(StubHandle(hwnd))
+}
+
+/// Run the callback for an accelerator command ID, returning whether it had one
+#[cfg(any(test, feature = "mock"))]
+pub fn run_command(command_id: u16) -> bool {
+    let callback = COMMAND_CALLBACKS.with(|callbacks| callbacks.borrow().get(&command_id).copied());
+    invoke(callback)
+}
+
+/// Run the callback for a control's command as WM_COMMAND would, returning whether it had one
+#[cfg(any(test, feature = "mock"))]
+pub fn send_command(control_id: u16) -> bool {
+    invoke(widget_command_callback(control_id))
+}
+
+/// Run the callback registered for a widget, as its control would on a change
+/// 
+/// Widgets with a control ID are dispatched through it, others by handle.
+fn notify(handle: StubHandle) -> bool {
+    let callback = node_control_id(handle)
+        .and_then(widget_command_callback)
+        .or_else(|| CALLBACKS.with(|callbacks| callbacks.borrow().get(&handle.0).copied()));
+    invoke(callback)
+}
+
+fn invoke(callback: Option<*mut dyn FnMut()>) -> bool {
+    let Some(callback) = callback else {
+        return false;
+    };
+    // Safety: Callback owners unregister them before they are dropped
+    luma_core::panic_guard::catch_callback_panic(|| unsafe { (*callback)() });
+    // Layout passes the callback requested run after it, as from a message loop
+    window::run_pending_layouts();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::{Button, Panel, ListBox, NumberBox, TextInput, CheckBox, ScrollViewer};
+    use crate::{Application, Window, Widget, Point, Accelerator, Key, BoxLayout, LayoutConstraints};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    
+    #[test]
+    fn test_widget_screen_bounds_follow_window() {
+        let mut window = Window::builder().size(400, 300).position(100, 50).build().unwrap();
+        let panel = Panel::builder().position(10, 20).size(200, 100).build(&window).unwrap();
+        let button = Button::builder().position(5, 5).size(80, 30).build(&panel).unwrap();
+        
+        assert_eq!(button.get_screen_bounds().unwrap(), Rect::new(115, 75, 80, 30));
+        
+        window.set_position(0, 0).unwrap();
+        assert_eq!(button.get_screen_bounds().unwrap(), Rect::new(15, 25, 80, 30));
+    }
+    
+    #[test]
+    fn test_button_click_runs_callback() {
+        let window = Window::builder().build().unwrap();
+        let clicks = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&clicks);
+        let button = Button::builder()
+            .on_click(move || counter.set(counter.get() + 1))
+            .build(&window)
+            .unwrap();
+        
+        assert!(click(button.hwnd()));
+        assert!(click(button.hwnd()));
+        assert_eq!(clicks.get(), 2);
+        
+        let hwnd = button.hwnd();
+        drop(button);
+        assert!(!click(hwnd));
+    }
+    
+    #[test]
+    fn test_accelerator_runs_callback() {
+        let mut app = Application::new().unwrap();
+        let ran = Rc::new(Cell::new(false));
+        let flag = Rc::clone(&ran);
+        app.register_accelerator(Accelerator::ctrl(Key::Char('S'), 7), move || flag.set(true))
+            .unwrap();
+        
+        assert!(run_command(7));
+        assert!(ran.get());
+        app.run().unwrap();
+    }
+    
+    #[test]
+    fn test_panel_clear_children() {
+        let window = Window::builder().build().unwrap();
+        let mut panel = Panel::builder().build(&window).unwrap();
+        let label = crate::widgets::Label::builder().build(&panel).unwrap();
+        panel.add(label);
+        Button::builder().on_click(|| {}).build(&panel).map(|button| panel.add(button)).unwrap();
+        assert_eq!(panel.child_count(), 2);
+        
+        panel.clear_children().unwrap();
+        assert_eq!(panel.child_count(), 0);
+    }
+    
+    #[test]
+    fn test_listbox_items_and_selection() {
+        let window = Window::builder().build().unwrap();
+        let mut listbox = ListBox::builder()
+            .items(["cherry", "apple"])
+            .sorted(true)
+            .build(&window)
+            .unwrap();
+        listbox.add_item("banana").unwrap();
+        assert_eq!(listbox.item_count().unwrap(), 3);
+        
+        listbox.set_selected_index(Some(2)).unwrap();
+        assert_eq!(listbox.get_selected_index().unwrap(), Some(2));
+        
+        // Removing an earlier item keeps the same item selected
+        listbox.remove_item(0).unwrap();
+        assert_eq!(listbox.get_selected_index().unwrap(), Some(1));
+        
+        assert!(listbox.set_selected_index(Some(5)).is_err());
+        assert!(listbox.remove_item(5).is_err());
+        
+        listbox.clear().unwrap();
+        assert_eq!(listbox.item_count().unwrap(), 0);
+        assert_eq!(listbox.get_selected_index().unwrap(), None);
+    }
+    
+    #[test]
+    fn test_input_widget_state() {
+        let window = Window::builder().build().unwrap();
+        
+        let mut number_box = NumberBox::builder().range(0.0, 10.0).value(5.0).build(&window).unwrap();
+        assert_eq!(number_box.value(), 5.0);
+        number_box.set_value(42.0).unwrap();
+        assert_eq!(number_box.value(), 10.0);
+        
+        let mut text_input = TextInput::builder().build(&window).unwrap();
+        text_input.set_text("hello").unwrap();
+        assert_eq!(text_input.get_text().unwrap(), "hello");
+        
+        let mut checkbox = CheckBox::builder().checked(true).build(&window).unwrap();
+        assert!(checkbox.is_checked().unwrap());
+        checkbox.set_checked(false).unwrap();
+        assert!(!checkbox.is_checked().unwrap());
+    }
+    
+    #[test]
+    fn test_scroll_offset_is_clamped() {
+        let window = Window::builder().build().unwrap();
+        let mut viewer = ScrollViewer::builder().size(100, 100).build(&window).unwrap();
+        let mut content = BoxLayout::vertical();
+        content.add(
+            Box::new(Button::builder().build(&viewer).unwrap()),
+            LayoutConstraints::default().preferred_height(300),
+        );
+        viewer.set_content(content).unwrap();
+        
+        viewer.scroll_to(0, 500).unwrap();
+        assert_eq!(viewer.scroll_offset().y, 200);
+        viewer.scroll_to(-10, -10).unwrap();
+        assert_eq!(viewer.scroll_offset(), Point::new(0, 0));
+    }
+    
+    #[test]
+    fn test_resize_runs_layout() {
+        let mut window = Window::builder().size(200, 100).build().unwrap();
+        let button = Button::builder().build(&window).unwrap();
+        let handle = StubHandle(button.hwnd());
+        
+        let mut layout = BoxLayout::vertical();
+        layout.add(Box::new(button), LayoutConstraints::default());
+        window.set_layout(layout).unwrap();
+        assert_eq!(screen_bounds(handle).unwrap().width, 200);
+        
+        window.set_size(300, 100).unwrap();
+        assert_eq!(screen_bounds(handle).unwrap().width, 300);
+    }
+    
+    #[test]
+    fn test_widgets_added_and_removed_after_show() {
+        let mut window = Window::builder().size(200, 100).build().unwrap();
+        window.show().unwrap();
+        
+        let first = Button::builder().build(&window).unwrap();
+        let first_handle = StubHandle(first.hwnd());
+        let first_id = first.id();
+        window.add_widget(first, LayoutConstraints::default().preferred_height(30)).unwrap();
+        let second = Button::builder().build(&window).unwrap();
+        let second_handle = StubHandle(second.hwnd());
+        window.add_widget(second, LayoutConstraints::default().preferred_height(30)).unwrap();
+        assert_eq!(screen_bounds(second_handle).unwrap().y, 30);
+        
+        window.remove_widget(first_id).unwrap();
+        assert!(screen_bounds(first_handle).is_err());
+        assert_eq!(screen_bounds(second_handle).unwrap().y, 0);
+        assert!(window.remove_widget(first_id).is_err());
+        
+        // The layout stays registered for resizes
+        window.set_size(300, 100).unwrap();
+        assert_eq!(screen_bounds(second_handle).unwrap().width, 300);
+    }
+    
+    #[test]
+    fn test_label_text_change_lays_out_again() {
+        use crate::widgets::Label;
+        
+        // The stub font is 7 pixels per character
+        let mut window = Window::builder().size(400, 100).build().unwrap();
+        window.set_layout(BoxLayout::horizontal()).unwrap();
+        let status = window
+            .add_widget_with_handle(Label::builder().text("Idle").build(&window).unwrap(), LayoutConstraints::default())
+            .unwrap();
+        let next = window
+            .add_widget_with_handle(Label::builder().text("Next").size(60, 20).build(&window).unwrap(), LayoutConstraints::default())
+            .unwrap();
+        assert_eq!(status.borrow().get_bounds().width, 28);
+        assert_eq!(next.borrow().get_bounds().x, 28);
+        
+        // Set through the handle while it is borrowed; the pass runs after the callback
+        let for_click = status.clone();
+        let button = Button::builder()
+            .on_click(move || for_click.borrow_mut().set_text("Working...").unwrap())
+            .build(&window)
+            .unwrap();
+        assert!(click(button.hwnd()));
+        assert_eq!(status.borrow().get_bounds().width, 70);
+        assert_eq!(next.borrow().get_bounds().x, 70);
+        
+        // A label with a size keeps the layout's default width
+        next.borrow_mut().set_text("Next, longer").unwrap();
+        window::run_pending_layouts();
+        assert_eq!(next.borrow().get_bounds().width, 100);
+    }
+    
+    #[test]
+    fn test_widget_tree_skips_scroll_canvas() {
+        let window = Window::builder().title("Tree").position(10, 10).build().unwrap();
+        let viewer = ScrollViewer::builder().position(5, 5).size(100, 100).build(&window).unwrap();
+        let _button = Button::builder().label("Inside").position(1, 2).size(50, 20).build(&viewer).unwrap();
+        
+        let tree = window.widget_tree().unwrap();
+        assert_eq!(tree.text, "Tree");
+        assert_eq!(tree.children.len(), 1);
+        
+        let viewer = &tree.children[0];
+        assert_eq!(viewer.kind, WidgetKind::ScrollViewer);
+        assert_eq!(viewer.children[0].kind, WidgetKind::Button);
+        assert_eq!(viewer.children[0].text, "Inside");
+        assert_eq!(viewer.children[0].bounds, Rect::new(16, 17, 50, 20));
+    }
+    
+    #[test]
+    fn test_opacity_out_of_range() {
+        let mut window = Window::builder().build().unwrap();
+        assert!(window.set_opacity(0.5).is_ok());
+        assert!(matches!(window.set_opacity(1.5), Err(Error::InvalidParameter(_))));
+        assert!(window.update_layered_bitmap(2, 2, &[0; 3]).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use luma_core::{Result, Icon};
+
+/// Stub tray icon backend; there is no notification area to show it in
+pub struct StubTrayIcon;
+
+impl StubTrayIcon {
+    /// Add a tray icon
+    pub fn new(_owner_hwnd: *mut std::ffi::c_void, _icon: &Icon, _tooltip: &str) -> Result<Self> {
+        Ok(Self)
+    }
+    
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, _tooltip: &str) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Show a balloon notification
+    pub fn show_balloon(&mut self, _title: &str, _text: &str) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Set the click callback pointer (the icon is never clicked)
+    pub fn set_click_callback_ptr(&mut self, _callback: Option<*mut dyn FnMut()>) {}
+}
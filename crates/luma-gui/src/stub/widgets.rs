@@ -0,0 +1,1395 @@
+use luma_core::{
+    Result, Error, Point, Size, Rect, Date, Color, Stretch, EllipsisMode, ButtonFlags, ListBoxFlags, WidgetKind,
+    keyboard::display_text,
+    traits::{
+        ButtonBackend, LabelBackend, TextInputBackend, CheckBoxBackend, ListBoxBackend, ListViewBackend,
+        PanelBackend, ScrollViewerBackend, NumberBoxBackend, DatePickerBackend, ImageViewBackend,
+        ProgressBarBackend, HyperlinkBackend, WidgetBackend,
+    },
+};
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::widgets::hyperlink::link_targets;
+use super::{
+    StubHandle, create_node, set_node_bounds, node_bounds, children, destroy_children, destroy_node,
+    set_node_text, node_text, record, notify, register_callback, unregister_callback,
+};
+
+/// Add the `hwnd` accessor and remove the node from the tree on drop
+macro_rules! stub_node {
+    ($widget:ident) => {
+        stub_node!($widget, |_widget| {});
+    };
+    ($widget:ident, |$this:ident| $cleanup:block) => {
+        impl $widget {
+            /// Get the stub handle, used like an HWND
+            pub fn hwnd(&self) -> StubHandle {
+                self.handle
+            }
+        }
+        
+        impl Drop for $widget {
+            fn drop(&mut self) {
+                let $this = &mut *self;
+                $cleanup
+                destroy_node($this.handle);
+            }
+        }
+    };
+}
+
+/// Implement `WidgetBackend` for a widget backed by one node
+/// 
+/// Enabled and visible state only go to the mock call log.
+macro_rules! stub_widget_backend {
+    ($widget:ident) => {
+        impl WidgetBackend for $widget {
+            fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+                set_node_bounds(self.handle, Rect::new(x, y, width, height));
+                Ok(())
+            }
+            
+            fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+                record(self.handle, || format!("set_enabled({})", enabled));
+                Ok(())
+            }
+            
+            fn set_visible(&mut self, visible: bool) -> Result<()> {
+                record(self.handle, || format!("set_visible({})", visible));
+                Ok(())
+            }
+            
+            fn raw_handle(&self) -> *mut std::ffi::c_void {
+                self.handle.as_raw()
+            }
+        }
+    };
+}
+
+/// Create a node for a widget at a position within its parent
+fn create_widget_node(parent_hwnd: *mut std::ffi::c_void, kind: WidgetKind, pos: Point, size: Size) -> StubHandle {
+    create_node(parent_hwnd, kind, Rect::new(pos.x, pos.y, size.width, size.height))
+}
+
+/// Size of a character in the stub backend's imaginary font
+const STUB_CHAR_SIZE: Size = Size { width: 7, height: 15 };
+
+/// Stub button backend
+pub struct StubButton {
+    handle: StubHandle,
+}
+
+impl ButtonBackend for StubButton {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        label: &str,
+        pos: Point,
+        size: Size,
+        flags: ButtonFlags,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::Button, pos, size);
+        set_node_text(handle, label);
+        record(handle, || format!("new({:?}, {:?})", label, flags));
+        Ok(Self { handle })
+    }
+    
+    fn set_label(&mut self, label: &str) -> Result<()> {
+        record(self.handle, || format!("set_label({:?})", label));
+        set_node_text(self.handle, label);
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubButton);
+
+impl StubButton {
+    /// Get the size that fits the label, measured with a fixed-width stand-in font
+    pub fn preferred_size(&self) -> Result<Size> {
+        let chars = display_text(&node_text(self.handle)).chars().count() as u32;
+        Ok(Size::new(chars * STUB_CHAR_SIZE.width + 24, STUB_CHAR_SIZE.height + 10))
+    }
+}
+
+stub_node!(StubButton);
+
+/// Stub label backend
+pub struct StubLabel {
+    handle: StubHandle,
+}
+
+impl LabelBackend for StubLabel {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        text: &str,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::Label, pos, size);
+        set_node_text(handle, text);
+        record(handle, || format!("new({:?})", text));
+        Ok(Self { handle })
+    }
+    
+    fn get_text(&self) -> Result<String> {
+        Ok(node_text(self.handle))
+    }
+    
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        set_label_text(self.handle, text);
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubLabel);
+
+impl StubLabel {
+    /// Get the size of the text, measured with a fixed-width stand-in font
+    pub fn preferred_size(&self) -> Result<Size> {
+        let chars = display_text(&node_text(self.handle)).chars().count() as u32;
+        Ok(Size::new(chars * STUB_CHAR_SIZE.width, STUB_CHAR_SIZE.height))
+    }
+    
+    /// Get a function that sets the label text
+    pub fn text_writer(&self) -> impl Fn(&str) -> Result<()> + 'static {
+        let handle = self.handle;
+        move |text| {
+            set_label_text(handle, text);
+            Ok(())
+        }
+    }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        record(self.handle, || format!("set_text_color({:?})", color));
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        record(self.handle, || format!("set_background({:?})", color));
+        Ok(())
+    }
+    
+    /// Set how text too long for the label is shortened (the text is kept whole)
+    pub fn set_ellipsis(&mut self, mode: EllipsisMode) {
+        record(self.handle, || format!("set_ellipsis({:?})", mode));
+    }
+}
+
+stub_node!(StubLabel);
+
+fn set_label_text(handle: StubHandle, text: &str) {
+    record(handle, || format!("set_text({:?})", text));
+    set_node_text(handle, text);
+}
+
+/// Stub text input backend
+/// 
+/// Its text is the node text. Like the edit control, it runs its change
+/// callback whenever the text is set, by the user or in code.
+pub struct StubTextInput {
+    handle: StubHandle,
+    /// Selection anchor and caret, as character offsets
+    selection: Cell<(usize, usize)>,
+    /// Text before the last edit, for undo
+    undo_text: RefCell<Option<String>>,
+}
+
+impl TextInputBackend for StubTextInput {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+        read_only: bool,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::TextInput, pos, size);
+        record(handle, || format!("new({})", read_only));
+        Ok(Self { handle, selection: Cell::new((0, 0)), undo_text: RefCell::new(None) })
+    }
+    
+    fn get_text(&self) -> Result<String> {
+        Ok(node_text(self.handle))
+    }
+    
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        record(self.handle, || format!("set_text({:?})", text));
+        set_input_text(self.handle, text);
+        // Like an edit control, new text puts the caret at the start and can't be undone
+        self.selection.set((0, 0));
+        self.undo_text.replace(None);
+        Ok(())
+    }
+    
+    fn set_read_only(&mut self, read_only: bool) -> Result<()> {
+        record(self.handle, || format!("set_read_only({})", read_only));
+        Ok(())
+    }
+    
+    fn set_max_length(&mut self, max_length: Option<u32>) -> Result<()> {
+        record(self.handle, || format!("set_max_length({:?})", max_length));
+        if max_length == Some(0) {
+            return Err(Error::InvalidParameter("Maximum text length must be at least 1".into()));
+        }
+        MAX_LENGTHS.with(|max_lengths| match max_length {
+            Some(max_length) => max_lengths.borrow_mut().insert(self.handle, max_length as usize),
+            None => max_lengths.borrow_mut().remove(&self.handle),
+        });
+        Ok(())
+    }
+    
+    fn set_placeholder(&mut self, text: &str, show_when_focused: bool) -> Result<()> {
+        record(self.handle, || format!("set_placeholder({:?}, {})", text, show_when_focused));
+        Ok(())
+    }
+    
+    fn get_selection(&self) -> Result<(usize, usize)> {
+        // Typed text may have shortened the text since the selection was made
+        let len = node_text(self.handle).chars().count();
+        let (anchor, caret) = self.selection.get();
+        let (anchor, caret) = (anchor.min(len), caret.min(len));
+        Ok((anchor.min(caret), anchor.max(caret)))
+    }
+    
+    fn set_selection(&mut self, start: usize, end: usize) -> Result<()> {
+        record(self.handle, || format!("set_selection({}, {})", start, end));
+        let len = node_text(self.handle).chars().count();
+        self.selection.set((start.min(len), end.min(len)));
+        Ok(())
+    }
+    
+    fn replace_selection(&mut self, text: &str) -> Result<()> {
+        record(self.handle, || format!("replace_selection({:?})", text));
+        self.replace(text)
+    }
+    
+    fn can_undo(&self) -> Result<bool> {
+        Ok(self.undo_text.borrow().is_some())
+    }
+    
+    fn undo(&mut self) -> Result<()> {
+        record(self.handle, || "undo()".to_string());
+        let Some(previous) = self.undo_text.take() else {
+            return Ok(());
+        };
+        // The undone text becomes the undo, so undoing again redoes
+        self.undo_text.replace(Some(node_text(self.handle)));
+        set_input_text(self.handle, &previous);
+        let len = previous.chars().count();
+        self.selection.set((len, len));
+        Ok(())
+    }
+    
+    fn cut(&mut self) -> Result<()> {
+        record(self.handle, || "cut()".to_string());
+        if self.copy_selection()? {
+            self.replace("")?;
+        }
+        Ok(())
+    }
+    
+    fn copy(&self) -> Result<()> {
+        record(self.handle, || "copy()".to_string());
+        self.copy_selection()?;
+        Ok(())
+    }
+    
+    fn paste(&mut self) -> Result<()> {
+        record(self.handle, || "paste()".to_string());
+        let text = clipboard_text();
+        self.replace(&filter_input(self.handle, text.lines().next().unwrap_or_default()))
+    }
+}
+
+stub_widget_backend!(StubTextInput);
+
+impl StubTextInput {
+    /// Set (or clear) the test characters must pass to be typed or pasted
+    /// 
+    /// Control characters are always allowed, and text set with `set_text`
+    /// is not filtered.
+    pub fn set_char_filter(&mut self, filter: Option<Box<CharFilter>>) {
+        record(self.handle, || format!("set_char_filter({})", if filter.is_some() { "Some(..)" } else { "None" }));
+        FILTERS.with(|filters| match filter {
+            Some(filter) => filters.borrow_mut().insert(self.handle, Rc::from(filter)),
+            None => filters.borrow_mut().remove(&self.handle),
+        });
+    }
+    
+    /// Check whether the control has the keyboard focus, which stub widgets never do
+    pub fn has_focus(&self) -> bool {
+        false
+    }
+    
+    /// Replace the selection with text as an undoable edit
+    /// 
+    /// Like typing, the text is cut short at the maximum length.
+    fn replace(&self, text: &str) -> Result<()> {
+        let (start, end) = self.get_selection()?;
+        let current = node_text(self.handle);
+        let old: Vec<char> = current.chars().collect();
+        let text = &truncate_input(self.handle, text, old.len() - (end - start));
+        let new: String = old[..start].iter().copied().chain(text.chars()).chain(old[end..].iter().copied()).collect();
+        self.undo_text.replace(Some(current));
+        set_input_text(self.handle, &new);
+        let caret = start + text.chars().count();
+        self.selection.set((caret, caret));
+        Ok(())
+    }
+    
+    /// Put the selected text on the clipboard, returning whether there was any
+    fn copy_selection(&self) -> Result<bool> {
+        let (start, end) = self.get_selection()?;
+        if start == end {
+            return Ok(false);
+        }
+        let selected: String = node_text(self.handle).chars().skip(start).take(end - start).collect();
+        CLIPBOARD.with(|clipboard| *clipboard.borrow_mut() = selected);
+        Ok(true)
+    }
+    
+    /// Register (or clear) the callback invoked when the text changes
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_change_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_callback(self.handle.0, ptr),
+            None => unregister_callback(self.handle.0),
+        }
+    }
+    
+    /// Get a function that reads the current text
+    pub fn text_reader(&self) -> impl Fn() -> Result<String> + 'static {
+        let handle = self.handle;
+        move || Ok(node_text(handle))
+    }
+    
+    /// Get a function that sets the text
+    pub fn text_writer(&self) -> impl Fn(&str) -> Result<()> + 'static {
+        let handle = self.handle;
+        move |text| {
+            record(handle, || format!("set_text({:?})", text));
+            set_input_text(handle, text);
+            Ok(())
+        }
+    }
+    
+    /// Get a function that sets the placeholder
+    pub fn placeholder_writer(&self, show_when_focused: bool) -> impl Fn(&str) -> Result<()> + 'static {
+        let handle = self.handle;
+        move |text| {
+            record(handle, || format!("set_placeholder({:?}, {})", text, show_when_focused));
+            Ok(())
+        }
+    }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        record(self.handle, || format!("set_text_color({:?})", color));
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        record(self.handle, || format!("set_background({:?})", color));
+        Ok(())
+    }
+}
+
+stub_node!(StubTextInput, |text_input| {
+    FILTERS.with(|filters| filters.borrow_mut().remove(&text_input.handle));
+    MAX_LENGTHS.with(|max_lengths| max_lengths.borrow_mut().remove(&text_input.handle));
+});
+
+/// Set the text of a text input and run its change callback
+pub(super) fn set_input_text(handle: StubHandle, text: &str) {
+    set_node_text(handle, text);
+    notify(handle);
+}
+
+thread_local! {
+    /// Text cut or copied from text inputs
+    static CLIPBOARD: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Test deciding which characters the user may enter
+type CharFilter = dyn Fn(char) -> bool;
+
+thread_local! {
+    /// Character filters of text inputs that have one
+    static FILTERS: RefCell<HashMap<StubHandle, Rc<CharFilter>>> = RefCell::new(HashMap::new());
+    /// Maximum lengths of text inputs that have one
+    static MAX_LENGTHS: RefCell<HashMap<StubHandle, usize>> = RefCell::new(HashMap::new());
+}
+
+/// Drop the characters a text input's filter would not let the user enter
+pub(super) fn filter_input(handle: StubHandle, text: &str) -> String {
+    let Some(filter) = FILTERS.with(|filters| filters.borrow().get(&handle).cloned()) else {
+        return text.to_string();
+    };
+    text.chars().filter(|&c| c.is_control() || filter(c)).collect()
+}
+
+/// Cut entered text to the room a text input's maximum length leaves beside `kept` characters
+pub(super) fn truncate_input(handle: StubHandle, text: &str, kept: usize) -> String {
+    match MAX_LENGTHS.with(|max_lengths| max_lengths.borrow().get(&handle).copied()) {
+        Some(max_length) => text.chars().take(max_length.saturating_sub(kept)).collect(),
+        None => text.to_string(),
+    }
+}
+
+/// Get the text on the stub clipboard
+pub(super) fn clipboard_text() -> String {
+    CLIPBOARD.with(|clipboard| clipboard.borrow().clone())
+}
+
+thread_local! {
+    /// Checked state of every checkbox, so clicks can toggle it by handle
+    static CHECKS: RefCell<HashMap<StubHandle, Rc<Cell<bool>>>> = RefCell::new(HashMap::new());
+}
+
+/// Toggle a checkbox as a click would, if the handle is one
+pub(super) fn toggle_checkbox(handle: StubHandle) {
+    if let Some(checked) = CHECKS.with(|checks| checks.borrow().get(&handle).cloned()) {
+        checked.set(!checked.get());
+    }
+}
+
+/// Stub checkbox backend
+pub struct StubCheckBox {
+    handle: StubHandle,
+    checked: Rc<Cell<bool>>,
+}
+
+impl CheckBoxBackend for StubCheckBox {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        label: &str,
+        pos: Point,
+        size: Size,
+        checked: bool,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::CheckBox, pos, size);
+        set_node_text(handle, label);
+        record(handle, || format!("new({:?}, {})", label, checked));
+        let checked = Rc::new(Cell::new(checked));
+        CHECKS.with(|checks| checks.borrow_mut().insert(handle, Rc::clone(&checked)));
+        Ok(Self { handle, checked })
+    }
+    
+    fn is_checked(&self) -> Result<bool> {
+        Ok(self.checked.get())
+    }
+    
+    fn set_checked(&mut self, checked: bool) -> Result<()> {
+        record(self.handle, || format!("set_checked({})", checked));
+        self.checked.set(checked);
+        Ok(())
+    }
+    
+    fn set_label(&mut self, label: &str) -> Result<()> {
+        record(self.handle, || format!("set_label({:?})", label));
+        set_node_text(self.handle, label);
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubCheckBox);
+
+impl StubCheckBox {
+    /// Get a function that reads the checked state
+    pub fn checked_reader(&self) -> impl Fn() -> Result<bool> + 'static {
+        let checked = Rc::clone(&self.checked);
+        move || Ok(checked.get())
+    }
+    
+    /// Get a function that sets the checked state
+    pub fn checked_writer(&self) -> impl Fn(bool) -> Result<()> + 'static {
+        let handle = self.handle;
+        let state = Rc::clone(&self.checked);
+        move |checked| {
+            record(handle, || format!("set_checked({})", checked));
+            state.set(checked);
+            Ok(())
+        }
+    }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        record(self.handle, || format!("set_text_color({:?})", color));
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        record(self.handle, || format!("set_background({:?})", color));
+        Ok(())
+    }
+}
+
+stub_node!(StubCheckBox, |checkbox| {
+    CHECKS.with(|checks| checks.borrow_mut().remove(&checkbox.handle));
+});
+
+/// Items and selection of a stub listbox
+#[derive(Default)]
+struct ListState {
+    items: Vec<String>,
+    selected: Vec<usize>,
+    /// Item the user last clicked
+    caret: Option<usize>,
+    activate: Option<*mut dyn FnMut()>,
+}
+
+thread_local! {
+    /// State of every listbox, so selections can be simulated by handle
+    static LISTS: RefCell<HashMap<StubHandle, Rc<RefCell<ListState>>>> = RefCell::new(HashMap::new());
+}
+
+/// Select items of a listbox as the user would, without invoking its callback
+pub(super) fn select_items(handle: StubHandle, indices: &[usize]) -> Result<()> {
+    let state = LISTS
+        .with(|lists| lists.borrow().get(&handle).cloned())
+        .ok_or_else(|| Error::InvalidParameter(format!("Not a listbox: {}", handle.0)))?;
+    let mut state = state.borrow_mut();
+    if let Some(&index) = indices.iter().find(|index| **index >= state.items.len()) {
+        return Err(Error::IndexOutOfRange { index, len: state.items.len() });
+    }
+    state.selected = indices.to_vec();
+    state.selected.sort_unstable();
+    state.selected.dedup();
+    state.caret = indices.last().copied();
+    Ok(())
+}
+
+/// Get a listbox's double-click callback
+pub(super) fn activate_callback(handle: StubHandle) -> Result<Option<*mut dyn FnMut()>> {
+    LISTS
+        .with(|lists| lists.borrow().get(&handle).map(|state| state.borrow().activate))
+        .ok_or_else(|| Error::InvalidParameter(format!("Not a listbox: {}", handle.0)))
+}
+
+/// Stub listbox backend
+/// 
+/// Like the native control, it does not report selection changes made
+/// in code; only simulated user selections invoke the selection callback.
+pub struct StubListBox {
+    handle: StubHandle,
+    flags: ListBoxFlags,
+    state: Rc<RefCell<ListState>>,
+}
+
+impl ListBoxBackend for StubListBox {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+        flags: ListBoxFlags,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::ListBox, pos, size);
+        record(handle, || format!("new({:?})", flags));
+        let state = Rc::<RefCell<ListState>>::default();
+        LISTS.with(|lists| lists.borrow_mut().insert(handle, Rc::clone(&state)));
+        Ok(Self { handle, flags, state })
+    }
+    
+    fn add_item(&mut self, item: &str) -> Result<()> {
+        record(self.handle, || format!("add_item({:?})", item));
+        let mut state = self.state.borrow_mut();
+        let index = if self.flags.contains(ListBoxFlags::SORTED) {
+            state.items.partition_point(|existing| existing.as_str() <= item)
+        } else {
+            state.items.len()
+        };
+        state.items.insert(index, item.to_string());
+        for selected in state.selected.iter_mut().filter(|selected| **selected >= index) {
+            *selected += 1;
+        }
+        Ok(())
+    }
+    
+    fn remove_item(&mut self, index: usize) -> Result<()> {
+        record(self.handle, || format!("remove_item({})", index));
+        let mut state = self.state.borrow_mut();
+        if index >= state.items.len() {
+            return Err(Error::IndexOutOfRange { index, len: state.items.len() });
+        }
+        state.items.remove(index);
+        state.selected.retain(|selected| *selected != index);
+        for selected in state.selected.iter_mut().filter(|selected| **selected > index) {
+            *selected -= 1;
+        }
+        Ok(())
+    }
+    
+    fn clear(&mut self) -> Result<()> {
+        record(self.handle, || "clear()".to_string());
+        *self.state.borrow_mut() = ListState::default();
+        Ok(())
+    }
+    
+    fn item_count(&self) -> Result<usize> {
+        Ok(self.state.borrow().items.len())
+    }
+    
+    fn get_selected_index(&self) -> Result<Option<usize>> {
+        if self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Use get_selected_indices() for multi-select listbox".into()
+            ));
+        }
+        
+        Ok(self.state.borrow().selected.first().copied())
+    }
+    
+    fn get_selected_indices(&self) -> Result<Vec<usize>> {
+        Ok(self.state.borrow().selected.clone())
+    }
+    
+    fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
+        if self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Use selection methods specific to multi-select for multi-select listbox".into()
+            ));
+        }
+        
+        record(self.handle, || format!("set_selected_index({:?})", index));
+        let mut state = self.state.borrow_mut();
+        match index {
+            Some(idx) if idx >= state.items.len() => {
+                Err(Error::IndexOutOfRange { index: idx, len: state.items.len() })
+            }
+            _ => {
+                state.selected = index.into_iter().collect();
+                Ok(())
+            }
+        }
+    }
+    
+    fn set_selected_indices(&mut self, indices: &[usize]) -> Result<()> {
+        if !self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Use set_selected_index() for single-select listbox".into()
+            ));
+        }
+        
+        record(self.handle, || format!("set_selected_indices({:?})", indices));
+        select_items(self.handle, indices)
+    }
+    
+    fn set_all_selected(&mut self, selected: bool) -> Result<()> {
+        if !self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Cannot select all items in a single-select listbox".into()
+            ));
+        }
+        
+        record(self.handle, || format!("set_all_selected({})", selected));
+        let mut state = self.state.borrow_mut();
+        state.selected = if selected { (0..state.items.len()).collect() } else { Vec::new() };
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubListBox);
+
+impl StubListBox {
+    /// Register (or clear) the callback invoked when the user changes the selection
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_selection_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_callback(self.handle.0, ptr),
+            None => unregister_callback(self.handle.0),
+        }
+    }
+    
+    /// Register (or clear) the callback invoked when an item is double-clicked
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_activate_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        self.state.borrow_mut().activate = callback;
+    }
+    
+    /// Get a function that reads the current selection
+    pub fn selection_reader(&self) -> impl Fn() -> Result<Vec<usize>> + 'static {
+        let state = Rc::clone(&self.state);
+        move || Ok(state.borrow().selected.clone())
+    }
+    
+    /// Get a function that reads which item was activated
+    pub fn activation_reader(&self) -> impl Fn() -> Option<usize> + 'static {
+        let state = Rc::clone(&self.state);
+        move || state.borrow().caret
+    }
+}
+
+stub_node!(StubListBox, |listbox| {
+    LISTS.with(|lists| lists.borrow_mut().remove(&listbox.handle));
+});
+
+/// Columns, rows and selection of a stub list view
+#[derive(Default)]
+struct TableState {
+    columns: Vec<(String, u32)>,
+    rows: Vec<Vec<String>>,
+    selected: Option<usize>,
+    column_click: Option<*mut dyn FnMut(usize)>,
+}
+
+thread_local! {
+    /// State of every list view, so selections and header clicks can be simulated by handle
+    static TABLES: RefCell<HashMap<StubHandle, Rc<RefCell<TableState>>>> = RefCell::new(HashMap::new());
+}
+
+fn table_state(handle: StubHandle) -> Result<Rc<RefCell<TableState>>> {
+    TABLES
+        .with(|tables| tables.borrow().get(&handle).cloned())
+        .ok_or_else(|| Error::InvalidParameter(format!("Not a list view: {}", handle.0)))
+}
+
+/// Select a row of a list view as the user would, without invoking its callback
+pub(super) fn select_row(handle: StubHandle, index: Option<usize>) -> Result<()> {
+    let state = table_state(handle)?;
+    let mut state = state.borrow_mut();
+    if let Some(index) = index.filter(|index| *index >= state.rows.len()) {
+        return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+    }
+    state.selected = index;
+    Ok(())
+}
+
+/// Get the column click callback of a list view, checking the column exists
+pub(super) fn column_click_callback(handle: StubHandle, column: usize) -> Result<Option<*mut dyn FnMut(usize)>> {
+    let state = table_state(handle)?;
+    let state = state.borrow();
+    if column >= state.columns.len() {
+        return Err(Error::InvalidParameter(format!("Invalid column: {}", column)));
+    }
+    Ok(state.column_click)
+}
+
+/// Orders two cells of a column
+type CellComparator<'a> = dyn Fn(&str, &str) -> Ordering + 'a;
+
+/// Sort the rows of a list view by a column, keeping the selected row selected
+fn sort_table(
+    handle: StubHandle,
+    state: &RefCell<TableState>,
+    column: usize,
+    ascending: bool,
+    compare: &CellComparator<'_>,
+) -> Result<()> {
+    record(handle, || format!("sort_rows({}, {})", column, ascending));
+    let mut state = state.borrow_mut();
+    if column >= state.columns.len() {
+        return Err(Error::InvalidParameter(format!("Invalid column: {}", column)));
+    }
+    
+    let selected = state.selected;
+    let mut rows: Vec<(usize, Vec<String>)> = std::mem::take(&mut state.rows).into_iter().enumerate().collect();
+    // Stable, like LVM_SORTITEMSEX; cells that were not given compare as empty
+    rows.sort_by(|(_, a), (_, b)| {
+        let cell = |row: &[String]| row.get(column).cloned().unwrap_or_default();
+        let order = compare(&cell(a), &cell(b));
+        if ascending { order } else { order.reverse() }
+    });
+    state.selected = selected.and_then(|selected| rows.iter().position(|(index, _)| *index == selected));
+    state.rows = rows.into_iter().map(|(_, row)| row).collect();
+    Ok(())
+}
+
+/// Stub list view backend
+/// 
+/// Like the native control, it does not report selection changes made
+/// in code; only simulated user selections invoke the selection callback.
+pub struct StubListView {
+    handle: StubHandle,
+    state: Rc<RefCell<TableState>>,
+}
+
+impl ListViewBackend for StubListView {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::ListView, pos, size);
+        record(handle, || "new()".to_string());
+        let state = Rc::<RefCell<TableState>>::default();
+        TABLES.with(|tables| tables.borrow_mut().insert(handle, Rc::clone(&state)));
+        Ok(Self { handle, state })
+    }
+    
+    fn add_column(&mut self, title: &str, width: u32) -> Result<()> {
+        record(self.handle, || format!("add_column({:?}, {})", title, width));
+        self.state.borrow_mut().columns.push((title.to_string(), width));
+        Ok(())
+    }
+    
+    fn column_count(&self) -> usize {
+        self.state.borrow().columns.len()
+    }
+    
+    fn add_row(&mut self, cells: &[&str]) -> Result<()> {
+        record(self.handle, || format!("add_row({:?})", cells));
+        let mut state = self.state.borrow_mut();
+        if cells.len() > state.columns.len() {
+            return Err(Error::InvalidParameter(format!(
+                "Row has {} cells but the list view has {} columns",
+                cells.len(),
+                state.columns.len()
+            )));
+        }
+        state.rows.push(cells.iter().map(|cell| cell.to_string()).collect());
+        Ok(())
+    }
+    
+    fn remove_row(&mut self, index: usize) -> Result<()> {
+        record(self.handle, || format!("remove_row({})", index));
+        let mut state = self.state.borrow_mut();
+        if index >= state.rows.len() {
+            return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+        }
+        state.rows.remove(index);
+        state.selected = match state.selected {
+            Some(selected) if selected == index => None,
+            Some(selected) if selected > index => Some(selected - 1),
+            selected => selected,
+        };
+        Ok(())
+    }
+    
+    fn clear_rows(&mut self) -> Result<()> {
+        record(self.handle, || "clear_rows()".to_string());
+        let mut state = self.state.borrow_mut();
+        state.rows.clear();
+        state.selected = None;
+        Ok(())
+    }
+    
+    fn row_count(&self) -> Result<usize> {
+        Ok(self.state.borrow().rows.len())
+    }
+    
+    fn get_row(&self, index: usize) -> Result<Vec<String>> {
+        let state = self.state.borrow();
+        let row = state
+            .rows
+            .get(index)
+            .ok_or_else(|| Error::InvalidParameter(format!("Invalid index: {}", index)))?;
+        // Cells that were not given read back empty, as in the native control
+        let mut cells = row.clone();
+        cells.resize(state.columns.len(), String::new());
+        Ok(cells)
+    }
+    
+    fn get_selected_index(&self) -> Result<Option<usize>> {
+        Ok(self.state.borrow().selected)
+    }
+    
+    fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
+        record(self.handle, || format!("set_selected_index({:?})", index));
+        select_row(self.handle, index)
+    }
+    
+    fn sort_rows(
+        &mut self,
+        column: usize,
+        ascending: bool,
+        compare: &dyn Fn(&str, &str) -> Ordering,
+    ) -> Result<()> {
+        sort_table(self.handle, &self.state, column, ascending, compare)
+    }
+}
+
+stub_widget_backend!(StubListView);
+
+impl StubListView {
+    /// Register (or clear) the callback invoked when the user changes the selection
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_selection_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_callback(self.handle.0, ptr),
+            None => unregister_callback(self.handle.0),
+        }
+    }
+    
+    /// Register (or clear) the callback invoked with a column's index when its header is clicked
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_column_click_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(usize)>) {
+        self.state.borrow_mut().column_click = callback;
+    }
+    
+    /// Get a function that reads the selected row
+    pub fn selection_reader(&self) -> impl Fn() -> Option<usize> + 'static {
+        let state = Rc::clone(&self.state);
+        move || state.borrow().selected
+    }
+    
+    /// Get a function that sorts the rows, like [`ListViewBackend::sort_rows`]
+    pub fn row_sorter(&self) -> impl Fn(usize, bool, &CellComparator<'_>) -> Result<()> + 'static {
+        let handle = self.handle;
+        let state = Rc::clone(&self.state);
+        move |column, ascending, compare| sort_table(handle, &state, column, ascending, compare)
+    }
+}
+
+stub_node!(StubListView, |list_view| {
+    TABLES.with(|tables| tables.borrow_mut().remove(&list_view.handle));
+});
+
+/// Stub panel backend
+pub struct StubPanel {
+    handle: StubHandle,
+}
+
+impl PanelBackend for StubPanel {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        Ok(Self {
+            handle: create_widget_node(parent_hwnd, WidgetKind::Panel, pos, size),
+        })
+    }
+    
+    fn child_count(&self) -> usize {
+        children(self.handle).len()
+    }
+    
+    fn clear_children(&mut self) -> Result<()> {
+        destroy_children(self.handle);
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubPanel);
+
+impl StubPanel {
+    /// Set the background color
+    pub fn set_background(&mut self, color: Color) -> Result<()> {
+        record(self.handle, || format!("set_background({:?})", color));
+        Ok(())
+    }
+}
+
+stub_node!(StubPanel);
+
+/// Stub scroll viewer backend
+/// 
+/// Children are placed on a canvas offset by the scroll position, so their
+/// screen bounds move as it scrolls.
+pub struct StubScrollViewer {
+    handle: StubHandle,
+    canvas: StubPanel,
+    content: Size,
+    offset: Point,
+}
+
+impl StubScrollViewer {
+    /// Clamp the offset to the scrollable range and move the canvas
+    fn apply_scroll(&mut self) {
+        let viewport = self.viewport();
+        let max_x = self.content.width.saturating_sub(viewport.width) as i32;
+        let max_y = self.content.height.saturating_sub(viewport.height) as i32;
+        self.offset = Point::new(self.offset.x.clamp(0, max_x), self.offset.y.clamp(0, max_y));
+        set_node_bounds(
+            self.canvas.handle,
+            Rect::new(-self.offset.x, -self.offset.y, self.content.width, self.content.height),
+        );
+    }
+    
+    fn viewport(&self) -> Size {
+        let bounds = node_bounds(self.handle).unwrap_or_default();
+        Size::new(bounds.width, bounds.height)
+    }
+}
+
+impl ScrollViewerBackend for StubScrollViewer {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::ScrollViewer, pos, size);
+        let canvas = StubPanel::new(handle.as_raw(), Point::new(0, 0), size)?;
+        Ok(Self {
+            handle,
+            canvas,
+            content: size,
+            offset: Point::new(0, 0),
+        })
+    }
+    
+    fn viewport_size(&self) -> Result<Size> {
+        Ok(self.viewport())
+    }
+    
+    fn set_content_size(&mut self, size: Size) -> Result<()> {
+        self.content = size;
+        self.apply_scroll();
+        Ok(())
+    }
+    
+    fn scroll_offset(&self) -> Point {
+        self.offset
+    }
+    
+    fn scroll_to(&mut self, offset: Point) -> Result<()> {
+        self.offset = offset;
+        self.apply_scroll();
+        Ok(())
+    }
+}
+
+impl WidgetBackend for StubScrollViewer {
+    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+        set_node_bounds(self.handle, Rect::new(x, y, width, height));
+        self.apply_scroll();
+        Ok(())
+    }
+    
+    fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+        record(self.handle, || format!("set_enabled({})", enabled));
+        Ok(())
+    }
+    
+    fn set_visible(&mut self, visible: bool) -> Result<()> {
+        record(self.handle, || format!("set_visible({})", visible));
+        Ok(())
+    }
+    
+    // Children are created on the scrolled canvas
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.canvas.raw_handle()
+    }
+}
+
+stub_node!(StubScrollViewer);
+
+/// Stub number box backend
+pub struct StubNumberBox {
+    handle: StubHandle,
+    value: f64,
+    min: f64,
+    max: f64,
+    decimals: usize,
+}
+
+impl NumberBoxBackend for StubNumberBox {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let mut number_box = Self {
+            handle: create_widget_node(parent_hwnd, WidgetKind::NumberBox, pos, size),
+            value: 0.0,
+            min: f64::MIN,
+            max: f64::MAX,
+            decimals: 0,
+        };
+        number_box.commit(0.0);
+        Ok(number_box)
+    }
+    
+    fn value(&self) -> f64 {
+        self.value
+    }
+    
+    fn set_value(&mut self, value: f64) -> Result<()> {
+        record(self.handle, || format!("set_value({})", value));
+        self.commit(value);
+        Ok(())
+    }
+    
+    fn set_range(&mut self, min: f64, max: f64) -> Result<()> {
+        if min > max {
+            return Err(Error::InvalidParameter(format!("Invalid range: {} > {}", min, max)));
+        }
+        record(self.handle, || format!("set_range({}, {})", min, max));
+        self.min = min;
+        self.max = max;
+        self.commit(self.value);
+        Ok(())
+    }
+    
+    fn set_step(&mut self, step: f64) -> Result<()> {
+        if step.is_nan() || step <= 0.0 {
+            return Err(Error::InvalidParameter(format!("Step must be positive, got {}", step)));
+        }
+        record(self.handle, || format!("set_step({})", step));
+        Ok(())
+    }
+    
+    fn set_decimals(&mut self, decimals: usize) -> Result<()> {
+        record(self.handle, || format!("set_decimals({})", decimals));
+        self.decimals = decimals;
+        self.commit(self.value);
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubNumberBox);
+
+impl StubNumberBox {
+    /// Set the callback invoked when the user changes the value
+    pub fn set_change_callback_ptr(&mut self, _callback: Option<*mut dyn FnMut(f64)>) {}
+    
+    
+    /// Clamp a value to the range and round it to the decimals shown, as the native control does
+    fn commit(&mut self, value: f64) {
+        let text = format!("{:.*}", self.decimals, value.clamp(self.min, self.max));
+        self.value = text.parse().unwrap_or(value);
+        set_node_text(self.handle, text);
+    }
+}
+
+stub_node!(StubNumberBox);
+
+/// Stub date picker backend
+pub struct StubDatePicker {
+    handle: StubHandle,
+    date: Date,
+}
+
+impl DatePickerBackend for StubDatePicker {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::DatePicker, pos, size);
+        let date = today();
+        set_node_text(handle, date.to_string());
+        Ok(Self { handle, date })
+    }
+    
+    fn get_date(&self) -> Result<Date> {
+        Ok(self.date)
+    }
+    
+    fn set_date(&mut self, date: Date) -> Result<()> {
+        if !date.is_valid() {
+            return Err(Error::InvalidParameter(format!("Invalid date: {}", date)));
+        }
+        record(self.handle, || format!("set_date({})", date));
+        set_node_text(self.handle, date.to_string());
+        self.date = date;
+        Ok(())
+    }
+    
+    fn set_format(&mut self, format: Option<&str>) -> Result<()> {
+        record(self.handle, || format!("set_format({:?})", format));
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubDatePicker);
+
+impl StubDatePicker {
+    /// Set the callback invoked when the user picks a date
+    pub fn set_change_callback_ptr(&mut self, _callback: Option<*mut dyn FnMut(Date)>) {}
+
+}
+
+stub_node!(StubDatePicker);
+
+/// Get today's date in UTC, which the native control starts at (in local time)
+fn today() -> Date {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400) as i64;
+    
+    // Convert days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u8;
+    let year = (year_of_era + era * 400 + i64::from(month <= 2)) as u16;
+    
+    Date { year, month, day }
+}
+
+/// Stub image view backend
+/// 
+/// Reads the size of a BMP file without decoding or drawing it.
+pub struct StubImageView {
+    handle: StubHandle,
+}
+
+impl ImageViewBackend for StubImageView {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        Ok(Self {
+            handle: create_widget_node(parent_hwnd, WidgetKind::ImageView, pos, size),
+        })
+    }
+    
+    fn load_file(&mut self, path: &Path) -> Result<Size> {
+        record(self.handle, || format!("load_file({:?})", path));
+        let size = std::fs::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| bitmap_size(&bytes).ok_or_else(|| "not a BMP file".to_string()))
+            .map_err(|e| Error::OperationFailed(format!("Failed to load image {}: {}", path.display(), e)))?;
+        Ok(size)
+    }
+    
+    fn clear(&mut self) {
+        record(self.handle, || "clear()".to_string());
+    }
+    
+    fn set_stretch(&mut self, stretch: Stretch) {
+        record(self.handle, || format!("set_stretch({:?})", stretch));
+    }
+}
+
+stub_widget_backend!(StubImageView);
+
+stub_node!(StubImageView);
+
+/// Stub progress bar backend
+/// 
+/// Clamps the value to the range like the native control, and keeps it while
+/// the marquee runs.
+pub struct StubProgressBar {
+    handle: StubHandle,
+    min: u32,
+    max: u32,
+    value: u32,
+}
+
+impl ProgressBarBackend for StubProgressBar {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        Ok(Self {
+            handle: create_widget_node(parent_hwnd, WidgetKind::ProgressBar, pos, size),
+            min: 0,
+            max: 100,
+            value: 0,
+        })
+    }
+    
+    fn set_range(&mut self, min: u32, max: u32) -> Result<()> {
+        if min > max {
+            return Err(Error::InvalidParameter(format!("Invalid progress range: {}..={}", min, max)));
+        }
+        record(self.handle, || format!("set_range({}, {})", min, max));
+        self.min = min;
+        self.max = max;
+        self.value = self.value.clamp(min, max);
+        Ok(())
+    }
+    
+    fn get_value(&self) -> Result<u32> {
+        Ok(self.value)
+    }
+    
+    fn set_value(&mut self, value: u32) -> Result<()> {
+        record(self.handle, || format!("set_value({})", value));
+        self.value = value.clamp(self.min, self.max);
+        Ok(())
+    }
+    
+    fn set_indeterminate(&mut self, indeterminate: bool, interval: Duration) -> Result<()> {
+        record(self.handle, || format!("set_indeterminate({}, {:?})", indeterminate, interval));
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubProgressBar);
+
+stub_node!(StubProgressBar);
+
+/// Markup and click callback of a stub hyperlink
+struct LinkState {
+    markup: String,
+    click: Option<*mut dyn FnMut(usize)>,
+}
+
+thread_local! {
+    /// State of every hyperlink, so link clicks can be simulated by handle
+    static LINKS: RefCell<HashMap<StubHandle, Rc<RefCell<LinkState>>>> = RefCell::new(HashMap::new());
+}
+
+/// Get the click callback of a hyperlink, checking the link exists
+pub(super) fn link_click_callback(handle: StubHandle, index: usize) -> Result<Option<*mut dyn FnMut(usize)>> {
+    let state = LINKS
+        .with(|links| links.borrow().get(&handle).cloned())
+        .ok_or_else(|| Error::InvalidParameter(format!("Not a hyperlink: {}", handle.0)))?;
+    let state = state.borrow();
+    if index >= link_targets(&state.markup).len() {
+        return Err(Error::InvalidParameter(format!("Invalid link: {}", index)));
+    }
+    Ok(state.click)
+}
+
+/// Stub hyperlink backend
+pub struct StubHyperlink {
+    handle: StubHandle,
+    state: Rc<RefCell<LinkState>>,
+}
+
+impl HyperlinkBackend for StubHyperlink {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        markup: &str,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        let handle = create_widget_node(parent_hwnd, WidgetKind::Hyperlink, pos, size);
+        set_node_text(handle, markup);
+        record(handle, || format!("new({:?})", markup));
+        let state = Rc::new(RefCell::new(LinkState { markup: markup.to_string(), click: None }));
+        LINKS.with(|links| links.borrow_mut().insert(handle, Rc::clone(&state)));
+        Ok(Self { handle, state })
+    }
+    
+    fn set_markup(&mut self, markup: &str) -> Result<()> {
+        record(self.handle, || format!("set_markup({:?})", markup));
+        set_node_text(self.handle, markup);
+        self.state.borrow_mut().markup = markup.to_string();
+        Ok(())
+    }
+}
+
+stub_widget_backend!(StubHyperlink);
+
+impl StubHyperlink {
+    /// Register (or clear) the callback invoked with a link's index when it is clicked
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_click_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(usize)>) {
+        self.state.borrow_mut().click = callback;
+    }
+}
+
+stub_node!(StubHyperlink, |hyperlink| {
+    LINKS.with(|links| links.borrow_mut().remove(&hyperlink.handle));
+});
+
+/// Read the dimensions from a BMP file header
+fn bitmap_size(bytes: &[u8]) -> Option<Size> {
+    if !bytes.starts_with(b"BM") {
+        return None;
+    }
+    let read_i32 = |offset: usize| {
+        bytes.get(offset..offset + 4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    // Height is negative for top-down bitmaps
+    Some(Size::new(read_i32(18)?.unsigned_abs(), read_i32(22)?.unsigned_abs()))
+}
@@ -0,0 +1,355 @@
+use luma_core::{
+    Result, Error, Size, Rect, Color, CornerPreference, Backdrop, Theme, WindowFlags, WindowState, WindowPlacement,
+    TaskbarProgressState, FlashMode, WidgetTree, WidgetKind, Container, traits::WindowBackend,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use super::{StubHandle, create_node, destroy_node, node_bounds, root_node, set_node_bounds, set_node_text, node_text, record};
+
+/// Work area windows are centered in, as if on a 1920x1080 screen with a taskbar
+const WORK_AREA: Rect = Rect { x: 0, y: 0, width: 1920, height: 1040 };
+
+thread_local! {
+    /// Layouts registered by windows, for resizes and requested layout passes
+    static LAYOUTS: RefCell<HashMap<StubHandle, *mut dyn Container>> = RefCell::new(HashMap::new());
+    /// Windows whose widgets asked for a layout pass that has not run yet
+    static PENDING_LAYOUTS: RefCell<Vec<StubHandle>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Ask the window holding a widget to lay its widgets out again
+/// 
+/// Like the message the Win32 backend posts, the pass runs once the current
+/// callback has returned (or from `Application::run`), so a widget can ask
+/// while it is borrowed.
+pub fn request_layout(handle: StubHandle) {
+    let window = root_node(handle);
+    PENDING_LAYOUTS.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        if !pending.contains(&window) {
+            pending.push(window);
+        }
+    });
+}
+
+/// Run the layout passes requested since the last call, at each window's current size
+pub(super) fn run_pending_layouts() {
+    let pending = PENDING_LAYOUTS.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    for window in pending {
+        let layout = LAYOUTS.with(|layouts| layouts.borrow().get(&window).copied());
+        if let (Some(layout), Some(bounds)) = (layout, node_bounds(window)) {
+            // Safety: The owner clears the layout pointer before dropping the layout.
+            // As after a resize, there is no caller to report a failed pass to.
+            let _ = unsafe { (*layout).layout(Size::new(bounds.width, bounds.height)) };
+        }
+    }
+}
+
+/// Stub window backend
+/// 
+/// Never displayed. Its bounds are tracked so child widgets have screen
+/// positions, and resizing runs the registered layout immediately.
+pub struct StubWindow {
+    handle: StubHandle,
+    visible: bool,
+    start_state: Option<WindowState>,
+    state: Cell<WindowState>,
+    state_callback: Cell<Option<*mut dyn FnMut(WindowState)>>,
+}
+
+impl StubWindow {
+    /// Register a layout for this window (for resize handling)
+    pub fn set_layout_ptr(&self, layout: *mut dyn Container) {
+        LAYOUTS.with(|layouts| layouts.borrow_mut().insert(self.handle, layout));
+    }
+    
+    /// Unregister the layout for this window
+    pub fn clear_layout_ptr(&self) {
+        LAYOUTS.with(|layouts| layouts.borrow_mut().remove(&self.handle));
+    }
+    
+    /// Set the client-area rectangle that drags the window like a title bar
+    pub fn set_drag_region(&mut self, _region: Option<Rect>) {}
+    
+    /// Move the window so its top-left corner is at a screen position
+    pub fn set_position(&mut self, x: i32, y: i32) -> Result<()> {
+        let bounds = self.bounds();
+        set_node_bounds(self.handle, Rect::new(x, y, bounds.width, bounds.height));
+        Ok(())
+    }
+    
+    /// Get the window's outer bounds in screen coordinates
+    pub fn screen_bounds(&self) -> Result<Rect> {
+        super::screen_bounds(self.handle)
+    }
+    
+    /// Get the scale factor of the window's monitor, always 1.0 (96 DPI)
+    pub fn scale_factor(&self) -> f32 {
+        1.0
+    }
+    
+    /// Move the window to the middle of the stub work area
+    pub fn center(&mut self) -> Result<()> {
+        let bounds = self.bounds();
+        let x = WORK_AREA.x + (WORK_AREA.width as i32 - bounds.width as i32) / 2;
+        let y = WORK_AREA.y + (WORK_AREA.height as i32 - bounds.height as i32) / 2;
+        self.set_position(x.max(WORK_AREA.x), y.max(WORK_AREA.y))
+    }
+    
+    /// Set the state the window is in when it is first shown
+    pub fn set_start_state(&mut self, state: WindowState) {
+        self.start_state = Some(state);
+    }
+    
+    /// Maximize, minimize or restore the window
+    pub fn set_state(&mut self, state: WindowState) -> Result<()> {
+        record(self.handle, || format!("set_state({:?})", state));
+        self.visible = true;
+        self.start_state = None;
+        self.change_state(state);
+        Ok(())
+    }
+    
+    /// Check whether the window is maximized
+    pub fn is_maximized(&self) -> bool {
+        self.state.get() == WindowState::Maximized
+    }
+    
+    /// Check whether the window is minimized
+    pub fn is_minimized(&self) -> bool {
+        self.state.get() == WindowState::Minimized
+    }
+    
+    /// Check whether the window is active; nothing ever has the focus here
+    pub fn is_active(&self) -> bool {
+        false
+    }
+    
+    /// Get the window's state and bounds
+    pub fn get_placement(&self) -> Result<WindowPlacement> {
+        let state = self.start_state.unwrap_or(self.state.get());
+        Ok(WindowPlacement::new(state, self.bounds()))
+    }
+    
+    /// Move the window to saved bounds and state
+    pub fn set_placement(&mut self, placement: WindowPlacement) -> Result<()> {
+        record(self.handle, || format!("set_placement({:?})", placement));
+        let bounds = placement.normal_bounds;
+        self.set_position(bounds.x, bounds.y)?;
+        self.set_size(bounds.width, bounds.height)?;
+        if self.visible {
+            self.start_state = None;
+            self.change_state(placement.state);
+        } else {
+            self.start_state = Some(placement.state);
+        }
+        Ok(())
+    }
+    
+    /// Set the callback invoked when the window is maximized, minimized or restored
+    pub fn set_state_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(WindowState)>) {
+        self.state_callback.set(callback);
+    }
+    
+    /// Enter a state, invoking the state callback if it changed, as WM_SIZE would
+    fn change_state(&self, state: WindowState) {
+        if self.state.replace(state) == state {
+            return;
+        }
+        if let Some(callback) = self.state_callback.get() {
+            // Safety: The owner clears the callback before dropping it
+            luma_core::panic_guard::catch_callback_panic(|| unsafe { (*callback)(state) });
+        }
+    }
+    
+    /// Describe the window and every widget in it
+    pub fn widget_tree(&self) -> Result<WidgetTree> {
+        super::widget_tree(self.handle)
+    }
+    
+    /// Keep the window above all non-topmost windows, or release it
+    pub fn set_always_on_top(&mut self, _on_top: bool) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Set the callback invoked when the system theme changes (it never does)
+    pub fn set_theme_callback_ptr(&mut self, _callback: Option<*mut dyn FnMut(Theme)>) {}
+    
+    /// Accept or reject files dropped onto the window (nothing is ever dropped)
+    pub fn set_accept_dropped_files(&mut self, _accept: bool) {}
+    
+    /// Set the callback invoked when files are dropped onto the window
+    pub fn set_files_dropped_callback_ptr(&mut self, _callback: Option<*mut dyn FnMut(Vec<PathBuf>)>) {}
+    
+    /// Use the dark title bar (ignored)
+    pub fn set_dark_mode(&mut self, _dark: bool) {}
+    
+    /// Set how the window corners are rounded (ignored)
+    pub fn set_corner_preference(&mut self, _preference: CornerPreference) {}
+    
+    /// Set the border color (ignored)
+    pub fn set_border_color(&mut self, _color: Color) {}
+    
+    /// Set the title bar color (ignored)
+    pub fn set_caption_color(&mut self, _color: Color) {}
+    
+    /// Set the background color (ignored)
+    pub fn set_background(&mut self, _color: Color) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Set the system backdrop material (ignored)
+    pub fn set_backdrop(&mut self, _backdrop: Backdrop) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Add or remove the layered style needed for transparency
+    pub fn set_layered(&mut self, _layered: bool) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Let mouse input pass through the window (nothing is ever clicked)
+    pub fn set_click_through(&mut self, click_through: bool) -> Result<()> {
+        record(self.handle, || format!("set_click_through({})", click_through));
+        Ok(())
+    }
+    
+    /// Limit the window to a set of rectangles, or `None` for the whole window
+    pub fn set_interactive_region(&mut self, region: Option<&[Rect]>) -> Result<()> {
+        record(self.handle, || format!("set_interactive_region({:?})", region));
+        Ok(())
+    }
+    
+    /// Show progress on the taskbar button, or `None` to remove it
+    pub fn set_taskbar_progress(&mut self, progress: Option<(u64, u64)>) -> Result<()> {
+        record(self.handle, || format!("set_taskbar_progress({:?})", progress));
+        Ok(())
+    }
+    
+    /// Change how the taskbar button shows progress
+    pub fn set_taskbar_state(&mut self, state: TaskbarProgressState) -> Result<()> {
+        record(self.handle, || format!("set_taskbar_state({:?})", state));
+        Ok(())
+    }
+    
+    /// Flash the title bar, the taskbar button or both
+    pub fn flash(&mut self, mode: FlashMode) -> Result<()> {
+        if let FlashMode::Caption(Some(0)) | FlashMode::Tray(Some(0)) | FlashMode::All(Some(0)) = mode {
+            return Err(Error::InvalidParameter("Flash count must be at least 1".to_string()));
+        }
+        record(self.handle, || format!("flash({:?})", mode));
+        Ok(())
+    }
+    
+    /// Flash until the window is activated
+    pub fn flash_until_focus(&mut self) -> Result<()> {
+        record(self.handle, || "flash_until_focus()".to_string());
+        Ok(())
+    }
+    
+    /// Set the opacity of the whole window, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn set_opacity(&mut self, opacity: f32) -> Result<()> {
+        if !(0.0..=1.0).contains(&opacity) {
+            return Err(Error::InvalidParameter(format!("Opacity must be in 0.0..=1.0, got {}", opacity)));
+        }
+        self.set_layered(true)
+    }
+    
+    /// Show the window at once; there is nothing to animate
+    pub fn fade_in(&mut self, _duration: Duration) -> Result<()> {
+        self.set_opacity(1.0)?;
+        self.show()
+    }
+    
+    /// Resize the window to a 32-bit ARGB image (the pixels are not drawn)
+    pub fn update_layered_bitmap(&mut self, width: u32, height: u32, pixels: &[u32]) -> Result<()> {
+        if pixels.len() != width as usize * height as usize {
+            return Err(Error::InvalidParameter(format!(
+                "Expected {} pixels for a {}x{} image, got {}",
+                width as usize * height as usize,
+                width,
+                height,
+                pixels.len()
+            )));
+        }
+        self.set_layered(true)?;
+        let bounds = self.bounds();
+        set_node_bounds(self.handle, Rect::new(bounds.x, bounds.y, width, height));
+        Ok(())
+    }
+    
+    fn bounds(&self) -> Rect {
+        node_bounds(self.handle).unwrap_or_default()
+    }
+}
+
+impl WindowBackend for StubWindow {
+    fn new(title: &str, width: u32, height: u32, flags: WindowFlags) -> Result<Self> {
+        let handle = create_node(std::ptr::null_mut(), WidgetKind::Window, Rect::new(0, 0, width, height));
+        set_node_text(handle, title);
+        record(handle, || format!("new({:?}, {}, {}, {:?})", title, width, height, flags));
+        Ok(Self {
+            handle,
+            visible: false,
+            start_state: None,
+            state: Cell::new(WindowState::Normal),
+            state_callback: Cell::new(None),
+        })
+    }
+    
+    fn set_title(&mut self, title: &str) -> Result<()> {
+        record(self.handle, || format!("set_title({:?})", title));
+        set_node_text(self.handle, title);
+        Ok(())
+    }
+    
+    fn get_title(&self) -> Result<String> {
+        Ok(node_text(self.handle))
+    }
+    
+    fn set_size(&mut self, width: u32, height: u32) -> Result<()> {
+        record(self.handle, || format!("set_size({}, {})", width, height));
+        let bounds = self.bounds();
+        set_node_bounds(self.handle, Rect::new(bounds.x, bounds.y, width, height));
+        if let Some(layout) = LAYOUTS.with(|layouts| layouts.borrow().get(&self.handle).copied()) {
+            // Safety: The owner clears the layout pointer before dropping the layout
+            unsafe { (*layout).layout(Size::new(width, height))? };
+        }
+        Ok(())
+    }
+    
+    fn show(&mut self) -> Result<()> {
+        self.visible = true;
+        match self.start_state.take() {
+            Some(state) => {
+                record(self.handle, || format!("show({:?})", state));
+                self.change_state(state);
+            }
+            None => record(self.handle, || "show()".to_string()),
+        }
+        Ok(())
+    }
+    
+    fn hide(&mut self) -> Result<()> {
+        record(self.handle, || "hide()".to_string());
+        self.visible = false;
+        Ok(())
+    }
+    
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.handle.as_raw()
+    }
+    
+    fn get_client_size(&self) -> Result<Size> {
+        let bounds = self.bounds();
+        Ok(Size::new(bounds.width, bounds.height))
+    }
+}
+
+impl Drop for StubWindow {
+    fn drop(&mut self) {
+        self.clear_layout_ptr();
+        destroy_node(self.handle);
+    }
+}
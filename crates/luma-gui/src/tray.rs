@@ -0,0 +1,47 @@
+use luma_core::{Result, Icon};
+use crate::window::Window;
+use crate::Win32TrayIcon;
+
+/// Icon in the system notification area (tray)
+/// 
+/// The icon is removed when the `TrayIcon` is dropped.
+pub struct TrayIcon {
+    backend: Win32TrayIcon,
+    on_click: Option<Box<dyn FnMut()>>,
+}
+
+impl TrayIcon {
+    /// Add a tray icon owned by `window`
+    /// 
+    /// Click events are routed through the owner window, which must outlive
+    /// the tray icon.
+    pub fn new(window: &Window, icon: Icon, tooltip: &str) -> Result<Self> {
+        Ok(Self {
+            backend: Win32TrayIcon::new(window.raw_handle(), &icon, tooltip)?,
+            on_click: None,
+        })
+    }
+    
+    /// Set the tooltip shown when hovering the icon
+    pub fn set_tooltip(&mut self, tooltip: &str) -> Result<()> {
+        self.backend.set_tooltip(tooltip)
+    }
+    
+    /// Show a balloon notification next to the icon
+    pub fn show_balloon(&mut self, title: &str, text: &str) -> Result<()> {
+        self.backend.show_balloon(title, text)
+    }
+    
+    /// Set the callback invoked when the icon is clicked
+    pub fn on_click<F>(&mut self, callback: F)
+    where
+        F: FnMut() + 'static,
+    {
+        let mut callback: Box<dyn FnMut()> = Box::new(callback);
+        
+        // SAFETY: The callback is owned by the TrayIcon and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+        self.backend.set_click_callback_ptr(Some(callback_ptr));
+        self.on_click = Some(callback);
+    }
+}
@@ -1,6 +1,6 @@
-use luma_core::{Result, Point, Size, ButtonFlags, Rect, WidgetId, Widget, traits::ButtonBackend};
-use crate::window::Window;
-use crate::Win32Button;
+use luma_core::{Result, Point, Size, ButtonFlags, Rect, WidgetId, Widget, traits::{ButtonBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32Button, screen_bounds};
 
 /// Cross-platform button widget
 pub struct Button {
@@ -17,6 +17,8 @@ impl Button {
     }
     
     /// Set the button label
+    /// 
+    /// Prefix a character with `&` to make it the button's Alt+key mnemonic.
     pub fn set_label(&mut self, label: &str) -> Result<()> {
         self.backend.set_label(label)
     }
@@ -26,7 +28,7 @@ impl Button {
         self.backend.set_enabled(enabled)
     }
     
-    /// Get the backend HWND (for callback registration)
+    /// Get the backend HWND
     pub(crate) fn hwnd(&self) -> isize {
         self.backend.hwnd().0
     }
@@ -43,17 +45,26 @@ impl Widget for Button {
         self.bounds
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
+    
+    fn preferred_size(&self) -> Option<Size> {
+        self.backend.preferred_size().ok()
+    }
 }
 
 impl Drop for Button {
     fn drop(&mut self) {
         // Unregister callback before widget is destroyed
         if self.on_click.is_some() {
-            crate::unregister_callback(self.hwnd());
+            crate::unregister_widget_callback(self.id);
         }
+        crate::release_control_id(self.id);
     }
 }
 
@@ -107,7 +118,7 @@ impl ButtonBuilder {
     }
     
     /// Build the button
-    pub fn build(self, parent: &Window) -> Result<Button> {
+    pub fn build(self, parent: &impl Parent) -> Result<Button> {
         let label = self.label.as_deref().unwrap_or("Button");
         let pos = self.position.unwrap_or(Point::new(0, 0));
         let size = self.size.unwrap_or(Size::new(100, 30));
@@ -123,10 +134,11 @@ impl ButtonBuilder {
             on_click: self.on_click,
         };
         
-        // Register callback if present
-        if button.on_click.is_some() {
-            let callback_ptr = button.on_click.as_mut().unwrap().as_mut() as *mut dyn FnMut();
-            crate::register_callback(button.hwnd(), callback_ptr);
+        // Clicks are dispatched through the control ID to the widget's callback
+        crate::assign_control_id(button.hwnd(), button.id)?;
+        if let Some(cb) = button.on_click.as_mut() {
+            let callback_ptr = cb.as_mut() as *mut dyn FnMut();
+            crate::register_widget_callback(button.id, None, callback_ptr);
         }
         
         Ok(button)
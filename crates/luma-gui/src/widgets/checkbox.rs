@@ -1,13 +1,13 @@
-use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::CheckBoxBackend};
-use crate::window::Window;
-use crate::Win32CheckBox;
+use luma_core::{Result, Point, Size, Rect, Color, WidgetId, Widget, traits::{CheckBoxBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32CheckBox, screen_bounds};
 
 /// Cross-platform checkbox widget
 pub struct CheckBox {
     backend: Win32CheckBox,
     id: WidgetId,
     bounds: Rect,
-    on_checked_changed: Option<Box<dyn FnMut(bool)>>,
+    on_checked_changed: Option<Box<dyn FnMut()>>,
 }
 
 impl CheckBox {
@@ -30,6 +30,52 @@ impl CheckBox {
     pub fn set_label(&mut self, label: &str) -> Result<()> {
         self.backend.set_label(label)
     }
+    
+    /// Set the text color, or `None` for the system color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        self.backend.set_text_color(color);
+    }
+    
+    /// Set the background color, or `None` for the background of the parent
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        self.backend.set_background(color)
+    }
+    
+    /// Set the callback invoked with the new state when the user toggles the checkbox
+    /// 
+    /// Not invoked by [`CheckBox::set_checked`]. Replaces any callback set
+    /// with [`CheckBoxBuilder::on_checked_changed`].
+    pub fn on_checked_changed<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(bool) + 'static,
+    {
+        let read_checked = self.backend.checked_reader();
+        let mut callback: Box<dyn FnMut()> = Box::new(move || {
+            if let Ok(checked) = read_checked() {
+                callback(checked);
+            }
+        });
+        
+        // SAFETY: The callback is owned by the CheckBox and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+        crate::register_widget_callback(self.id, None, callback_ptr);
+        self.on_checked_changed = Some(callback);
+    }
+    
+    /// Get the backend HWND
+    pub(crate) fn hwnd(&self) -> isize {
+        self.backend.hwnd().0
+    }
+}
+
+impl Drop for CheckBox {
+    fn drop(&mut self) {
+        // Unregister callback before widget is destroyed
+        if self.on_checked_changed.is_some() {
+            crate::unregister_widget_callback(self.id);
+        }
+        crate::release_control_id(self.id);
+    }
 }
 
 impl Widget for CheckBox {
@@ -43,6 +89,10 @@ impl Widget for CheckBox {
         self.bounds
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
@@ -56,6 +106,8 @@ pub struct CheckBoxBuilder {
     size: Option<Size>,
     checked: bool,
     on_checked_changed: Option<Box<dyn FnMut(bool)>>,
+    text_color: Option<Color>,
+    background: Option<Color>,
 }
 
 impl CheckBoxBuilder {
@@ -65,6 +117,8 @@ impl CheckBoxBuilder {
     }
     
     /// Set the checkbox label
+    /// 
+    /// Prefix a character with `&` to make it the checkbox's Alt+key mnemonic.
     pub fn label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
         self
@@ -88,7 +142,7 @@ impl CheckBoxBuilder {
         self
     }
     
-    /// Set the checked changed callback
+    /// Set the callback invoked with the new state when the user toggles the checkbox
     pub fn on_checked_changed<F>(mut self, callback: F) -> Self
     where
         F: FnMut(bool) + 'static,
@@ -97,20 +151,76 @@ impl CheckBoxBuilder {
         self
     }
     
+    /// Set the text color
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+    
+    /// Set the background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+    
     /// Build the checkbox
-    pub fn build(self, parent: &Window) -> Result<CheckBox> {
+    pub fn build(self, parent: &impl Parent) -> Result<CheckBox> {
         let label = self.label.as_deref().unwrap_or("Checkbox");
         let pos = self.position.unwrap_or(Point::new(0, 0));
         let size = self.size.unwrap_or(Size::new(150, 20));
         
         let parent_hwnd = parent.raw_handle();
-        let backend = Win32CheckBox::new(parent_hwnd, label, pos, size, self.checked)?;
+        let mut backend = Win32CheckBox::new(parent_hwnd, label, pos, size, self.checked)?;
+        if self.text_color.is_some() {
+            backend.set_text_color(self.text_color);
+        }
+        if self.background.is_some() {
+            backend.set_background(self.background)?;
+        }
         
-        Ok(CheckBox {
+        let mut checkbox = CheckBox {
             backend,
             id: WidgetId::new(),
             bounds: Rect::from_point_size(pos, size),
-            on_checked_changed: self.on_checked_changed,
-        })
+            on_checked_changed: None,
+        };
+        
+        // Toggles are dispatched through the control ID to the widget's callback
+        crate::assign_control_id(checkbox.hwnd(), checkbox.id)?;
+        
+        // Register callback if present
+        if let Some(callback) = self.on_checked_changed {
+            checkbox.on_checked_changed(callback);
+        }
+        
+        Ok(checkbox)
+    }
+}
+
+#[cfg(feature = "binding")]
+impl crate::binding::Bindable for CheckBox {
+    fn property_writer(&self, property: &str) -> Option<crate::binding::PropertyWriter> {
+        match property {
+            "IsChecked" => {
+                let write_checked = self.backend.checked_writer();
+                Some(Box::new(move |text| {
+                    let checked = text.parse().map_err(|_| {
+                        luma_core::Error::InvalidParameter(format!("Not a boolean: {}", text))
+                    })?;
+                    write_checked(checked)
+                }))
+            }
+            _ => None,
+        }
+    }
+    
+    fn watch_property(&mut self, property: &str, mut callback: Box<dyn FnMut(String)>) -> Result<()> {
+        match property {
+            "IsChecked" => {
+                self.on_checked_changed(move |checked| callback(checked.to_string()));
+                Ok(())
+            }
+            _ => Err(luma_core::Error::InvalidParameter(format!("Unknown bindable property: {}", property))),
+        }
     }
 }
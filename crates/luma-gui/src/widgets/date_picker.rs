@@ -0,0 +1,160 @@
+use luma_core::{Result, Point, Size, Rect, Date, WidgetId, Widget, traits::{DatePickerBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32DatePicker, screen_bounds};
+
+/// Cross-platform date picker widget with a drop-down calendar
+pub struct DatePicker {
+    backend: Win32DatePicker,
+    id: WidgetId,
+    bounds: Rect,
+    on_change: Option<Box<dyn FnMut(Date)>>,
+}
+
+impl DatePicker {
+    /// Create a date picker builder
+    pub fn builder() -> DatePickerBuilder {
+        DatePickerBuilder::default()
+    }
+    
+    /// Get the selected date
+    pub fn get_date(&self) -> Result<Date> {
+        self.backend.get_date()
+    }
+    
+    /// Set the selected date
+    pub fn set_date(&mut self, date: Date) -> Result<()> {
+        self.backend.set_date(date)
+    }
+    
+    /// Set the display format, or `None` for the system short date
+    /// 
+    /// Uses the Windows date format pictures: `d`/`dd` day, `ddd`/`dddd`
+    /// weekday name, `M`/`MM` month, `MMM`/`MMMM` month name, `yy`/`yyyy`
+    /// year. Literal text goes in single quotes.
+    pub fn set_format(&mut self, format: Option<&str>) -> Result<()> {
+        self.backend.set_format(format)
+    }
+    
+    /// Set the callback invoked when the user picks a date
+    /// 
+    /// Replaces any callback set with [`DatePickerBuilder::on_change`].
+    pub fn on_change<F>(&mut self, callback: F)
+    where
+        F: FnMut(Date) + 'static,
+    {
+        let mut callback: Box<dyn FnMut(Date)> = Box::new(callback);
+        
+        // SAFETY: The callback is owned by the DatePicker and cleared on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(Date);
+        self.backend.set_change_callback_ptr(Some(callback_ptr));
+        self.on_change = Some(callback);
+    }
+}
+
+impl Widget for DatePicker {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+impl Drop for DatePicker {
+    fn drop(&mut self) {
+        // Clear callback before it is freed
+        if self.on_change.is_some() {
+            self.backend.set_change_callback_ptr(None);
+        }
+    }
+}
+
+/// Builder for creating date pickers
+#[derive(Default)]
+pub struct DatePickerBuilder {
+    date: Option<Date>,
+    format: Option<String>,
+    position: Option<Point>,
+    size: Option<Size>,
+    on_change: Option<Box<dyn FnMut(Date)>>,
+}
+
+impl DatePickerBuilder {
+    /// Create a new date picker builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the initial date (today by default)
+    pub fn date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+    
+    /// Set the display format (see [`DatePicker::set_format`])
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Set the callback invoked when the user picks a date
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Date) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+    
+    /// Build the date picker
+    pub fn build(self, parent: &impl Parent) -> Result<DatePicker> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(200, 24));
+        
+        let parent_hwnd = parent.raw_handle();
+        let mut backend = Win32DatePicker::new(parent_hwnd, pos, size)?;
+        
+        if let Some(format) = self.format.as_deref() {
+            backend.set_format(Some(format))?;
+        }
+        if let Some(date) = self.date {
+            backend.set_date(date)?;
+        }
+        
+        let mut date_picker = DatePicker {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            on_change: None,
+        };
+        
+        if let Some(callback) = self.on_change {
+            date_picker.on_change(callback);
+        }
+        
+        Ok(date_picker)
+    }
+}
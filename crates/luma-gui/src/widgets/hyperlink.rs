@@ -0,0 +1,201 @@
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::{HyperlinkBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32Hyperlink, screen_bounds};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Callback invoked with the URL of an activated link
+type LinkCallback = Box<dyn FnMut(&str)>;
+
+/// Cross-platform hyperlink widget
+/// 
+/// Shows text where the parts wrapped in `<a>` tags are clickable links, as in
+/// `Read the <a href="https://example.com/privacy">privacy policy</a>`.
+/// Clicking a link, or pressing Enter while it has focus, calls the click
+/// callback with its `href`, or with the link's text if it has none.
+pub struct Hyperlink {
+    backend: Win32Hyperlink,
+    id: WidgetId,
+    bounds: Rect,
+    /// URL of each link, in markup order
+    targets: Rc<RefCell<Vec<String>>>,
+    on_click: Option<Box<dyn FnMut(usize)>>,
+}
+
+impl Hyperlink {
+    /// Create a hyperlink builder
+    pub fn builder() -> HyperlinkBuilder {
+        HyperlinkBuilder::default()
+    }
+    
+    /// Replace the text and links
+    pub fn set_text(&mut self, markup: &str) -> Result<()> {
+        self.backend.set_markup(markup)?;
+        *self.targets.borrow_mut() = link_targets(markup);
+        Ok(())
+    }
+    
+    /// Set the callback invoked with the URL of a link when the user activates it
+    /// 
+    /// Replaces any callback set with [`HyperlinkBuilder::on_click`].
+    pub fn on_click<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(&str) + 'static,
+    {
+        let targets = Rc::clone(&self.targets);
+        let mut callback: Box<dyn FnMut(usize)> = Box::new(move |index| {
+            // Release the borrow before the callback, which may change the text
+            let url = targets.borrow().get(index).cloned();
+            if let Some(url) = url {
+                callback(&url);
+            }
+        });
+        
+        // SAFETY: The callback is owned by the Hyperlink and cleared on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(usize);
+        self.backend.set_click_callback_ptr(Some(callback_ptr));
+        self.on_click = Some(callback);
+    }
+}
+
+impl Widget for Hyperlink {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+impl Drop for Hyperlink {
+    fn drop(&mut self) {
+        // Clear callback before it is freed
+        if self.on_click.is_some() {
+            self.backend.set_click_callback_ptr(None);
+        }
+    }
+}
+
+/// Builder for creating hyperlinks
+#[derive(Default)]
+pub struct HyperlinkBuilder {
+    text: Option<String>,
+    position: Option<Point>,
+    size: Option<Size>,
+    on_click: Option<LinkCallback>,
+}
+
+impl HyperlinkBuilder {
+    /// Create a new hyperlink builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the text, with links in `<a>` tags
+    pub fn text(mut self, markup: impl Into<String>) -> Self {
+        self.text = Some(markup.into());
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Set the callback invoked with the URL of a link when the user activates it
+    pub fn on_click<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.on_click = Some(Box::new(callback));
+        self
+    }
+    
+    /// Build the hyperlink
+    pub fn build(self, parent: &impl Parent) -> Result<Hyperlink> {
+        let markup = self.text.as_deref().unwrap_or("");
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(200, 20));
+        
+        let parent_hwnd = parent.raw_handle();
+        let backend = Win32Hyperlink::new(parent_hwnd, markup, pos, size)?;
+        
+        let mut hyperlink = Hyperlink {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            targets: Rc::new(RefCell::new(link_targets(markup))),
+            on_click: None,
+        };
+        
+        if let Some(callback) = self.on_click {
+            hyperlink.on_click(callback);
+        }
+        
+        Ok(hyperlink)
+    }
+}
+
+/// Get the URL of each `<a>` link in markup, in order
+/// 
+/// A link's URL is its `href` attribute, or its text if it has none.
+pub(crate) fn link_targets(markup: &str) -> Vec<String> {
+    // Tags are matched case-insensitively; ASCII lowercasing keeps byte offsets
+    let lower = markup.to_ascii_lowercase();
+    let mut targets = Vec::new();
+    let mut rest = 0;
+    
+    while let Some(start) = lower[rest..].find("<a").map(|i| rest + i) {
+        let after_name = start + 2;
+        // Skip tags like <abbr> that only start with "a"
+        if !lower[after_name..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace()) {
+            rest = after_name;
+            continue;
+        }
+        let Some(tag_end) = lower[after_name..].find('>').map(|i| after_name + i) else {
+            break;
+        };
+        let text_end = lower[tag_end..].find("</a>").map_or(markup.len(), |i| tag_end + i);
+        
+        let href = attribute(&markup[after_name..tag_end], &lower[after_name..tag_end], "href");
+        targets.push(href.unwrap_or(&markup[tag_end + 1..text_end]).to_string());
+        rest = text_end;
+    }
+    targets
+}
+
+/// Get the value of a quoted attribute from the inside of a tag
+fn attribute<'a>(attributes: &'a str, lower: &str, name: &str) -> Option<&'a str> {
+    let mut rest = 0;
+    while let Some(found) = lower[rest..].find(name).map(|i| rest + i) {
+        rest = found + name.len();
+        // Only whole attribute names, not the end of a longer one
+        if lower[..found].ends_with(|c: char| !c.is_ascii_whitespace()) {
+            continue;
+        }
+        let value = lower[rest..].trim_start().strip_prefix('=')?.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value_start = attributes.len() - value.len() + 1;
+        let value_end = attributes[value_start..].find(quote)? + value_start;
+        return Some(&attributes[value_start..value_end]);
+    }
+    None
+}
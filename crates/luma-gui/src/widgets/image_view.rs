@@ -0,0 +1,126 @@
+use luma_core::{Result, Point, Size, Rect, Stretch, WidgetId, Widget, traits::{ImageViewBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32ImageView, screen_bounds};
+use std::path::{Path, PathBuf};
+
+/// Cross-platform widget that displays a picture
+/// 
+/// Windows currently loads bitmap (`.bmp`) files only.
+pub struct ImageView {
+    backend: Win32ImageView,
+    id: WidgetId,
+    bounds: Rect,
+    image_size: Option<Size>,
+}
+
+impl ImageView {
+    /// Create an image view builder
+    pub fn builder() -> ImageViewBuilder {
+        ImageViewBuilder::default()
+    }
+    
+    /// Load and display an image file
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        self.image_size = Some(self.backend.load_file(path.as_ref())?);
+        Ok(())
+    }
+    
+    /// Remove the displayed image
+    pub fn clear(&mut self) {
+        self.backend.clear();
+        self.image_size = None;
+    }
+    
+    /// Set how the image is scaled to the view
+    pub fn set_stretch(&mut self, stretch: Stretch) {
+        self.backend.set_stretch(stretch);
+    }
+    
+    /// Get the size in pixels of the loaded image
+    pub fn image_size(&self) -> Option<Size> {
+        self.image_size
+    }
+}
+
+impl Widget for ImageView {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+/// Builder for creating image views
+#[derive(Default)]
+pub struct ImageViewBuilder {
+    file: Option<PathBuf>,
+    stretch: Stretch,
+    position: Option<Point>,
+    size: Option<Size>,
+}
+
+impl ImageViewBuilder {
+    /// Create a new image view builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the image file to display
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(path.into());
+        self
+    }
+    
+    /// Set how the image is scaled (default [`Stretch::Uniform`])
+    pub fn stretch(mut self, stretch: Stretch) -> Self {
+        self.stretch = stretch;
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Build the image view
+    pub fn build(self, parent: &impl Parent) -> Result<ImageView> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(100, 100));
+        
+        let parent_hwnd = parent.raw_handle();
+        let mut backend = Win32ImageView::new(parent_hwnd, pos, size)?;
+        backend.set_stretch(self.stretch);
+        
+        let mut image_view = ImageView {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            image_size: None,
+        };
+        
+        if let Some(file) = self.file {
+            image_view.load_file(file)?;
+        }
+        
+        Ok(image_view)
+    }
+}
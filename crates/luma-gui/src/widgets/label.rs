@@ -1,12 +1,14 @@
-use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::LabelBackend};
-use crate::window::Window;
-use crate::Win32Label;
+use luma_core::{Result, Point, Size, Rect, Color, EllipsisMode, WidgetId, Widget, traits::{LabelBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32Label, screen_bounds, request_layout};
 
 /// Cross-platform label widget
 pub struct Label {
     backend: Win32Label,
     id: WidgetId,
     bounds: Rect,
+    /// Built without a size, so layouts size the label to its text
+    auto_size: bool,
 }
 
 impl Label {
@@ -15,9 +17,40 @@ impl Label {
         LabelBuilder::default()
     }
     
+    /// Get the label text, including any `&` mnemonic prefix
+    pub fn get_text(&self) -> Result<String> {
+        self.backend.get_text()
+    }
+    
     /// Set the label text
+    /// 
+    /// A `&`-prefixed mnemonic moves focus to the widget created after the label.
+    /// A label built without a size is sized to its text by layouts, so its
+    /// window lays out again once the current callback has returned. A label
+    /// built with a size keeps its bounds.
     pub fn set_text(&mut self, text: &str) -> Result<()> {
-        self.backend.set_text(text)
+        self.backend.set_text(text)?;
+        if self.auto_size {
+            request_layout(self.backend.hwnd());
+        }
+        Ok(())
+    }
+    
+    /// Set the text color, or `None` for the system color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        self.backend.set_text_color(color);
+    }
+    
+    /// Set the background color, or `None` for the background of the parent
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        self.backend.set_background(color)
+    }
+    
+    /// Set how text too long for the label is shortened
+    /// 
+    /// The label's text is unchanged; only what is drawn is shortened.
+    pub fn set_ellipsis(&mut self, mode: EllipsisMode) {
+        self.backend.set_ellipsis(mode);
     }
 }
 
@@ -32,9 +65,21 @@ impl Widget for Label {
         self.bounds
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
+    
+    fn preferred_size(&self) -> Option<Size> {
+        if self.auto_size {
+            self.backend.preferred_size().ok()
+        } else {
+            None
+        }
+    }
 }
 
 /// Builder for creating labels
@@ -43,6 +88,9 @@ pub struct LabelBuilder {
     text: Option<String>,
     position: Option<Point>,
     size: Option<Size>,
+    text_color: Option<Color>,
+    background: Option<Color>,
+    ellipsis: EllipsisMode,
 }
 
 impl LabelBuilder {
@@ -64,24 +112,81 @@ impl LabelBuilder {
     }
     
     /// Set the label size
+    /// 
+    /// Without one, layouts size the label to fit its text.
     pub fn size(mut self, width: u32, height: u32) -> Self {
         self.size = Some(Size::new(width, height));
         self
     }
     
+    /// Set the text color
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+    
+    /// Set the background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+    
+    /// Shorten text too long for the label with an ellipsis ("…")
+    /// 
+    /// Use [`EllipsisMode::Path`] for file paths, to keep the file name visible.
+    pub fn ellipsize(mut self, mode: EllipsisMode) -> Self {
+        self.ellipsis = mode;
+        self
+    }
+    
     /// Build the label
-    pub fn build(self, parent: &Window) -> Result<Label> {
+    pub fn build(self, parent: &impl Parent) -> Result<Label> {
         let text = self.text.as_deref().unwrap_or("Label");
         let pos = self.position.unwrap_or(Point::new(0, 0));
         let size = self.size.unwrap_or(Size::new(100, 20));
         
         let parent_hwnd = parent.raw_handle();
-        let backend = Win32Label::new(parent_hwnd, text, pos, size)?;
+        let mut backend = Win32Label::new(parent_hwnd, text, pos, size)?;
+        if self.text_color.is_some() {
+            backend.set_text_color(self.text_color);
+        }
+        if self.background.is_some() {
+            backend.set_background(self.background)?;
+        }
+        if self.ellipsis != EllipsisMode::None {
+            backend.set_ellipsis(self.ellipsis);
+        }
         
         Ok(Label {
             backend,
             id: WidgetId::new(),
             bounds: Rect::from_point_size(pos, size),
+            auto_size: self.size.is_none(),
         })
     }
 }
+
+#[cfg(feature = "binding")]
+impl crate::binding::Bindable for Label {
+    fn property_writer(&self, property: &str) -> Option<crate::binding::PropertyWriter> {
+        match property {
+            "Text" => {
+                // Like set_text, so a bound label built without a size still fits its text
+                let write_text = self.backend.text_writer();
+                let (auto_size, hwnd) = (self.auto_size, self.backend.hwnd());
+                Some(Box::new(move |text: &str| {
+                    write_text(text)?;
+                    if auto_size {
+                        request_layout(hwnd);
+                    }
+                    Ok(())
+                }))
+            }
+            _ => None,
+        }
+    }
+    
+    fn watch_property(&mut self, property: &str, _callback: Box<dyn FnMut(String)>) -> Result<()> {
+        Err(luma_core::Error::InvalidParameter(format!("Label.{} cannot be changed by the user", property)))
+    }
+}
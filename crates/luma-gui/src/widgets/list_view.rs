@@ -0,0 +1,375 @@
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::{ListViewBackend, WidgetBackend}};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::parent::Parent;
+use crate::{Win32ListView, screen_bounds};
+
+/// Orders two cells of a column
+type Comparator = Rc<dyn Fn(&str, &str) -> Ordering>;
+
+/// The user's column header click callback, shared with the header click handler
+type ColumnClickSlot = Rc<RefCell<Option<Box<dyn FnMut(usize)>>>>;
+
+/// How a list view sorts, shared with the header click handler
+#[derive(Default)]
+struct SortState {
+    sortable: bool,
+    comparators: HashMap<usize, Comparator>,
+    sorted_by: Option<(usize, bool)>,
+}
+
+impl SortState {
+    /// Get the comparator for a column, comparing text by default
+    fn comparator(&self, column: usize) -> Comparator {
+        self.comparators
+            .get(&column)
+            .cloned()
+            .unwrap_or_else(|| Rc::new(|a: &str, b: &str| a.cmp(b)))
+    }
+    
+    /// Get the sort for a header click: the other direction for the sorted
+    /// column, else ascending
+    fn next_sort(&self, column: usize) -> Option<(usize, bool)> {
+        if !self.sortable {
+            return None;
+        }
+        match self.sorted_by {
+            Some((sorted, ascending)) if sorted == column => Some((column, !ascending)),
+            _ => Some((column, true)),
+        }
+    }
+}
+
+/// Compare cells as numbers, for numeric columns
+/// 
+/// Text that is not a number sorts after numbers, by text. Use with
+/// [`ListView::set_column_comparator`] so that "10" sorts after "9".
+pub fn compare_numeric(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Cross-platform list view widget: a table of rows with column headers
+pub struct ListView {
+    backend: Win32ListView,
+    id: WidgetId,
+    bounds: Rect,
+    on_selection_changed: Option<Box<dyn FnMut()>>,
+    /// Sorts on header clicks; the backend holds a pointer to it
+    _on_header_click: Box<dyn FnMut(usize)>,
+    column_click: ColumnClickSlot,
+    sort: Rc<RefCell<SortState>>,
+}
+
+impl ListView {
+    /// Create a list view builder
+    pub fn builder() -> ListViewBuilder {
+        ListViewBuilder::default()
+    }
+    
+    /// Add a column at the right, with a header title and a width in pixels
+    pub fn add_column(&mut self, title: &str, width: u32) -> Result<()> {
+        self.backend.add_column(title, width)
+    }
+    
+    /// Get the number of columns
+    pub fn column_count(&self) -> usize {
+        self.backend.column_count()
+    }
+    
+    /// Add a row at the bottom, one cell per column
+    /// 
+    /// Missing trailing cells are left empty. Fails if there are more cells than columns.
+    pub fn add_row(&mut self, cells: &[&str]) -> Result<()> {
+        self.backend.add_row(cells)
+    }
+    
+    /// Remove a row by index
+    pub fn remove_row(&mut self, index: usize) -> Result<()> {
+        self.backend.remove_row(index)
+    }
+    
+    /// Remove all rows, keeping the columns
+    pub fn clear_rows(&mut self) -> Result<()> {
+        self.backend.clear_rows()
+    }
+    
+    /// Get the number of rows
+    pub fn row_count(&self) -> Result<usize> {
+        self.backend.row_count()
+    }
+    
+    /// Get the cells of a row, one per column
+    pub fn get_row(&self, index: usize) -> Result<Vec<String>> {
+        self.backend.get_row(index)
+    }
+    
+    /// Get the selected row
+    pub fn get_selected_index(&self) -> Result<Option<usize>> {
+        self.backend.get_selected_index()
+    }
+    
+    /// Select a row, or clear the selection with `None`
+    pub fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
+        self.backend.set_selected_index(index)
+    }
+    
+    /// Set the callback invoked with the selected row when the user selects or deselects one
+    /// 
+    /// Moving the selection deselects the old row first, so the callback may
+    /// see `None` just before the new row. Replaces any callback set with
+    /// [`ListViewBuilder::on_select`].
+    pub fn on_select<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(Option<usize>) + 'static,
+    {
+        let read_selection = self.backend.selection_reader();
+        let mut callback: Box<dyn FnMut()> = Box::new(move || callback(read_selection()));
+        
+        // SAFETY: The callback is owned by the ListView and cleared on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+        self.backend.set_selection_callback_ptr(Some(callback_ptr));
+        self.on_selection_changed = Some(callback);
+    }
+    
+    /// Set the callback invoked with a column's index when the user clicks its header
+    /// 
+    /// If the list view is sortable, the rows are sorted by the column before
+    /// the callback runs. Replaces any callback set with
+    /// [`ListViewBuilder::on_column_click`].
+    pub fn on_column_click<F>(&mut self, callback: F)
+    where
+        F: FnMut(usize) + 'static,
+    {
+        *self.column_click.borrow_mut() = Some(Box::new(callback));
+    }
+    
+    /// Sort the rows by a column, with the column's comparator
+    /// 
+    /// The selected row stays selected. Rows added later are not sorted
+    /// into place.
+    pub fn sort_by_column(&mut self, column: usize, ascending: bool) -> Result<()> {
+        let compare = self.sort.borrow().comparator(column);
+        self.backend.sort_rows(column, ascending, &*compare)?;
+        self.sort.borrow_mut().sorted_by = Some((column, ascending));
+        Ok(())
+    }
+    
+    /// Get the column the rows were last sorted by, and whether ascending
+    pub fn sorted_by(&self) -> Option<(usize, bool)> {
+        self.sort.borrow().sorted_by
+    }
+    
+    /// Set whether clicking a column header sorts the rows by that column
+    /// 
+    /// The first click sorts ascending; clicking the same header again
+    /// reverses the order.
+    pub fn set_sortable(&mut self, sortable: bool) {
+        self.sort.borrow_mut().sortable = sortable;
+    }
+    
+    /// Check if clicking a column header sorts the rows
+    pub fn is_sortable(&self) -> bool {
+        self.sort.borrow().sortable
+    }
+    
+    /// Set how a column's cells are ordered when sorting, instead of by text
+    /// 
+    /// See [`compare_numeric`] for numeric columns.
+    pub fn set_column_comparator<F>(&mut self, column: usize, compare: F)
+    where
+        F: Fn(&str, &str) -> Ordering + 'static,
+    {
+        self.sort.borrow_mut().comparators.insert(column, Rc::new(compare));
+    }
+}
+
+/// Build the column header click handler: sort if sortable, then run the user's callback
+fn header_click_handler(
+    backend: &Win32ListView,
+    sort: &Rc<RefCell<SortState>>,
+    column_click: &ColumnClickSlot,
+) -> Box<dyn FnMut(usize)> {
+    let sort_rows = backend.row_sorter();
+    let sort = Rc::clone(sort);
+    let column_click = Rc::clone(column_click);
+    Box::new(move |column| {
+        let next = sort.borrow().next_sort(column);
+        if let Some((column, ascending)) = next {
+            let compare = sort.borrow().comparator(column);
+            if sort_rows(column, ascending, &*compare).is_ok() {
+                sort.borrow_mut().sorted_by = Some((column, ascending));
+            }
+        }
+        if let Some(callback) = column_click.borrow_mut().as_mut() {
+            callback(column);
+        }
+    })
+}
+
+impl Widget for ListView {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+impl Drop for ListView {
+    fn drop(&mut self) {
+        // Clear callbacks before they are freed
+        if self.on_selection_changed.is_some() {
+            self.backend.set_selection_callback_ptr(None);
+        }
+        self.backend.set_column_click_callback_ptr(None);
+    }
+}
+
+/// Builder for creating list views
+#[derive(Default)]
+pub struct ListViewBuilder {
+    columns: Vec<(String, u32)>,
+    rows: Vec<Vec<String>>,
+    position: Option<Point>,
+    size: Option<Size>,
+    on_select: Option<Box<dyn FnMut(Option<usize>)>>,
+    on_column_click: Option<Box<dyn FnMut(usize)>>,
+    sortable: bool,
+    comparators: Vec<(usize, Comparator)>,
+}
+
+impl ListViewBuilder {
+    /// Create a new list view builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add a column with a header title and a width in pixels
+    pub fn column(mut self, title: impl Into<String>, width: u32) -> Self {
+        self.columns.push((title.into(), width));
+        self
+    }
+    
+    /// Add a row (can pass any iterable of string-like cells)
+    pub fn row<I, S>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows.push(cells.into_iter().map(|cell| cell.into()).collect());
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Set the selection callback (see [`ListView::on_select`])
+    pub fn on_select<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Option<usize>) + 'static,
+    {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+    
+    /// Set the column header click callback (see [`ListView::on_column_click`])
+    pub fn on_column_click<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(usize) + 'static,
+    {
+        self.on_column_click = Some(Box::new(callback));
+        self
+    }
+    
+    /// Set whether clicking a column header sorts the rows (see [`ListView::set_sortable`])
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+    
+    /// Set how a column's cells are ordered (see [`ListView::set_column_comparator`])
+    pub fn comparator<F>(mut self, column: usize, compare: F) -> Self
+    where
+        F: Fn(&str, &str) -> Ordering + 'static,
+    {
+        self.comparators.push((column, Rc::new(compare)));
+        self
+    }
+    
+    /// Build the list view
+    /// 
+    /// Fails if a row has more cells than there are columns.
+    pub fn build(self, parent: &impl Parent) -> Result<ListView> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(300, 150));
+        
+        let parent_hwnd = parent.raw_handle();
+        let mut backend = Win32ListView::new(parent_hwnd, pos, size)?;
+        
+        let sort = Rc::new(RefCell::new(SortState {
+            sortable: self.sortable,
+            comparators: self.comparators.into_iter().collect(),
+            sorted_by: None,
+        }));
+        let column_click = ColumnClickSlot::default();
+        let mut on_header_click = header_click_handler(&backend, &sort, &column_click);
+        
+        // SAFETY: The handler is owned by the ListView and cleared on drop
+        let callback_ptr = on_header_click.as_mut() as *mut dyn FnMut(usize);
+        backend.set_column_click_callback_ptr(Some(callback_ptr));
+        
+        let mut list_view = ListView {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            on_selection_changed: None,
+            _on_header_click: on_header_click,
+            column_click,
+            sort,
+        };
+        
+        for (title, width) in &self.columns {
+            list_view.add_column(title, *width)?;
+        }
+        for row in &self.rows {
+            let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+            list_view.add_row(&cells)?;
+        }
+        
+        if let Some(callback) = self.on_select {
+            list_view.on_select(callback);
+        }
+        if let Some(callback) = self.on_column_click {
+            list_view.on_column_click(callback);
+        }
+        
+        Ok(list_view)
+    }
+}
@@ -1,14 +1,14 @@
-use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, ListBoxFlags, traits::ListBoxBackend};
-use crate::window::Window;
-use crate::Win32ListBox;
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, ListBoxFlags, traits::{ListBoxBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32ListBox, screen_bounds};
 
 /// Cross-platform listbox widget
 pub struct ListBox {
     backend: Win32ListBox,
     id: WidgetId,
     bounds: Rect,
-    on_select_single: Option<Box<dyn FnMut(Option<usize>)>>,
-    on_select_multi: Option<Box<dyn FnMut(Vec<usize>)>>,
+    on_selection_changed: Option<Box<dyn FnMut()>>,
+    on_activate: Option<Box<dyn FnMut()>>,
 }
 
 impl ListBox {
@@ -23,6 +23,9 @@ impl ListBox {
     }
     
     /// Remove an item by index
+    /// 
+    /// Fails with [`Error::IndexOutOfRange`](luma_core::Error::IndexOutOfRange)
+    /// if there is no item at `index`.
     pub fn remove_item(&mut self, index: usize) -> Result<()> {
         self.backend.remove_item(index)
     }
@@ -51,6 +54,36 @@ impl ListBox {
     pub fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
         self.backend.set_selected_index(index)
     }
+    
+    /// Set selected indices (for multi-select), replacing the selection
+    /// 
+    /// Pass an empty slice to clear the selection. Fails if any index is out
+    /// of range, leaving the selection unchanged.
+    pub fn set_selected_indices(&mut self, indices: &[usize]) -> Result<()> {
+        self.backend.set_selected_indices(indices)
+    }
+    
+    /// Select every item (for multi-select)
+    pub fn select_all(&mut self) -> Result<()> {
+        self.backend.set_all_selected(true)
+    }
+    
+    /// Clear the selection (for multi-select)
+    pub fn deselect_all(&mut self) -> Result<()> {
+        self.backend.set_all_selected(false)
+    }
+}
+
+impl Drop for ListBox {
+    fn drop(&mut self) {
+        // Unregister callback before widget is destroyed
+        if self.on_selection_changed.is_some() {
+            self.backend.set_selection_callback_ptr(None);
+        }
+        if self.on_activate.is_some() {
+            self.backend.set_activate_callback_ptr(None);
+        }
+    }
 }
 
 impl Widget for ListBox {
@@ -64,6 +97,10 @@ impl Widget for ListBox {
         self.bounds
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
@@ -78,6 +115,7 @@ pub struct ListBoxBuilder {
     flags: Option<ListBoxFlags>,
     on_select_single: Option<Box<dyn FnMut(Option<usize>)>>,
     on_select_multi: Option<Box<dyn FnMut(Vec<usize>)>>,
+    on_activate: Option<Box<dyn FnMut(usize)>>,
 }
 
 impl ListBoxBuilder {
@@ -154,8 +192,17 @@ impl ListBoxBuilder {
         self
     }
     
+    /// Set the callback invoked with an item's index when it is double-clicked
+    pub fn on_activate<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(usize) + 'static,
+    {
+        self.on_activate = Some(Box::new(callback));
+        self
+    }
+    
     /// Build the listbox
-    pub fn build(self, parent: &Window) -> Result<ListBox> {
+    pub fn build(self, parent: &impl Parent) -> Result<ListBox> {
         // Validate: cannot have both callbacks
         if self.on_select_single.is_some() && self.on_select_multi.is_some() {
             return Err(luma_core::Error::InvalidParameter(
@@ -170,12 +217,37 @@ impl ListBoxBuilder {
         let parent_hwnd = parent.raw_handle();
         let backend = Win32ListBox::new(parent_hwnd, pos, size, flags)?;
         
+        // Wrap the user's callback in one that reads the new selection
+        let read_selection = backend.selection_reader();
+        let on_selection_changed: Option<Box<dyn FnMut()>> =
+            match (self.on_select_single, self.on_select_multi) {
+                (Some(mut callback), _) => Some(Box::new(move || {
+                    if let Ok(indices) = read_selection() {
+                        callback(indices.first().copied());
+                    }
+                })),
+                (None, Some(mut callback)) => Some(Box::new(move || {
+                    if let Ok(indices) = read_selection() {
+                        callback(indices);
+                    }
+                })),
+                (None, None) => None,
+            };
+        let read_activated = backend.activation_reader();
+        let on_activate = self.on_activate.map(|mut callback| -> Box<dyn FnMut()> {
+            Box::new(move || {
+                if let Some(index) = read_activated() {
+                    callback(index);
+                }
+            })
+        });
+        
         let mut listbox = ListBox {
             backend,
             id: WidgetId::new(),
             bounds: Rect::from_point_size(pos, size),
-            on_select_single: self.on_select_single,
-            on_select_multi: self.on_select_multi,
+            on_selection_changed,
+            on_activate,
         };
         
         // Add initial items
@@ -183,6 +255,16 @@ impl ListBoxBuilder {
             listbox.add_item(&item)?;
         }
         
+        // Register callback if present
+        if let Some(callback) = listbox.on_selection_changed.as_mut() {
+            let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+            listbox.backend.set_selection_callback_ptr(Some(callback_ptr));
+        }
+        if let Some(callback) = listbox.on_activate.as_mut() {
+            let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+            listbox.backend.set_activate_callback_ptr(Some(callback_ptr));
+        }
+        
         Ok(listbox)
     }
 }
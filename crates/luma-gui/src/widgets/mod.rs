@@ -3,9 +3,25 @@ pub mod label;
 pub mod textinput;
 pub mod checkbox;
 pub mod listbox;
+pub mod list_view;
+pub mod panel;
+pub mod scroll_viewer;
+pub mod number_box;
+pub mod date_picker;
+pub mod image_view;
+pub mod progress_bar;
+pub mod hyperlink;
 
 pub use button::{Button, ButtonBuilder};
 pub use label::{Label, LabelBuilder};
 pub use textinput::{TextInput, TextInputBuilder};
 pub use checkbox::{CheckBox, CheckBoxBuilder};
 pub use listbox::{ListBox, ListBoxBuilder};
+pub use list_view::{ListView, ListViewBuilder, compare_numeric};
+pub use panel::{Panel, PanelBuilder};
+pub use scroll_viewer::{ScrollViewer, ScrollViewerBuilder};
+pub use number_box::{NumberBox, NumberBoxBuilder};
+pub use date_picker::{DatePicker, DatePickerBuilder};
+pub use image_view::{ImageView, ImageViewBuilder};
+pub use progress_bar::{ProgressBar, ProgressBarBuilder};
+pub use hyperlink::{Hyperlink, HyperlinkBuilder};
@@ -0,0 +1,188 @@
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::{NumberBoxBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32NumberBox, screen_bounds};
+
+/// Cross-platform numeric input with up/down buttons
+/// 
+/// Typed text is validated when editing finishes (Enter or focus loss);
+/// anything that is not a number reverts to the previous value.
+pub struct NumberBox {
+    backend: Win32NumberBox,
+    id: WidgetId,
+    bounds: Rect,
+    on_change: Option<Box<dyn FnMut(f64)>>,
+}
+
+impl NumberBox {
+    /// Create a number box builder
+    pub fn builder() -> NumberBoxBuilder {
+        NumberBoxBuilder::default()
+    }
+    
+    /// Get the current value
+    pub fn value(&self) -> f64 {
+        self.backend.value()
+    }
+    
+    /// Set the value, clamped to the range
+    pub fn set_value(&mut self, value: f64) -> Result<()> {
+        self.backend.set_value(value)
+    }
+    
+    /// Set the allowed range, clamping the current value into it
+    pub fn set_range(&mut self, min: f64, max: f64) -> Result<()> {
+        self.backend.set_range(min, max)
+    }
+    
+    /// Set the callback invoked when the user changes the value
+    /// 
+    /// Replaces any callback set with [`NumberBoxBuilder::on_change`].
+    pub fn on_change<F>(&mut self, callback: F)
+    where
+        F: FnMut(f64) + 'static,
+    {
+        let mut callback: Box<dyn FnMut(f64)> = Box::new(callback);
+        
+        // SAFETY: The callback is owned by the NumberBox and cleared on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(f64);
+        self.backend.set_change_callback_ptr(Some(callback_ptr));
+        self.on_change = Some(callback);
+    }
+}
+
+impl Widget for NumberBox {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        // The edit box is the left part of the control; the up/down buttons sit beside it
+        let edit = screen_bounds(self.backend.hwnd())?;
+        Ok(Rect::from_point_size(edit.point(), self.bounds.size()))
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+impl Drop for NumberBox {
+    fn drop(&mut self) {
+        // Clear callback before it is freed
+        if self.on_change.is_some() {
+            self.backend.set_change_callback_ptr(None);
+        }
+    }
+}
+
+/// Builder for creating number boxes
+pub struct NumberBoxBuilder {
+    value: f64,
+    range: Option<(f64, f64)>,
+    step: f64,
+    decimals: usize,
+    position: Option<Point>,
+    size: Option<Size>,
+    on_change: Option<Box<dyn FnMut(f64)>>,
+}
+
+impl Default for NumberBoxBuilder {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            range: None,
+            step: 1.0,
+            decimals: 0,
+            position: None,
+            size: None,
+            on_change: None,
+        }
+    }
+}
+
+impl NumberBoxBuilder {
+    /// Create a new number box builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the initial value
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+    
+    /// Set the allowed range (unbounded by default)
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+    
+    /// Set the amount added or removed by the up/down buttons (default 1)
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+    
+    /// Set the number of decimal places shown (default 0)
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Set the callback invoked when the user changes the value
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(f64) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+    
+    /// Build the number box
+    pub fn build(self, parent: &impl Parent) -> Result<NumberBox> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(100, 24));
+        
+        let parent_hwnd = parent.raw_handle();
+        let mut backend = Win32NumberBox::new(parent_hwnd, pos, size)?;
+        
+        backend.set_step(self.step)?;
+        backend.set_decimals(self.decimals)?;
+        if let Some((min, max)) = self.range {
+            backend.set_range(min, max)?;
+        }
+        backend.set_value(self.value)?;
+        
+        let mut number_box = NumberBox {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            on_change: None,
+        };
+        
+        if let Some(callback) = self.on_change {
+            number_box.on_change(callback);
+        }
+        
+        Ok(number_box)
+    }
+}
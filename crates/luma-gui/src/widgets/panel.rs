@@ -0,0 +1,112 @@
+use luma_core::{Result, Point, Size, Rect, Color, WidgetId, Widget, traits::{PanelBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32Panel, screen_bounds};
+
+/// Cross-platform panel widget that groups child widgets
+/// 
+/// Build children with the panel as their parent, then hand them to
+/// [`Panel::add`] so the panel owns them. [`Panel::clear_children`] drops
+/// owned children first, so their handles and callbacks are released exactly once.
+pub struct Panel {
+    backend: Win32Panel,
+    id: WidgetId,
+    bounds: Rect,
+    children: Vec<Box<dyn Widget>>,
+}
+
+impl Panel {
+    /// Create a panel builder
+    pub fn builder() -> PanelBuilder {
+        PanelBuilder::default()
+    }
+    
+    /// Take ownership of a child widget created in this panel
+    pub fn add(&mut self, child: impl Widget + 'static) {
+        self.children.push(Box::new(child));
+    }
+    
+    /// Get the number of child widgets in the panel
+    pub fn child_count(&self) -> usize {
+        self.backend.child_count()
+    }
+    
+    /// Set the color the panel is painted with, instead of its parent's background
+    pub fn set_background(&mut self, color: Color) -> Result<()> {
+        self.backend.set_background(color)
+    }
+    
+    /// Destroy all child widgets in the panel
+    pub fn clear_children(&mut self) -> Result<()> {
+        // Owned widgets clean up after themselves; anything left was not added
+        self.children.clear();
+        self.backend.clear_children()
+    }
+}
+
+impl Parent for Panel {
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.backend.raw_handle()
+    }
+}
+
+impl Widget for Panel {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+/// Builder for creating panels
+#[derive(Default)]
+pub struct PanelBuilder {
+    position: Option<Point>,
+    size: Option<Size>,
+}
+
+impl PanelBuilder {
+    /// Create a new panel builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the panel position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the panel size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Build the panel
+    pub fn build(self, parent: &impl Parent) -> Result<Panel> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(200, 200));
+        
+        let parent_hwnd = parent.raw_handle();
+        let backend = Win32Panel::new(parent_hwnd, pos, size)?;
+        
+        Ok(Panel {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            children: Vec::new(),
+        })
+    }
+}
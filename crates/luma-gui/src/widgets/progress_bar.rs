@@ -0,0 +1,173 @@
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::{ProgressBarBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32ProgressBar, screen_bounds};
+use std::time::Duration;
+
+/// Time between steps of the indeterminate animation unless set otherwise
+const DEFAULT_MARQUEE_INTERVAL: Duration = Duration::from_millis(30);
+
+/// Cross-platform progress bar widget
+/// 
+/// Shows a value within a range, or a repeating animation while the amount
+/// of work left is unknown.
+pub struct ProgressBar {
+    backend: Win32ProgressBar,
+    id: WidgetId,
+    bounds: Rect,
+    indeterminate: bool,
+    marquee_interval: Duration,
+}
+
+impl ProgressBar {
+    /// Create a progress bar builder
+    pub fn builder() -> ProgressBarBuilder {
+        ProgressBarBuilder::default()
+    }
+    
+    /// Get the current value
+    /// 
+    /// While indeterminate, this is the value shown once the bar is determinate again.
+    pub fn value(&self) -> Result<u32> {
+        self.backend.get_value()
+    }
+    
+    /// Set the current value, clamped to the range
+    pub fn set_value(&mut self, value: u32) -> Result<()> {
+        self.backend.set_value(value)
+    }
+    
+    /// Set the values at the empty and full ends of the bar
+    /// 
+    /// Fails if `min` is greater than `max`.
+    pub fn set_range(&mut self, min: u32, max: u32) -> Result<()> {
+        self.backend.set_range(min, max)
+    }
+    
+    /// Check whether the bar is showing the indeterminate animation
+    pub fn is_indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+    
+    /// Start or stop the indeterminate ("working…") animation
+    /// 
+    /// Stopping it shows the bar's value again, including any value set
+    /// while the animation ran.
+    pub fn set_indeterminate(&mut self, indeterminate: bool) -> Result<()> {
+        self.backend.set_indeterminate(indeterminate, self.marquee_interval)?;
+        self.indeterminate = indeterminate;
+        Ok(())
+    }
+    
+    /// Set the time between steps of the indeterminate animation
+    /// 
+    /// Shorter intervals move faster. Applies at once if the animation is running.
+    pub fn set_marquee_interval(&mut self, interval: Duration) -> Result<()> {
+        self.marquee_interval = interval;
+        if self.indeterminate {
+            self.backend.set_indeterminate(true, interval)?;
+        }
+        Ok(())
+    }
+}
+
+impl Widget for ProgressBar {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        Ok(())
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+/// Builder for creating progress bars
+#[derive(Default)]
+pub struct ProgressBarBuilder {
+    range: Option<(u32, u32)>,
+    value: Option<u32>,
+    indeterminate: bool,
+    marquee_interval: Option<Duration>,
+    position: Option<Point>,
+    size: Option<Size>,
+}
+
+impl ProgressBarBuilder {
+    /// Create a new progress bar builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the values at the empty and full ends of the bar (0 to 100 by default)
+    pub fn range(mut self, min: u32, max: u32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+    
+    /// Set the initial value
+    pub fn value(mut self, value: u32) -> Self {
+        self.value = Some(value);
+        self
+    }
+    
+    /// Start with the indeterminate animation running
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+    
+    /// Set the time between steps of the indeterminate animation (30 ms by default)
+    pub fn marquee_interval(mut self, interval: Duration) -> Self {
+        self.marquee_interval = Some(interval);
+        self
+    }
+    
+    /// Set the position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Build the progress bar
+    pub fn build(self, parent: &impl Parent) -> Result<ProgressBar> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(200, 20));
+        
+        let parent_hwnd = parent.raw_handle();
+        let mut backend = Win32ProgressBar::new(parent_hwnd, pos, size)?;
+        if let Some((min, max)) = self.range {
+            backend.set_range(min, max)?;
+        }
+        if let Some(value) = self.value {
+            backend.set_value(value)?;
+        }
+        
+        let mut progress_bar = ProgressBar {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            indeterminate: false,
+            marquee_interval: self.marquee_interval.unwrap_or(DEFAULT_MARQUEE_INTERVAL),
+        };
+        if self.indeterminate {
+            progress_bar.set_indeterminate(true)?;
+        }
+        
+        Ok(progress_bar)
+    }
+}
@@ -0,0 +1,134 @@
+use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, Container, BoxLayout, traits::{ScrollViewerBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32ScrollViewer, with_deferred_positioning, screen_bounds};
+
+/// Number of measure passes when showing or hiding a scroll bar changes the viewport
+const MAX_MEASURE_PASSES: usize = 3;
+
+/// Container that scrolls content larger than itself
+/// 
+/// Create child widgets with the scroll viewer as their parent, arrange them
+/// in a layout, and pass it to [`ScrollViewer::set_content`]. The layout is
+/// measured at its natural size; scroll bars appear when that is larger
+/// than the visible area.
+pub struct ScrollViewer {
+    backend: Win32ScrollViewer,
+    id: WidgetId,
+    bounds: Rect,
+    content: Option<Box<dyn Container>>,
+}
+
+impl ScrollViewer {
+    /// Create a scroll viewer builder
+    pub fn builder() -> ScrollViewerBuilder {
+        ScrollViewerBuilder::default()
+    }
+    
+    /// Set the layout of the scrolled content
+    pub fn set_content(&mut self, layout: BoxLayout) -> Result<()> {
+        self.content = Some(Box::new(layout));
+        self.relayout()
+    }
+    
+    /// Get the current scroll offset of the content
+    pub fn scroll_offset(&self) -> Point {
+        self.backend.scroll_offset()
+    }
+    
+    /// Scroll so the given content position is at the top-left of the view
+    pub fn scroll_to(&mut self, x: i32, y: i32) -> Result<()> {
+        self.backend.scroll_to(Point::new(x, y))
+    }
+    
+    /// Measure the content against the viewport and lay it out
+    fn relayout(&mut self) -> Result<()> {
+        let Some(content) = self.content.as_mut() else {
+            return Ok(());
+        };
+        
+        // Scroll bars take space from the viewport, so repeat until it settles
+        let mut viewport = self.backend.viewport_size()?;
+        let mut size = viewport;
+        for _ in 0..MAX_MEASURE_PASSES {
+            let natural = content.natural_size(viewport);
+            size = Size::new(natural.width.max(viewport.width), natural.height.max(viewport.height));
+            self.backend.set_content_size(size)?;
+            
+            let new_viewport = self.backend.viewport_size()?;
+            if new_viewport == viewport {
+                break;
+            }
+            viewport = new_viewport;
+        }
+        
+        with_deferred_positioning(|| content.layout(size))
+    }
+}
+
+impl Parent for ScrollViewer {
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.backend.raw_handle()
+    }
+}
+
+impl Widget for ScrollViewer {
+    fn set_bounds(&mut self, bounds: Rect) -> Result<()> {
+        self.bounds = bounds;
+        self.backend.set_bounds(bounds.x, bounds.y, bounds.width, bounds.height)?;
+        self.relayout()
+    }
+    
+    fn get_bounds(&self) -> Rect {
+        self.bounds
+    }
+    
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
+    fn id(&self) -> WidgetId {
+        self.id
+    }
+}
+
+/// Builder for creating scroll viewers
+#[derive(Default)]
+pub struct ScrollViewerBuilder {
+    position: Option<Point>,
+    size: Option<Size>,
+}
+
+impl ScrollViewerBuilder {
+    /// Create a new scroll viewer builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the scroll viewer position
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some(Point::new(x, y));
+        self
+    }
+    
+    /// Set the scroll viewer size
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+    
+    /// Build the scroll viewer
+    pub fn build(self, parent: &impl Parent) -> Result<ScrollViewer> {
+        let pos = self.position.unwrap_or(Point::new(0, 0));
+        let size = self.size.unwrap_or(Size::new(300, 200));
+        
+        let parent_hwnd = parent.raw_handle();
+        let backend = Win32ScrollViewer::new(parent_hwnd, pos, size)?;
+        
+        Ok(ScrollViewer {
+            backend,
+            id: WidgetId::new(),
+            bounds: Rect::from_point_size(pos, size),
+            content: None,
+        })
+    }
+}
@@ -1,12 +1,15 @@
-use luma_core::{Result, Point, Size, Rect, WidgetId, Widget, traits::TextInputBackend};
-use crate::window::Window;
-use crate::Win32TextInput;
+use luma_core::{Result, Point, Size, Rect, Color, WidgetId, Widget, traits::{TextInputBackend, WidgetBackend}};
+use crate::parent::Parent;
+use crate::{Win32TextInput, screen_bounds};
 
 /// Cross-platform text input widget
 pub struct TextInput {
     backend: Win32TextInput,
     id: WidgetId,
     bounds: Rect,
+    on_text_changed: Option<Box<dyn FnMut()>>,
+    /// Whether the placeholder stays visible while the input has focus
+    placeholder_when_focused: bool,
 }
 
 impl TextInput {
@@ -29,6 +32,135 @@ impl TextInput {
     pub fn set_read_only(&mut self, read_only: bool) -> Result<()> {
         self.backend.set_read_only(read_only)
     }
+    
+    /// Get the selected range as character offsets, start first
+    /// 
+    /// When nothing is selected both offsets are the caret position.
+    pub fn get_selection(&self) -> Result<(usize, usize)> {
+        self.backend.get_selection()
+    }
+    
+    /// Select the characters from `start` up to (not including) `end`
+    /// 
+    /// Offsets count characters and are clamped to the text. The caret goes
+    /// to `end`, so `end` may come before `start` to select backwards.
+    pub fn set_selection(&mut self, start: usize, end: usize) -> Result<()> {
+        self.backend.set_selection(start, end)
+    }
+    
+    /// Select all of the text
+    pub fn select_all(&mut self) -> Result<()> {
+        self.backend.set_selection(0, usize::MAX)
+    }
+    
+    /// Move the caret to a character offset, clearing the selection
+    /// 
+    /// Use `usize::MAX` for the end of the text.
+    pub fn set_caret(&mut self, pos: usize) -> Result<()> {
+        self.backend.set_selection(pos, pos)
+    }
+    
+    /// Insert text at the caret, replacing the selection if there is one
+    /// 
+    /// The caret ends up after the inserted text, and the user can undo the
+    /// insertion. Runs the text changed callback like any other edit.
+    pub fn insert_text_at_caret(&mut self, text: &str) -> Result<()> {
+        self.backend.replace_selection(text)
+    }
+    
+    /// Check whether there is an edit to undo
+    pub fn can_undo(&self) -> Result<bool> {
+        self.backend.can_undo()
+    }
+    
+    /// Undo the last edit
+    /// 
+    /// Like the native control, only the last edit is kept, and undoing again
+    /// redoes it. Text set with [`TextInput::set_text`] can't be undone.
+    pub fn undo(&mut self) -> Result<()> {
+        self.backend.undo()
+    }
+    
+    /// Move the selected text to the clipboard
+    pub fn cut(&mut self) -> Result<()> {
+        self.backend.cut()
+    }
+    
+    /// Copy the selected text to the clipboard
+    pub fn copy(&self) -> Result<()> {
+        self.backend.copy()
+    }
+    
+    /// Replace the selection with the text on the clipboard
+    pub fn paste(&mut self) -> Result<()> {
+        self.backend.paste()
+    }
+    
+    /// Check whether the input has the keyboard focus
+    /// 
+    /// Lets window-wide commands, such as an Edit accelerator, act on the input
+    /// the user is typing in.
+    pub fn has_focus(&self) -> bool {
+        self.backend.has_focus()
+    }
+    
+    /// Set the grey hint shown while the input is empty, like XAML's `PlaceholderText`
+    /// 
+    /// Pass `""` to remove it. The hint disappears while the input has focus
+    /// unless `show_when_focused` is set.
+    pub fn set_placeholder(&mut self, text: &str, show_when_focused: bool) -> Result<()> {
+        self.backend.set_placeholder(text, show_when_focused)?;
+        self.placeholder_when_focused = show_when_focused;
+        Ok(())
+    }
+    
+    /// Limit how many characters the user can enter, or `None` for no limit
+    /// 
+    /// The control rejects typing and pasting past the limit. Text already
+    /// there, or set in code, is not cut short. Fails for a limit of zero.
+    pub fn set_max_length(&mut self, max_length: Option<u32>) -> Result<()> {
+        self.backend.set_max_length(max_length)
+    }
+    
+    /// Set the text color, or `None` for the system color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        self.backend.set_text_color(color);
+    }
+    
+    /// Set the background color, or `None` for the system color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        self.backend.set_background(color)
+    }
+    
+    /// Set the callback invoked with the new text whenever it changes
+    /// 
+    /// Runs for text set in code as well as typed by the user. Replaces any
+    /// callback set with [`TextInputBuilder::on_text_changed`].
+    pub fn on_text_changed<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(String) + 'static,
+    {
+        let read_text = self.backend.text_reader();
+        let mut callback: Box<dyn FnMut()> = Box::new(move || {
+            if let Ok(text) = read_text() {
+                callback(text);
+            }
+        });
+        
+        // SAFETY: The callback is owned by the TextInput and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut();
+        self.backend.set_change_callback_ptr(Some(callback_ptr));
+        self.on_text_changed = Some(callback);
+    }
+}
+
+impl Drop for TextInput {
+    fn drop(&mut self) {
+        // Unregister callback before widget is destroyed
+        if self.on_text_changed.is_some() {
+            self.backend.set_change_callback_ptr(None);
+        }
+    }
 }
 
 impl Widget for TextInput {
@@ -42,6 +174,10 @@ impl Widget for TextInput {
         self.bounds
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.backend.hwnd())
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
@@ -54,6 +190,13 @@ pub struct TextInputBuilder {
     position: Option<Point>,
     size: Option<Size>,
     read_only: bool,
+    on_text_changed: Option<Box<dyn FnMut(String)>>,
+    text_color: Option<Color>,
+    background: Option<Color>,
+    char_filter: Option<Box<dyn Fn(char) -> bool>>,
+    max_length: Option<u32>,
+    placeholder: Option<String>,
+    placeholder_when_focused: bool,
 }
 
 impl TextInputBuilder {
@@ -86,18 +229,106 @@ impl TextInputBuilder {
         self
     }
     
+    /// Set the callback invoked with the new text whenever it changes
+    pub fn on_text_changed<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(String) + 'static,
+    {
+        self.on_text_changed = Some(Box::new(callback));
+        self
+    }
+    
+    /// Set the grey hint shown while the input is empty, like XAML's `PlaceholderText`
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+    
+    /// Keep showing the placeholder while the empty input has focus
+    /// 
+    /// By default the hint disappears as soon as the input is focused.
+    pub fn show_placeholder_when_focused(mut self, show: bool) -> Self {
+        self.placeholder_when_focused = show;
+        self
+    }
+    
+    /// Limit how many characters the user can enter, like XAML's `MaxLength`
+    /// 
+    /// Building fails for a limit of zero.
+    pub fn max_length(mut self, max_length: u32) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+    
+    /// Only let the user type or paste characters the filter accepts
+    /// 
+    /// Rejected characters are dropped from pasted text, and editing keys such
+    /// as backspace always work. Text set in code is not filtered.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// # use luma_gui::prelude::*;
+    /// # let window = Window::builder().build()?;
+    /// let phone = TextInput::builder()
+    ///     .char_filter(|c| c.is_ascii_digit() || c == '-')
+    ///     .build(&window)?;
+    /// # Ok::<(), luma_gui::Error>(())
+    /// ```
+    pub fn char_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(char) -> bool + 'static,
+    {
+        self.char_filter = Some(Box::new(filter));
+        self
+    }
+    
+    /// Only let the user enter the digits 0-9
+    pub fn numeric(self) -> Self {
+        self.char_filter(|c| c.is_ascii_digit())
+    }
+    
+    /// Set the text color
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+    
+    /// Set the background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+    
     /// Build the text input
-    pub fn build(self, parent: &Window) -> Result<TextInput> {
+    pub fn build(self, parent: &impl Parent) -> Result<TextInput> {
         let pos = self.position.unwrap_or(Point::new(0, 0));
         let size = self.size.unwrap_or(Size::new(200, 24));
         
         let parent_hwnd = parent.raw_handle();
-        let backend = Win32TextInput::new(parent_hwnd, pos, size, self.read_only)?;
+        let mut backend = Win32TextInput::new(parent_hwnd, pos, size, self.read_only)?;
+        if self.text_color.is_some() {
+            backend.set_text_color(self.text_color);
+        }
+        if self.background.is_some() {
+            backend.set_background(self.background)?;
+        }
+        if self.char_filter.is_some() {
+            backend.set_char_filter(self.char_filter);
+        }
+        if self.max_length.is_some() {
+            backend.set_max_length(self.max_length)?;
+        }
+        if let Some(placeholder) = &self.placeholder {
+            backend.set_placeholder(placeholder, self.placeholder_when_focused)?;
+        }
         
         let mut text_input = TextInput {
             backend,
             id: WidgetId::new(),
             bounds: Rect::from_point_size(pos, size),
+            on_text_changed: None,
+            placeholder_when_focused: self.placeholder_when_focused,
         };
         
         // Set initial text if provided
@@ -105,6 +336,45 @@ impl TextInputBuilder {
             text_input.set_text(&text)?;
         }
         
+        // Register the callback after the initial text so it does not fire for it
+        if let Some(callback) = self.on_text_changed {
+            text_input.on_text_changed(callback);
+        }
+        
         Ok(text_input)
     }
 }
+
+#[cfg(feature = "binding")]
+impl crate::binding::Bindable for TextInput {
+    fn property_writer(&self, property: &str) -> Option<crate::binding::PropertyWriter> {
+        match property {
+            "Text" => {
+                let read_text = self.backend.text_reader();
+                let write_text = self.backend.text_writer();
+                // Leave unchanged text alone, so the edit control does not report a change
+                Some(Box::new(move |text| {
+                    if read_text()? == text {
+                        return Ok(());
+                    }
+                    write_text(text)
+                }))
+            }
+            "PlaceholderText" => Some(Box::new(self.backend.placeholder_writer(self.placeholder_when_focused))),
+            _ => None,
+        }
+    }
+    
+    fn watch_property(&mut self, property: &str, callback: Box<dyn FnMut(String)>) -> Result<()> {
+        match property {
+            "Text" => {
+                self.on_text_changed(callback);
+                Ok(())
+            }
+            "PlaceholderText" => Err(luma_core::Error::InvalidParameter(
+                "TextInput.PlaceholderText cannot be changed by the user".into(),
+            )),
+            _ => Err(luma_core::Error::InvalidParameter(format!("Unknown bindable property: {}", property))),
+        }
+    }
+}
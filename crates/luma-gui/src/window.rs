@@ -1,11 +1,16 @@
-use luma_core::{Result, Point, WindowFlags, traits::WindowBackend, Rect, Container, WidgetId, Widget};
-use crate::Win32Window;
+use luma_core::{Result, Error, Point, WindowFlags, LayoutConstraints, traits::WindowBackend, Rect, Container, WidgetId, Widget, WidgetHandle, WidgetTree, Color, CornerPreference, Backdrop, Theme, WindowState, WindowPlacement, TaskbarProgressState, FlashMode};
+use crate::{Win32Window, with_deferred_positioning};
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// Cross-platform window
 pub struct Window {
     pub(crate) backend: Win32Window,
     id: WidgetId,
-    layout: Option<Box<dyn Container>>,
+    layout: Option<Box<BoxLayout>>,
+    on_theme_changed: Option<Box<dyn FnMut(Theme)>>,
+    on_files_dropped: Option<Box<dyn FnMut(Vec<PathBuf>)>>,
+    on_state_changed: Option<Box<dyn FnMut(WindowState)>>,
 }
 
 impl Window {
@@ -25,24 +30,289 @@ impl Window {
     }
     
     /// Set the window title
+    /// 
+    /// The taskbar button and Alt+Tab show the same text; there is no
+    /// separate taskbar name.
     pub fn set_title(&mut self, title: &str) -> Result<()> {
         self.backend.set_title(title)
     }
     
+    /// Get the window title
+    pub fn title(&self) -> Result<String> {
+        self.backend.get_title()
+    }
+    
     /// Set the window size
     pub fn set_size(&mut self, width: u32, height: u32) -> Result<()> {
         self.backend.set_size(width, height)
     }
     
-    /// Set the layout for this window
-    pub fn set_layout(&mut self, mut layout: BoxLayout) -> Result<()> {
-        // Trigger initial layout with actual client area size
-        let size = self.backend.get_client_size()?;
-        layout.layout(size)?;
+    /// Move the window so its top-left corner is at a screen position
+    pub fn set_position(&mut self, x: i32, y: i32) -> Result<()> {
+        self.backend.set_position(x, y)
+    }
+    
+    /// Move the window to the middle of the work area of its monitor
+    pub fn center(&mut self) -> Result<()> {
+        self.backend.center()
+    }
+    
+    /// Get the scale factor of the window's monitor, such as 1.5 at 150%
+    /// 
+    /// Multiply device-independent pixels (DIPs) by this to get physical
+    /// pixels; see [`luma_core::Dip::to_px`].
+    pub fn scale_factor(&self) -> f32 {
+        self.backend.scale_factor()
+    }
+    
+    /// Maximize, minimize or restore the window
+    /// 
+    /// Shows the window if it is hidden.
+    pub fn set_state(&mut self, state: WindowState) -> Result<()> {
+        self.backend.set_state(state)
+    }
+    
+    /// Get the window's state and the bounds it has when neither maximized
+    /// nor minimized
+    /// 
+    /// Save this when the window closes and pass it to
+    /// [`Window::set_placement`] next time to reopen the window where the
+    /// user left it.
+    pub fn get_placement(&self) -> Result<WindowPlacement> {
+        self.backend.get_placement()
+    }
+    
+    /// Move the window to a saved placement
+    /// 
+    /// A window that is not shown yet takes the saved state when it is shown.
+    pub fn set_placement(&mut self, placement: WindowPlacement) -> Result<()> {
+        self.backend.set_placement(placement)
+    }
+    
+    /// Check whether the window is maximized
+    pub fn is_maximized(&self) -> bool {
+        self.backend.is_maximized()
+    }
+    
+    /// Check whether the window is minimized
+    pub fn is_minimized(&self) -> bool {
+        self.backend.is_minimized()
+    }
+    
+    /// Check whether the window is the foreground window the user is working in
+    pub fn is_active(&self) -> bool {
+        self.backend.is_active()
+    }
+    
+    /// Set the callback invoked with the new state when the window is
+    /// maximized, minimized or restored
+    /// 
+    /// Replaces any callback set with [`WindowBuilder::on_state_changed`].
+    /// Changes made with [`Window::set_state`] are reported too.
+    pub fn on_state_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(WindowState) + 'static,
+    {
+        let mut callback: Box<dyn FnMut(WindowState)> = Box::new(callback);
+        
+        // SAFETY: The callback is owned by the Window and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(WindowState);
+        self.backend.set_state_callback_ptr(Some(callback_ptr));
+        self.on_state_changed = Some(callback);
+    }
+    
+    /// Set the client-area rectangle that can be used to drag the window
+    /// 
+    /// Borderless windows have no title bar, so this is how they are moved.
+    /// The rectangle is in client coordinates.
+    pub fn set_drag_region(&mut self, region: Rect) {
+        self.backend.set_drag_region(Some(region));
+    }
+    
+    /// Remove the drag region set with [`Window::set_drag_region`]
+    pub fn clear_drag_region(&mut self) {
+        self.backend.set_drag_region(None);
+    }
+    
+    /// Set the callback invoked when the system light/dark theme changes
+    /// 
+    /// Replaces any callback set with [`WindowBuilder::on_theme_changed`].
+    pub fn on_theme_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(Theme) + 'static,
+    {
+        let mut callback: Box<dyn FnMut(Theme)> = Box::new(callback);
+        
+        // SAFETY: The callback is owned by the Window and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(Theme);
+        self.backend.set_theme_callback_ptr(Some(callback_ptr));
+        self.on_theme_changed = Some(callback);
+    }
+    
+    /// Accept or refuse files dragged onto the window
+    /// 
+    /// Dropped files are passed to the [`Window::on_files_dropped`] callback.
+    pub fn set_accept_dropped_files(&mut self, accept: bool) {
+        self.backend.set_accept_dropped_files(accept);
+    }
+    
+    /// Set the callback invoked with the paths of files dropped on the window
+    /// 
+    /// Replaces any callback set with [`WindowBuilder::on_files_dropped`].
+    /// Files are only accepted after [`Window::set_accept_dropped_files`] or
+    /// [`WindowBuilder::accept_dropped_files`].
+    pub fn on_files_dropped<F>(&mut self, callback: F)
+    where
+        F: FnMut(Vec<PathBuf>) + 'static,
+    {
+        let mut callback: Box<dyn FnMut(Vec<PathBuf>)> = Box::new(callback);
         
+        // SAFETY: The callback is owned by the Window and unregistered on drop
+        let callback_ptr = callback.as_mut() as *mut dyn FnMut(Vec<PathBuf>);
+        self.backend.set_files_dropped_callback_ptr(Some(callback_ptr));
+        self.on_files_dropped = Some(callback);
+    }
+    
+    /// Use a dark title bar and frame
+    /// 
+    /// Pair with [`Application::system_theme`](crate::Application::system_theme)
+    /// or [`Window::on_theme_changed`] to follow the system setting.
+    pub fn set_dark_mode(&mut self, dark: bool) {
+        self.backend.set_dark_mode(dark);
+    }
+    
+    /// Set how the window corners are rounded
+    /// 
+    /// Requires Windows 11; ignored on older systems.
+    pub fn set_corner_preference(&mut self, preference: CornerPreference) {
+        self.backend.set_corner_preference(preference);
+    }
+    
+    /// Set the window border color
+    /// 
+    /// Requires Windows 11; ignored on older systems.
+    pub fn set_border_color(&mut self, color: Color) {
+        self.backend.set_border_color(color);
+    }
+    
+    /// Set the title bar background color
+    /// 
+    /// Requires Windows 11; ignored on older systems.
+    pub fn set_caption_color(&mut self, color: Color) {
+        self.backend.set_caption_color(color);
+    }
+    
+    /// Set the color the window's client area is painted with
+    /// 
+    /// Replaces the system window color. A backdrop set with
+    /// [`Window::set_backdrop`] still shows instead.
+    pub fn set_background(&mut self, color: Color) -> Result<()> {
+        self.backend.set_background(color)
+    }
+    
+    /// Set the system backdrop material (Mica, Acrylic) behind the window
+    /// 
+    /// Requires Windows 11 22H2 or later. On older systems this returns an
+    /// error and the window keeps its normal background, so the error can
+    /// safely be ignored.
+    pub fn set_backdrop(&mut self, backdrop: Backdrop) -> Result<()> {
+        self.backend.set_backdrop(backdrop)
+    }
+    
+    /// Pin the window above other windows, or release it
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<()> {
+        self.backend.set_always_on_top(on_top)
+    }
+    
+    /// Enable or disable the layered style used for window transparency
+    /// 
+    /// [`Window::set_opacity`] and [`Window::update_layered_bitmap`] enable it
+    /// automatically; disabling it makes the window fully opaque again.
+    pub fn set_layered(&mut self, layered: bool) -> Result<()> {
+        self.backend.set_layered(layered)
+    }
+    
+    /// Let mouse clicks pass through the window to whatever is beneath it
+    /// 
+    /// Suits overlays that only display information. This makes the window
+    /// layered, keeping it opaque unless an opacity is set; turning layering
+    /// off with [`Window::set_layered`] also ends click-through.
+    pub fn set_click_through(&mut self, click_through: bool) -> Result<()> {
+        self.backend.set_click_through(click_through)
+    }
+    
+    /// Limit the window to some rectangles, or pass `None` for the whole window
+    /// 
+    /// Rectangles are relative to the window's top-left corner, including any
+    /// frame. The window is only drawn and clickable inside them; clicks
+    /// elsewhere reach the window beneath, so only part of an overlay stays
+    /// interactive.
+    pub fn set_interactive_region(&mut self, region: Option<&[Rect]>) -> Result<()> {
+        self.backend.set_interactive_region(region)
+    }
+    
+    /// Show progress on the window's taskbar button, or pass `None` to remove it
+    /// 
+    /// The progress is `(completed, total)`; `completed` is clamped to
+    /// `total`. Setting a value switches the button to
+    /// [`TaskbarProgressState::Normal`] unless it is paused or in error. Does
+    /// nothing where the taskbar can't show progress.
+    pub fn set_taskbar_progress(&mut self, progress: Option<(u64, u64)>) -> Result<()> {
+        self.backend.set_taskbar_progress(progress)
+    }
+    
+    /// Change how the window's taskbar button shows progress
+    /// 
+    /// Use [`TaskbarProgressState::Indeterminate`] while the amount of work is
+    /// unknown, and `Error` or `Paused` to color the progress red or yellow.
+    pub fn set_taskbar_state(&mut self, state: TaskbarProgressState) -> Result<()> {
+        self.backend.set_taskbar_state(state)
+    }
+    
+    /// Flash the title bar, the taskbar button or both to get the user's attention
+    /// 
+    /// Flashing for a count of `Some(0)` is an error. A count of `None`
+    /// flashes until [`FlashMode::Stop`]; to stop when the user switches to
+    /// the window instead, use [`Window::flash_until_focus`].
+    pub fn flash(&mut self, mode: FlashMode) -> Result<()> {
+        self.backend.flash(mode)
+    }
+    
+    /// Flash the title bar and taskbar button until the user switches to the window
+    /// 
+    /// Suits background work finishing while the user is in another app; a
+    /// window that is already active does not flash.
+    pub fn flash_until_focus(&mut self) -> Result<()> {
+        self.backend.flash_until_focus()
+    }
+    
+    /// Set the opacity of the whole window, from 0.0 (invisible) to 1.0 (opaque)
+    pub fn set_opacity(&mut self, opacity: f32) -> Result<()> {
+        self.backend.set_opacity(opacity)
+    }
+    
+    /// Show the window, fading it in from transparent over `duration`
+    pub fn fade_in(&mut self, duration: Duration) -> Result<()> {
+        self.backend.fade_in(duration)
+    }
+    
+    /// Draw the window from a 32-bit ARGB image with per-pixel transparency
+    /// 
+    /// `pixels` holds `width * height` values of `0xAARRGGBB`, row by row from
+    /// the top. The window takes the size of the image and child widgets are
+    /// no longer drawn, so this suits splash screens and overlays.
+    pub fn update_layered_bitmap(&mut self, width: u32, height: u32, pixels: &[u32]) -> Result<()> {
+        self.backend.update_layered_bitmap(width, height, pixels)
+    }
+    
+    /// Set the layout for this window
+    pub fn set_layout(&mut self, layout: BoxLayout) -> Result<()> {
         // Store layout in the window
         self.layout = Some(Box::new(layout));
         
+        // Trigger initial layout with actual client area size
+        self.relayout()?;
+        
         // Register the layout pointer with the Win32 backend for resize handling
         // SAFETY: The layout lives as long as the Window, and we unregister on drop
         let layout_ptr = self.layout.as_mut().unwrap().as_mut() as *mut dyn Container;
@@ -51,6 +321,81 @@ impl Window {
         Ok(())
     }
     
+    /// Add a widget to the window's layout and lay the window out again
+    /// 
+    /// Works before or after the window is shown; create the widget with the
+    /// window (or one of its panels) as its parent first. Starts a vertical
+    /// layout if the window has none.
+    pub fn add_widget(&mut self, widget: impl Widget + 'static, constraints: LayoutConstraints) -> Result<()> {
+        match self.layout.as_mut() {
+            Some(layout) => {
+                layout.add(Box::new(widget), constraints);
+                self.relayout()
+            }
+            None => {
+                let mut layout = BoxLayout::vertical();
+                layout.add(Box::new(widget), constraints);
+                self.set_layout(layout)
+            }
+        }
+    }
+    
+    /// Add a widget to the window's layout, keeping a handle to it
+    /// 
+    /// Like [`Window::add_widget`], but the returned handle can still reach
+    /// the widget by its concrete type, for example to update a status label
+    /// from a button's callback.
+    pub fn add_widget_with_handle<W: Widget + 'static>(
+        &mut self,
+        widget: W,
+        constraints: LayoutConstraints,
+    ) -> Result<WidgetHandle<W>> {
+        let handle = WidgetHandle::new(widget);
+        self.add_widget(handle.clone(), constraints)?;
+        Ok(handle)
+    }
+    
+    /// Remove a widget from the window's layout, destroy it and lay the window out again
+    /// 
+    /// Fails if the layout has no child with the ID. A widget must not remove
+    /// itself from inside one of its own callbacks.
+    pub fn remove_widget(&mut self, id: WidgetId) -> Result<()> {
+        let removed = self.layout.as_mut().and_then(|layout| layout.remove(id));
+        if removed.is_none() {
+            return Err(Error::InvalidParameter(format!("No widget with ID {} in the window's layout", id.as_u64())));
+        }
+        drop(removed);
+        self.relayout()
+    }
+    
+    /// Get the window's layout, to change it before calling [`Window::relayout`]
+    pub fn layout_mut(&mut self) -> Option<&mut BoxLayout> {
+        self.layout.as_deref_mut()
+    }
+    
+    /// Lay out the window's widgets again at the current client size
+    /// 
+    /// All children are moved in one batch, so many widgets can be
+    /// rearranged at once without flicker. Unlike a resize, this always
+    /// repositions every child. Does nothing without a layout.
+    pub fn relayout(&mut self) -> Result<()> {
+        let Some(layout) = self.layout.as_mut() else {
+            return Ok(());
+        };
+        let size = self.backend.get_client_size()?;
+        layout.set_scale_factor(self.backend.scale_factor());
+        layout.invalidate();
+        with_deferred_positioning(|| layout.layout(size))
+    }
+    
+    /// Describe the window and every widget in it
+    /// 
+    /// The tree is read from the native controls, so it includes widgets
+    /// owned by layouts as well as ones kept elsewhere.
+    pub fn widget_tree(&self) -> Result<WidgetTree> {
+        self.backend.widget_tree()
+    }
+    
     /// Get the window ID
     pub fn id(&self) -> WidgetId {
         self.id
@@ -72,7 +417,13 @@ pub struct WindowBuilder {
     width: Option<u32>,
     height: Option<u32>,
     position: Option<Point>,
+    centered: bool,
+    start_state: Option<WindowState>,
     flags: Option<WindowFlags>,
+    accept_dropped_files: bool,
+    on_theme_changed: Option<Box<dyn FnMut(Theme)>>,
+    on_files_dropped: Option<Box<dyn FnMut(Vec<PathBuf>)>>,
+    on_state_changed: Option<Box<dyn FnMut(WindowState)>>,
 }
 
 impl WindowBuilder {
@@ -94,13 +445,31 @@ impl WindowBuilder {
         self
     }
     
-    /// Set the window position
+    /// Set the screen position of the window's top-left corner
     pub fn position(mut self, x: i32, y: i32) -> Self {
         self.position = Some(Point::new(x, y));
         self
     }
     
+    /// Open the window in the middle of the screen, instead of at `position`
+    /// 
+    /// A window that starts maximized returns to the middle when it is restored.
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+    
+    /// Set whether the window opens maximized, minimized or at its own size
+    pub fn start_state(mut self, state: WindowState) -> Self {
+        self.start_state = Some(state);
+        self
+    }
+    
     /// Set window flags
+    /// 
+    /// Accepts individual bits or one of the presets such as
+    /// `WindowFlags::dialog()`, `WindowFlags::borderless()` or
+    /// `WindowFlags::tool_window()`.
     pub fn flags(mut self, flags: WindowFlags) -> Self {
         self.flags = Some(flags);
         self
@@ -114,6 +483,48 @@ impl WindowBuilder {
         self
     }
     
+    /// Keep the window on top of other windows
+    pub fn always_on_top(mut self, on_top: bool) -> Self {
+        let mut flags = self.flags.unwrap_or_default();
+        flags.set(WindowFlags::ALWAYS_ON_TOP, on_top);
+        self.flags = Some(flags);
+        self
+    }
+    
+    /// Set the callback invoked when the system light/dark theme changes
+    pub fn on_theme_changed<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Theme) + 'static,
+    {
+        self.on_theme_changed = Some(Box::new(callback));
+        self
+    }
+    
+    /// Accept files dragged onto the window
+    pub fn accept_dropped_files(mut self, accept: bool) -> Self {
+        self.accept_dropped_files = accept;
+        self
+    }
+    
+    /// Set the callback invoked with the paths of files dropped on the window
+    pub fn on_files_dropped<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Vec<PathBuf>) + 'static,
+    {
+        self.on_files_dropped = Some(Box::new(callback));
+        self
+    }
+    
+    /// Set the callback invoked with the new state when the window is
+    /// maximized, minimized or restored
+    pub fn on_state_changed<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(WindowState) + 'static,
+    {
+        self.on_state_changed = Some(Box::new(callback));
+        self
+    }
+    
     /// Build the window
     pub fn build(self) -> Result<Window> {
         let title = self.title.as_deref().unwrap_or("Window");
@@ -121,12 +532,45 @@ impl WindowBuilder {
         let height = self.height.unwrap_or(600);
         let flags = self.flags.unwrap_or_default();
         
-        let backend = Win32Window::new(title, width, height, flags)?;
+        let mut backend = Win32Window::new(title, width, height, flags)?;
+        if self.centered {
+            backend.center()?;
+        } else if let Some(position) = self.position {
+            backend.set_position(position.x, position.y)?;
+        }
+        if let Some(state) = self.start_state {
+            backend.set_start_state(state);
+        }
+        
+        // Register the theme callback if present
+        let mut on_theme_changed = self.on_theme_changed;
+        if let Some(ref mut callback) = on_theme_changed {
+            let callback_ptr = callback.as_mut() as *mut dyn FnMut(Theme);
+            backend.set_theme_callback_ptr(Some(callback_ptr));
+        }
+        
+        let mut on_files_dropped = self.on_files_dropped;
+        if let Some(ref mut callback) = on_files_dropped {
+            let callback_ptr = callback.as_mut() as *mut dyn FnMut(Vec<PathBuf>);
+            backend.set_files_dropped_callback_ptr(Some(callback_ptr));
+        }
+        if self.accept_dropped_files {
+            backend.set_accept_dropped_files(true);
+        }
+        
+        let mut on_state_changed = self.on_state_changed;
+        if let Some(ref mut callback) = on_state_changed {
+            let callback_ptr = callback.as_mut() as *mut dyn FnMut(WindowState);
+            backend.set_state_callback_ptr(Some(callback_ptr));
+        }
         
         Ok(Window {
             backend,
             id: WidgetId::new(),
             layout: None,
+            on_theme_changed,
+            on_files_dropped,
+            on_state_changed,
         })
     }
 }
@@ -142,6 +586,10 @@ impl Widget for Window {
         Rect::new(0, 0, 800, 600)
     }
     
+    fn get_screen_bounds(&self) -> Result<Rect> {
+        self.backend.screen_bounds()
+    }
+    
     fn id(&self) -> WidgetId {
         self.id
     }
@@ -0,0 +1,134 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+use luma_core::{Result, Error, Key, Modifiers, Accelerator, keyboard::mnemonic};
+use crate::utils::{window_text, class_name};
+
+/// Build a Win32 accelerator table
+/// 
+/// Returns `None` for an empty set, since Win32 cannot create an empty table.
+pub fn create_accelerator_table(accelerators: &[Accelerator]) -> Result<Option<HACCEL>> {
+    if accelerators.is_empty() {
+        return Ok(None);
+    }
+    
+    let entries = accelerators
+        .iter()
+        .map(|accel| {
+            let key = key_to_virtual_key(accel.key).ok_or_else(|| {
+                Error::InvalidParameter(format!("Key {:?} cannot be used as an accelerator", accel.key))
+            })?;
+            Ok(ACCEL {
+                fVirt: modifiers_to_flags(accel.modifiers),
+                key: key.0,
+                cmd: accel.command_id,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    unsafe {
+        CreateAcceleratorTableW(&entries)
+            .map(Some)
+            .map_err(|e| Error::Platform(format!("CreateAcceleratorTableW failed: {}", e)))
+    }
+}
+
+/// Handle an Alt+key press by activating the control whose label has that mnemonic
+/// 
+/// Buttons and checkboxes are clicked; a label moves focus to the control that
+/// follows it. Returns `true` if a matching control was found.
+pub fn handle_mnemonic(msg: &MSG) -> bool {
+    let Some(typed) = char::from_u32(msg.wParam.0 as u32).and_then(|c| c.to_lowercase().next()) else {
+        return false;
+    };
+    
+    unsafe {
+        let root = GetAncestor(msg.hwnd, GA_ROOT);
+        let Some(target) = find_mnemonic_control(root, typed) else {
+            return false;
+        };
+        
+        tracing::debug!("Mnemonic '{}' activated HWND={:?}", typed, target);
+        
+        if class_name(target).eq_ignore_ascii_case("Button") {
+            SendMessageW(target, BM_CLICK, WPARAM(0), LPARAM(0));
+        } else {
+            // Labels aren't focusable; focus the next enabled sibling instead
+            let mut next = GetWindow(target, GW_HWNDNEXT);
+            while next.0 != 0 && !IsWindowEnabled(next).as_bool() {
+                next = GetWindow(next, GW_HWNDNEXT);
+            }
+            if next.0 != 0 {
+                SetFocus(next);
+            }
+        }
+    }
+    
+    true
+}
+
+/// Find a visible, enabled control under `root` whose label has the given mnemonic
+unsafe fn find_mnemonic_control(root: HWND, typed: char) -> Option<HWND> {
+    struct Search {
+        typed: char,
+        found: Option<HWND>,
+    }
+    
+    unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let search = &mut *(lparam.0 as *mut Search);
+        if IsWindowVisible(hwnd).as_bool()
+            && IsWindowEnabled(hwnd).as_bool()
+            && mnemonic(&window_text(hwnd)) == Some(search.typed)
+        {
+            search.found = Some(hwnd);
+            return FALSE;
+        }
+        TRUE
+    }
+    
+    let mut search = Search { typed, found: None };
+    let _ = EnumChildWindows(root, Some(visit), LPARAM(&mut search as *mut Search as isize));
+    search.found
+}
+
+/// Convert modifiers to accelerator flags
+fn modifiers_to_flags(modifiers: Modifiers) -> ACCEL_VIRT_FLAGS {
+    let mut flags = FVIRTKEY;
+    if modifiers.contains(Modifiers::CONTROL) {
+        flags |= FCONTROL;
+    }
+    if modifiers.contains(Modifiers::SHIFT) {
+        flags |= FSHIFT;
+    }
+    if modifiers.contains(Modifiers::ALT) {
+        flags |= FALT;
+    }
+    flags
+}
+
+/// Convert a key to a Win32 virtual key code
+fn key_to_virtual_key(key: Key) -> Option<VIRTUAL_KEY> {
+    let vk = match key {
+        // Letter and digit virtual key codes are their uppercase ASCII values
+        Key::Char(c) if c.is_ascii_alphanumeric() => VIRTUAL_KEY(c.to_ascii_uppercase() as u16),
+        Key::Char(_) => return None,
+        Key::F(n @ 1..=24) => VIRTUAL_KEY(VK_F1.0 + n as u16 - 1),
+        Key::F(_) => return None,
+        Key::Enter => VK_RETURN,
+        Key::Escape => VK_ESCAPE,
+        Key::Tab => VK_TAB,
+        Key::Space => VK_SPACE,
+        Key::Backspace => VK_BACK,
+        Key::Delete => VK_DELETE,
+        Key::Insert => VK_INSERT,
+        Key::Home => VK_HOME,
+        Key::End => VK_END,
+        Key::PageUp => VK_PRIOR,
+        Key::PageDown => VK_NEXT,
+        Key::Left => VK_LEFT,
+        Key::Right => VK_RIGHT,
+        Key::Up => VK_UP,
+        Key::Down => VK_DOWN,
+    };
+    Some(vk)
+}
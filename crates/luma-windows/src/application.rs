@@ -1,5 +1,6 @@
 use windows::Win32::UI::WindowsAndMessaging::*;
-use luma_core::{Result, traits::ApplicationBackend};
+use luma_core::{Result, Error, Accelerator, traits::ApplicationBackend};
+use crate::accelerator::{create_accelerator_table, handle_mnemonic};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 
@@ -9,6 +10,7 @@ static APP_RUNNING: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
 /// Win32 application backend
 pub struct Win32Application {
     running: bool,
+    accelerators: Option<HACCEL>,
 }
 
 impl ApplicationBackend for Win32Application {
@@ -17,6 +19,7 @@ impl ApplicationBackend for Win32Application {
         
         Ok(Self {
             running: false,
+            accelerators: None,
         })
     }
     
@@ -26,30 +29,42 @@ impl ApplicationBackend for Win32Application {
         
         tracing::info!("Starting Win32 message loop");
         
-        unsafe {
-            let mut msg = MSG::default();
-            
-            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
-                TranslateMessage(&msg);
-                DispatchMessageW(&msg);
-            }
-        }
+        let result = unsafe { self.message_loop() };
         
         self.running = false;
         *APP_RUNNING.lock().unwrap() = false;
         
         tracing::info!("Win32 message loop ended");
         
-        Ok(())
+        result
     }
     
     fn quit(&mut self) -> Result<()> {
-        unsafe {
-            PostQuitMessage(0);
-        }
+        Self::post_quit();
         self.running = false;
         Ok(())
     }
+    
+    fn set_accelerators(&mut self, accelerators: &[Accelerator]) -> Result<()> {
+        let table = create_accelerator_table(accelerators)?;
+        if let Some(old) = std::mem::replace(&mut self.accelerators, table) {
+            unsafe {
+                let _ = DestroyAcceleratorTable(old);
+            }
+        }
+        tracing::debug!("Installed {} accelerators", accelerators.len());
+        Ok(())
+    }
+}
+
+impl Drop for Win32Application {
+    fn drop(&mut self) {
+        if let Some(table) = self.accelerators.take() {
+            unsafe {
+                let _ = DestroyAcceleratorTable(table);
+            }
+        }
+    }
 }
 
 impl Win32Application {
@@ -57,4 +72,51 @@ impl Win32Application {
     pub fn is_running() -> bool {
         *APP_RUNNING.lock().unwrap()
     }
+    
+    /// Ask the message loop of the current thread to exit
+    /// 
+    /// Posts WM_QUIT, which `run` returns on once the messages already
+    /// queued have been dispatched. Needs no application instance, so widget
+    /// callbacks can call it while `run` is dispatching to them.
+    pub fn post_quit() {
+        unsafe {
+            PostQuitMessage(0);
+        }
+    }
+    
+    /// Dispatch messages until WM_QUIT arrives
+    unsafe fn message_loop(&self) -> Result<()> {
+        let mut msg = MSG::default();
+        
+        loop {
+            // 0 for WM_QUIT, -1 on failure
+            match GetMessageW(&mut msg, None, 0, 0).0 {
+                0 => return Ok(()),
+                -1 => {
+                    return Err(Error::Platform(format!(
+                        "GetMessageW failed: {}",
+                        windows::core::Error::from_win32()
+                    )));
+                }
+                _ => {}
+            }
+            
+            let _span = tracing::trace_span!("dispatch", hwnd = msg.hwnd.0, message = msg.message).entered();
+            
+            // Accelerators are delivered to the top-level window as WM_COMMAND
+            if let Some(table) = self.accelerators {
+                let root = GetAncestor(msg.hwnd, GA_ROOT);
+                if TranslateAcceleratorW(root, table, &msg) != 0 {
+                    continue;
+                }
+            }
+            
+            if msg.message == WM_SYSCHAR && handle_mnemonic(&msg) {
+                continue;
+            }
+            
+            TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
 }
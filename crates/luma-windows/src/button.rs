@@ -1,8 +1,12 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, Point, Size, ButtonFlags, traits::ButtonBackend};
-use crate::utils::{to_wide_string, is_valid_hwnd};
+use luma_core::{Result, Error, Point, Size, ButtonFlags, traits::ButtonBackend, keyboard::display_text};
+use crate::utils::{to_wide_string, is_valid_hwnd, measure_text, window_text};
+use crate::widget_backend::child_widget_backend;
+
+/// Space around a button's label: the push button border plus a margin
+const LABEL_PADDING: Size = Size { width: 24, height: 10 };
 
 /// Win32 button backend
 pub struct Win32Button {
@@ -68,42 +72,31 @@ impl ButtonBackend for Win32Button {
         }
         Ok(())
     }
-    
-    fn set_enabled(&mut self, enabled: bool) -> Result<()> {
-        // TODO: Implement EnableWindow once we figure out the correct import
-        // For now, this is a no-op
-        tracing::warn!("set_enabled not yet implemented: {}", enabled);
-        Ok(())
-    }
-    
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
-        unsafe {
-            SetWindowPos(
-                self.hwnd,
-                HWND::default(),
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER | SWP_NOACTIVATE,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
-        }
-        Ok(())
-    }
 }
 
+child_widget_backend!(Win32Button);
+
 impl Win32Button {
     /// Get the raw HWND handle
     pub fn hwnd(&self) -> HWND {
         self.hwnd
     }
+    
+    /// Get the size that fits the label in the button's font, plus padding
+    pub fn preferred_size(&self) -> Result<Size> {
+        let text = measure_text(self.hwnd, &display_text(&window_text(self.hwnd)))?;
+        Ok(Size::new(text.width + LABEL_PADDING.width, text.height + LABEL_PADDING.height))
+    }
 }
 
 impl Drop for Win32Button {
     fn drop(&mut self) {
         tracing::debug!("Destroying button: HWND={:?}", self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
         }
     }
 }
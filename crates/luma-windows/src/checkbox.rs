@@ -1,11 +1,13 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, Point, Size, traits::CheckBoxBackend};
-use crate::utils::{to_wide_string, is_valid_hwnd};
+use luma_core::{Result, Error, Point, Size, Color, traits::CheckBoxBackend};
+use crate::utils::{to_wide_string, is_valid_hwnd, set_window_text};
+use crate::widget_backend::child_widget_backend;
+use crate::control_colors::{set_text_color, set_background_color, clear_control_colors};
 
 // Button styles and states
-const BS_AUTOCHECKBOX: u32 = 0x0003;
+pub(crate) const BS_AUTOCHECKBOX: u32 = 0x0003;
 
 // Button state constants
 const BST_UNCHECKED: u32 = 0x0000;
@@ -77,62 +79,81 @@ impl CheckBoxBackend for Win32CheckBox {
     }
     
     fn is_checked(&self) -> Result<bool> {
-        unsafe {
-            let state = SendMessageW(self.hwnd, BM_GETCHECK, WPARAM(0), LPARAM(0));
-            Ok(state.0 as u32 == BST_CHECKED)
-        }
+        Ok(checked_state(self.hwnd))
     }
     
     fn set_checked(&mut self, checked: bool) -> Result<()> {
-        unsafe {
-            let check_state = if checked { BST_CHECKED } else { BST_UNCHECKED };
-            SendMessageW(
-                self.hwnd,
-                BM_SETCHECK,
-                WPARAM(check_state as usize),
-                LPARAM(0),
-            );
-        }
+        set_checked_state(self.hwnd, checked);
         Ok(())
     }
     
     fn set_label(&mut self, label: &str) -> Result<()> {
-        unsafe {
-            let wide_label = to_wide_string(label);
-            SetWindowTextW(self.hwnd, windows::core::PCWSTR(wide_label.as_ptr()))
-                .map_err(|e| Error::OperationFailed(format!("SetWindowTextW failed: {}", e)))?;
-        }
-        Ok(())
-    }
-    
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
-        unsafe {
-            SetWindowPos(
-                self.hwnd,
-                HWND::default(),
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER | SWP_NOACTIVATE,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
-        }
-        Ok(())
+        set_window_text(self.hwnd, label)
     }
 }
 
+child_widget_backend!(Win32CheckBox);
+
 impl Win32CheckBox {
     /// Get the raw HWND handle
     pub fn hwnd(&self) -> HWND {
         self.hwnd
     }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        set_text_color(self.hwnd, color);
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        set_background_color(self.hwnd, color)
+    }
+    
+    /// Get a function that reads the checked state
+    /// 
+    /// Click callbacks and data bindings cannot borrow the checkbox, so they
+    /// use this and [`Win32CheckBox::checked_writer`] instead.
+    pub fn checked_reader(&self) -> impl Fn() -> Result<bool> + 'static {
+        let hwnd = self.hwnd;
+        move || Ok(checked_state(hwnd))
+    }
+    
+    /// Get a function that sets the checked state
+    pub fn checked_writer(&self) -> impl Fn(bool) -> Result<()> + 'static {
+        let hwnd = self.hwnd;
+        move |checked| {
+            set_checked_state(hwnd, checked);
+            Ok(())
+        }
+    }
 }
 
 impl Drop for Win32CheckBox {
     fn drop(&mut self) {
         tracing::debug!("Destroying checkbox: HWND={:?}", self.hwnd);
+        clear_control_colors(self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
         }
     }
 }
+
+/// Get whether a checkbox is checked
+fn checked_state(hwnd: HWND) -> bool {
+    unsafe {
+        let state = SendMessageW(hwnd, BM_GETCHECK, WPARAM(0), LPARAM(0));
+        state.0 as u32 == BST_CHECKED
+    }
+}
+
+/// Check or uncheck a checkbox (without sending BN_CLICKED)
+fn set_checked_state(hwnd: HWND, checked: bool) {
+    unsafe {
+        let check_state = if checked { BST_CHECKED } else { BST_UNCHECKED };
+        SendMessageW(hwnd, BM_SETCHECK, WPARAM(check_state as usize), LPARAM(0));
+    }
+}
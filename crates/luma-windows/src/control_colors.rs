@@ -0,0 +1,110 @@
+// Per-control text and background colors, applied through WM_CTLCOLOR* messages
+//
+// Controls ask their parent for colors when they paint, so the window and
+// panel procedures answer with `ctl_color` using the colors registered here.
+
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::UI::WindowsAndMessaging::{WM_CTLCOLORSTATIC, WM_CTLCOLOREDIT};
+use luma_core::{Result, Error, Color};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// Colors set on one control
+#[derive(Default)]
+struct ControlColors {
+    text: Option<COLORREF>,
+    /// The background color and a brush of it, owned by the map
+    background: Option<(COLORREF, HBRUSH)>,
+}
+
+/// Global map of control HWND to its colors for handling WM_CTLCOLOR*
+static CONTROL_COLORS: OnceCell<Mutex<HashMap<isize, ControlColors>>> = OnceCell::new();
+
+fn get_colors_map() -> &'static Mutex<HashMap<isize, ControlColors>> {
+    CONTROL_COLORS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Set (or clear) the text color of a control
+pub fn set_text_color(hwnd: HWND, color: Option<Color>) {
+    {
+        let mut map = get_colors_map().lock().unwrap();
+        map.entry(hwnd.0).or_default().text = color.map(|color| COLORREF(color.to_win32_colorref()));
+    }
+    unsafe {
+        let _ = InvalidateRect(hwnd, None, true);
+    }
+}
+
+/// Set (or clear) the background color of a control
+pub fn set_background_color(hwnd: HWND, color: Option<Color>) -> Result<()> {
+    let background = match color {
+        Some(color) => {
+            let colorref = COLORREF(color.to_win32_colorref());
+            let brush = unsafe { CreateSolidBrush(colorref) };
+            if brush.is_invalid() {
+                return Err(Error::Platform("CreateSolidBrush failed".into()));
+            }
+            Some((colorref, brush))
+        }
+        None => None,
+    };
+    
+    let old = {
+        let mut map = get_colors_map().lock().unwrap();
+        std::mem::replace(&mut map.entry(hwnd.0).or_default().background, background)
+    };
+    unsafe {
+        if let Some((_, brush)) = old {
+            let _ = DeleteObject(brush);
+        }
+        let _ = InvalidateRect(hwnd, None, true);
+    }
+    Ok(())
+}
+
+/// Forget a control's colors and free its brush, when it is destroyed
+pub fn clear_control_colors(hwnd: HWND) {
+    let removed = get_colors_map().lock().unwrap().remove(&hwnd.0);
+    if let Some((_, brush)) = removed.and_then(|colors| colors.background) {
+        unsafe {
+            let _ = DeleteObject(brush);
+        }
+    }
+}
+
+/// Answer a WM_CTLCOLOR* message from a control
+/// 
+/// `inherited` is the background brush of the control's parent, if it has a
+/// custom one; labels and check boxes without a background of their own are
+/// drawn transparently over it. Returns `None` to leave the message to the
+/// default procedure.
+pub(crate) unsafe fn ctl_color(msg: u32, hdc: HDC, control: HWND, inherited: Option<HBRUSH>) -> Option<LRESULT> {
+    let (text, background) = {
+        let map = get_colors_map().lock().ok()?;
+        match map.get(&control.0) {
+            Some(colors) => (colors.text, colors.background),
+            None => (None, None),
+        }
+    };
+    
+    if let Some(text) = text {
+        SetTextColor(hdc, text);
+    }
+    if let Some((color, brush)) = background {
+        SetBkColor(hdc, color);
+        return Some(LRESULT(brush.0));
+    }
+    if let (WM_CTLCOLORSTATIC, Some(brush)) = (msg, inherited) {
+        SetBkMode(hdc, TRANSPARENT);
+        return Some(LRESULT(brush.0));
+    }
+    
+    // The default procedure would reset the text color, so pick its background here
+    text.map(|_| {
+        let system = if msg == WM_CTLCOLOREDIT { COLOR_WINDOW } else { COLOR_BTNFACE };
+        SetBkColor(hdc, COLORREF(GetSysColor(system)));
+        LRESULT(GetSysColorBrush(system).0)
+    })
+}
@@ -0,0 +1,119 @@
+// Control IDs identifying widgets in WM_COMMAND
+//
+// Each widget with a command callback gets a control ID (the `LOWORD(wparam)`
+// of its WM_COMMAND notifications) mapped to its `WidgetId`, so the window
+// procedure dispatches "control ID -> widget ID -> handler" without keying on
+// the control's HWND. A widget keeps its ID until it is released, so a control
+// recreated for the same widget reuses it.
+
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::{SetWindowLongPtrW, GWLP_ID};
+use luma_core::{Result, Error, WidgetId};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// First control ID handed out; lower IDs are left for IDOK, IDCANCEL and
+/// other dialog command IDs
+const FIRST_CONTROL_ID: u16 = 0x0100;
+
+/// Control IDs in use and the widgets they belong to
+struct ControlIds {
+    widgets: HashMap<u16, WidgetId>,
+    ids: HashMap<WidgetId, u16>,
+    next: u16,
+}
+
+impl ControlIds {
+    fn new() -> Self {
+        Self { widgets: HashMap::new(), ids: HashMap::new(), next: FIRST_CONTROL_ID }
+    }
+    
+    /// Get the widget's control ID, allocating the next free one if it has none
+    fn assign(&mut self, widget: WidgetId) -> Option<u16> {
+        if let Some(&id) = self.ids.get(&widget) {
+            return Some(id);
+        }
+        
+        let mut candidate = self.next;
+        for _ in FIRST_CONTROL_ID..=u16::MAX {
+            let id = candidate;
+            candidate = if id == u16::MAX { FIRST_CONTROL_ID } else { id + 1 };
+            if !self.widgets.contains_key(&id) {
+                self.next = candidate;
+                self.widgets.insert(id, widget);
+                self.ids.insert(widget, id);
+                return Some(id);
+            }
+        }
+        None
+    }
+    
+    fn release(&mut self, widget: WidgetId) {
+        if let Some(id) = self.ids.remove(&widget) {
+            self.widgets.remove(&id);
+        }
+    }
+}
+
+/// Global control ID allocations
+static CONTROL_IDS: OnceCell<Mutex<ControlIds>> = OnceCell::new();
+
+fn get_control_ids() -> &'static Mutex<ControlIds> {
+    CONTROL_IDS.get_or_init(|| Mutex::new(ControlIds::new()))
+}
+
+/// Give a control the widget's control ID, allocating one if the widget has none
+/// 
+/// Returns the ID, which the control sends as `LOWORD(wparam)` of WM_COMMAND.
+/// Fails if every control ID is in use.
+pub fn assign_control_id(hwnd: isize, widget: WidgetId) -> Result<u16> {
+    let id = get_control_ids()
+        .lock()
+        .unwrap()
+        .assign(widget)
+        .ok_or_else(|| Error::OperationFailed("No free control IDs".into()))?;
+    unsafe {
+        SetWindowLongPtrW(HWND(hwnd), GWLP_ID, id as isize);
+    }
+    tracing::debug!("Assigned control ID={} to widget {:?}", id, widget);
+    Ok(id)
+}
+
+/// Free a widget's control ID for reuse by other widgets
+pub fn release_control_id(widget: WidgetId) {
+    get_control_ids().lock().unwrap().release(widget);
+}
+
+/// Get the widget a control ID was assigned to
+pub fn widget_for_control_id(id: u16) -> Option<WidgetId> {
+    get_control_ids().lock().ok()?.widgets.get(&id).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_assign_reuses_widget_id() {
+        let mut ids = ControlIds::new();
+        let (a, b) = (WidgetId::new(), WidgetId::new());
+        assert_eq!(ids.assign(a), Some(FIRST_CONTROL_ID));
+        assert_eq!(ids.assign(b), Some(FIRST_CONTROL_ID + 1));
+        assert_eq!(ids.assign(a), Some(FIRST_CONTROL_ID));
+        
+        ids.release(a);
+        assert_eq!(ids.widgets.get(&FIRST_CONTROL_ID), None);
+        assert_eq!(ids.assign(a), Some(FIRST_CONTROL_ID + 2));
+    }
+    
+    #[test]
+    fn test_assign_wraps_to_free_ids() {
+        let mut ids = ControlIds::new();
+        let first = WidgetId::new();
+        ids.assign(first);
+        ids.next = u16::MAX;
+        assert_eq!(ids.assign(WidgetId::new()), Some(u16::MAX));
+        assert_eq!(ids.assign(WidgetId::new()), Some(FIRST_CONTROL_ID + 1));
+    }
+}
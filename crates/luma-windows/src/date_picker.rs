@@ -0,0 +1,209 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Controls::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, Date, traits::DatePickerBackend};
+use crate::utils::{to_wide_string, is_valid_hwnd};
+use crate::widget_backend::child_widget_backend;
+use crate::window::{register_notify_handler, unregister_notify_handler};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// Wrapper to make date change callback pointer Send
+#[derive(Clone, Copy)]
+struct DateCallbackPtr(*mut dyn FnMut(Date));
+unsafe impl Send for DateCallbackPtr {}
+
+/// Global map of date picker HWND to change callback for handling DTN_DATETIMECHANGE
+static DATE_CALLBACKS: OnceCell<Mutex<HashMap<isize, DateCallbackPtr>>> = OnceCell::new();
+
+fn get_date_callbacks_map() -> &'static Mutex<HashMap<isize, DateCallbackPtr>> {
+    DATE_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 date picker backend (SysDateTimePick32 control)
+pub struct Win32DatePicker {
+    hwnd: HWND,
+}
+
+impl DatePickerBackend for Win32DatePicker {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 date picker: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        unsafe {
+            let icc = INITCOMMONCONTROLSEX {
+                dwSize: std::mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: ICC_DATE_CLASSES,
+            };
+            let _ = InitCommonControlsEx(&icc);
+            
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                DATETIMEPICK_CLASSW,
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(DTS_SHORTDATECENTURYFORMAT),
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation("DatePicker creation failed".into()));
+            }
+            
+            register_notify_handler(hwnd.0, handle_date_notify);
+            
+            tracing::debug!("DatePicker created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd })
+        }
+    }
+    
+    fn get_date(&self) -> Result<Date> {
+        let mut time = SYSTEMTIME::default();
+        let result = unsafe {
+            SendMessageW(
+                self.hwnd,
+                DTM_GETSYSTEMTIME,
+                WPARAM(0),
+                LPARAM(&mut time as *mut SYSTEMTIME as isize),
+            )
+        };
+        
+        if result.0 as u32 != GDT_VALID.0 {
+            return Err(Error::OperationFailed("Date picker has no date selected".into()));
+        }
+        systemtime_to_date(&time)
+            .ok_or_else(|| Error::OperationFailed("Date picker returned an invalid date".into()))
+    }
+    
+    fn set_date(&mut self, date: Date) -> Result<()> {
+        if !date.is_valid() {
+            return Err(Error::InvalidParameter(format!("Invalid date: {}", date)));
+        }
+        
+        let time = SYSTEMTIME {
+            wYear: date.year,
+            wMonth: date.month as u16,
+            wDay: date.day as u16,
+            ..Default::default()
+        };
+        let result = unsafe {
+            SendMessageW(
+                self.hwnd,
+                DTM_SETSYSTEMTIME,
+                WPARAM(GDT_VALID.0 as usize),
+                LPARAM(&time as *const SYSTEMTIME as isize),
+            )
+        };
+        
+        if result.0 == 0 {
+            return Err(Error::OperationFailed(format!("DTM_SETSYSTEMTIME rejected {}", date)));
+        }
+        Ok(())
+    }
+    
+    fn set_format(&mut self, format: Option<&str>) -> Result<()> {
+        // A null format restores the style's default
+        let wide_format = format.map(to_wide_string);
+        let format_ptr = wide_format.as_ref().map_or(std::ptr::null(), |f| f.as_ptr());
+        let result = unsafe {
+            SendMessageW(self.hwnd, DTM_SETFORMATW, WPARAM(0), LPARAM(format_ptr as isize))
+        };
+        
+        if result.0 == 0 {
+            return Err(Error::OperationFailed("DTM_SETFORMATW failed".into()));
+        }
+        Ok(())
+    }
+}
+
+child_widget_backend!(Win32DatePicker);
+
+impl Win32DatePicker {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+    
+    /// Set the callback invoked when the user picks a date
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// date picker is dropped.
+    pub fn set_change_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(Date)>) {
+        let mut map = get_date_callbacks_map().lock().unwrap();
+        match callback {
+            Some(ptr) => {
+                map.insert(self.hwnd.0, DateCallbackPtr(ptr));
+            }
+            None => {
+                map.remove(&self.hwnd.0);
+            }
+        }
+    }
+}
+
+impl Drop for Win32DatePicker {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying date picker: HWND={:?}", self.hwnd);
+        unregister_notify_handler(self.hwnd.0);
+        get_date_callbacks_map().lock().unwrap().remove(&self.hwnd.0);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Convert the date part of a SYSTEMTIME
+fn systemtime_to_date(time: &SYSTEMTIME) -> Option<Date> {
+    Date::new(time.wYear, u8::try_from(time.wMonth).ok()?, u8::try_from(time.wDay).ok()?)
+}
+
+/// Invoke the change callback when the user picks a date
+unsafe fn handle_date_notify(header: *const NMHDR) -> LRESULT {
+    if (*header).code != DTN_DATETIMECHANGE {
+        return LRESULT(0);
+    }
+    
+    let notification = &*(header as *const NMDATETIMECHANGE);
+    if notification.dwFlags != GDT_VALID {
+        return LRESULT(0);
+    }
+    
+    // Copy the pointer out so the callback runs without the lock held
+    let callback = get_date_callbacks_map()
+        .lock()
+        .ok()
+        .and_then(|map| map.get(&(*header).hwndFrom.0).copied());
+    
+    if let (Some(callback), Some(date)) = (callback, systemtime_to_date(&notification.st)) {
+        tracing::debug!("Date picker changed to {}", date);
+        (*callback.0)(date);
+    }
+    LRESULT(0)
+}
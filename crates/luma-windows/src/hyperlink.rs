@@ -0,0 +1,160 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Controls::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, traits::HyperlinkBackend};
+use crate::utils::{to_wide_string, is_valid_hwnd, set_window_text};
+use crate::widget_backend::child_widget_backend;
+use crate::window::{register_notify_handler, unregister_notify_handler};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// Wrapper to make link click callback pointer Send
+#[derive(Clone, Copy)]
+struct LinkCallbackPtr(*mut dyn FnMut(usize));
+unsafe impl Send for LinkCallbackPtr {}
+
+/// Global map of hyperlink HWND to click callback for handling NM_CLICK and NM_RETURN
+static LINK_CALLBACKS: OnceCell<Mutex<HashMap<isize, LinkCallbackPtr>>> = OnceCell::new();
+
+fn get_link_callbacks_map() -> &'static Mutex<HashMap<isize, LinkCallbackPtr>> {
+    LINK_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 hyperlink backend (SysLink control)
+/// 
+/// SysLink is only available from version 6 of the common controls, which
+/// the application manifest must request.
+pub struct Win32Hyperlink {
+    hwnd: HWND,
+}
+
+impl HyperlinkBackend for Win32Hyperlink {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        markup: &str,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 hyperlink: markup='{}', pos=({}, {}), size={}x{}",
+            markup,
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        unsafe {
+            let icc = INITCOMMONCONTROLSEX {
+                dwSize: std::mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: ICC_LINK_CLASS,
+            };
+            let _ = InitCommonControlsEx(&icc);
+            
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            let wide_markup = to_wide_string(markup);
+            
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                WC_LINK,
+                windows::core::PCWSTR(wide_markup.as_ptr()),
+                WS_CHILD | WS_VISIBLE | WS_TABSTOP,
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation(
+                    "Hyperlink creation failed (SysLink needs common controls version 6)".into(),
+                ));
+            }
+            
+            register_notify_handler(hwnd.0, handle_link_notify);
+            
+            tracing::debug!("Hyperlink created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd })
+        }
+    }
+    
+    fn set_markup(&mut self, markup: &str) -> Result<()> {
+        set_window_text(self.hwnd, markup)
+    }
+}
+
+child_widget_backend!(Win32Hyperlink);
+
+impl Win32Hyperlink {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+    
+    /// Set the callback invoked with the index of a link when the user
+    /// clicks it or presses Enter on it
+    /// 
+    /// Links are numbered in the order they appear in the markup. The pointer
+    /// must stay valid until it is replaced, cleared or the hyperlink is dropped.
+    pub fn set_click_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(usize)>) {
+        let mut map = get_link_callbacks_map().lock().unwrap();
+        match callback {
+            Some(ptr) => {
+                map.insert(self.hwnd.0, LinkCallbackPtr(ptr));
+            }
+            None => {
+                map.remove(&self.hwnd.0);
+            }
+        }
+    }
+}
+
+impl Drop for Win32Hyperlink {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying hyperlink: HWND={:?}", self.hwnd);
+        unregister_notify_handler(self.hwnd.0);
+        get_link_callbacks_map().lock().unwrap().remove(&self.hwnd.0);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Invoke the click callback when the user activates a link
+unsafe fn handle_link_notify(header: *const NMHDR) -> LRESULT {
+    let code = (*header).code;
+    if code != NM_CLICK && code != NM_RETURN {
+        return LRESULT(0);
+    }
+    
+    let notification = &*(header as *const NMLINK);
+    let Ok(index) = usize::try_from(notification.item.iLink) else {
+        return LRESULT(0);
+    };
+    
+    // Copy the pointer out so the callback runs without the lock held
+    let callback = get_link_callbacks_map()
+        .lock()
+        .ok()
+        .and_then(|map| map.get(&(*header).hwndFrom.0).copied());
+    
+    if let Some(callback) = callback {
+        tracing::debug!("Hyperlink {} activated", index);
+        (*callback.0)(index);
+    }
+    LRESULT(0)
+}
@@ -0,0 +1,291 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, Stretch, traits::{ImageViewBackend, WidgetBackend}, image::stretch_rect};
+use crate::utils::{to_wide_string, is_valid_hwnd};
+use crate::window_pos::set_child_bounds;
+use crate::widget_backend::{set_control_enabled, set_control_visible};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Window class name for image views
+pub(crate) const IMAGE_VIEW_CLASS_NAME: &str = "LumaImageView";
+
+/// Ensure the image view class is registered (only once)
+static IMAGE_VIEW_CLASS_REGISTERED: OnceCell<()> = OnceCell::new();
+
+/// Bitmap and scaling of an image view, consulted by WM_PAINT
+struct ImageState {
+    bitmap: Option<HBITMAP>,
+    size: Size,
+    stretch: Stretch,
+}
+
+/// Global map of image view HWND to its image
+static IMAGE_STATES: OnceCell<Mutex<HashMap<isize, ImageState>>> = OnceCell::new();
+
+fn get_image_states_map() -> &'static Mutex<HashMap<isize, ImageState>> {
+    IMAGE_STATES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 image view backend
+/// 
+/// Loads bitmaps (`.bmp`) with `LoadImageW` and scales them in `WM_PAINT`.
+pub struct Win32ImageView {
+    hwnd: HWND,
+}
+
+impl ImageViewBackend for Win32ImageView {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 image view: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        IMAGE_VIEW_CLASS_REGISTERED.get_or_try_init(register_image_view_class)?;
+        
+        unsafe {
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            let class_name = to_wide_string(IMAGE_VIEW_CLASS_NAME);
+            
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                windows::core::PCWSTR(class_name.as_ptr()),
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE,
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation("ImageView creation failed".into()));
+            }
+            
+            get_image_states_map().lock().unwrap().insert(hwnd.0, ImageState {
+                bitmap: None,
+                size: Size::zero(),
+                stretch: Stretch::default(),
+            });
+            
+            tracing::debug!("ImageView created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd })
+        }
+    }
+    
+    fn load_file(&mut self, path: &Path) -> Result<Size> {
+        tracing::debug!("Loading image: {}", path.display());
+        
+        let wide_path = to_wide_string(&path.to_string_lossy());
+        let bitmap = unsafe {
+            LoadImageW(
+                None,
+                windows::core::PCWSTR(wide_path.as_ptr()),
+                IMAGE_BITMAP,
+                0,
+                0,
+                LR_LOADFROMFILE | LR_CREATEDIBSECTION,
+            )
+        }
+        .map(|handle| HBITMAP(handle.0))
+        .map_err(|e| Error::OperationFailed(format!("Failed to load image {}: {}", path.display(), e)))?;
+        
+        let mut info = BITMAP::default();
+        unsafe {
+            GetObjectW(
+                bitmap,
+                std::mem::size_of::<BITMAP>() as i32,
+                Some(&mut info as *mut BITMAP as *mut std::ffi::c_void),
+            );
+        }
+        let size = Size::new(info.bmWidth.unsigned_abs(), info.bmHeight.unsigned_abs());
+        
+        self.replace_bitmap(Some(bitmap), size);
+        Ok(size)
+    }
+    
+    fn clear(&mut self) {
+        self.replace_bitmap(None, Size::zero());
+    }
+    
+    fn set_stretch(&mut self, stretch: Stretch) {
+        if let Some(state) = get_image_states_map().lock().unwrap().get_mut(&self.hwnd.0) {
+            state.stretch = stretch;
+        }
+        self.invalidate();
+    }
+}
+
+impl WidgetBackend for Win32ImageView {
+    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+        set_child_bounds(self.hwnd, x, y, width as i32, height as i32)?;
+        self.invalidate();
+        Ok(())
+    }
+    
+    fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+        set_control_enabled(self.hwnd, enabled);
+        Ok(())
+    }
+    
+    fn set_visible(&mut self, visible: bool) -> Result<()> {
+        set_control_visible(self.hwnd, visible);
+        Ok(())
+    }
+    
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.hwnd.0 as *mut std::ffi::c_void
+    }
+}
+
+impl Win32ImageView {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+    
+    /// Swap in a new bitmap, deleting the previous one
+    fn replace_bitmap(&mut self, bitmap: Option<HBITMAP>, size: Size) {
+        let old = get_image_states_map().lock().unwrap().get_mut(&self.hwnd.0).and_then(|state| {
+            state.size = size;
+            std::mem::replace(&mut state.bitmap, bitmap)
+        });
+        if let Some(old) = old {
+            unsafe {
+                let _ = DeleteObject(old);
+            }
+        }
+        self.invalidate();
+    }
+    
+    /// Schedule a repaint of the whole view
+    fn invalidate(&self) {
+        unsafe {
+            let _ = InvalidateRect(self.hwnd, None, false);
+        }
+    }
+}
+
+impl Drop for Win32ImageView {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying image view: HWND={:?}", self.hwnd);
+        let state = get_image_states_map().lock().unwrap().remove(&self.hwnd.0);
+        unsafe {
+            if let Some(bitmap) = state.and_then(|state| state.bitmap) {
+                let _ = DeleteObject(bitmap);
+            }
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Register the window class for image views
+fn register_image_view_class() -> Result<()> {
+    tracing::debug!("Registering window class: {}", IMAGE_VIEW_CLASS_NAME);
+    
+    unsafe {
+        let hinstance = GetModuleHandleW(None).map_err(|e| {
+            Error::Platform(format!("Failed to get module handle: {}", e))
+        })?;
+        
+        let class_name = to_wide_string(IMAGE_VIEW_CLASS_NAME);
+        
+        let wc = WNDCLASSW {
+            style: CS_HREDRAW | CS_VREDRAW,
+            lpfnWndProc: Some(image_view_proc),
+            cbClsExtra: 0,
+            cbWndExtra: 0,
+            hInstance: hinstance.into(),
+            hIcon: HICON::default(),
+            hCursor: LoadCursorW(None, IDC_ARROW).ok().unwrap_or_default(),
+            // WM_PAINT fills the background itself
+            hbrBackground: HBRUSH::default(),
+            lpszMenuName: windows::core::PCWSTR::null(),
+            lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+        };
+        
+        if RegisterClassW(&wc) == 0 {
+            return Err(Error::Platform("RegisterClassW failed".into()));
+        }
+        Ok(())
+    }
+}
+
+/// Window procedure for image views
+unsafe extern "system" fn image_view_proc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    match msg {
+        // Painting covers the whole client area, so skip erasing to avoid flicker
+        WM_ERASEBKGND => LRESULT(1),
+        WM_PAINT => {
+            let mut ps = PAINTSTRUCT::default();
+            let hdc = BeginPaint(hwnd, &mut ps);
+            paint_image(hwnd, hdc);
+            let _ = EndPaint(hwnd, &ps);
+            LRESULT(0)
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+/// Draw the background and the scaled bitmap
+unsafe fn paint_image(hwnd: HWND, hdc: HDC) {
+    let mut client = RECT::default();
+    if GetClientRect(hwnd, &mut client).is_err() {
+        return;
+    }
+    FillRect(hdc, &client, HBRUSH((COLOR_WINDOW.0 + 1) as isize));
+    
+    let (bitmap, image_size, stretch) = match get_image_states_map().lock().unwrap().get(&hwnd.0) {
+        Some(ImageState { bitmap: Some(bitmap), size, stretch }) => (*bitmap, *size, *stretch),
+        _ => return,
+    };
+    
+    let area = Size::new(client.right as u32, client.bottom as u32);
+    let dest = stretch_rect(image_size, area, stretch);
+    
+    let memory_dc = CreateCompatibleDC(hdc);
+    let previous = SelectObject(memory_dc, bitmap);
+    
+    // HALFTONE gives smooth downscaling; it needs the brush origin reset
+    SetStretchBltMode(hdc, HALFTONE);
+    let _ = SetBrushOrgEx(hdc, 0, 0, None);
+    let _ = StretchBlt(
+        hdc,
+        dest.x,
+        dest.y,
+        dest.width as i32,
+        dest.height as i32,
+        memory_dc,
+        0,
+        0,
+        image_size.width as i32,
+        image_size.height as i32,
+        SRCCOPY,
+    );
+    
+    SelectObject(memory_dc, previous);
+    let _ = DeleteDC(memory_dc);
+}
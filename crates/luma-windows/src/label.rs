@@ -1,8 +1,17 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::Graphics::Gdi::InvalidateRect;
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, Point, Size, traits::LabelBackend};
-use crate::utils::{to_wide_string, is_valid_hwnd};
+use luma_core::{Result, Error, Point, Size, Color, EllipsisMode, traits::LabelBackend, keyboard::display_text};
+use crate::utils::{to_wide_string, is_valid_hwnd, window_text, set_window_text, measure_text};
+use crate::widget_backend::child_widget_backend;
+use crate::control_colors::{set_text_color, set_background_color, clear_control_colors};
+
+// Static control ellipsis styles
+const SS_ENDELLIPSIS: u32 = 0x4000;
+const SS_PATHELLIPSIS: u32 = 0x8000;
+const SS_WORDELLIPSIS: u32 = 0xC000;
+const SS_ELLIPSISMASK: u32 = 0xC000;
 
 /// Win32 label backend (STATIC control)
 pub struct Win32Label {
@@ -62,43 +71,76 @@ impl LabelBackend for Win32Label {
         }
     }
     
-    fn set_text(&mut self, text: &str) -> Result<()> {
-        unsafe {
-            let wide_text = to_wide_string(text);
-            SetWindowTextW(self.hwnd, windows::core::PCWSTR(wide_text.as_ptr()))
-                .map_err(|e| Error::OperationFailed(format!("SetWindowTextW failed: {}", e)))?;
-        }
-        Ok(())
+    fn get_text(&self) -> Result<String> {
+        Ok(window_text(self.hwnd))
     }
     
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
-        unsafe {
-            SetWindowPos(
-                self.hwnd,
-                HWND::default(),
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER | SWP_NOACTIVATE,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
-        }
-        Ok(())
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        set_window_text(self.hwnd, text)
     }
 }
 
+child_widget_backend!(Win32Label);
+
 impl Win32Label {
     /// Get the raw HWND handle
     pub fn hwnd(&self) -> HWND {
         self.hwnd
     }
+    
+    /// Get the size of the text in the label's font
+    pub fn preferred_size(&self) -> Result<Size> {
+        measure_text(self.hwnd, &display_text(&window_text(self.hwnd)))
+    }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        set_text_color(self.hwnd, color);
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        set_background_color(self.hwnd, color)
+    }
+    
+    /// Set how text too long for the label is shortened
+    pub fn set_ellipsis(&mut self, mode: EllipsisMode) {
+        unsafe {
+            let style = GetWindowLongPtrW(self.hwnd, GWL_STYLE) as u32 & !SS_ELLIPSISMASK;
+            SetWindowLongPtrW(self.hwnd, GWL_STYLE, (style | ellipsis_style(mode)) as isize);
+            let _ = InvalidateRect(self.hwnd, None, true);
+        }
+    }
+    
+    /// Get a function that sets the label text
+    /// 
+    /// Data bindings cannot borrow the label, so they use this to update it
+    /// when their source changes.
+    pub fn text_writer(&self) -> impl Fn(&str) -> Result<()> + 'static {
+        let hwnd = self.hwnd;
+        move |text| set_window_text(hwnd, text)
+    }
 }
 
 impl Drop for Win32Label {
     fn drop(&mut self) {
         tracing::debug!("Destroying label: HWND={:?}", self.hwnd);
+        clear_control_colors(self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
         }
     }
 }
+
+/// Convert an EllipsisMode to its static control style
+fn ellipsis_style(mode: EllipsisMode) -> u32 {
+    match mode {
+        EllipsisMode::None => 0,
+        EllipsisMode::End => SS_ENDELLIPSIS,
+        EllipsisMode::Path => SS_PATHELLIPSIS,
+        EllipsisMode::Word => SS_WORDELLIPSIS,
+    }
+}
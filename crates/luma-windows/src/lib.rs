@@ -1,20 +1,60 @@
 // Windows (Win32) backend for Luma GUI framework
 
 pub mod application;
+pub mod accelerator;
 pub mod window;
 pub mod button;
 pub mod label;
 pub mod textinput;
 pub mod checkbox;
 pub mod listbox;
+pub mod list_view;
 pub mod panel;
+pub mod scroll_viewer;
+pub mod number_box;
+pub mod date_picker;
+pub mod image_view;
+pub mod progress_bar;
+pub mod hyperlink;
+pub mod control_colors;
+pub mod control_ids;
+pub mod window_pos;
+pub mod widget_backend;
 pub mod utils;
+pub mod theme;
+pub mod shell;
+pub mod tray;
+pub mod taskbar;
+pub mod widget_tree;
 
 pub use application::Win32Application;
-pub use window::{Win32Window, register_callback, unregister_callback};
+pub use window::{
+    Win32Window,
+    register_callback, register_notification_callback, unregister_callback,
+    unregister_notification_callback,
+    register_command_callback, unregister_command_callback,
+    register_widget_callback, unregister_widget_callback,
+    register_notify_handler, unregister_notify_handler, NotifyHandler,
+    request_layout,
+};
 pub use button::Win32Button;
 pub use label::Win32Label;
 pub use textinput::Win32TextInput;
 pub use checkbox::Win32CheckBox;
 pub use listbox::Win32ListBox;
+pub use list_view::Win32ListView;
 pub use panel::Win32Panel;
+pub use scroll_viewer::Win32ScrollViewer;
+pub use number_box::Win32NumberBox;
+pub use date_picker::Win32DatePicker;
+pub use image_view::Win32ImageView;
+pub use progress_bar::Win32ProgressBar;
+pub use hyperlink::Win32Hyperlink;
+pub use control_colors::{set_text_color, set_background_color};
+pub use control_ids::{assign_control_id, release_control_id};
+pub use window_pos::{with_deferred_positioning, set_child_bounds};
+pub use utils::screen_bounds;
+pub use theme::system_theme;
+pub use shell::shell_open;
+pub use tray::Win32TrayIcon;
+pub use widget_tree::widget_tree;
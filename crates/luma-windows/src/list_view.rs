@@ -0,0 +1,435 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Controls::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, traits::ListViewBackend};
+use crate::utils::{to_wide_string, from_wide_string, is_valid_hwnd};
+use crate::widget_backend::child_widget_backend;
+use crate::window::{register_notify_handler, unregister_notify_handler};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+use std::cmp::Ordering;
+
+/// Longest cell text read back from the control, in UTF-16 units
+const MAX_CELL_LEN: usize = 1024;
+
+/// Callbacks of one list view, as raw pointers owned by the widget
+#[derive(Clone, Copy, Default)]
+struct ListViewCallbacks {
+    selection: Option<*mut dyn FnMut()>,
+    column_click: Option<*mut dyn FnMut(usize)>,
+}
+unsafe impl Send for ListViewCallbacks {}
+
+/// Global map of list view HWND to callbacks for handling LVN_ITEMCHANGED and LVN_COLUMNCLICK
+static LIST_VIEW_CALLBACKS: OnceCell<Mutex<HashMap<isize, ListViewCallbacks>>> = OnceCell::new();
+
+fn get_list_view_callbacks_map() -> &'static Mutex<HashMap<isize, ListViewCallbacks>> {
+    LIST_VIEW_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 list view backend (SysListView32 control in report mode)
+pub struct Win32ListView {
+    hwnd: HWND,
+    columns: usize,
+}
+
+impl ListViewBackend for Win32ListView {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 list view: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        unsafe {
+            let icc = INITCOMMONCONTROLSEX {
+                dwSize: std::mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: ICC_LISTVIEW_CLASSES,
+            };
+            let _ = InitCommonControlsEx(&icc);
+            
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            let style = WS_CHILD | WS_VISIBLE | WS_TABSTOP
+                | WINDOW_STYLE(LVS_REPORT | LVS_SINGLESEL | LVS_SHOWSELALWAYS);
+            
+            let hwnd = CreateWindowExW(
+                WS_EX_CLIENTEDGE, // Sunken border, like the listbox
+                WC_LISTVIEWW,
+                windows::core::PCWSTR::null(),
+                style,
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation("ListView creation failed".into()));
+            }
+            
+            // Select whole rows rather than just the first cell
+            let extended = LVS_EX_FULLROWSELECT | LVS_EX_GRIDLINES;
+            SendMessageW(hwnd, LVM_SETEXTENDEDLISTVIEWSTYLE, WPARAM(extended as usize), LPARAM(extended as isize));
+            
+            register_notify_handler(hwnd.0, handle_list_view_notify);
+            
+            tracing::debug!("ListView created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd, columns: 0 })
+        }
+    }
+    
+    fn add_column(&mut self, title: &str, width: u32) -> Result<()> {
+        let mut wide_title = to_wide_string(title);
+        let column = LVCOLUMNW {
+            mask: LVCF_TEXT | LVCF_WIDTH | LVCF_SUBITEM,
+            cx: width as i32,
+            pszText: windows::core::PWSTR(wide_title.as_mut_ptr()),
+            iSubItem: self.columns as i32,
+            ..Default::default()
+        };
+        let result = unsafe {
+            SendMessageW(
+                self.hwnd,
+                LVM_INSERTCOLUMNW,
+                WPARAM(self.columns),
+                LPARAM(&column as *const LVCOLUMNW as isize),
+            )
+        };
+        
+        if result.0 < 0 {
+            return Err(Error::OperationFailed(format!("Failed to add column '{}'", title)));
+        }
+        self.columns += 1;
+        Ok(())
+    }
+    
+    fn column_count(&self) -> usize {
+        self.columns
+    }
+    
+    fn add_row(&mut self, cells: &[&str]) -> Result<()> {
+        if cells.len() > self.columns {
+            return Err(Error::InvalidParameter(format!(
+                "Row has {} cells but the list view has {} columns",
+                cells.len(),
+                self.columns
+            )));
+        }
+        
+        let index = self.row_count()?;
+        let mut wide_first = to_wide_string(cells.first().copied().unwrap_or(""));
+        let item = LVITEMW {
+            mask: LVIF_TEXT,
+            iItem: index as i32,
+            pszText: windows::core::PWSTR(wide_first.as_mut_ptr()),
+            ..Default::default()
+        };
+        let result = unsafe {
+            SendMessageW(self.hwnd, LVM_INSERTITEMW, WPARAM(0), LPARAM(&item as *const LVITEMW as isize))
+        };
+        if result.0 < 0 {
+            return Err(Error::OperationFailed("Failed to add row to list view".into()));
+        }
+        
+        // The other columns are sub-items of the row
+        let row = result.0 as usize;
+        for (column, cell) in cells.iter().enumerate().skip(1) {
+            let mut wide_cell = to_wide_string(cell);
+            let sub_item = LVITEMW {
+                iSubItem: column as i32,
+                pszText: windows::core::PWSTR(wide_cell.as_mut_ptr()),
+                ..Default::default()
+            };
+            unsafe {
+                SendMessageW(self.hwnd, LVM_SETITEMTEXTW, WPARAM(row), LPARAM(&sub_item as *const LVITEMW as isize));
+            }
+        }
+        Ok(())
+    }
+    
+    fn remove_row(&mut self, index: usize) -> Result<()> {
+        let result = unsafe { SendMessageW(self.hwnd, LVM_DELETEITEM, WPARAM(index), LPARAM(0)) };
+        if result.0 == 0 {
+            return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+        }
+        Ok(())
+    }
+    
+    fn clear_rows(&mut self) -> Result<()> {
+        unsafe {
+            SendMessageW(self.hwnd, LVM_DELETEALLITEMS, WPARAM(0), LPARAM(0));
+        }
+        Ok(())
+    }
+    
+    fn row_count(&self) -> Result<usize> {
+        let count = unsafe { SendMessageW(self.hwnd, LVM_GETITEMCOUNT, WPARAM(0), LPARAM(0)) };
+        Ok(count.0.max(0) as usize)
+    }
+    
+    fn get_row(&self, index: usize) -> Result<Vec<String>> {
+        if index >= self.row_count()? {
+            return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+        }
+        
+        Ok((0..self.columns).map(|column| cell_text(self.hwnd, index, column)).collect())
+    }
+    
+    fn get_selected_index(&self) -> Result<Option<usize>> {
+        Ok(selected_row(self.hwnd))
+    }
+    
+    fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
+        if let Some(index) = index {
+            if index >= self.row_count()? {
+                return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+            }
+        }
+        
+        // Index -1 changes the state of every row
+        let state_mask = LIST_VIEW_ITEM_STATE_FLAGS(LVIS_SELECTED.0 | LVIS_FOCUSED.0);
+        unsafe {
+            set_row_state(self.hwnd, usize::MAX, LIST_VIEW_ITEM_STATE_FLAGS(0), state_mask);
+            if let Some(index) = index {
+                set_row_state(self.hwnd, index, state_mask, state_mask);
+                SendMessageW(self.hwnd, LVM_ENSUREVISIBLE, WPARAM(index), LPARAM(0));
+            }
+        }
+        Ok(())
+    }
+    
+    fn sort_rows(
+        &mut self,
+        column: usize,
+        ascending: bool,
+        compare: &dyn Fn(&str, &str) -> std::cmp::Ordering,
+    ) -> Result<()> {
+        sort_rows(self.hwnd, self.columns, column, ascending, compare)
+    }
+}
+
+child_widget_backend!(Win32ListView);
+
+impl Win32ListView {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+    
+    /// Set the callback invoked when a row is selected or deselected
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// list view is dropped.
+    pub fn set_selection_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        let mut map = get_list_view_callbacks_map().lock().unwrap();
+        map.entry(self.hwnd.0).or_default().selection = callback;
+    }
+    
+    /// Set the callback invoked with a column's index when its header is clicked
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// list view is dropped.
+    pub fn set_column_click_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(usize)>) {
+        let mut map = get_list_view_callbacks_map().lock().unwrap();
+        map.entry(self.hwnd.0).or_default().column_click = callback;
+    }
+    
+    /// Get a function that reads the selected row
+    /// 
+    /// Selection callbacks cannot borrow the list view, so they use this to
+    /// look up which row is selected when they fire.
+    pub fn selection_reader(&self) -> impl Fn() -> Option<usize> + 'static {
+        let hwnd = self.hwnd;
+        move || selected_row(hwnd)
+    }
+    
+    /// Get a function that sorts the rows, like [`ListViewBackend::sort_rows`]
+    /// 
+    /// Header click callbacks cannot borrow the list view, so they use this
+    /// to sort by the clicked column.
+    pub fn row_sorter(&self) -> impl Fn(usize, bool, &CellComparator<'_>) -> Result<()> + 'static {
+        let hwnd = self.hwnd;
+        let columns = self.columns;
+        move |column, ascending, compare| sort_rows(hwnd, columns, column, ascending, compare)
+    }
+}
+
+impl Drop for Win32ListView {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying list view: HWND={:?}", self.hwnd);
+        unregister_notify_handler(self.hwnd.0);
+        get_list_view_callbacks_map().lock().unwrap().remove(&self.hwnd.0);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Get the selected row of a list view
+fn selected_row(hwnd: HWND) -> Option<usize> {
+    // Search from index -1 to start at the first row
+    let index = unsafe {
+        SendMessageW(hwnd, LVM_GETNEXTITEM, WPARAM(usize::MAX), LPARAM(LVNI_SELECTED as isize))
+    };
+    usize::try_from(index.0).ok()
+}
+
+/// Get the text of one cell
+fn cell_text(hwnd: HWND, row: usize, column: usize) -> String {
+    let mut buffer = vec![0u16; MAX_CELL_LEN];
+    let mut item = LVITEMW {
+        iSubItem: column as i32,
+        pszText: windows::core::PWSTR(buffer.as_mut_ptr()),
+        cchTextMax: buffer.len() as i32,
+        ..Default::default()
+    };
+    // Returns the number of characters copied, without the terminator
+    let len = unsafe {
+        SendMessageW(hwnd, LVM_GETITEMTEXTW, WPARAM(row), LPARAM(&mut item as *mut LVITEMW as isize))
+    };
+    buffer.truncate((len.0.max(0) as usize).min(MAX_CELL_LEN));
+    from_wide_string(&buffer)
+}
+
+/// Orders two cells of a column
+type CellComparator<'a> = dyn Fn(&str, &str) -> Ordering + 'a;
+
+/// What the row comparator needs, passed to it through LVM_SORTITEMSEX
+struct SortContext<'a> {
+    hwnd: HWND,
+    column: usize,
+    ascending: bool,
+    compare: &'a CellComparator<'a>,
+}
+
+/// Sort the rows of a list view by a column and mark the column's header
+fn sort_rows(
+    hwnd: HWND,
+    columns: usize,
+    column: usize,
+    ascending: bool,
+    compare: &CellComparator<'_>,
+) -> Result<()> {
+    if column >= columns {
+        return Err(Error::InvalidParameter(format!("Invalid column: {}", column)));
+    }
+    
+    let context = SortContext { hwnd, column, ascending, compare };
+    let result = unsafe {
+        SendMessageW(
+            hwnd,
+            LVM_SORTITEMSEX,
+            WPARAM(&context as *const SortContext as usize),
+            LPARAM(compare_rows as *const () as isize),
+        )
+    };
+    if result.0 == 0 {
+        return Err(Error::OperationFailed("LVM_SORTITEMSEX failed".into()));
+    }
+    
+    unsafe { show_sort_arrow(hwnd, columns, column, ascending) };
+    Ok(())
+}
+
+/// Compare two rows by their position, for LVM_SORTITEMSEX
+unsafe extern "system" fn compare_rows(first: LPARAM, second: LPARAM, context: LPARAM) -> i32 {
+    let context = &*(context.0 as *const SortContext);
+    let first = cell_text(context.hwnd, first.0 as usize, context.column);
+    let second = cell_text(context.hwnd, second.0 as usize, context.column);
+    
+    // A panic must not unwind into the control; treat the rows as equal instead
+    let order = luma_core::panic_guard::catch_callback_panic(|| (context.compare)(&first, &second))
+        .unwrap_or(Ordering::Equal);
+    if context.ascending { order as i32 } else { order.reverse() as i32 }
+}
+
+/// Show the sort direction on one column's header and clear it from the others
+unsafe fn show_sort_arrow(hwnd: HWND, columns: usize, sorted: usize, ascending: bool) {
+    let header = HWND(SendMessageW(hwnd, LVM_GETHEADER, WPARAM(0), LPARAM(0)).0);
+    if !is_valid_hwnd(header) {
+        return;
+    }
+    
+    for column in 0..columns {
+        let mut item = HDITEMW {
+            mask: HDI_FORMAT,
+            ..Default::default()
+        };
+        SendMessageW(header, HDM_GETITEMW, WPARAM(column), LPARAM(&mut item as *mut HDITEMW as isize));
+        let mut format = item.fmt.0 & !(HDF_SORTUP.0 | HDF_SORTDOWN.0);
+        if column == sorted {
+            format |= if ascending { HDF_SORTUP.0 } else { HDF_SORTDOWN.0 };
+        }
+        item.fmt = HEADER_CONTROL_FORMAT_FLAGS(format);
+        SendMessageW(header, HDM_SETITEMW, WPARAM(column), LPARAM(&item as *const HDITEMW as isize));
+    }
+}
+
+/// Set the state bits in `mask` of a row (or every row, for `usize::MAX`)
+unsafe fn set_row_state(
+    hwnd: HWND,
+    index: usize,
+    state: LIST_VIEW_ITEM_STATE_FLAGS,
+    mask: LIST_VIEW_ITEM_STATE_FLAGS,
+) {
+    let item = LVITEMW {
+        state,
+        stateMask: mask,
+        ..Default::default()
+    };
+    SendMessageW(hwnd, LVM_SETITEMSTATE, WPARAM(index), LPARAM(&item as *const LVITEMW as isize));
+}
+
+/// Invoke the list view's callbacks for selection changes and header clicks
+unsafe fn handle_list_view_notify(header: *const NMHDR) -> LRESULT {
+    let code = (*header).code;
+    if code != LVN_ITEMCHANGED && code != LVN_COLUMNCLICK {
+        return LRESULT(0);
+    }
+    
+    // Copy the pointers out so the callbacks run without the lock held
+    let Some(callbacks) = get_list_view_callbacks_map()
+        .lock()
+        .ok()
+        .and_then(|map| map.get(&(*header).hwndFrom.0).copied())
+    else {
+        return LRESULT(0);
+    };
+    
+    let notification = &*(header as *const NMLISTVIEW);
+    if code == LVN_COLUMNCLICK {
+        if let Some(callback) = callbacks.column_click {
+            tracing::debug!("List view column {} clicked", notification.iSubItem);
+            (*callback)(notification.iSubItem.max(0) as usize);
+        }
+    } else {
+        // Other state changes, such as focus moving, do not change the selection
+        let changed = (notification.uNewState ^ notification.uOldState) & LVIS_SELECTED.0;
+        if notification.uChanged.0 & LVIF_STATE.0 != 0 && changed != 0 {
+            if let Some(callback) = callbacks.selection {
+                (*callback)();
+            }
+        }
+    }
+    LRESULT(0)
+}
@@ -3,6 +3,8 @@ use windows::Win32::UI::WindowsAndMessaging::*;
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use luma_core::{Result, Error, Point, Size, ListBoxFlags, traits::ListBoxBackend};
 use crate::utils::{to_wide_string, from_wide_string, is_valid_hwnd};
+use crate::widget_backend::child_widget_backend;
+use crate::window::{register_notification_callback, unregister_notification_callback};
 
 // ListBox constants and messages
 const LB_ADDSTRING: u32 = 0x0180;
@@ -10,7 +12,9 @@ const LB_DELETESTRING: u32 = 0x0182;
 const LB_RESETCONTENT: u32 = 0x0184;
 const LB_GETCOUNT: u32 = 0x018B;
 const LB_GETCURSEL: u32 = 0x0188;
+const LB_GETCARETINDEX: u32 = 0x019F;
 const LB_SETCURSEL: u32 = 0x0186;
+const LB_SETSEL: u32 = 0x0185;
 const LB_GETSELCOUNT: u32 = 0x0190;
 const LB_GETSELITEMS: u32 = 0x0191;
 const LB_GETTEXTLEN: u32 = 0x018A;
@@ -18,6 +22,10 @@ const LB_GETTEXT: u32 = 0x0189;
 const LB_ERR: i32 = -1;
 const LB_ERRSPACE: i32 = -2;
 
+// ListBox notifications
+const LBN_SELCHANGE: u32 = 1;
+const LBN_DBLCLK: u32 = 2;
+
 // ListBox styles
 const LBS_NOTIFY: u32 = 0x0001;
 const LBS_SORT: u32 = 0x0002;
@@ -105,7 +113,7 @@ impl ListBoxBackend for Win32ListBox {
             );
             
             if result.0 == LB_ERR as isize {
-                return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+                return Err(self.index_error(index));
             }
         }
         Ok(())
@@ -135,51 +143,11 @@ impl ListBoxBackend for Win32ListBox {
             ));
         }
         
-        unsafe {
-            let index = SendMessageW(self.hwnd, LB_GETCURSEL, WPARAM(0), LPARAM(0));
-            if index.0 == LB_ERR as isize {
-                Ok(None) // No selection
-            } else {
-                Ok(Some(index.0 as usize))
-            }
-        }
+        Ok(selected_index(self.hwnd))
     }
     
     fn get_selected_indices(&self) -> Result<Vec<usize>> {
-        if !self.flags.contains(ListBoxFlags::MULTI_SELECT) {
-            // For single-select, return 0 or 1 items
-            return match self.get_selected_index()? {
-                Some(idx) => Ok(vec![idx]),
-                None => Ok(vec![]),
-            };
-        }
-        
-        unsafe {
-            // Get number of selected items
-            let sel_count = SendMessageW(self.hwnd, LB_GETSELCOUNT, WPARAM(0), LPARAM(0));
-            if sel_count.0 == LB_ERR as isize {
-                return Err(Error::OperationFailed("Failed to get selection count".into()));
-            }
-            
-            if sel_count.0 == 0 {
-                return Ok(vec![]);
-            }
-            
-            // Get selected indices
-            let mut indices: Vec<i32> = vec![0; sel_count.0 as usize];
-            let result = SendMessageW(
-                self.hwnd,
-                LB_GETSELITEMS,
-                WPARAM(sel_count.0 as usize),
-                LPARAM(indices.as_mut_ptr() as isize),
-            );
-            
-            if result.0 == LB_ERR as isize {
-                return Err(Error::OperationFailed("Failed to get selected indices".into()));
-            }
-            
-            Ok(indices.iter().map(|&i| i as usize).collect())
-        }
+        selected_indices(self.hwnd, self.flags)
     }
     
     fn set_selected_index(&mut self, index: Option<usize>) -> Result<()> {
@@ -197,29 +165,54 @@ impl ListBoxBackend for Win32ListBox {
             
             let result = SendMessageW(self.hwnd, LB_SETCURSEL, wparam, LPARAM(0));
             
-            if result.0 == LB_ERR as isize && index.is_some() {
-                return Err(Error::InvalidParameter(format!("Invalid index: {:?}", index)));
+            if let Some(index) = index.filter(|_| result.0 == LB_ERR as isize) {
+                return Err(self.index_error(index));
             }
         }
         Ok(())
     }
     
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+    fn set_selected_indices(&mut self, indices: &[usize]) -> Result<()> {
+        if !self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Use set_selected_index() for single-select listbox".into()
+            ));
+        }
+        
+        let len = self.item_count()?;
+        if let Some(&index) = indices.iter().find(|index| **index >= len) {
+            return Err(Error::IndexOutOfRange { index, len });
+        }
+        
+        self.set_all_selected(false)?;
         unsafe {
-            SetWindowPos(
-                self.hwnd,
-                HWND::default(),
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER | SWP_NOACTIVATE,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+            for &index in indices {
+                SendMessageW(self.hwnd, LB_SETSEL, WPARAM(1), LPARAM(index as isize));
+            }
+        }
+        Ok(())
+    }
+    
+    fn set_all_selected(&mut self, selected: bool) -> Result<()> {
+        if !self.flags.contains(ListBoxFlags::MULTI_SELECT) {
+            return Err(Error::OperationFailed(
+                "Cannot select all items in a single-select listbox".into()
+            ));
+        }
+        
+        unsafe {
+            // An index of -1 applies to every item
+            let result = SendMessageW(self.hwnd, LB_SETSEL, WPARAM(selected as usize), LPARAM(-1));
+            if result.0 == LB_ERR as isize {
+                return Err(Error::OperationFailed("Failed to change selection".into()));
+            }
         }
         Ok(())
     }
 }
 
+child_widget_backend!(Win32ListBox);
+
 impl Win32ListBox {
     /// Get the raw HWND handle
     pub fn hwnd(&self) -> HWND {
@@ -238,7 +231,7 @@ impl Win32ListBox {
             );
             
             if len.0 == LB_ERR as isize {
-                return Err(Error::InvalidParameter(format!("Invalid index: {}", index)));
+                return Err(self.index_error(index));
             }
             
             if len.0 == 0 {
@@ -262,17 +255,123 @@ impl Win32ListBox {
             Ok(from_wide_string(&buffer))
         }
     }
+    
+    /// Describe an index the control rejected, with the current item count
+    fn index_error(&self, index: usize) -> Error {
+        match self.item_count() {
+            Ok(len) => Error::IndexOutOfRange { index, len },
+            Err(e) => e,
+        }
+    }
+}
+
+impl Win32ListBox {
+    /// Register (or clear) the callback invoked when the selection changes
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_selection_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_notification_callback(self.hwnd.0, LBN_SELCHANGE, ptr),
+            None => unregister_notification_callback(self.hwnd.0, LBN_SELCHANGE),
+        }
+    }
+    
+    /// Register (or clear) the callback invoked when an item is double-clicked
+    /// 
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_activate_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_notification_callback(self.hwnd.0, LBN_DBLCLK, ptr),
+            None => unregister_notification_callback(self.hwnd.0, LBN_DBLCLK),
+        }
+    }
+    
+    /// Get a function that reads which item was activated
+    /// 
+    /// That is the selected item, or for multi-select listboxes the item with
+    /// the focus rectangle, since a double-click can also deselect it.
+    pub fn activation_reader(&self) -> impl Fn() -> Option<usize> + 'static {
+        let hwnd = self.hwnd;
+        let flags = self.flags;
+        move || {
+            if !flags.contains(ListBoxFlags::MULTI_SELECT) {
+                return selected_index(hwnd);
+            }
+            let index = unsafe { SendMessageW(hwnd, LB_GETCARETINDEX, WPARAM(0), LPARAM(0)) };
+            usize::try_from(index.0).ok()
+        }
+    }
+    
+    /// Get a function that reads the current selection
+    /// 
+    /// Selection callbacks cannot borrow the listbox, so they use this to
+    /// look up which items are selected when they fire.
+    pub fn selection_reader(&self) -> impl Fn() -> Result<Vec<usize>> + 'static {
+        let hwnd = self.hwnd;
+        let flags = self.flags;
+        move || selected_indices(hwnd, flags)
+    }
 }
 
 impl Drop for Win32ListBox {
     fn drop(&mut self) {
         tracing::debug!("Destroying listbox: HWND={:?}", self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Get the selected item of a single-select listbox
+fn selected_index(hwnd: HWND) -> Option<usize> {
+    unsafe {
+        let index = SendMessageW(hwnd, LB_GETCURSEL, WPARAM(0), LPARAM(0));
+        if index.0 == LB_ERR as isize {
+            None // No selection
+        } else {
+            Some(index.0 as usize)
         }
     }
 }
 
+/// Get the selected items of a listbox
+fn selected_indices(hwnd: HWND, flags: ListBoxFlags) -> Result<Vec<usize>> {
+    if !flags.contains(ListBoxFlags::MULTI_SELECT) {
+        // For single-select, return 0 or 1 items
+        return Ok(selected_index(hwnd).into_iter().collect());
+    }
+    
+    unsafe {
+        // Get number of selected items
+        let sel_count = SendMessageW(hwnd, LB_GETSELCOUNT, WPARAM(0), LPARAM(0));
+        if sel_count.0 == LB_ERR as isize {
+            return Err(Error::OperationFailed("Failed to get selection count".into()));
+        }
+        
+        if sel_count.0 == 0 {
+            return Ok(vec![]);
+        }
+        
+        // Get selected indices
+        let mut indices: Vec<i32> = vec![0; sel_count.0 as usize];
+        let result = SendMessageW(
+            hwnd,
+            LB_GETSELITEMS,
+            WPARAM(sel_count.0 as usize),
+            LPARAM(indices.as_mut_ptr() as isize),
+        );
+        
+        if result.0 == LB_ERR as isize {
+            return Err(Error::OperationFailed("Failed to get selected indices".into()));
+        }
+        
+        Ok(indices.iter().map(|&i| i as usize).collect())
+    }
+}
+
 /// Convert ListBoxFlags to Win32 listbox style
 fn listbox_flags_to_style(flags: ListBoxFlags) -> WINDOW_STYLE {
     let mut style = WS_CHILD | WS_VISIBLE | WS_TABSTOP | WS_BORDER | 
@@ -0,0 +1,391 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Controls::*;
+use windows::Win32::UI::Shell::{SetWindowSubclass, RemoveWindowSubclass, DefSubclassProc};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, traits::{NumberBoxBackend, WidgetBackend}};
+use luma_core::panic_guard::catch_callback_panic;
+use crate::utils::{to_wide_string, from_wide_string, is_valid_hwnd};
+use crate::window_pos::set_child_bounds;
+use crate::widget_backend::{set_control_enabled, set_control_visible};
+use crate::window::{register_notify_handler, unregister_notify_handler};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+// Edit control styles
+const ES_RIGHT: u32 = 0x0002;
+const ES_AUTOHSCROLL: u32 = 0x0080;
+
+/// Subclass ID for number box text validation
+const NUMBER_BOX_SUBCLASS_ID: usize = 1;
+
+/// The up-down control's own position never changes; every click is turned
+/// into a step of the value, so it sits in the middle of a wide range
+const UPDOWN_RANGE: i32 = 10_000;
+
+/// Wrapper to make value change callback pointer Send
+#[derive(Clone, Copy)]
+struct ChangeCallbackPtr(*mut dyn FnMut(f64));
+unsafe impl Send for ChangeCallbackPtr {}
+
+/// Value and settings of a number box, shared with its message handlers
+struct NumberState {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    decimals: usize,
+    callback: Option<ChangeCallbackPtr>,
+}
+
+impl NumberState {
+    fn format(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+}
+
+/// Global map of edit control HWND to number box state
+static NUMBER_STATES: OnceCell<Mutex<HashMap<isize, NumberState>>> = OnceCell::new();
+
+fn get_number_states_map() -> &'static Mutex<HashMap<isize, NumberState>> {
+    NUMBER_STATES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 number box backend (EDIT control with an up-down buddy)
+pub struct Win32NumberBox {
+    edit: HWND,
+    updown: HWND,
+}
+
+impl NumberBoxBackend for Win32NumberBox {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 number box: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        unsafe {
+            let icc = INITCOMMONCONTROLSEX {
+                dwSize: std::mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: ICC_UPDOWN_CLASS,
+            };
+            let _ = InitCommonControlsEx(&icc);
+            
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            let (edit_width, updown_width) = split_width(size.width);
+            
+            let edit = CreateWindowExW(
+                WS_EX_CLIENTEDGE,
+                windows::core::w!("EDIT"),
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(ES_RIGHT | ES_AUTOHSCROLL),
+                pos.x,
+                pos.y,
+                edit_width,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(edit) {
+                return Err(Error::WidgetCreation("NumberBox edit creation failed".into()));
+            }
+            
+            let updown = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                UPDOWN_CLASSW,
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE | WINDOW_STYLE(UDS_ARROWKEYS | UDS_HOTTRACK),
+                pos.x + edit_width,
+                pos.y,
+                updown_width,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(updown) {
+                let _ = DestroyWindow(edit);
+                return Err(Error::WidgetCreation("NumberBox up-down creation failed".into()));
+            }
+            
+            SendMessageW(updown, UDM_SETBUDDY, WPARAM(edit.0 as usize), LPARAM(0));
+            SendMessageW(updown, UDM_SETRANGE32, WPARAM(-UPDOWN_RANGE as usize), LPARAM(UPDOWN_RANGE as isize));
+            SendMessageW(updown, UDM_SETPOS32, WPARAM(0), LPARAM(0));
+            
+            let state = NumberState {
+                value: 0.0,
+                min: f64::MIN,
+                max: f64::MAX,
+                step: 1.0,
+                decimals: 0,
+                callback: None,
+            };
+            let text = state.format();
+            get_number_states_map().lock().unwrap().insert(edit.0, state);
+            set_edit_text(edit, &text);
+            
+            let _ = SetWindowSubclass(edit, Some(number_edit_subclass_proc), NUMBER_BOX_SUBCLASS_ID, 0);
+            register_notify_handler(updown.0, handle_updown_notify);
+            
+            tracing::debug!("NumberBox created successfully: edit={:?}, updown={:?}", edit, updown);
+            
+            Ok(Self { edit, updown })
+        }
+    }
+    
+    fn value(&self) -> f64 {
+        get_number_states_map()
+            .lock()
+            .unwrap()
+            .get(&self.edit.0)
+            .map_or(0.0, |state| state.value)
+    }
+    
+    fn set_value(&mut self, value: f64) -> Result<()> {
+        commit_value(self.edit, value, false);
+        Ok(())
+    }
+    
+    fn set_range(&mut self, min: f64, max: f64) -> Result<()> {
+        if min > max {
+            return Err(Error::InvalidParameter(format!("Invalid range: {} > {}", min, max)));
+        }
+        let value = self.update_state(|state| {
+            state.min = min;
+            state.max = max;
+        });
+        commit_value(self.edit, value, false);
+        Ok(())
+    }
+    
+    fn set_step(&mut self, step: f64) -> Result<()> {
+        if step.is_nan() || step <= 0.0 {
+            return Err(Error::InvalidParameter(format!("Step must be positive, got {}", step)));
+        }
+        self.update_state(|state| state.step = step);
+        Ok(())
+    }
+    
+    fn set_decimals(&mut self, decimals: usize) -> Result<()> {
+        let value = self.update_state(|state| state.decimals = decimals);
+        commit_value(self.edit, value, false);
+        Ok(())
+    }
+}
+
+impl WidgetBackend for Win32NumberBox {
+    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+        let (edit_width, updown_width) = split_width(width);
+        set_child_bounds(self.edit, x, y, edit_width, height as i32)?;
+        set_child_bounds(self.updown, x + edit_width, y, updown_width, height as i32)?;
+        Ok(())
+    }
+    
+    fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+        set_control_enabled(self.edit, enabled);
+        set_control_enabled(self.updown, enabled);
+        Ok(())
+    }
+    
+    fn set_visible(&mut self, visible: bool) -> Result<()> {
+        set_control_visible(self.edit, visible);
+        set_control_visible(self.updown, visible);
+        Ok(())
+    }
+    
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.edit.0 as *mut std::ffi::c_void
+    }
+}
+
+impl Win32NumberBox {
+    /// Get the raw HWND handle of the edit control
+    pub fn hwnd(&self) -> HWND {
+        self.edit
+    }
+    
+    /// Set the callback invoked when the user changes the value
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// number box is dropped.
+    pub fn set_change_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(f64)>) {
+        self.update_state(|state| state.callback = callback.map(ChangeCallbackPtr));
+    }
+    
+    /// Modify the shared state, returning the current value
+    fn update_state(&self, update: impl FnOnce(&mut NumberState)) -> f64 {
+        let mut map = get_number_states_map().lock().unwrap();
+        match map.get_mut(&self.edit.0) {
+            Some(state) => {
+                update(state);
+                state.value
+            }
+            None => 0.0,
+        }
+    }
+}
+
+impl Drop for Win32NumberBox {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying number box: edit={:?}, updown={:?}", self.edit, self.updown);
+        unregister_notify_handler(self.updown.0);
+        get_number_states_map().lock().unwrap().remove(&self.edit.0);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.edit).as_bool() {
+                let _ = RemoveWindowSubclass(self.edit, Some(number_edit_subclass_proc), NUMBER_BOX_SUBCLASS_ID);
+                let _ = DestroyWindow(self.edit);
+            }
+            if IsWindow(self.updown).as_bool() {
+                let _ = DestroyWindow(self.updown);
+            }
+        }
+    }
+}
+
+/// Split the total width between the edit control and the up-down buttons
+fn split_width(width: u32) -> (i32, i32) {
+    let updown_width = unsafe { GetSystemMetrics(SM_CXVSCROLL) }.min(width as i32);
+    (width as i32 - updown_width, updown_width)
+}
+
+/// Clamp a value into the range and show it, invoking the callback if asked
+/// and the value changed
+fn commit_value(edit: HWND, value: f64, notify: bool) {
+    // Update the state and copy out what is needed so the lock is released
+    // before touching the control or running the callback
+    let update = {
+        let mut map = get_number_states_map().lock().unwrap();
+        map.get_mut(&edit.0).map(|state| {
+            let clamped = value.clamp(state.min, state.max);
+            let changed = clamped != state.value;
+            state.value = clamped;
+            (state.format(), changed, state.callback, clamped)
+        })
+    };
+    
+    let Some((text, changed, callback, value)) = update else {
+        return;
+    };
+    
+    set_edit_text(edit, &text);
+    
+    if changed && notify {
+        if let Some(callback) = callback {
+            // Safety: The callback owner clears it before it is dropped
+            unsafe { (*callback.0)(value) };
+        }
+    }
+}
+
+/// Check whether a window is the edit control of a number box
+pub(crate) fn is_number_box(hwnd: HWND) -> bool {
+    get_number_states_map().lock().unwrap().contains_key(&hwnd.0)
+}
+
+/// Parse the typed text, reverting to the current value if it is not a number
+fn commit_text(edit: HWND) {
+    let text = unsafe {
+        let len = GetWindowTextLengthW(edit);
+        let mut buffer: Vec<u16> = vec![0; (len + 1) as usize];
+        let copied = GetWindowTextW(edit, &mut buffer);
+        buffer.truncate(copied.max(0) as usize);
+        from_wide_string(&buffer)
+    };
+    
+    let current = get_number_states_map()
+        .lock()
+        .unwrap()
+        .get(&edit.0)
+        .map(|state| state.value);
+    
+    if let Some(current) = current {
+        let value = text.trim().parse::<f64>().ok().filter(|v| v.is_finite()).unwrap_or(current);
+        commit_value(edit, value, true);
+    }
+}
+
+/// Set the text of the edit control
+fn set_edit_text(edit: HWND, text: &str) {
+    let wide_text = to_wide_string(text);
+    unsafe {
+        let _ = SetWindowTextW(edit, windows::core::PCWSTR(wide_text.as_ptr()));
+    }
+}
+
+/// Turn an up-down click into a step of the value
+unsafe fn handle_updown_notify(header: *const NMHDR) -> LRESULT {
+    if (*header).code != UDN_DELTAPOS {
+        return LRESULT(0);
+    }
+    
+    let notification = &*(header as *const NMUPDOWN);
+    let edit = HWND(SendMessageW((*header).hwndFrom, UDM_GETBUDDY, WPARAM(0), LPARAM(0)).0);
+    
+    // Apply any text typed before the click first
+    commit_text(edit);
+    
+    let target = get_number_states_map()
+        .lock()
+        .unwrap()
+        .get(&edit.0)
+        .map(|state| state.value + notification.iDelta as f64 * state.step);
+    if let Some(target) = target {
+        commit_value(edit, target, true);
+    }
+    
+    // Nonzero keeps the up-down control at its own position
+    LRESULT(1)
+}
+
+/// Filter typed characters and commit the text when editing finishes
+unsafe extern "system" fn number_edit_subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    _data: usize,
+) -> LRESULT {
+    // Committing can run the change callback, so guard against its panics
+    catch_callback_panic(|| handle_number_edit_message(hwnd, msg, wparam, lparam)).unwrap_or(LRESULT(0))
+}
+
+/// Handle a message for the number box's edit control
+unsafe fn handle_number_edit_message(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    match msg {
+        WM_CHAR => {
+            let c = char::from_u32(wparam.0 as u32).unwrap_or('\0');
+            if c == '\r' {
+                commit_text(hwnd);
+                return LRESULT(0);
+            }
+            // Allow editing keys (backspace, Ctrl+C/V/X) and number characters
+            if !(c.is_control() || c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')) {
+                return LRESULT(0);
+            }
+        }
+        WM_KILLFOCUS => {
+            commit_text(hwnd);
+        }
+        _ => {}
+    }
+    DefSubclassProc(hwnd, msg, wparam, lparam)
+}
@@ -1,12 +1,24 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::Graphics::Gdi::{
+    HBRUSH, HDC, PAINTSTRUCT, BeginPaint, EndPaint, FillRect, CreateSolidBrush, DeleteObject, InvalidateRect,
+};
+use windows::Win32::UI::Shell::{SetWindowSubclass, GetWindowSubclass, RemoveWindowSubclass, DefSubclassProc};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, Point, Size, traits::PanelBackend};
+use luma_core::{Result, Error, Point, Size, Color, traits::PanelBackend};
 use crate::utils::is_valid_hwnd;
+use crate::widget_backend::child_widget_backend;
+use crate::window::{unregister_callback, unregister_notify_handler};
+use crate::control_colors::{ctl_color, clear_control_colors};
+
+/// Subclass ID for panel message forwarding
+const PANEL_SUBCLASS_ID: usize = 1;
 
 /// Win32 panel (container) backend
 pub struct Win32Panel {
     hwnd: HWND,
+    /// Brush set with `set_background`, also passed to the subclass procedure
+    background: Option<HBRUSH>,
 }
 
 impl PanelBackend for Win32Panel {
@@ -50,37 +62,130 @@ impl PanelBackend for Win32Panel {
                 return Err(Error::WidgetCreation("Panel creation failed".into()));
             }
             
+            // Controls notify their direct parent, so pass their WM_COMMAND and
+            // WM_NOTIFY messages up to the window where callbacks are dispatched
+            let _ = SetWindowSubclass(hwnd, Some(panel_subclass_proc), PANEL_SUBCLASS_ID, 0);
+            
             tracing::debug!("Panel created successfully: HWND={:?}", hwnd);
             
-            Ok(Self { hwnd })
+            Ok(Self { hwnd, background: None })
         }
     }
     
-    fn raw_handle(&self) -> *mut std::ffi::c_void {
-        self.hwnd.0 as *mut std::ffi::c_void
+    fn child_count(&self) -> usize {
+        direct_children(self.hwnd).len()
+    }
+    
+    fn clear_children(&mut self) -> Result<()> {
+        let children = direct_children(self.hwnd);
+        tracing::debug!("Clearing {} children of panel HWND={:?}", children.len(), self.hwnd);
+        
+        for child in children {
+            unregister_callback(child.0);
+            unregister_notify_handler(child.0);
+            clear_control_colors(child);
+            unsafe {
+                DestroyWindow(child)
+                    .map_err(|e| Error::OperationFailed(format!("DestroyWindow failed: {}", e)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+child_widget_backend!(Win32Panel);
+
+impl Win32Panel {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
     }
     
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+    /// Paint the panel with a solid color instead of its parent's background
+    pub fn set_background(&mut self, color: Color) -> Result<()> {
         unsafe {
-            SetWindowPos(
-                self.hwnd,
-                None,
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+            let brush = CreateSolidBrush(COLORREF(color.to_win32_colorref()));
+            if brush.is_invalid() {
+                return Err(Error::Platform("CreateSolidBrush failed".into()));
+            }
+            
+            // Setting the subclass again only replaces its reference data
+            let _ = SetWindowSubclass(self.hwnd, Some(panel_subclass_proc), PANEL_SUBCLASS_ID, brush.0 as usize);
+            if let Some(old) = self.background.replace(brush) {
+                let _ = DeleteObject(old);
+            }
+            let _ = InvalidateRect(self.hwnd, None, true);
         }
         Ok(())
     }
 }
 
+/// Collect the direct children of a window (not grandchildren)
+pub(crate) fn direct_children(parent: HWND) -> Vec<HWND> {
+    unsafe extern "system" fn collect(child: HWND, lparam: LPARAM) -> BOOL {
+        let children = &mut *(lparam.0 as *mut Vec<HWND>);
+        children.push(child);
+        TRUE
+    }
+    
+    let mut children: Vec<HWND> = Vec::new();
+    unsafe {
+        let _ = EnumChildWindows(parent, Some(collect), LPARAM(&mut children as *mut Vec<HWND> as isize));
+        children.retain(|&child| GetParent(child) == parent);
+    }
+    children
+}
+
+/// Check whether a window is a panel
+pub(crate) fn is_panel(hwnd: HWND) -> bool {
+    unsafe { GetWindowSubclass(hwnd, Some(panel_subclass_proc), PANEL_SUBCLASS_ID, None).as_bool() }
+}
+
+/// Forward child control notifications to the panel's parent, and paint
+/// the background brush passed as the reference data, if any
+unsafe extern "system" fn panel_subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    data: usize,
+) -> LRESULT {
+    match msg {
+        WM_COMMAND | WM_NOTIFY => SendMessageW(GetParent(hwnd), msg, wparam, lparam),
+        WM_CTLCOLORSTATIC | WM_CTLCOLOREDIT | WM_CTLCOLORBTN if data != 0 => {
+            // Labels and check boxes in the panel take its background
+            let control = HWND(lparam.0);
+            ctl_color(msg, HDC(wparam.0 as isize), control, Some(HBRUSH(data as isize)))
+                .unwrap_or_else(|| DefSubclassProc(hwnd, msg, wparam, lparam))
+        }
+        // Otherwise the window (or outer panel) picks the colors
+        WM_CTLCOLORSTATIC | WM_CTLCOLOREDIT | WM_CTLCOLORBTN => SendMessageW(GetParent(hwnd), msg, wparam, lparam),
+        WM_ERASEBKGND if data != 0 => LRESULT(1),
+        WM_PAINT if data != 0 => {
+            // The panel has no text, so its background is all there is to draw
+            let mut ps = PAINTSTRUCT::default();
+            let hdc = BeginPaint(hwnd, &mut ps);
+            FillRect(hdc, &ps.rcPaint, HBRUSH(data as isize));
+            EndPaint(hwnd, &ps);
+            LRESULT(0)
+        }
+        _ => DefSubclassProc(hwnd, msg, wparam, lparam),
+    }
+}
+
 impl Drop for Win32Panel {
     fn drop(&mut self) {
         tracing::debug!("Destroying panel: HWND={:?}", self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            let _ = RemoveWindowSubclass(self.hwnd, Some(panel_subclass_proc), PANEL_SUBCLASS_ID);
+            if let Some(brush) = self.background.take() {
+                let _ = DeleteObject(brush);
+            }
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
         }
     }
 }
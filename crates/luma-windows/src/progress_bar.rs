@@ -0,0 +1,167 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Controls::{InitCommonControlsEx, INITCOMMONCONTROLSEX, ICC_PROGRESS_CLASS};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, traits::ProgressBarBackend};
+use crate::utils::is_valid_hwnd;
+use crate::widget_backend::child_widget_backend;
+use std::time::Duration;
+
+// Progress bar styles
+const PBS_MARQUEE: u32 = 0x0008;
+
+// Progress bar messages
+const PBM_SETPOS: u32 = WM_USER + 2;
+const PBM_SETRANGE32: u32 = WM_USER + 6;
+const PBM_GETPOS: u32 = WM_USER + 8;
+const PBM_SETMARQUEE: u32 = WM_USER + 10;
+
+/// Win32 progress bar backend (msctls_progress32 control)
+pub struct Win32ProgressBar {
+    hwnd: HWND,
+    /// Value to restore when leaving marquee mode, which resets the position
+    value: u32,
+}
+
+impl ProgressBarBackend for Win32ProgressBar {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 progress bar: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        unsafe {
+            let icc = INITCOMMONCONTROLSEX {
+                dwSize: std::mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: ICC_PROGRESS_CLASS,
+            };
+            let _ = InitCommonControlsEx(&icc);
+            
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                windows::core::w!("msctls_progress32"),
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE,
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation("ProgressBar creation failed".into()));
+            }
+            
+            tracing::debug!("ProgressBar created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd, value: 0 })
+        }
+    }
+    
+    fn set_range(&mut self, min: u32, max: u32) -> Result<()> {
+        if min > max {
+            return Err(Error::InvalidParameter(format!("Invalid progress range: {}..={}", min, max)));
+        }
+        unsafe {
+            SendMessageW(self.hwnd, PBM_SETRANGE32, WPARAM(min as usize), LPARAM(max as isize));
+        }
+        // The control clamps the position to the new range
+        self.value = self.get_value()?;
+        Ok(())
+    }
+    
+    fn get_value(&self) -> Result<u32> {
+        if self.is_marquee() {
+            return Ok(self.value);
+        }
+        let position = unsafe { SendMessageW(self.hwnd, PBM_GETPOS, WPARAM(0), LPARAM(0)) };
+        Ok(position.0 as u32)
+    }
+    
+    fn set_value(&mut self, value: u32) -> Result<()> {
+        if self.is_marquee() {
+            // Shown once the bar is determinate again
+            self.value = value;
+            return Ok(());
+        }
+        unsafe {
+            SendMessageW(self.hwnd, PBM_SETPOS, WPARAM(value as usize), LPARAM(0));
+        }
+        self.value = self.get_value()?;
+        Ok(())
+    }
+    
+    fn set_indeterminate(&mut self, indeterminate: bool, interval: Duration) -> Result<()> {
+        let marquee = self.is_marquee();
+        unsafe {
+            if indeterminate {
+                if !marquee {
+                    self.value = self.get_value()?;
+                    set_marquee_style(self.hwnd, true);
+                }
+                // Sent again while running, this just changes the speed
+                let interval_ms = interval.as_millis().clamp(1, u32::MAX as u128) as isize;
+                SendMessageW(self.hwnd, PBM_SETMARQUEE, WPARAM(1), LPARAM(interval_ms));
+            } else if marquee {
+                SendMessageW(self.hwnd, PBM_SETMARQUEE, WPARAM(0), LPARAM(0));
+                set_marquee_style(self.hwnd, false);
+                SendMessageW(self.hwnd, PBM_SETPOS, WPARAM(self.value as usize), LPARAM(0));
+            }
+        }
+        Ok(())
+    }
+}
+
+child_widget_backend!(Win32ProgressBar);
+
+impl Win32ProgressBar {
+    /// Get the raw HWND handle
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+    
+    /// Check whether the bar is animating instead of showing its value
+    fn is_marquee(&self) -> bool {
+        let style = unsafe { GetWindowLongPtrW(self.hwnd, GWL_STYLE) } as u32;
+        style & PBS_MARQUEE != 0
+    }
+}
+
+impl Drop for Win32ProgressBar {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying progress bar: HWND={:?}", self.hwnd);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Add or remove the marquee style
+/// 
+/// Removing it is what returns the control to drawing its position; stopping
+/// the animation alone leaves the marquee on screen.
+unsafe fn set_marquee_style(hwnd: HWND, marquee: bool) {
+    let style = GetWindowLongPtrW(hwnd, GWL_STYLE) as u32;
+    let style = if marquee { style | PBS_MARQUEE } else { style & !PBS_MARQUEE };
+    SetWindowLongPtrW(hwnd, GWL_STYLE, style as isize);
+}
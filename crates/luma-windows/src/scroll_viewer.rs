@@ -0,0 +1,359 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::Graphics::Gdi::{HBRUSH, COLOR_WINDOW};
+use windows::Win32::UI::Controls::SetScrollInfo;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use luma_core::{Result, Error, Point, Size, traits::{ScrollViewerBackend, PanelBackend, WidgetBackend}};
+use crate::panel::Win32Panel;
+use crate::utils::{to_wide_string, is_valid_hwnd};
+use crate::widget_backend::{set_control_enabled, set_control_visible};
+use once_cell::sync::OnceCell;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// Window class name for scroll viewers
+pub(crate) const SCROLL_VIEWER_CLASS_NAME: &str = "LumaScrollViewer";
+
+/// Pixels scrolled by the scroll bar arrows and one mouse wheel notch line
+const LINE_SIZE: i32 = 20;
+
+/// Lines scrolled per mouse wheel notch
+const WHEEL_LINES: i32 = 3;
+
+/// Ensure the scroll viewer class is registered (only once)
+static SCROLL_VIEWER_CLASS_REGISTERED: OnceCell<()> = OnceCell::new();
+
+/// Per-viewer scroll state consulted by the window procedure
+struct ScrollState {
+    canvas: isize,
+    content: Size,
+    offset: Point,
+}
+
+/// Global map of scroll viewer HWND to its scroll state
+static SCROLL_STATES: OnceCell<Mutex<HashMap<isize, ScrollState>>> = OnceCell::new();
+
+fn get_scroll_states_map() -> &'static Mutex<HashMap<isize, ScrollState>> {
+    SCROLL_STATES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Win32 scroll viewer backend
+///
+/// Child widgets live on a canvas window sized to the content; scrolling
+/// moves the canvas inside the viewer, so children keep their content
+/// coordinates.
+pub struct Win32ScrollViewer {
+    hwnd: HWND,
+    canvas: Win32Panel,
+}
+
+impl ScrollViewerBackend for Win32ScrollViewer {
+    fn new(
+        parent_hwnd: *mut std::ffi::c_void,
+        pos: Point,
+        size: Size,
+    ) -> Result<Self> {
+        tracing::debug!(
+            "Creating Win32 scroll viewer: pos=({}, {}), size={}x{}",
+            pos.x,
+            pos.y,
+            size.width,
+            size.height
+        );
+        
+        SCROLL_VIEWER_CLASS_REGISTERED.get_or_try_init(register_scroll_viewer_class)?;
+        
+        unsafe {
+            let hinstance = GetModuleHandleW(None).map_err(|e| {
+                Error::Platform(format!("Failed to get module handle: {}", e))
+            })?;
+            
+            let parent = HWND(parent_hwnd as isize);
+            let class_name = to_wide_string(SCROLL_VIEWER_CLASS_NAME);
+            
+            let hwnd = CreateWindowExW(
+                WS_EX_CONTROLPARENT,
+                windows::core::PCWSTR(class_name.as_ptr()),
+                windows::core::PCWSTR::null(),
+                WS_CHILD | WS_VISIBLE | WS_CLIPCHILDREN | WS_VSCROLL | WS_HSCROLL,
+                pos.x,
+                pos.y,
+                size.width as i32,
+                size.height as i32,
+                parent,
+                None,
+                hinstance,
+                None,
+            );
+            
+            if !is_valid_hwnd(hwnd) {
+                return Err(Error::WidgetCreation("Scroll viewer creation failed".into()));
+            }
+            
+            let canvas = Win32Panel::new(hwnd.0 as *mut std::ffi::c_void, Point::new(0, 0), size)?;
+            
+            get_scroll_states_map().lock().unwrap().insert(hwnd.0, ScrollState {
+                canvas: canvas.raw_handle() as isize,
+                content: size,
+                offset: Point::new(0, 0),
+            });
+            apply_scroll(hwnd);
+            
+            tracing::debug!("Scroll viewer created successfully: HWND={:?}", hwnd);
+            
+            Ok(Self { hwnd, canvas })
+        }
+    }
+    
+    fn viewport_size(&self) -> Result<Size> {
+        client_size(self.hwnd)
+            .ok_or_else(|| Error::OperationFailed("GetClientRect failed".into()))
+    }
+    
+    fn set_content_size(&mut self, size: Size) -> Result<()> {
+        if let Some(state) = get_scroll_states_map().lock().unwrap().get_mut(&self.hwnd.0) {
+            state.content = size;
+        }
+        apply_scroll(self.hwnd);
+        Ok(())
+    }
+    
+    fn scroll_offset(&self) -> Point {
+        get_scroll_states_map()
+            .lock()
+            .unwrap()
+            .get(&self.hwnd.0)
+            .map(|state| state.offset)
+            .unwrap_or_default()
+    }
+    
+    fn scroll_to(&mut self, offset: Point) -> Result<()> {
+        if let Some(state) = get_scroll_states_map().lock().unwrap().get_mut(&self.hwnd.0) {
+            state.offset = offset;
+        }
+        apply_scroll(self.hwnd);
+        Ok(())
+    }
+}
+
+impl WidgetBackend for Win32ScrollViewer {
+    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+        // Not deferred: relayout reads the new viewport size straight away
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                HWND::default(),
+                x,
+                y,
+                width as i32,
+                height as i32,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+        }
+        Ok(())
+    }
+    
+    fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+        set_control_enabled(self.hwnd, enabled);
+        Ok(())
+    }
+    
+    fn set_visible(&mut self, visible: bool) -> Result<()> {
+        set_control_visible(self.hwnd, visible);
+        Ok(())
+    }
+    
+    // Children are created on the scrolled canvas
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        self.canvas.raw_handle()
+    }
+}
+
+impl Win32ScrollViewer {
+    /// Get the HWND of the viewport (not the scrolled canvas)
+    pub fn hwnd(&self) -> HWND {
+        self.hwnd
+    }
+}
+
+impl Drop for Win32ScrollViewer {
+    fn drop(&mut self) {
+        tracing::debug!("Destroying scroll viewer: HWND={:?}", self.hwnd);
+        get_scroll_states_map().lock().unwrap().remove(&self.hwnd.0);
+        unsafe {
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Register the scroll viewer window class
+fn register_scroll_viewer_class() -> Result<()> {
+    tracing::debug!("Registering window class: {}", SCROLL_VIEWER_CLASS_NAME);
+    
+    unsafe {
+        let hinstance = GetModuleHandleW(None).map_err(|e| {
+            Error::Platform(format!("Failed to get module handle: {}", e))
+        })?;
+        
+        let class_name = to_wide_string(SCROLL_VIEWER_CLASS_NAME);
+        
+        let wc = WNDCLASSW {
+            style: CS_HREDRAW | CS_VREDRAW,
+            lpfnWndProc: Some(scroll_viewer_proc),
+            cbClsExtra: 0,
+            cbWndExtra: 0,
+            hInstance: hinstance.into(),
+            hIcon: HICON::default(),
+            hCursor: LoadCursorW(None, IDC_ARROW).ok().unwrap_or_default(),
+            hbrBackground: HBRUSH((COLOR_WINDOW.0 + 1) as isize),
+            lpszMenuName: windows::core::PCWSTR::null(),
+            lpszClassName: windows::core::PCWSTR(class_name.as_ptr()),
+        };
+        
+        if RegisterClassW(&wc) == 0 {
+            return Err(Error::Platform("RegisterClassW failed".into()));
+        }
+        Ok(())
+    }
+}
+
+/// Get the client area size of a window
+fn client_size(hwnd: HWND) -> Option<Size> {
+    let mut rect = RECT::default();
+    unsafe { GetClientRect(hwnd, &mut rect).ok()? };
+    Some(Size::new((rect.right - rect.left) as u32, (rect.bottom - rect.top) as u32))
+}
+
+/// Clamp the offset to the content, update the scroll bars, and move the canvas
+fn apply_scroll(hwnd: HWND) {
+    let Some(viewport) = client_size(hwnd) else {
+        return;
+    };
+    
+    // Copy the state out so no lock is held while Win32 sends WM_SIZE back to us
+    let (canvas, content, offset) = {
+        let mut map = get_scroll_states_map().lock().unwrap();
+        let Some(state) = map.get_mut(&hwnd.0) else {
+            return;
+        };
+        let max_x = content_overflow(state.content.width, viewport.width);
+        let max_y = content_overflow(state.content.height, viewport.height);
+        state.offset = Point::new(state.offset.x.clamp(0, max_x), state.offset.y.clamp(0, max_y));
+        (HWND(state.canvas), state.content, state.offset)
+    };
+    
+    unsafe {
+        set_scroll_bar(hwnd, SB_HORZ, content.width, viewport.width, offset.x);
+        set_scroll_bar(hwnd, SB_VERT, content.height, viewport.height, offset.y);
+        let _ = SetWindowPos(
+            canvas,
+            HWND::default(),
+            -offset.x,
+            -offset.y,
+            content.width as i32,
+            content.height as i32,
+            SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+/// How far content of one length can scroll inside a viewport of another
+fn content_overflow(content: u32, viewport: u32) -> i32 {
+    content.saturating_sub(viewport) as i32
+}
+
+/// Set the range, page, and position of one scroll bar
+///
+/// Windows hides the bar when the page covers the whole range.
+unsafe fn set_scroll_bar(hwnd: HWND, bar: SCROLLBAR_CONSTANTS, content: u32, viewport: u32, pos: i32) {
+    let info = SCROLLINFO {
+        cbSize: std::mem::size_of::<SCROLLINFO>() as u32,
+        fMask: SIF_RANGE | SIF_PAGE | SIF_POS,
+        nMin: 0,
+        nMax: (content as i32 - 1).max(0),
+        nPage: viewport,
+        nPos: pos,
+        nTrackPos: 0,
+    };
+    SetScrollInfo(hwnd, bar, &info, TRUE);
+}
+
+/// Work out the new offset along one axis for a WM_VSCROLL/WM_HSCROLL request
+fn scroll_request_target(hwnd: HWND, bar: SCROLLBAR_CONSTANTS, request: SCROLLBAR_COMMAND, current: i32, page: i32) -> i32 {
+    match request {
+        SB_LINEUP => current - LINE_SIZE,
+        SB_LINEDOWN => current + LINE_SIZE,
+        SB_PAGEUP => current - page,
+        SB_PAGEDOWN => current + page,
+        SB_TOP => 0,
+        SB_BOTTOM => i32::MAX,
+        SB_THUMBTRACK | SB_THUMBPOSITION => {
+            // The position in WPARAM is only 16 bits; ask for the full value
+            let mut info = SCROLLINFO {
+                cbSize: std::mem::size_of::<SCROLLINFO>() as u32,
+                fMask: SIF_TRACKPOS,
+                ..Default::default()
+            };
+            match unsafe { GetScrollInfo(hwnd, bar, &mut info) } {
+                Ok(()) => info.nTrackPos,
+                Err(_) => current,
+            }
+        }
+        _ => current,
+    }
+}
+
+/// Move the content of a viewer along one axis
+fn scroll_axis(hwnd: HWND, vertical: bool, target: impl FnOnce(i32, i32) -> i32) {
+    let Some(viewport) = client_size(hwnd) else {
+        return;
+    };
+    
+    if let Some(state) = get_scroll_states_map().lock().unwrap().get_mut(&hwnd.0) {
+        if vertical {
+            state.offset.y = target(state.offset.y, viewport.height as i32);
+        } else {
+            state.offset.x = target(state.offset.x, viewport.width as i32);
+        }
+    }
+    apply_scroll(hwnd);
+}
+
+/// Window procedure for scroll viewers
+unsafe extern "system" fn scroll_viewer_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_VSCROLL | WM_HSCROLL => {
+            let vertical = msg == WM_VSCROLL;
+            let bar = if vertical { SB_VERT } else { SB_HORZ };
+            let request = SCROLLBAR_COMMAND((wparam.0 & 0xFFFF) as i32);
+            scroll_axis(hwnd, vertical, |current, page| {
+                scroll_request_target(hwnd, bar, request, current, page)
+            });
+            LRESULT(0)
+        }
+        WM_MOUSEWHEEL => {
+            let delta = ((wparam.0 >> 16) & 0xFFFF) as u16 as i16 as i32;
+            let lines = delta * WHEEL_LINES / WHEEL_DELTA as i32;
+            scroll_axis(hwnd, true, |current, _| current - lines * LINE_SIZE);
+            LRESULT(0)
+        }
+        WM_SIZE => {
+            // Keep the offset valid and the thumbs sized to the new viewport
+            apply_scroll(hwnd);
+            LRESULT(0)
+        }
+        WM_COMMAND | WM_NOTIFY => {
+            // Pass child notifications up to the window where callbacks are dispatched
+            SendMessageW(GetParent(hwnd), msg, wparam, lparam)
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
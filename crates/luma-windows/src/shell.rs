@@ -0,0 +1,53 @@
+use windows::core::{w, PCWSTR};
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::Shell::ShellExecuteW;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+use luma_core::{Result, Error};
+use crate::utils::to_wide_string;
+
+/// Open a file, folder or URL with its default handler
+/// 
+/// Uses the shell's "open" verb, so a URL opens in the default browser and a
+/// folder in Explorer.
+pub fn shell_open(target: &str) -> Result<()> {
+    tracing::debug!("Opening '{}' with the shell", target);
+    
+    let wide_target = to_wide_string(target);
+    let result = unsafe {
+        ShellExecuteW(
+            HWND(0),
+            w!("open"),
+            PCWSTR(wide_target.as_ptr()),
+            PCWSTR::null(),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+    
+    // Values above 32 mean success; the rest are error codes
+    if result.0 <= 32 {
+        return Err(Error::OperationFailed(format!(
+            "Failed to open '{}': {}",
+            target,
+            shell_error_message(result.0)
+        )));
+    }
+    Ok(())
+}
+
+/// Describe an error code returned by ShellExecuteW
+fn shell_error_message(code: isize) -> &'static str {
+    match code {
+        0 | 8 => "out of memory or resources",
+        2 => "file not found",
+        3 => "path not found",
+        5 => "access denied",
+        11 => "invalid executable",
+        26 => "sharing violation",
+        27 => "incomplete file association",
+        28..=30 => "DDE transaction failed",
+        31 => "no application is associated with this file type",
+        32 => "DLL not found",
+        _ => "unknown error",
+    }
+}
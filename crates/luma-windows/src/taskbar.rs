@@ -0,0 +1,70 @@
+use windows::Win32::Foundation::HWND;
+use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED};
+use windows::Win32::UI::Shell::{
+    ITaskbarList3, TaskbarList, TBPFLAG, TBPF_ERROR, TBPF_INDETERMINATE, TBPF_NOPROGRESS, TBPF_NORMAL, TBPF_PAUSED,
+};
+use luma_core::TaskbarProgressState;
+
+thread_local! {
+    /// The taskbar interface, created on first use; `None` if it is unavailable
+    static TASKBAR: Option<ITaskbarList3> = create_taskbar_list();
+}
+
+/// Create the shell's taskbar interface
+fn create_taskbar_list() -> Option<ITaskbarList3> {
+    unsafe {
+        // Fails harmlessly if COM is already initialized on this thread
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let taskbar: ITaskbarList3 = CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| tracing::debug!("Taskbar progress unavailable: {}", e))
+            .ok()?;
+        taskbar.HrInit().map_err(|e| tracing::debug!("Taskbar progress unavailable: {}", e)).ok()?;
+        Some(taskbar)
+    }
+}
+
+/// Show progress on a window's taskbar button, or `None` to remove it
+/// 
+/// Does nothing where the taskbar interface can't be created.
+pub fn set_taskbar_progress(hwnd: HWND, progress: Option<(u64, u64)>) {
+    TASKBAR.with(|taskbar| {
+        let Some(taskbar) = taskbar else {
+            return;
+        };
+        let result = unsafe {
+            match progress {
+                Some((completed, total)) => taskbar.SetProgressValue(hwnd, completed.min(total), total),
+                None => taskbar.SetProgressState(hwnd, TBPF_NOPROGRESS),
+            }
+        };
+        // Fails until the taskbar button exists, shortly after the window is shown
+        if let Err(e) = result {
+            tracing::debug!("Failed to set taskbar progress: {}", e);
+        }
+    });
+}
+
+/// Change how a window's taskbar button shows progress
+/// 
+/// Does nothing where the taskbar interface can't be created.
+pub fn set_taskbar_state(hwnd: HWND, state: TaskbarProgressState) {
+    TASKBAR.with(|taskbar| {
+        let Some(taskbar) = taskbar else {
+            return;
+        };
+        if let Err(e) = unsafe { taskbar.SetProgressState(hwnd, progress_flag(state)) } {
+            tracing::debug!("Failed to set taskbar progress state: {}", e);
+        }
+    });
+}
+
+/// Convert a progress state to its taskbar flag
+fn progress_flag(state: TaskbarProgressState) -> TBPFLAG {
+    match state {
+        TaskbarProgressState::NoProgress => TBPF_NOPROGRESS,
+        TaskbarProgressState::Indeterminate => TBPF_INDETERMINATE,
+        TaskbarProgressState::Normal => TBPF_NORMAL,
+        TaskbarProgressState::Error => TBPF_ERROR,
+        TaskbarProgressState::Paused => TBPF_PAUSED,
+    }
+}
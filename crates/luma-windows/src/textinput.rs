@@ -1,20 +1,48 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Input::KeyboardAndMouse::GetFocus;
+use windows::Win32::UI::Shell::{SetWindowSubclass, RemoveWindowSubclass, DefSubclassProc};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, Point, Size, traits::TextInputBackend};
-use crate::utils::{to_wide_string, from_wide_string, is_valid_hwnd};
+use luma_core::{Result, Error, Point, Size, Color, traits::TextInputBackend};
+use luma_core::panic_guard::catch_callback_panic;
+use crate::utils::{from_wide_string, to_wide_string, is_valid_hwnd, window_text, set_window_text, clipboard_text};
+use crate::window::{register_notification_callback, unregister_callback};
+use crate::widget_backend::child_widget_backend;
+use crate::control_colors::{set_text_color, set_background_color, clear_control_colors};
 
 // Edit control styles
 const ES_LEFT: u32 = 0x0000;
 const ES_AUTOHSCROLL: u32 = 0x0080;
+const ES_NOHIDESEL: u32 = 0x0100;
 const ES_READONLY: u32 = 0x0800;
 
 // Edit control messages
+const EM_GETSEL: u32 = 0x00B0;
+const EM_SETSEL: u32 = 0x00B1;
+const EM_SCROLLCARET: u32 = 0x00B7;
+const EM_REPLACESEL: u32 = 0x00C2;
+const EM_SETLIMITTEXT: u32 = 0x00C5;
+const EM_CANUNDO: u32 = 0x00C6;
+const EM_UNDO: u32 = 0x00C7;
 const EM_SETREADONLY: u32 = 0x00CF;
 
+// Edit control messages from the common controls
+const EM_SETCUEBANNER: u32 = 0x1501;
+
+// Edit control notifications
+const EN_CHANGE: u32 = 0x0300;
+
+/// Subclass ID for character filtering
+const TEXT_INPUT_SUBCLASS_ID: usize = 1;
+
+/// Test deciding which characters the user may enter
+type CharFilter = dyn Fn(char) -> bool;
+
 /// Win32 text input backend (EDIT control)
 pub struct Win32TextInput {
     hwnd: HWND,
+    /// Boxed twice so the subclass data can be a thin pointer to it
+    char_filter: Option<Box<Box<CharFilter>>>,
 }
 
 impl TextInputBackend for Win32TextInput {
@@ -41,8 +69,9 @@ impl TextInputBackend for Win32TextInput {
             let parent = HWND(parent_hwnd as isize);
             
             // EDIT control with ES_LEFT (left-aligned), ES_AUTOHSCROLL (auto-scroll)
+            // and ES_NOHIDESEL, so a selection made from a button stays visible
             let mut style = WS_CHILD | WS_VISIBLE | WS_TABSTOP | WS_BORDER | 
-                            WINDOW_STYLE(ES_LEFT as u32 | ES_AUTOHSCROLL as u32);
+                            WINDOW_STYLE(ES_LEFT as u32 | ES_AUTOHSCROLL as u32 | ES_NOHIDESEL);
             
             if read_only {
                 style |= WINDOW_STYLE(ES_READONLY as u32);
@@ -69,7 +98,7 @@ impl TextInputBackend for Win32TextInput {
             
             tracing::debug!("TextInput created successfully: HWND={:?}", hwnd);
             
-            Ok(Self { hwnd })
+            Ok(Self { hwnd, char_filter: None })
         }
     }
     
@@ -93,12 +122,7 @@ impl TextInputBackend for Win32TextInput {
     }
     
     fn set_text(&mut self, text: &str) -> Result<()> {
-        unsafe {
-            let wide_text = to_wide_string(text);
-            SetWindowTextW(self.hwnd, windows::core::PCWSTR(wide_text.as_ptr()))
-                .map_err(|e| Error::OperationFailed(format!("SetWindowTextW failed: {}", e)))?;
-        }
-        Ok(())
+        set_window_text(self.hwnd, text)
     }
     
     fn set_read_only(&mut self, read_only: bool) -> Result<()> {
@@ -113,34 +137,248 @@ impl TextInputBackend for Win32TextInput {
         Ok(())
     }
     
-    fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+    fn set_max_length(&mut self, max_length: Option<u32>) -> Result<()> {
+        // The control reads zero as the largest limit it has
+        if max_length == Some(0) {
+            return Err(Error::InvalidParameter("Maximum text length must be at least 1".into()));
+        }
+        unsafe {
+            SendMessageW(self.hwnd, EM_SETLIMITTEXT, WPARAM(max_length.unwrap_or(0) as usize), LPARAM(0));
+        }
+        Ok(())
+    }
+    
+    fn set_placeholder(&mut self, text: &str, show_when_focused: bool) -> Result<()> {
+        set_cue_banner(self.hwnd, text, show_when_focused)
+    }
+    
+    fn get_selection(&self) -> Result<(usize, usize)> {
+        let mut start = 0u32;
+        let mut end = 0u32;
+        unsafe {
+            // The packed return value is limited to 16 bits, so read through the pointers
+            SendMessageW(
+                self.hwnd,
+                EM_GETSEL,
+                WPARAM(&mut start as *mut u32 as usize),
+                LPARAM(&mut end as *mut u32 as isize),
+            );
+        }
+        let text = window_text(self.hwnd);
+        Ok((char_offset(&text, start as usize), char_offset(&text, end as usize)))
+    }
+    
+    fn set_selection(&mut self, start: usize, end: usize) -> Result<()> {
+        let text = window_text(self.hwnd);
         unsafe {
-            SetWindowPos(
+            SendMessageW(
                 self.hwnd,
-                HWND::default(),
-                x,
-                y,
-                width as i32,
-                height as i32,
-                SWP_NOZORDER | SWP_NOACTIVATE,
-            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+                EM_SETSEL,
+                WPARAM(utf16_offset(&text, start)),
+                LPARAM(utf16_offset(&text, end) as isize),
+            );
+            SendMessageW(self.hwnd, EM_SCROLLCARET, WPARAM(0), LPARAM(0));
+        }
+        Ok(())
+    }
+    
+    fn replace_selection(&mut self, text: &str) -> Result<()> {
+        let wide = to_wide_string(text);
+        unsafe {
+            // A non-zero wParam lets the user undo the insertion
+            SendMessageW(self.hwnd, EM_REPLACESEL, WPARAM(1), LPARAM(wide.as_ptr() as isize));
+        }
+        Ok(())
+    }
+    
+    fn can_undo(&self) -> Result<bool> {
+        let result = unsafe { SendMessageW(self.hwnd, EM_CANUNDO, WPARAM(0), LPARAM(0)) };
+        Ok(result.0 != 0)
+    }
+    
+    fn undo(&mut self) -> Result<()> {
+        if !self.can_undo()? {
+            return Ok(());
+        }
+        let result = unsafe { SendMessageW(self.hwnd, EM_UNDO, WPARAM(0), LPARAM(0)) };
+        if result.0 == 0 {
+            return Err(Error::OperationFailed("EM_UNDO failed".into()));
+        }
+        Ok(())
+    }
+    
+    fn cut(&mut self) -> Result<()> {
+        unsafe {
+            SendMessageW(self.hwnd, WM_CUT, WPARAM(0), LPARAM(0));
+        }
+        Ok(())
+    }
+    
+    fn copy(&self) -> Result<()> {
+        unsafe {
+            SendMessageW(self.hwnd, WM_COPY, WPARAM(0), LPARAM(0));
+        }
+        Ok(())
+    }
+    
+    fn paste(&mut self) -> Result<()> {
+        unsafe {
+            SendMessageW(self.hwnd, WM_PASTE, WPARAM(0), LPARAM(0));
         }
         Ok(())
     }
 }
 
+child_widget_backend!(Win32TextInput);
+
 impl Win32TextInput {
     /// Get the raw HWND handle
     pub fn hwnd(&self) -> HWND {
         self.hwnd
     }
+    
+    /// Set (or clear) the test characters must pass to be typed or pasted
+    /// 
+    /// Control characters such as backspace are always allowed, and text set
+    /// with `set_text` is not filtered.
+    pub fn set_char_filter(&mut self, filter: Option<Box<CharFilter>>) {
+        unsafe {
+            let _ = RemoveWindowSubclass(self.hwnd, Some(char_filter_subclass_proc), TEXT_INPUT_SUBCLASS_ID);
+        }
+        self.char_filter = filter.map(Box::new);
+        if let Some(filter) = &self.char_filter {
+            let data = filter.as_ref() as *const Box<CharFilter> as usize;
+            unsafe {
+                let _ = SetWindowSubclass(self.hwnd, Some(char_filter_subclass_proc), TEXT_INPUT_SUBCLASS_ID, data);
+            }
+        }
+    }
+    
+    /// Check whether the control has the keyboard focus
+    pub fn has_focus(&self) -> bool {
+        unsafe { GetFocus() == self.hwnd }
+    }
+    
+    /// Set (or clear) the text color
+    pub fn set_text_color(&mut self, color: Option<Color>) {
+        set_text_color(self.hwnd, color);
+    }
+    
+    /// Set (or clear) the background color
+    pub fn set_background(&mut self, color: Option<Color>) -> Result<()> {
+        set_background_color(self.hwnd, color)
+    }
+    
+    /// Register (or clear) the callback invoked when the text changes
+    /// 
+    /// The edit control reports changes made with `set_text` as well as typing.
+    /// The caller owns the callback and must clear it before the callback is dropped.
+    pub fn set_change_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        match callback {
+            Some(ptr) => register_notification_callback(self.hwnd.0, EN_CHANGE, ptr),
+            None => unregister_callback(self.hwnd.0),
+        }
+    }
+    
+    /// Get a function that reads the current text
+    pub fn text_reader(&self) -> impl Fn() -> Result<String> + 'static {
+        let hwnd = self.hwnd;
+        move || Ok(window_text(hwnd))
+    }
+    
+    /// Get a function that sets the text
+    pub fn text_writer(&self) -> impl Fn(&str) -> Result<()> + 'static {
+        let hwnd = self.hwnd;
+        move |text| set_window_text(hwnd, text)
+    }
+    
+    /// Get a function that sets the placeholder
+    pub fn placeholder_writer(&self, show_when_focused: bool) -> impl Fn(&str) -> Result<()> + 'static {
+        let hwnd = self.hwnd;
+        move |text| set_cue_banner(hwnd, text, show_when_focused)
+    }
 }
 
 impl Drop for Win32TextInput {
     fn drop(&mut self) {
         tracing::debug!("Destroying text input: HWND={:?}", self.hwnd);
+        clear_control_colors(self.hwnd);
         unsafe {
-            let _ = DestroyWindow(self.hwnd);
+            // Already gone if a parent panel destroyed its children
+            if IsWindow(self.hwnd).as_bool() {
+                if self.char_filter.is_some() {
+                    let _ = RemoveWindowSubclass(self.hwnd, Some(char_filter_subclass_proc), TEXT_INPUT_SUBCLASS_ID);
+                }
+                let _ = DestroyWindow(self.hwnd);
+            }
+        }
+    }
+}
+
+/// Set the cue banner an edit control shows while empty
+fn set_cue_banner(hwnd: HWND, text: &str, show_when_focused: bool) -> Result<()> {
+    let wide = to_wide_string(text);
+    let result = unsafe {
+        SendMessageW(hwnd, EM_SETCUEBANNER, WPARAM(show_when_focused as usize), LPARAM(wide.as_ptr() as isize))
+    };
+    // Fails without version 6 of the common controls
+    if result.0 == 0 {
+        return Err(Error::OperationFailed("EM_SETCUEBANNER failed".into()));
+    }
+    Ok(())
+}
+
+/// Drop typed characters the filter rejects, and filter pasted text
+unsafe extern "system" fn char_filter_subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    data: usize,
+) -> LRESULT {
+    // Safety: The subclass is removed before the filter is dropped
+    let filter = &*(data as *const Box<CharFilter>);
+    match msg {
+        WM_CHAR => {
+            // Surrogate halves are not chars on their own and are let through
+            let Some(c) = char::from_u32(wparam.0 as u32) else {
+                return DefSubclassProc(hwnd, msg, wparam, lparam);
+            };
+            let allowed = c.is_control() || catch_callback_panic(|| filter(c)).unwrap_or(false);
+            if !allowed {
+                return LRESULT(0);
+            }
         }
+        WM_PASTE => {
+            let Some(text) = clipboard_text(hwnd) else {
+                return LRESULT(0);
+            };
+            // A single-line edit control pastes only the first line
+            let first_line = text.lines().next().unwrap_or_default();
+            let filtered: String = catch_callback_panic(|| first_line.chars().filter(|&c| filter(c)).collect())
+                .unwrap_or_default();
+            let wide = to_wide_string(&filtered);
+            SendMessageW(hwnd, EM_REPLACESEL, WPARAM(1), LPARAM(wide.as_ptr() as isize));
+            return LRESULT(0);
+        }
+        _ => {}
     }
+    DefSubclassProc(hwnd, msg, wparam, lparam)
+}
+
+/// Convert a character offset into the text to UTF-16 units, as edit controls count
+fn utf16_offset(text: &str, chars: usize) -> usize {
+    text.chars().take(chars).map(char::len_utf16).sum()
+}
+
+/// Convert an offset in UTF-16 units to a character offset into the text
+fn char_offset(text: &str, units: usize) -> usize {
+    let mut seen = 0;
+    text.chars()
+        .take_while(|c| {
+            seen += c.len_utf16();
+            seen <= units
+        })
+        .count()
 }
@@ -0,0 +1,33 @@
+use windows::core::w;
+use windows::Win32::System::Registry::*;
+use luma_core::Theme;
+
+/// Read the app theme (light/dark) currently selected in Windows settings
+/// 
+/// Falls back to `Theme::Light` when the setting is missing, as on
+/// Windows versions without a dark mode.
+pub fn system_theme() -> Theme {
+    let mut value: u32 = 1;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize"),
+            w!("AppsUseLightTheme"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut value as *mut u32 as *mut std::ffi::c_void),
+            Some(&mut size),
+        )
+    };
+    
+    match result {
+        Ok(()) if value == 0 => Theme::Dark,
+        Ok(()) => Theme::Light,
+        Err(e) => {
+            tracing::debug!("AppsUseLightTheme not readable, assuming light theme: {}", e);
+            Theme::Light
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::Win32::UI::Shell::*;
+use luma_core::{Result, Error, Icon};
+use crate::utils::to_wide_string;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+/// Message sent to the owner window for tray icon mouse events
+pub(crate) const WM_TRAYICON: u32 = WM_APP + 1;
+
+/// Next tray icon id (ids only need to be unique per owner window)
+static NEXT_TRAY_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Wrapper to make callback pointer Send
+struct TrayCallbackPtr(*mut dyn FnMut());
+unsafe impl Send for TrayCallbackPtr {}
+
+/// Global map of tray icon id to click callback for handling WM_TRAYICON
+static TRAY_CALLBACKS: OnceCell<Mutex<HashMap<u32, TrayCallbackPtr>>> = OnceCell::new();
+
+fn get_tray_callbacks_map() -> &'static Mutex<HashMap<u32, TrayCallbackPtr>> {
+    TRAY_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Handle a WM_TRAYICON message from the window procedure
+/// 
+/// # Safety
+/// Must only be called from the window procedure, on the UI thread
+pub(crate) unsafe fn handle_tray_message(wparam: WPARAM, lparam: LPARAM) {
+    let id = wparam.0 as u32;
+    let event = (lparam.0 & 0xFFFF) as u32;
+    
+    if event != WM_LBUTTONUP {
+        return;
+    }
+    
+    // Copy the pointer out so the callback runs without the lock held
+    let callback = get_tray_callbacks_map()
+        .lock()
+        .ok()
+        .and_then(|map| map.get(&id).map(|ptr| ptr.0));
+    
+    if let Some(callback) = callback {
+        tracing::debug!("Tray icon {} clicked", id);
+        (*callback)();
+    }
+}
+
+/// Win32 notification area (system tray) icon
+pub struct Win32TrayIcon {
+    owner: HWND,
+    id: u32,
+    hicon: HICON,
+    /// Icon was loaded from a file and must be destroyed (stock icons are shared)
+    owns_icon: bool,
+}
+
+impl Win32TrayIcon {
+    /// Add an icon to the notification area
+    /// 
+    /// Mouse events are delivered to `owner_hwnd`, whose window procedure
+    /// forwards clicks to the registered callback.
+    pub fn new(owner_hwnd: *mut std::ffi::c_void, icon: &Icon, tooltip: &str) -> Result<Self> {
+        tracing::debug!("Creating tray icon: tooltip='{}'", tooltip);
+        
+        let (hicon, owns_icon) = load_icon(icon)?;
+        let tray = Self {
+            owner: HWND(owner_hwnd as isize),
+            id: NEXT_TRAY_ID.fetch_add(1, Ordering::Relaxed),
+            hicon,
+            owns_icon,
+        };
+        
+        let mut data = tray.notify_data();
+        data.uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP;
+        data.uCallbackMessage = WM_TRAYICON;
+        data.hIcon = hicon;
+        copy_wide(&mut data.szTip, tooltip);
+        
+        unsafe {
+            if !Shell_NotifyIconW(NIM_ADD, &data).as_bool() {
+                return Err(Error::WidgetCreation("Shell_NotifyIconW(NIM_ADD) failed".into()));
+            }
+        }
+        
+        Ok(tray)
+    }
+    
+    /// Set the tooltip shown when hovering the icon
+    pub fn set_tooltip(&mut self, tooltip: &str) -> Result<()> {
+        let mut data = self.notify_data();
+        data.uFlags = NIF_TIP;
+        copy_wide(&mut data.szTip, tooltip);
+        self.modify(&data)
+    }
+    
+    /// Show a balloon notification next to the icon
+    pub fn show_balloon(&mut self, title: &str, text: &str) -> Result<()> {
+        let mut data = self.notify_data();
+        data.uFlags = NIF_INFO;
+        data.dwInfoFlags = NIIF_INFO;
+        copy_wide(&mut data.szInfoTitle, title);
+        copy_wide(&mut data.szInfo, text);
+        self.modify(&data)
+    }
+    
+    /// Set the callback invoked when the icon is clicked
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// icon is dropped.
+    pub fn set_click_callback_ptr(&mut self, callback: Option<*mut dyn FnMut()>) {
+        let mut map = get_tray_callbacks_map().lock().unwrap();
+        match callback {
+            Some(ptr) => {
+                map.insert(self.id, TrayCallbackPtr(ptr));
+            }
+            None => {
+                map.remove(&self.id);
+            }
+        }
+    }
+    
+    /// Notification data identifying this icon
+    fn notify_data(&self) -> NOTIFYICONDATAW {
+        NOTIFYICONDATAW {
+            cbSize: std::mem::size_of::<NOTIFYICONDATAW>() as u32,
+            hWnd: self.owner,
+            uID: self.id,
+            ..Default::default()
+        }
+    }
+    
+    fn modify(&self, data: &NOTIFYICONDATAW) -> Result<()> {
+        unsafe {
+            if !Shell_NotifyIconW(NIM_MODIFY, data).as_bool() {
+                return Err(Error::OperationFailed("Shell_NotifyIconW(NIM_MODIFY) failed".into()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Win32TrayIcon {
+    fn drop(&mut self) {
+        self.set_click_callback_ptr(None);
+        
+        let data = self.notify_data();
+        unsafe {
+            let _ = Shell_NotifyIconW(NIM_DELETE, &data);
+            if self.owns_icon {
+                let _ = DestroyIcon(self.hicon);
+            }
+        }
+    }
+}
+
+/// Load an HICON, returning whether the caller owns (must destroy) it
+fn load_icon(icon: &Icon) -> Result<(HICON, bool)> {
+    unsafe {
+        let stock = match icon {
+            Icon::Application => IDI_APPLICATION,
+            Icon::Information => IDI_INFORMATION,
+            Icon::Warning => IDI_WARNING,
+            Icon::Error => IDI_ERROR,
+            Icon::File(path) => {
+                let wide_path = to_wide_string(&path.to_string_lossy());
+                let handle = LoadImageW(
+                    None,
+                    windows::core::PCWSTR(wide_path.as_ptr()),
+                    IMAGE_ICON,
+                    0,
+                    0,
+                    LR_LOADFROMFILE | LR_DEFAULTSIZE,
+                ).map_err(|e| Error::InvalidParameter(format!("Failed to load icon '{}': {}", path.display(), e)))?;
+                return Ok((HICON(handle.0), true));
+            }
+        };
+        
+        let hicon = LoadIconW(None, stock)
+            .map_err(|e| Error::Platform(format!("LoadIconW failed: {}", e)))?;
+        Ok((hicon, false))
+    }
+}
+
+/// Copy a string into a fixed-size, null-terminated UTF-16 buffer, truncating if needed
+fn copy_wide(buffer: &mut [u16], s: &str) {
+    let wide: Vec<u16> = s.encode_utf16().take(buffer.len() - 1).collect();
+    buffer[..wide.len()].copy_from_slice(&wide);
+    buffer[wide.len()] = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_copy_wide_truncates() {
+        let mut buffer = [0xFFFFu16; 4];
+        copy_wide(&mut buffer, "abcdef");
+        assert_eq!(buffer, [b'a' as u16, b'b' as u16, b'c' as u16, 0]);
+    }
+}
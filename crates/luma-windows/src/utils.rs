@@ -1,5 +1,14 @@
 use windows::core::PCWSTR;
-use windows::Win32::Foundation::HWND;
+use windows::Win32::Foundation::{HWND, RECT, SIZE, WPARAM, LPARAM};
+use windows::Win32::Graphics::Gdi::{GetDC, ReleaseDC, SelectObject, GetTextExtentPoint32W, HGDIOBJ};
+use windows::Win32::Foundation::HGLOBAL;
+use windows::Win32::System::DataExchange::{OpenClipboard, GetClipboardData, CloseClipboard};
+use windows::Win32::System::Memory::{GlobalLock, GlobalUnlock};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetWindowRect, GetClassNameW, GetWindowTextW, GetWindowTextLengthW, SetWindowTextW,
+    SendMessageW, WM_GETFONT,
+};
+use luma_core::{Result, Error, Rect, Size};
 
 /// Convert a Rust string to a wide (UTF-16) string for Windows APIs
 pub fn to_wide_string(s: &str) -> Vec<u16> {
@@ -26,6 +35,101 @@ pub fn is_valid_hwnd(hwnd: HWND) -> bool {
     hwnd.0 != 0
 }
 
+/// Get the bounds of a window in screen coordinates
+pub fn screen_bounds(hwnd: HWND) -> Result<Rect> {
+    let mut rect = RECT::default();
+    unsafe {
+        GetWindowRect(hwnd, &mut rect)
+            .map_err(|e| Error::OperationFailed(format!("GetWindowRect failed: {}", e)))?;
+    }
+    Ok(rect_from_win32(rect))
+}
+
+/// Get the text of a window
+pub fn window_text(hwnd: HWND) -> String {
+    unsafe {
+        let len = GetWindowTextLengthW(hwnd);
+        let mut buffer: Vec<u16> = vec![0; (len.max(0) + 1) as usize];
+        let copied = GetWindowTextW(hwnd, &mut buffer);
+        buffer.truncate(copied.max(0) as usize);
+        from_wide_string(&buffer)
+    }
+}
+
+/// Set the text of a window
+pub fn set_window_text(hwnd: HWND, text: &str) -> Result<()> {
+    let wide_text = to_wide_string(text);
+    unsafe {
+        SetWindowTextW(hwnd, PCWSTR(wide_text.as_ptr()))
+            .map_err(|e| Error::OperationFailed(format!("SetWindowTextW failed: {}", e)))
+    }
+}
+
+/// Clipboard format for UTF-16 text
+const CF_UNICODETEXT: u32 = 13;
+
+/// Get the text on the clipboard, or `None` if it holds no text or is in use
+pub fn clipboard_text(owner: HWND) -> Option<String> {
+    unsafe {
+        OpenClipboard(owner).ok()?;
+        let text = GetClipboardData(CF_UNICODETEXT).ok().and_then(|handle| {
+            let memory = HGLOBAL(handle.0 as *mut std::ffi::c_void);
+            let ptr = GlobalLock(memory) as *const u16;
+            if ptr.is_null() {
+                return None;
+            }
+            let text = from_wide_ptr(PCWSTR(ptr));
+            let _ = GlobalUnlock(memory);
+            Some(text)
+        });
+        let _ = CloseClipboard();
+        text
+    }
+}
+
+/// Get the class name of a window
+pub fn class_name(hwnd: HWND) -> String {
+    let mut buffer = [0u16; 256];
+    let len = unsafe { GetClassNameW(hwnd, &mut buffer) }.max(0) as usize;
+    from_wide_string(&buffer[..len])
+}
+
+/// Measure a line of text in the font a window draws with
+pub fn measure_text(hwnd: HWND, text: &str) -> Result<Size> {
+    let wide: Vec<u16> = text.encode_utf16().collect();
+    let mut size = SIZE::default();
+    unsafe {
+        let hdc = GetDC(hwnd);
+        if hdc.is_invalid() {
+            return Err(Error::OperationFailed("GetDC failed".into()));
+        }
+        
+        // Controls without a font set draw with the system font, which the DC starts with
+        let font = SendMessageW(hwnd, WM_GETFONT, WPARAM(0), LPARAM(0));
+        let previous = (font.0 != 0).then(|| SelectObject(hdc, HGDIOBJ(font.0)));
+        let measured = GetTextExtentPoint32W(hdc, &wide, &mut size).as_bool();
+        if let Some(previous) = previous {
+            SelectObject(hdc, previous);
+        }
+        ReleaseDC(hwnd, hdc);
+        
+        if !measured {
+            return Err(Error::OperationFailed("GetTextExtentPoint32W failed".into()));
+        }
+    }
+    Ok(Size::new(size.cx.max(0) as u32, size.cy.max(0) as u32))
+}
+
+/// Convert a Win32 edge rectangle to a Luma rectangle
+pub fn rect_from_win32(rect: RECT) -> Rect {
+    Rect::new(
+        rect.left,
+        rect.top,
+        (rect.right - rect.left).max(0) as u32,
+        (rect.bottom - rect.top).max(0) as u32,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,6 +142,15 @@ mod tests {
         assert_eq!(wide[5], 0); // null terminator
     }
     
+    #[test]
+    fn test_rect_from_win32() {
+        let rect = RECT { left: -10, top: 20, right: 90, bottom: 45 };
+        assert_eq!(rect_from_win32(rect), Rect::new(-10, 20, 100, 25));
+        
+        let inverted = RECT { left: 10, top: 10, right: 5, bottom: 5 };
+        assert_eq!(rect_from_win32(inverted), Rect::new(10, 10, 0, 0));
+    }
+    
     #[test]
     fn test_from_wide_string() {
         let wide: Vec<u16> = vec!['H' as u16, 'i' as u16];
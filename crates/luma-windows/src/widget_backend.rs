@@ -0,0 +1,51 @@
+// Shared `WidgetBackend` operations for child controls
+//
+// Most widgets are a single child window, so they get bounds, enabled and
+// visible state from `child_widget_backend!`. Widgets made of several windows
+// or that react to a resize implement the trait themselves with the helpers.
+
+use windows::Win32::Foundation::{BOOL, HWND};
+use windows::Win32::UI::Input::KeyboardAndMouse::EnableWindow;
+use windows::Win32::UI::WindowsAndMessaging::{ShowWindow, SW_HIDE, SW_SHOWNA};
+
+/// Implement `WidgetBackend` for a widget backend with an `hwnd` field
+macro_rules! child_widget_backend {
+    ($widget:ident) => {
+        impl luma_core::traits::WidgetBackend for $widget {
+            fn set_bounds(&mut self, x: i32, y: i32, width: u32, height: u32) -> luma_core::Result<()> {
+                $crate::window_pos::set_child_bounds(self.hwnd, x, y, width as i32, height as i32)
+            }
+            
+            fn set_enabled(&mut self, enabled: bool) -> luma_core::Result<()> {
+                $crate::widget_backend::set_control_enabled(self.hwnd, enabled);
+                Ok(())
+            }
+            
+            fn set_visible(&mut self, visible: bool) -> luma_core::Result<()> {
+                $crate::widget_backend::set_control_visible(self.hwnd, visible);
+                Ok(())
+            }
+            
+            fn raw_handle(&self) -> *mut std::ffi::c_void {
+                self.hwnd.0 as *mut std::ffi::c_void
+            }
+        }
+    };
+}
+pub(crate) use child_widget_backend;
+
+/// Enable or disable a control
+pub fn set_control_enabled(hwnd: HWND, enabled: bool) {
+    unsafe {
+        // Returns the previous state, not success
+        let _ = EnableWindow(hwnd, BOOL::from(enabled));
+    }
+}
+
+/// Show or hide a control, without activating it
+pub fn set_control_visible(hwnd: HWND, visible: bool) {
+    unsafe {
+        // Returns the previous visibility, not success
+        let _ = ShowWindow(hwnd, if visible { SW_SHOWNA } else { SW_HIDE });
+    }
+}
@@ -0,0 +1,82 @@
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::{GetWindowLongW, GWL_STYLE};
+use luma_core::{Result, WidgetTree, WidgetKind};
+use crate::utils::{screen_bounds, window_text, class_name};
+use crate::panel::{direct_children, is_panel};
+use crate::number_box::is_number_box;
+use crate::checkbox::BS_AUTOCHECKBOX;
+use crate::window::WINDOW_CLASS_NAME;
+use crate::scroll_viewer::SCROLL_VIEWER_CLASS_NAME;
+use crate::image_view::IMAGE_VIEW_CLASS_NAME;
+
+// Button style bits that select the kind of button
+const BS_TYPEMASK: u32 = 0x000F;
+
+// Common control class names
+const UPDOWN_CLASS_NAME: &str = "msctls_updown32";
+const DATE_PICKER_CLASS_NAME: &str = "SysDateTimePick32";
+const PROGRESS_BAR_CLASS_NAME: &str = "msctls_progress32";
+const HYPERLINK_CLASS_NAME: &str = "SysLink";
+
+/// Describe a window or control and everything inside it
+/// 
+/// Children are listed in z-order, which for Luma widgets is creation order.
+pub fn widget_tree(hwnd: HWND) -> Result<WidgetTree> {
+    let kind = widget_kind(hwnd);
+    
+    // A scroll viewer's only child is the canvas holding its content
+    let children = if kind == WidgetKind::ScrollViewer {
+        direct_children(hwnd).into_iter().flat_map(direct_children).collect()
+    } else {
+        direct_children(hwnd)
+    };
+    
+    Ok(WidgetTree {
+        text: window_text(hwnd),
+        bounds: screen_bounds(hwnd)?,
+        children: children
+            .into_iter()
+            // The spin buttons are part of a number box, described by its edit control
+            .filter(|child| !class_name(*child).eq_ignore_ascii_case(UPDOWN_CLASS_NAME))
+            .map(widget_tree)
+            .collect::<Result<_>>()?,
+        kind,
+    })
+}
+
+/// Work out which Luma widget a native control implements
+fn widget_kind(hwnd: HWND) -> WidgetKind {
+    let class = class_name(hwnd);
+    let is = |name: &str| class.eq_ignore_ascii_case(name);
+    
+    if is(WINDOW_CLASS_NAME) {
+        WidgetKind::Window
+    } else if is("Button") {
+        let style = unsafe { GetWindowLongW(hwnd, GWL_STYLE) } as u32;
+        if style & BS_TYPEMASK == BS_AUTOCHECKBOX {
+            WidgetKind::CheckBox
+        } else {
+            WidgetKind::Button
+        }
+    } else if is("Static") {
+        if is_panel(hwnd) { WidgetKind::Panel } else { WidgetKind::Label }
+    } else if is("Edit") {
+        if is_number_box(hwnd) { WidgetKind::NumberBox } else { WidgetKind::TextInput }
+    } else if is("ListBox") {
+        WidgetKind::ListBox
+    } else if is("SysListView32") {
+        WidgetKind::ListView
+    } else if is(SCROLL_VIEWER_CLASS_NAME) {
+        WidgetKind::ScrollViewer
+    } else if is(IMAGE_VIEW_CLASS_NAME) {
+        WidgetKind::ImageView
+    } else if is(DATE_PICKER_CLASS_NAME) {
+        WidgetKind::DatePicker
+    } else if is(PROGRESS_BAR_CLASS_NAME) {
+        WidgetKind::ProgressBar
+    } else if is(HYPERLINK_CLASS_NAME) {
+        WidgetKind::Hyperlink
+    } else {
+        WidgetKind::Other(class)
+    }
+}
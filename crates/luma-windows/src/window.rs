@@ -1,15 +1,28 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::Graphics::Dwm::*;
+use windows::Win32::UI::Controls::{MARGINS, NMHDR};
+use windows::Win32::UI::HiDpi::GetDpiForWindow;
+use windows::Win32::UI::Shell::{DragAcceptFiles, DragQueryFileW, DragFinish, HDROP};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use luma_core::{Result, Error, WindowFlags, traits::WindowBackend, Container, Size};
-use crate::utils::{to_wide_string, is_valid_hwnd};
+use luma_core::{Result, Error, WindowFlags, traits::WindowBackend, Container, Size, Rect, Point, Color, CornerPreference, Backdrop, Theme, WidgetTree, WidgetId, WindowState, WindowPlacement, TaskbarProgressState, FlashMode};
+use luma_core::panic_guard::catch_callback_panic;
+use crate::utils::{to_wide_string, from_wide_ptr, is_valid_hwnd, screen_bounds, window_text};
+use crate::theme::system_theme;
+use crate::tray::{WM_TRAYICON, handle_tray_message};
+use crate::taskbar::{set_taskbar_progress, set_taskbar_state};
+use crate::window_pos::with_deferred_positioning;
+use crate::control_colors::ctl_color;
+use crate::control_ids::widget_for_control_id;
 use once_cell::sync::OnceCell;
 use std::sync::Mutex;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 /// Window class name for Luma windows
-const WINDOW_CLASS_NAME: &str = "LumaWindow";
+pub(crate) const WINDOW_CLASS_NAME: &str = "LumaWindow";
 
 /// Ensure the window class is registered (only once)
 static WINDOW_CLASS_REGISTERED: OnceCell<()> = OnceCell::new();
@@ -26,33 +39,248 @@ fn get_layouts_map() -> &'static Mutex<HashMap<isize, LayoutPtr>> {
 }
 
 /// Wrapper to make callback pointer Send
+#[derive(Clone, Copy)]
 struct CallbackPtr(*mut dyn FnMut());
 unsafe impl Send for CallbackPtr {}
 
-/// Global map of widget HWND to callback for handling WM_COMMAND
-static WIDGET_CALLBACKS: OnceCell<Mutex<HashMap<isize, CallbackPtr>>> = OnceCell::new();
+/// A widget callback, optionally limited to one WM_COMMAND notification code
+struct WidgetCallback {
+    notification: Option<u32>,
+    callback: CallbackPtr,
+}
+
+/// Global map of widget HWND and notification code to callback for handling
+/// WM_COMMAND; a `None` code fires for any notification without its own callback
+static WIDGET_CALLBACKS: OnceCell<Mutex<HashMap<(isize, Option<u32>), CallbackPtr>>> = OnceCell::new();
 
-fn get_callbacks_map() -> &'static Mutex<HashMap<isize, CallbackPtr>> {
+fn get_callbacks_map() -> &'static Mutex<HashMap<(isize, Option<u32>), CallbackPtr>> {
     WIDGET_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
 /// Register a callback for a widget HWND
 pub fn register_callback(hwnd: isize, callback: *mut dyn FnMut()) {
     let mut map = get_callbacks_map().lock().unwrap();
-    map.insert(hwnd, CallbackPtr(callback));
+    map.insert((hwnd, None), CallbackPtr(callback));
     tracing::debug!("Registered callback for widget HWND={:?}", hwnd);
 }
 
-/// Unregister a callback for a widget HWND
+/// Register a callback for a widget HWND that only fires for one notification code
+/// 
+/// Used by controls such as listboxes that send several kinds of WM_COMMAND
+/// notifications (focus, selection, double-click). Each code can have its own
+/// callback.
+pub fn register_notification_callback(hwnd: isize, notification: u32, callback: *mut dyn FnMut()) {
+    let mut map = get_callbacks_map().lock().unwrap();
+    map.insert((hwnd, Some(notification)), CallbackPtr(callback));
+    tracing::debug!("Registered callback for widget HWND={:?}, notification={}", hwnd, notification);
+}
+
+/// Unregister every callback for a widget HWND
 pub fn unregister_callback(hwnd: isize) {
     let mut map = get_callbacks_map().lock().unwrap();
-    map.remove(&hwnd);
+    map.retain(|(widget, _), _| *widget != hwnd);
     tracing::debug!("Unregistered callback for widget HWND={:?}", hwnd);
 }
 
+/// Unregister the callback for one notification code of a widget HWND
+pub fn unregister_notification_callback(hwnd: isize, notification: u32) {
+    let mut map = get_callbacks_map().lock().unwrap();
+    map.remove(&(hwnd, Some(notification)));
+    tracing::debug!("Unregistered callback for widget HWND={:?}, notification={}", hwnd, notification);
+}
+
+/// Global map of widget ID to callback for WM_COMMAND sent by its control ID
+static WIDGET_COMMANDS: OnceCell<Mutex<HashMap<WidgetId, WidgetCallback>>> = OnceCell::new();
+
+fn get_widget_commands_map() -> &'static Mutex<HashMap<WidgetId, WidgetCallback>> {
+    WIDGET_COMMANDS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Register a callback for a widget, reached through its control ID
+/// 
+/// The widget's control must have been given an ID with
+/// [`assign_control_id`](crate::control_ids::assign_control_id). Pass a
+/// notification code to only fire for that kind of WM_COMMAND.
+pub fn register_widget_callback(widget: WidgetId, notification: Option<u32>, callback: *mut dyn FnMut()) {
+    let mut map = get_widget_commands_map().lock().unwrap();
+    map.insert(widget, WidgetCallback { notification, callback: CallbackPtr(callback) });
+    tracing::debug!("Registered callback for widget {:?}", widget);
+}
+
+/// Unregister the callback for a widget
+pub fn unregister_widget_callback(widget: WidgetId) {
+    let mut map = get_widget_commands_map().lock().unwrap();
+    map.remove(&widget);
+    tracing::debug!("Unregistered callback for widget {:?}", widget);
+}
+
+/// Find the callback for a control's WM_COMMAND: control ID, then widget ID, then handler
+fn widget_command_callback(control_id: u16, notification_code: u32) -> Option<CallbackPtr> {
+    let widget = widget_for_control_id(control_id)?;
+    let map = get_widget_commands_map().lock().ok()?;
+    map.get(&widget)
+        .filter(|entry| !matches!(entry.notification, Some(code) if code != notification_code))
+        .map(|entry| entry.callback)
+}
+
+/// Handler for WM_NOTIFY messages sent by a control
+/// 
+/// Receives the notification header (the start of the control's notification
+/// structure) and returns the message result.
+pub type NotifyHandler = unsafe fn(*const NMHDR) -> LRESULT;
+
+/// Global map of control HWND to WM_NOTIFY handler
+static NOTIFY_HANDLERS: OnceCell<Mutex<HashMap<isize, NotifyHandler>>> = OnceCell::new();
+
+fn get_notify_handlers_map() -> &'static Mutex<HashMap<isize, NotifyHandler>> {
+    NOTIFY_HANDLERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Register a WM_NOTIFY handler for a control HWND
+pub fn register_notify_handler(hwnd: isize, handler: NotifyHandler) {
+    let mut map = get_notify_handlers_map().lock().unwrap();
+    map.insert(hwnd, handler);
+    tracing::debug!("Registered notify handler for HWND={:?}", hwnd);
+}
+
+/// Unregister the WM_NOTIFY handler for a control HWND
+pub fn unregister_notify_handler(hwnd: isize) {
+    let mut map = get_notify_handlers_map().lock().unwrap();
+    map.remove(&hwnd);
+    tracing::debug!("Unregistered notify handler for HWND={:?}", hwnd);
+}
+
+/// Global map of command ID to callback for accelerator commands
+static COMMAND_CALLBACKS: OnceCell<Mutex<HashMap<u16, CallbackPtr>>> = OnceCell::new();
+
+fn get_command_callbacks_map() -> &'static Mutex<HashMap<u16, CallbackPtr>> {
+    COMMAND_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Register a callback for a command ID sent by an accelerator
+pub fn register_command_callback(command_id: u16, callback: *mut dyn FnMut()) {
+    let mut map = get_command_callbacks_map().lock().unwrap();
+    map.insert(command_id, CallbackPtr(callback));
+    tracing::debug!("Registered callback for command ID={}", command_id);
+}
+
+/// Unregister the callback for a command ID
+pub fn unregister_command_callback(command_id: u16) {
+    let mut map = get_command_callbacks_map().lock().unwrap();
+    map.remove(&command_id);
+    tracing::debug!("Unregistered callback for command ID={}", command_id);
+}
+
+/// Wrapper to make theme callback pointer Send
+struct ThemeCallbackPtr(*mut dyn FnMut(Theme));
+unsafe impl Send for ThemeCallbackPtr {}
+
+/// Global map of window HWND to theme callback for handling WM_SETTINGCHANGE
+static THEME_CALLBACKS: OnceCell<Mutex<HashMap<isize, ThemeCallbackPtr>>> = OnceCell::new();
+
+fn get_theme_callbacks_map() -> &'static Mutex<HashMap<isize, ThemeCallbackPtr>> {
+    THEME_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Wrapper to make file drop callback pointer Send
+struct DropCallbackPtr(*mut dyn FnMut(Vec<PathBuf>));
+unsafe impl Send for DropCallbackPtr {}
+
+/// Global map of window HWND to file drop callback for handling WM_DROPFILES
+static DROP_CALLBACKS: OnceCell<Mutex<HashMap<isize, DropCallbackPtr>>> = OnceCell::new();
+
+fn get_drop_callbacks_map() -> &'static Mutex<HashMap<isize, DropCallbackPtr>> {
+    DROP_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// A window state callback and the state it was last told about
+struct StateCallback {
+    callback: *mut dyn FnMut(WindowState),
+    state: WindowState,
+}
+unsafe impl Send for StateCallback {}
+
+/// Global map of window HWND to state callback for handling WM_SIZE
+static STATE_CALLBACKS: OnceCell<Mutex<HashMap<isize, StateCallback>>> = OnceCell::new();
+
+fn get_state_callbacks_map() -> &'static Mutex<HashMap<isize, StateCallback>> {
+    STATE_CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Timer ID used for fade animations
+const FADE_TIMER_ID: usize = 1;
+
+/// Interval between fade animation steps in milliseconds (about 60 Hz)
+const FADE_INTERVAL_MS: u32 = 16;
+
+/// An opacity animation in progress
+struct Fade {
+    start: Instant,
+    duration: Duration,
+}
+
+/// Global map of window HWND to its running fade for handling WM_TIMER
+static WINDOW_FADES: OnceCell<Mutex<HashMap<isize, Fade>>> = OnceCell::new();
+
+fn get_fades_map() -> &'static Mutex<HashMap<isize, Fade>> {
+    WINDOW_FADES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Timer ID used to throttle layout during a drag-resize
+const RELAYOUT_TIMER_ID: usize = 2;
+
+/// Interval between layout passes during a drag-resize in milliseconds
+const RELAYOUT_INTERVAL_MS: u32 = 16;
+
+/// Posted to a top-level window to lay its widgets out again at the current size
+const WM_LUMA_RELAYOUT: u32 = WM_APP + 2;
+
+/// Ask the top-level window holding a widget to lay its widgets out again
+/// 
+/// The request is posted, so the layout runs from the message loop after
+/// the caller returns. A widget can ask while it is borrowed, such as a label
+/// given new text through a `WidgetHandle` from a button's callback.
+pub fn request_layout(hwnd: HWND) {
+    unsafe {
+        let root = GetAncestor(hwnd, GA_ROOT);
+        if is_valid_hwnd(root) {
+            let _ = PostMessageW(root, WM_LUMA_RELAYOUT, WPARAM(0), LPARAM(0));
+        }
+    }
+}
+
+/// Global map of HWND to the size awaiting layout, for windows in a move/size loop
+static SIZE_MOVES: OnceCell<Mutex<HashMap<isize, Option<Size>>>> = OnceCell::new();
+
+fn get_size_moves_map() -> &'static Mutex<HashMap<isize, Option<Size>>> {
+    SIZE_MOVES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Per-window chrome settings consulted by the window procedure
+#[derive(Default)]
+struct WindowChrome {
+    /// Draw no system frame (borderless window)
+    custom_frame: bool,
+    /// Client-area rectangle that drags the window like a title bar
+    drag_region: Option<Rect>,
+    /// A system backdrop is active, so the background must stay transparent
+    backdrop: bool,
+    /// Brush painting the client area instead of the class's system color
+    background: Option<HBRUSH>,
+}
+
+/// Global map of HWND to chrome settings for handling WM_NCCALCSIZE/WM_NCHITTEST
+static WINDOW_CHROME: OnceCell<Mutex<HashMap<isize, WindowChrome>>> = OnceCell::new();
+
+fn get_chrome_map() -> &'static Mutex<HashMap<isize, WindowChrome>> {
+    WINDOW_CHROME.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 /// Win32 window backend
 pub struct Win32Window {
     hwnd: HWND,
+    /// State the window is first shown in, until it has been shown
+    start_state: Option<WindowState>,
 }
 
 impl Win32Window {
@@ -69,6 +297,589 @@ impl Win32Window {
         map.remove(&self.hwnd.0);
         tracing::debug!("Unregistered layout for HWND={:?}", self.hwnd);
     }
+    
+    /// Set the client-area rectangle that drags the window like a title bar
+    /// 
+    /// Mainly useful for borderless windows (no `WindowFlags::TITLED`), which
+    /// otherwise cannot be moved. Pass `None` to remove the drag region.
+    pub fn set_drag_region(&mut self, region: Option<Rect>) {
+        let mut map = get_chrome_map().lock().unwrap();
+        map.entry(self.hwnd.0).or_default().drag_region = region;
+    }
+    
+    /// Move the window so its top-left corner is at a screen position
+    pub fn set_position(&mut self, x: i32, y: i32) -> Result<()> {
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                None,
+                x,
+                y,
+                0,
+                0,
+                SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+        }
+        Ok(())
+    }
+    
+    /// Get the window's outer bounds in screen coordinates
+    pub fn screen_bounds(&self) -> Result<Rect> {
+        screen_bounds(self.hwnd)
+    }
+    
+    /// Show progress on the window's taskbar button, or `None` to remove it
+    /// 
+    /// `completed` is clamped to `total`. Switches the button to the normal
+    /// (green) state from no progress or indeterminate.
+    pub fn set_taskbar_progress(&mut self, progress: Option<(u64, u64)>) -> Result<()> {
+        set_taskbar_progress(self.hwnd, progress);
+        Ok(())
+    }
+    
+    /// Change how the window's taskbar button shows progress
+    pub fn set_taskbar_state(&mut self, state: TaskbarProgressState) -> Result<()> {
+        set_taskbar_state(self.hwnd, state);
+        Ok(())
+    }
+    
+    /// Flash the title bar, the taskbar button or both
+    /// 
+    /// A count of `None` flashes on a timer until stopped.
+    pub fn flash(&mut self, mode: FlashMode) -> Result<()> {
+        let (flags, count) = match mode {
+            FlashMode::Caption(count) => (FLASHW_CAPTION, count),
+            FlashMode::Tray(count) => (FLASHW_TRAY, count),
+            FlashMode::All(count) => (FLASHW_ALL, count),
+            FlashMode::Stop => {
+                self.flash_window(FLASHW_STOP, 0);
+                return Ok(());
+            }
+        };
+        match count {
+            Some(0) => return Err(Error::InvalidParameter("Flash count must be at least 1".to_string())),
+            Some(count) => self.flash_window(flags, count),
+            None => self.flash_window(flags | FLASHW_TIMER, 0),
+        }
+        Ok(())
+    }
+    
+    /// Flash the title bar and taskbar button until the window comes to the foreground
+    pub fn flash_until_focus(&mut self) -> Result<()> {
+        self.flash_window(FLASHW_ALL | FLASHW_TIMERNOFG, 0);
+        Ok(())
+    }
+    
+    /// Call FlashWindowEx, whose result is the previous caption state rather than an error
+    fn flash_window(&self, flags: FLASHWINFO_FLAGS, count: u32) {
+        let info = FLASHWINFO {
+            cbSize: std::mem::size_of::<FLASHWINFO>() as u32,
+            hwnd: self.hwnd,
+            dwFlags: flags,
+            uCount: count,
+            // Zero uses the cursor blink rate
+            dwTimeout: 0,
+        };
+        unsafe {
+            let _ = FlashWindowEx(&info);
+        }
+    }
+    
+    /// Get the scale factor of the window's monitor, such as 1.5 at 150%
+    pub fn scale_factor(&self) -> f32 {
+        window_scale(self.hwnd)
+    }
+    
+    /// Move the window to the middle of the work area of its monitor
+    /// 
+    /// A window larger than the work area keeps its title bar on screen.
+    pub fn center(&mut self) -> Result<()> {
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        unsafe {
+            let monitor = MonitorFromWindow(self.hwnd, MONITOR_DEFAULTTONEAREST);
+            if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+                return Err(Error::OperationFailed("GetMonitorInfoW failed".into()));
+            }
+        }
+        
+        let bounds = self.screen_bounds()?;
+        let work = info.rcWork;
+        let x = work.left + (work.right - work.left - bounds.width as i32) / 2;
+        let y = work.top + (work.bottom - work.top - bounds.height as i32) / 2;
+        self.set_position(x.max(work.left), y.max(work.top))
+    }
+    
+    /// Set the state the window is in when it is first shown
+    pub fn set_start_state(&mut self, state: WindowState) {
+        self.start_state = Some(state);
+    }
+    
+    /// Check whether the window fills the work area of its monitor
+    pub fn is_maximized(&self) -> bool {
+        unsafe { IsZoomed(self.hwnd).as_bool() }
+    }
+    
+    /// Check whether the window is minimized to the taskbar
+    pub fn is_minimized(&self) -> bool {
+        unsafe { IsIconic(self.hwnd).as_bool() }
+    }
+    
+    /// Check whether the window is the foreground window
+    pub fn is_active(&self) -> bool {
+        unsafe { GetForegroundWindow() == self.hwnd }
+    }
+    
+    /// Get the window's state and the bounds it has when neither maximized nor minimized
+    pub fn get_placement(&self) -> Result<WindowPlacement> {
+        let mut placement = WINDOWPLACEMENT {
+            length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+            ..Default::default()
+        };
+        unsafe {
+            GetWindowPlacement(self.hwnd, &mut placement)
+                .map_err(|e| Error::OperationFailed(format!("GetWindowPlacement failed: {}", e)))?;
+        }
+        
+        let normal = placement.rcNormalPosition;
+        Ok(WindowPlacement {
+            // A window not shown yet is in the state it will be shown in
+            state: self.start_state.unwrap_or_else(|| window_state(self.hwnd)),
+            normal_bounds: Rect::new(
+                normal.left,
+                normal.top,
+                (normal.right - normal.left).max(0) as u32,
+                (normal.bottom - normal.top).max(0) as u32,
+            ),
+        })
+    }
+    
+    /// Move the window to saved bounds and state
+    /// 
+    /// A hidden window stays hidden and takes the state when it is shown.
+    pub fn set_placement(&mut self, placement: WindowPlacement) -> Result<()> {
+        let bounds = placement.normal_bounds;
+        let visible = unsafe { IsWindowVisible(self.hwnd).as_bool() };
+        let show = if visible {
+            self.start_state = None;
+            show_command(placement.state)
+        } else {
+            self.start_state = Some(placement.state);
+            SW_HIDE
+        };
+        
+        let placement = WINDOWPLACEMENT {
+            length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+            showCmd: show.0 as u32,
+            rcNormalPosition: RECT {
+                left: bounds.x,
+                top: bounds.y,
+                right: bounds.x + bounds.width as i32,
+                bottom: bounds.y + bounds.height as i32,
+            },
+            ..Default::default()
+        };
+        unsafe {
+            SetWindowPlacement(self.hwnd, &placement)
+                .map_err(|e| Error::OperationFailed(format!("SetWindowPlacement failed: {}", e)))?;
+        }
+        Ok(())
+    }
+    
+    /// Set the callback invoked when the window is maximized, minimized or restored
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// window is dropped.
+    pub fn set_state_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(WindowState)>) {
+        let state = window_state(self.hwnd);
+        let mut map = get_state_callbacks_map().lock().unwrap();
+        match callback {
+            Some(callback) => {
+                map.insert(self.hwnd.0, StateCallback { callback, state });
+            }
+            None => {
+                map.remove(&self.hwnd.0);
+            }
+        }
+    }
+    
+    /// Maximize, minimize or restore the window, showing it if it is hidden
+    pub fn set_state(&mut self, state: WindowState) -> Result<()> {
+        self.start_state = None;
+        unsafe {
+            ShowWindow(self.hwnd, show_command(state));
+        }
+        Ok(())
+    }
+    
+    /// Describe the window and every widget in it
+    pub fn widget_tree(&self) -> Result<WidgetTree> {
+        crate::widget_tree::widget_tree(self.hwnd)
+    }
+    
+    /// Keep the window above all non-topmost windows, or release it
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<()> {
+        let insert_after = if on_top { HWND_TOPMOST } else { HWND_NOTOPMOST };
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                insert_after,
+                0,
+                0,
+                0,
+                0,
+                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+            ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))?;
+        }
+        Ok(())
+    }
+    
+    /// Set the callback invoked when the system light/dark theme changes
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// window is dropped.
+    pub fn set_theme_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(Theme)>) {
+        let mut map = get_theme_callbacks_map().lock().unwrap();
+        match callback {
+            Some(ptr) => {
+                map.insert(self.hwnd.0, ThemeCallbackPtr(ptr));
+            }
+            None => {
+                map.remove(&self.hwnd.0);
+            }
+        }
+    }
+    
+    /// Accept or refuse files dragged from Explorer onto the window
+    pub fn set_accept_dropped_files(&mut self, accept: bool) {
+        unsafe {
+            DragAcceptFiles(self.hwnd, accept);
+        }
+    }
+    
+    /// Set the callback invoked with the paths of files dropped on the window
+    /// 
+    /// The pointer must stay valid until it is replaced, cleared or the
+    /// window is dropped.
+    pub fn set_files_dropped_callback_ptr(&mut self, callback: Option<*mut dyn FnMut(Vec<PathBuf>)>) {
+        let mut map = get_drop_callbacks_map().lock().unwrap();
+        match callback {
+            Some(ptr) => {
+                map.insert(self.hwnd.0, DropCallbackPtr(ptr));
+            }
+            None => {
+                map.remove(&self.hwnd.0);
+            }
+        }
+    }
+    
+    /// Use the dark title bar and frame (Windows 10 20H1+)
+    pub fn set_dark_mode(&mut self, dark: bool) {
+        self.set_dwm_attribute(DWMWA_USE_IMMERSIVE_DARK_MODE, &BOOL::from(dark));
+    }
+    
+    /// Set how the compositor rounds the window corners (Windows 11+)
+    pub fn set_corner_preference(&mut self, preference: CornerPreference) {
+        let value = match preference {
+            CornerPreference::Default => DWMWCP_DEFAULT,
+            CornerPreference::DoNotRound => DWMWCP_DONOTROUND,
+            CornerPreference::Round => DWMWCP_ROUND,
+            CornerPreference::RoundSmall => DWMWCP_ROUNDSMALL,
+        };
+        self.set_dwm_attribute(DWMWA_WINDOW_CORNER_PREFERENCE, &value);
+    }
+    
+    /// Set the color of the window border (Windows 11+)
+    pub fn set_border_color(&mut self, color: Color) {
+        self.set_dwm_attribute(DWMWA_BORDER_COLOR, &color_to_colorref(color));
+    }
+    
+    /// Set the color of the window caption (Windows 11+)
+    pub fn set_caption_color(&mut self, color: Color) {
+        self.set_dwm_attribute(DWMWA_CAPTION_COLOR, &color_to_colorref(color));
+    }
+    
+    /// Paint the client area with a solid color instead of the system window color
+    /// 
+    /// A system backdrop, if set, still takes precedence.
+    pub fn set_background(&mut self, color: Color) -> Result<()> {
+        let brush = unsafe { CreateSolidBrush(color_to_colorref(color)) };
+        if brush.is_invalid() {
+            return Err(Error::Platform("CreateSolidBrush failed".into()));
+        }
+        
+        let old = {
+            let mut map = get_chrome_map().lock().unwrap();
+            map.entry(self.hwnd.0).or_default().background.replace(brush)
+        };
+        unsafe {
+            if let Some(old) = old {
+                let _ = DeleteObject(old);
+            }
+            let _ = InvalidateRect(self.hwnd, None, true);
+        }
+        Ok(())
+    }
+    
+    /// Draw a system backdrop material behind the client area
+    /// 
+    /// Fails with `Error::Platform` on systems without backdrop support
+    /// (anything before Windows 11 22H2); the window is left unchanged.
+    pub fn set_backdrop(&mut self, backdrop: Backdrop) -> Result<()> {
+        let value = match backdrop {
+            Backdrop::None => DWMSBT_NONE,
+            Backdrop::Mica => DWMSBT_MAINWINDOW,
+            Backdrop::MicaAlt => DWMSBT_TABBEDWINDOW,
+            Backdrop::Acrylic => DWMSBT_TRANSIENTWINDOW,
+        };
+        
+        unsafe {
+            DwmSetWindowAttribute(
+                self.hwnd,
+                DWMWA_SYSTEMBACKDROP_TYPE,
+                &value as *const DWM_SYSTEMBACKDROP_TYPE as *const std::ffi::c_void,
+                std::mem::size_of::<DWM_SYSTEMBACKDROP_TYPE>() as u32,
+            ).map_err(|e| Error::Platform(format!("System backdrop not supported (requires Windows 11 22H2+): {}", e)))?;
+            
+            // The backdrop only shows through the part of the client area that
+            // the frame is extended into, so extend it over the whole window
+            let inset = if backdrop == Backdrop::None { 0 } else { -1 };
+            let margins = MARGINS {
+                cxLeftWidth: inset,
+                cxRightWidth: inset,
+                cyTopHeight: inset,
+                cyBottomHeight: inset,
+            };
+            DwmExtendFrameIntoClientArea(self.hwnd, &margins)
+                .map_err(|e| Error::OperationFailed(format!("DwmExtendFrameIntoClientArea failed: {}", e)))?;
+        }
+        
+        {
+            let mut map = get_chrome_map().lock().unwrap();
+            map.entry(self.hwnd.0).or_default().backdrop = backdrop != Backdrop::None;
+        }
+        
+        unsafe {
+            let _ = InvalidateRect(self.hwnd, None, true);
+        }
+        Ok(())
+    }
+    
+    /// Add or remove the layered style needed for transparency
+    /// 
+    /// Removing it restores a fully opaque window, and ends click-through as
+    /// that only works on layered windows.
+    pub fn set_layered(&mut self, layered: bool) -> Result<()> {
+        unsafe {
+            let ex_style = GetWindowLongPtrW(self.hwnd, GWL_EXSTYLE);
+            let ex_style = if layered {
+                ex_style | WS_EX_LAYERED.0 as isize
+            } else {
+                ex_style & !((WS_EX_LAYERED.0 | WS_EX_TRANSPARENT.0) as isize)
+            };
+            SetWindowLongPtrW(self.hwnd, GWL_EXSTYLE, ex_style);
+        }
+        Ok(())
+    }
+    
+    /// Set the opacity of the whole window, from 0.0 (invisible) to 1.0 (opaque)
+    /// 
+    /// Makes the window layered if it is not already.
+    pub fn set_opacity(&mut self, opacity: f32) -> Result<()> {
+        if !(0.0..=1.0).contains(&opacity) {
+            return Err(Error::InvalidParameter(format!("Opacity must be in 0.0..=1.0, got {}", opacity)));
+        }
+        self.set_layered(true)?;
+        apply_opacity(self.hwnd, opacity)
+    }
+    
+    /// Show the window with its opacity animated from transparent to opaque
+    pub fn fade_in(&mut self, duration: Duration) -> Result<()> {
+        self.set_opacity(0.0)?;
+        self.show()?;
+        
+        get_fades_map().lock().unwrap().insert(self.hwnd.0, Fade {
+            start: Instant::now(),
+            duration,
+        });
+        unsafe {
+            if SetTimer(self.hwnd, FADE_TIMER_ID, FADE_INTERVAL_MS, None) == 0 {
+                get_fades_map().lock().unwrap().remove(&self.hwnd.0);
+                return apply_opacity(self.hwnd, 1.0);
+            }
+        }
+        Ok(())
+    }
+    
+    /// Draw the window from a 32-bit ARGB image with per-pixel transparency
+    /// 
+    /// `pixels` holds `width * height` values of `0xAARRGGBB`, row by row from
+    /// the top, with straight (non-premultiplied) alpha. The window is
+    /// resized to the image and no longer paints itself; child controls are
+    /// not drawn. Call [`Win32Window::set_layered`] with `false` to undo.
+    pub fn update_layered_bitmap(&mut self, width: u32, height: u32, pixels: &[u32]) -> Result<()> {
+        if pixels.len() != width as usize * height as usize {
+            return Err(Error::InvalidParameter(format!(
+                "Expected {} pixels for a {}x{} image, got {}",
+                width as usize * height as usize,
+                width,
+                height,
+                pixels.len()
+            )));
+        }
+        
+        self.set_layered(true)?;
+        
+        unsafe {
+            let screen_dc = GetDC(None);
+            let memory_dc = CreateCompatibleDC(screen_dc);
+            
+            // Negative height makes a top-down DIB, matching the pixel order
+            let info = BITMAPINFO {
+                bmiHeader: BITMAPINFOHEADER {
+                    biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                    biWidth: width as i32,
+                    biHeight: -(height as i32),
+                    biPlanes: 1,
+                    biBitCount: 32,
+                    biCompression: BI_RGB.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            
+            let mut bits: *mut std::ffi::c_void = std::ptr::null_mut();
+            let result = CreateDIBSection(memory_dc, &info, DIB_RGB_COLORS, &mut bits, None, 0)
+                .map_err(|e| Error::OperationFailed(format!("CreateDIBSection failed: {}", e)))
+                .and_then(|bitmap| {
+                    // UpdateLayeredWindow expects premultiplied BGRA
+                    let dib = std::slice::from_raw_parts_mut(bits as *mut u32, pixels.len());
+                    for (out, &pixel) in dib.iter_mut().zip(pixels) {
+                        *out = premultiply(pixel);
+                    }
+                    
+                    let previous = SelectObject(memory_dc, bitmap);
+                    let size = SIZE { cx: width as i32, cy: height as i32 };
+                    let source = POINT { x: 0, y: 0 };
+                    let blend = BLENDFUNCTION {
+                        BlendOp: AC_SRC_OVER as u8,
+                        BlendFlags: 0,
+                        SourceConstantAlpha: 255,
+                        AlphaFormat: AC_SRC_ALPHA as u8,
+                    };
+                    let result = UpdateLayeredWindow(
+                        self.hwnd,
+                        screen_dc,
+                        None,
+                        Some(&size),
+                        memory_dc,
+                        Some(&source),
+                        COLORREF(0),
+                        Some(&blend),
+                        ULW_ALPHA,
+                    ).map_err(|e| Error::OperationFailed(format!("UpdateLayeredWindow failed: {}", e)));
+                    
+                    SelectObject(memory_dc, previous);
+                    let _ = DeleteObject(bitmap);
+                    result
+                });
+            
+            let _ = DeleteDC(memory_dc);
+            ReleaseDC(None, screen_dc);
+            result
+        }
+    }
+    
+    /// Let mouse input pass through the window to whatever is beneath it
+    /// 
+    /// Click-through needs the layered style, so enabling it makes the window
+    /// layered; a window that was not layered yet is kept fully opaque.
+    /// Opacity and layered bitmaps set before or after are unaffected.
+    pub fn set_click_through(&mut self, click_through: bool) -> Result<()> {
+        unsafe {
+            let ex_style = GetWindowLongPtrW(self.hwnd, GWL_EXSTYLE);
+            if !click_through {
+                SetWindowLongPtrW(self.hwnd, GWL_EXSTYLE, ex_style & !(WS_EX_TRANSPARENT.0 as isize));
+                return Ok(());
+            }
+            
+            let was_layered = ex_style & WS_EX_LAYERED.0 as isize != 0;
+            SetWindowLongPtrW(
+                self.hwnd,
+                GWL_EXSTYLE,
+                ex_style | (WS_EX_LAYERED.0 | WS_EX_TRANSPARENT.0) as isize,
+            );
+            // A layered window is not drawn until it is given an opacity
+            if !was_layered {
+                apply_opacity(self.hwnd, 1.0)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Limit the window to a set of rectangles, or `None` for the whole window
+    /// 
+    /// Rectangles are relative to the top-left corner of the window, frame
+    /// included. Outside them the window is neither drawn nor hit, so clicks
+    /// there reach the window beneath.
+    pub fn set_interactive_region(&mut self, region: Option<&[Rect]>) -> Result<()> {
+        unsafe {
+            let hrgn = match region {
+                Some(rects) => {
+                    let hrgn = CreateRectRgn(0, 0, 0, 0);
+                    for rect in rects {
+                        let part = CreateRectRgn(
+                            rect.x,
+                            rect.y,
+                            rect.x + rect.width as i32,
+                            rect.y + rect.height as i32,
+                        );
+                        let combined = CombineRgn(hrgn, hrgn, part, RGN_OR);
+                        let _ = DeleteObject(part);
+                        if combined == RGN_ERROR {
+                            let _ = DeleteObject(hrgn);
+                            return Err(Error::OperationFailed("CombineRgn failed".into()));
+                        }
+                    }
+                    hrgn
+                }
+                None => HRGN(0),
+            };
+            
+            // The window owns the region once it is set
+            if SetWindowRgn(self.hwnd, hrgn, true) == 0 {
+                if hrgn.0 != 0 {
+                    let _ = DeleteObject(hrgn);
+                }
+                return Err(Error::Platform(format!(
+                    "SetWindowRgn failed: {}",
+                    windows::core::Error::from_win32()
+                )));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Apply a DWM window attribute, ignoring failure
+    /// 
+    /// Older versions of Windows reject attributes they do not know about;
+    /// appearance tweaks are optional, so that is not treated as an error.
+    fn set_dwm_attribute<T>(&self, attribute: DWMWINDOWATTRIBUTE, value: &T) {
+        let result = unsafe {
+            DwmSetWindowAttribute(
+                self.hwnd,
+                attribute,
+                value as *const T as *const std::ffi::c_void,
+                std::mem::size_of::<T>() as u32,
+            )
+        };
+        
+        if let Err(e) = result {
+            tracing::debug!("DwmSetWindowAttribute({}) not applied: {}", attribute.0, e);
+        }
+    }
 }
 
 impl WindowBackend for Win32Window {
@@ -87,9 +898,10 @@ impl WindowBackend for Win32Window {
             let window_title = to_wide_string(title);
             
             let style = window_flags_to_style(flags);
+            let ex_style = window_flags_to_ex_style(flags);
             
             let hwnd = CreateWindowExW(
-                WINDOW_EX_STYLE(0),
+                ex_style,
                 windows::core::PCWSTR(class_name.as_ptr()),
                 windows::core::PCWSTR(window_title.as_ptr()),
                 style,
@@ -109,7 +921,26 @@ impl WindowBackend for Win32Window {
             
             tracing::debug!("Win32 window created successfully: HWND={:?}", hwnd);
             
-            Ok(Self { hwnd })
+            if !flags.contains(WindowFlags::TITLED) {
+                get_chrome_map().lock().unwrap().insert(hwnd.0, WindowChrome {
+                    custom_frame: true,
+                    ..Default::default()
+                });
+                
+                // WM_NCCALCSIZE was already sent during creation; ask for it again
+                // now that the window is known to use a custom frame
+                let _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    0,
+                    0,
+                    0,
+                    0,
+                    SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_FRAMECHANGED,
+                );
+            }
+            
+            Ok(Self { hwnd, start_state: None })
         }
     }
     
@@ -122,6 +953,10 @@ impl WindowBackend for Win32Window {
         Ok(())
     }
     
+    fn get_title(&self) -> Result<String> {
+        Ok(window_text(self.hwnd))
+    }
+    
     fn set_size(&mut self, width: u32, height: u32) -> Result<()> {
         unsafe {
             SetWindowPos(
@@ -138,8 +973,9 @@ impl WindowBackend for Win32Window {
     }
     
     fn show(&mut self) -> Result<()> {
+        let command = self.start_state.take().map_or(SW_SHOW, show_command);
         unsafe {
-            ShowWindow(self.hwnd, SW_SHOW);
+            ShowWindow(self.hwnd, command);
             UpdateWindow(self.hwnd);
         }
         tracing::debug!("Window shown");
@@ -176,6 +1012,17 @@ impl Drop for Win32Window {
         tracing::debug!("Destroying Win32 window: HWND={:?}", self.hwnd);
         // Clean up layout registration
         self.clear_layout_ptr();
+        let chrome = get_chrome_map().lock().unwrap().remove(&self.hwnd.0);
+        if let Some(brush) = chrome.and_then(|chrome| chrome.background) {
+            unsafe {
+                let _ = DeleteObject(brush);
+            }
+        }
+        self.set_theme_callback_ptr(None);
+        self.set_files_dropped_callback_ptr(None);
+        self.set_state_callback_ptr(None);
+        get_fades_map().lock().unwrap().remove(&self.hwnd.0);
+        get_size_moves_map().lock().unwrap().remove(&self.hwnd.0);
         unsafe {
             let _ = DestroyWindow(self.hwnd);
         }
@@ -217,11 +1064,24 @@ fn register_window_class() -> Result<()> {
 }
 
 /// Window procedure for handling messages
+/// 
+/// User callbacks run inside message handling; a panic in one is caught here
+/// rather than unwinding across the FFI boundary.
 unsafe extern "system" fn window_proc(
     hwnd: HWND,
     msg: u32,
     wparam: WPARAM,
     lparam: LPARAM,
+) -> LRESULT {
+    catch_callback_panic(|| handle_window_message(hwnd, msg, wparam, lparam)).unwrap_or(LRESULT(0))
+}
+
+/// Handle a message for a Luma window
+unsafe fn handle_window_message(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
 ) -> LRESULT {
     match msg {
         WM_DESTROY => {
@@ -237,32 +1097,181 @@ unsafe extern "system" fn window_proc(
         WM_PAINT => {
             let mut ps = PAINTSTRUCT::default();
             let hdc = BeginPaint(hwnd, &mut ps);
-            // Paint background
-            FillRect(hdc, &ps.rcPaint, HBRUSH((COLOR_WINDOW.0 + 1) as isize));
+            // Paint background; black is rendered transparent where the
+            // frame is extended, letting the system backdrop show through
+            let brush = if has_backdrop(hwnd) {
+                HBRUSH(GetStockObject(BLACK_BRUSH).0)
+            } else {
+                custom_background(hwnd).unwrap_or(HBRUSH((COLOR_WINDOW.0 + 1) as isize))
+            };
+            FillRect(hdc, &ps.rcPaint, brush);
             EndPaint(hwnd, &ps);
             LRESULT(0)
         }
+        WM_ERASEBKGND if has_backdrop(hwnd) => {
+            // The class brush would cover the backdrop; WM_PAINT handles it
+            LRESULT(1)
+        }
+        WM_ERASEBKGND => match custom_background(hwnd) {
+            Some(brush) => {
+                // Erase with the window's own color rather than the class brush
+                let mut client = RECT::default();
+                let _ = GetClientRect(hwnd, &mut client);
+                FillRect(HDC(wparam.0 as isize), &client, brush);
+                LRESULT(1)
+            }
+            None => DefWindowProcW(hwnd, msg, wparam, lparam),
+        },
+        WM_CTLCOLORSTATIC | WM_CTLCOLOREDIT | WM_CTLCOLORBTN => {
+            // Labels, check boxes and panels without a color of their own
+            // take the window's background
+            let control = HWND(lparam.0);
+            match ctl_color(msg, HDC(wparam.0 as isize), control, custom_background(hwnd)) {
+                Some(result) => result,
+                None => DefWindowProcW(hwnd, msg, wparam, lparam),
+            }
+        }
+        WM_NCCALCSIZE => {
+            // With a custom frame the client area covers the whole window rect,
+            // so no system border or caption is drawn
+            if wparam.0 != 0 && has_custom_frame(hwnd) {
+                return LRESULT(0);
+            }
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
+        WM_NCHITTEST => {
+            let screen_x = (lparam.0 & 0xFFFF) as i16 as i32;
+            let screen_y = ((lparam.0 >> 16) & 0xFFFF) as i16 as i32;
+            
+            if let Some(hit) = custom_frame_hit_test(hwnd, screen_x, screen_y) {
+                return LRESULT(hit as isize);
+            }
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
+        WM_TRAYICON => {
+            handle_tray_message(wparam, lparam);
+            LRESULT(0)
+        }
+        WM_LUMA_RELAYOUT => {
+            let mut client = RECT::default();
+            if GetClientRect(hwnd, &mut client).is_ok() {
+                let width = (client.right - client.left).max(0) as u32;
+                let height = (client.bottom - client.top).max(0) as u32;
+                layout_window(hwnd, Size::new(width, height));
+            }
+            LRESULT(0)
+        }
+        WM_SETTINGCHANGE => {
+            // Light/dark mode switches arrive as an "ImmersiveColorSet" change
+            let area = windows::core::PCWSTR(lparam.0 as *const u16);
+            if !area.is_null() && from_wide_ptr(area) == "ImmersiveColorSet" {
+                // Copy the pointer out so the callback runs without the lock held
+                let callback = get_theme_callbacks_map()
+                    .lock()
+                    .ok()
+                    .and_then(|map| map.get(&hwnd.0).map(|ptr| ptr.0));
+                
+                if let Some(callback) = callback {
+                    let theme = system_theme();
+                    tracing::debug!("System theme changed to {:?}", theme);
+                    (*callback)(theme);
+                }
+            }
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
+        WM_NOTIFY => {
+            let header = lparam.0 as *const NMHDR;
+            if header.is_null() {
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            }
+            
+            // Copy the handler out so it runs without the lock held
+            let handler = get_notify_handlers_map()
+                .lock()
+                .ok()
+                .and_then(|map| map.get(&(*header).hwndFrom.0).copied());
+            
+            match handler {
+                Some(handler) => handler(header),
+                None => DefWindowProcW(hwnd, msg, wparam, lparam),
+            }
+        }
+        WM_TIMER if wparam.0 == FADE_TIMER_ID => {
+            step_fade(hwnd);
+            LRESULT(0)
+        }
+        WM_DROPFILES => {
+            let hdrop = HDROP(wparam.0 as isize);
+            let paths = dropped_file_paths(hdrop);
+            DragFinish(hdrop);
+            
+            tracing::debug!("Dropped {} files on HWND={:?}", paths.len(), hwnd);
+            
+            // Copy the pointer out so the callback runs without the lock held
+            let callback = get_drop_callbacks_map()
+                .lock()
+                .ok()
+                .and_then(|map| map.get(&hwnd.0).map(|ptr| ptr.0));
+            
+            if let Some(callback) = callback {
+                (*callback)(paths);
+            }
+            LRESULT(0)
+        }
         WM_SIZE => {
-            // Handle window resize - re-layout all widgets
             let width = (lparam.0 & 0xFFFF) as u32;
             let height = ((lparam.0 >> 16) & 0xFFFF) as u32;
+            let size = Size::new(width, height);
             
-            // Get the layout for this window and trigger re-layout
-            if let Ok(map) = get_layouts_map().lock() {
-                if let Some(layout_ptr) = map.get(&hwnd.0) {
-                    if !layout_ptr.0.is_null() {
-                        let layout = &mut *layout_ptr.0;
-                        let new_size = Size::new(width, height);
-                        
-                        if let Err(e) = layout.layout(new_size) {
-                            tracing::error!("Layout failed during resize: {}", e);
-                        }
-                    }
+            let state = match wparam.0 as u32 {
+                SIZE_MAXIMIZED => Some(WindowState::Maximized),
+                SIZE_MINIMIZED => Some(WindowState::Minimized),
+                SIZE_RESTORED => Some(WindowState::Normal),
+                _ => None,
+            };
+            if let Some(state) = state {
+                notify_state_changed(hwnd, state);
+            }
+            
+            // While the user drags the frame, WM_SIZE arrives for every pixel;
+            // keep only the latest size and lay out on the next timer tick
+            let deferred = match get_size_moves_map().lock().unwrap().get_mut(&hwnd.0) {
+                Some(pending) => {
+                    *pending = Some(size);
+                    true
                 }
+                None => false,
+            };
+            if !deferred {
+                layout_window(hwnd, size);
             }
             
             DefWindowProcW(hwnd, msg, wparam, lparam)
         }
+        WM_ENTERSIZEMOVE => {
+            get_size_moves_map().lock().unwrap().insert(hwnd.0, None);
+            SetTimer(hwnd, RELAYOUT_TIMER_ID, RELAYOUT_INTERVAL_MS, None);
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
+        WM_TIMER if wparam.0 == RELAYOUT_TIMER_ID => {
+            let pending = get_size_moves_map()
+                .lock()
+                .unwrap()
+                .get_mut(&hwnd.0)
+                .and_then(Option::take);
+            if let Some(size) = pending {
+                layout_window(hwnd, size);
+            }
+            LRESULT(0)
+        }
+        WM_EXITSIZEMOVE => {
+            let _ = KillTimer(hwnd, RELAYOUT_TIMER_ID);
+            let pending = get_size_moves_map().lock().unwrap().remove(&hwnd.0).flatten();
+            if let Some(size) = pending {
+                layout_window(hwnd, size);
+            }
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
         WM_COMMAND => {
             // Handle button clicks, checkbox changes, listbox selections
             // HIWORD(wparam) = notification code, LOWORD(wparam) = control ID
@@ -276,15 +1285,29 @@ unsafe extern "system" fn window_proc(
                 notification_code
             );
             
-            // Look up and invoke callback
-            if let Ok(mut map) = get_callbacks_map().lock() {
-                if let Some(callback_ptr) = map.get_mut(&control_hwnd.0) {
-                    if !callback_ptr.0.is_null() {
-                        // Safety: Callback pointer is valid as long as widget exists
-                        // Widget Drop implementations must unregister callbacks
-                        let callback = &mut *callback_ptr.0;
-                        callback();
-                    }
+            // Look up the callback, releasing the lock before invoking it so the
+            // callback can create or destroy widgets (which register/unregister)
+            let command_id = (wparam.0 & 0xFFFF) as u16;
+            let callback_ptr = if control_hwnd.0 == 0 {
+                // No control: an accelerator (code 1) or menu (code 0) command
+                get_command_callbacks_map().lock().ok().and_then(|map| map.get(&command_id).copied())
+            } else {
+                // Widgets with a control ID first, then controls registered by HWND
+                widget_command_callback(command_id, notification_code).or_else(|| {
+                    get_callbacks_map().lock().ok().and_then(|map| {
+                        map.get(&(control_hwnd.0, Some(notification_code)))
+                            .or_else(|| map.get(&(control_hwnd.0, None)))
+                            .copied()
+                    })
+                })
+            };
+            
+            if let Some(callback_ptr) = callback_ptr {
+                if !callback_ptr.0.is_null() {
+                    // Safety: Callback pointer is valid as long as widget exists
+                    // Widget Drop implementations must unregister callbacks
+                    let callback = &mut *callback_ptr.0;
+                    callback();
                 }
             }
             
@@ -294,6 +1317,219 @@ unsafe extern "system" fn window_proc(
     }
 }
 
+/// Set the constant alpha of a layered window
+fn apply_opacity(hwnd: HWND, opacity: f32) -> Result<()> {
+    unsafe {
+        SetLayeredWindowAttributes(hwnd, COLORREF(0), opacity_to_alpha(opacity), LWA_ALPHA)
+            .map_err(|e| Error::OperationFailed(format!("SetLayeredWindowAttributes failed: {}", e)))
+    }
+}
+
+/// Convert an opacity in `0.0..=1.0` to a layered window alpha
+fn opacity_to_alpha(opacity: f32) -> u8 {
+    (opacity * 255.0).round() as u8
+}
+
+/// Convert a straight-alpha ARGB pixel to premultiplied alpha
+fn premultiply(pixel: u32) -> u32 {
+    let alpha = pixel >> 24;
+    let scale = |shift: u32| (((pixel >> shift) & 0xFF) * alpha / 255) << shift;
+    (alpha << 24) | scale(16) | scale(8) | scale(0)
+}
+
+/// Lay out a window's widgets for a new client size, moving them in one batch
+unsafe fn layout_window(hwnd: HWND, size: Size) {
+    let _span = tracing::debug_span!("resize", hwnd = hwnd.0, width = size.width, height = size.height).entered();
+    
+    if let Ok(map) = get_layouts_map().lock() {
+        if let Some(layout_ptr) = map.get(&hwnd.0) {
+            if !layout_ptr.0.is_null() {
+                let layout = &mut *layout_ptr.0;
+                layout.set_scale_factor(window_scale(hwnd));
+                if let Err(e) = with_deferred_positioning(|| layout.layout(size)) {
+                    tracing::error!("Layout failed during resize: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Get the DPI scale factor for a window
+/// 
+/// Processes that are not DPI aware are always told 96 DPI, so their DIP
+/// constraints lay out at 1:1 and Windows stretches the window instead.
+fn window_scale(hwnd: HWND) -> f32 {
+    match unsafe { GetDpiForWindow(hwnd) } {
+        // Zero for an invalid window
+        0 => 1.0,
+        dpi => luma_core::geometry::scale_for_dpi(dpi),
+    }
+}
+
+/// Advance a fade animation by one timer tick
+fn step_fade(hwnd: HWND) {
+    let progress = {
+        let mut map = get_fades_map().lock().unwrap();
+        let Some(fade) = map.get(&hwnd.0) else {
+            return;
+        };
+        let progress = if fade.duration.is_zero() {
+            1.0
+        } else {
+            (fade.start.elapsed().as_secs_f32() / fade.duration.as_secs_f32()).min(1.0)
+        };
+        if progress >= 1.0 {
+            map.remove(&hwnd.0);
+        }
+        progress
+    };
+    
+    if progress >= 1.0 {
+        unsafe {
+            let _ = KillTimer(hwnd, FADE_TIMER_ID);
+        }
+    }
+    if let Err(e) = apply_opacity(hwnd, progress) {
+        tracing::error!("Fade step failed: {}", e);
+    }
+}
+
+/// Read the paths of the files in a drop
+unsafe fn dropped_file_paths(hdrop: HDROP) -> Vec<PathBuf> {
+    // Index 0xFFFFFFFF asks for the number of files
+    let count = DragQueryFileW(hdrop, u32::MAX, None);
+    (0..count)
+        .map(|index| {
+            // Calling without a buffer returns the length, excluding the nul
+            let len = DragQueryFileW(hdrop, index, None) as usize;
+            let mut buffer = vec![0u16; len + 1];
+            let copied = DragQueryFileW(hdrop, index, Some(&mut buffer)) as usize;
+            PathBuf::from(String::from_utf16_lossy(&buffer[..copied]))
+        })
+        .collect()
+}
+
+/// Check whether a window draws its own frame
+fn has_custom_frame(hwnd: HWND) -> bool {
+    get_chrome_map()
+        .lock()
+        .map(|map| map.get(&hwnd.0).is_some_and(|chrome| chrome.custom_frame))
+        .unwrap_or(false)
+}
+
+/// Check whether a window has a system backdrop applied
+fn has_backdrop(hwnd: HWND) -> bool {
+    get_chrome_map()
+        .lock()
+        .map(|map| map.get(&hwnd.0).is_some_and(|chrome| chrome.backdrop))
+        .unwrap_or(false)
+}
+
+/// Get the background brush set with `set_background`, if any
+fn custom_background(hwnd: HWND) -> Option<HBRUSH> {
+    get_chrome_map()
+        .lock()
+        .ok()
+        .and_then(|map| map.get(&hwnd.0).and_then(|chrome| chrome.background))
+}
+
+/// Hit-test resize borders and the drag region of a window
+/// 
+/// Returns `None` when the default window procedure should decide.
+unsafe fn custom_frame_hit_test(hwnd: HWND, screen_x: i32, screen_y: i32) -> Option<u32> {
+    let (custom_frame, drag_region) = {
+        let map = get_chrome_map().lock().ok()?;
+        let chrome = map.get(&hwnd.0)?;
+        (chrome.custom_frame, chrome.drag_region)
+    };
+    
+    // Without a system frame, resize borders have to be reported by hand
+    let resizable = (GetWindowLongPtrW(hwnd, GWL_STYLE) as u32 & WS_THICKFRAME.0) != 0;
+    if custom_frame && resizable && !IsZoomed(hwnd).as_bool() {
+        let mut window_rect = RECT::default();
+        if GetWindowRect(hwnd, &mut window_rect).is_ok() {
+            let border = GetSystemMetrics(SM_CXSIZEFRAME) + GetSystemMetrics(SM_CXPADDEDBORDER);
+            let left = screen_x < window_rect.left + border;
+            let right = screen_x >= window_rect.right - border;
+            let top = screen_y < window_rect.top + border;
+            let bottom = screen_y >= window_rect.bottom - border;
+            
+            let hit = match (left, right, top, bottom) {
+                (true, _, true, _) => Some(HTTOPLEFT),
+                (_, true, true, _) => Some(HTTOPRIGHT),
+                (true, _, _, true) => Some(HTBOTTOMLEFT),
+                (_, true, _, true) => Some(HTBOTTOMRIGHT),
+                (true, _, _, _) => Some(HTLEFT),
+                (_, true, _, _) => Some(HTRIGHT),
+                (_, _, true, _) => Some(HTTOP),
+                (_, _, _, true) => Some(HTBOTTOM),
+                _ => None,
+            };
+            if hit.is_some() {
+                return hit;
+            }
+        }
+    }
+    
+    let region = drag_region?;
+    let mut point = POINT { x: screen_x, y: screen_y };
+    if !ScreenToClient(hwnd, &mut point).as_bool() {
+        return None;
+    }
+    
+    if region.contains(Point::new(point.x, point.y)) {
+        Some(HTCAPTION)
+    } else {
+        None
+    }
+}
+
+/// Convert a Color to a Win32 COLORREF (0x00BBGGRR, alpha ignored)
+fn color_to_colorref(color: Color) -> COLORREF {
+    COLORREF(color.to_win32_colorref())
+}
+
+/// Get the current state of a window
+fn window_state(hwnd: HWND) -> WindowState {
+    unsafe {
+        if IsIconic(hwnd).as_bool() {
+            WindowState::Minimized
+        } else if IsZoomed(hwnd).as_bool() {
+            WindowState::Maximized
+        } else {
+            WindowState::Normal
+        }
+    }
+}
+
+/// Invoke the window's state callback if the state differs from the last one reported
+/// 
+/// WM_SIZE reports SIZE_RESTORED for every resize of a normal window, so
+/// only actual transitions reach the callback.
+unsafe fn notify_state_changed(hwnd: HWND, state: WindowState) {
+    // Copy the pointer out so the callback runs without the lock held
+    let callback = get_state_callbacks_map().lock().ok().and_then(|mut map| {
+        let entry = map.get_mut(&hwnd.0)?;
+        let changed = entry.state != state;
+        entry.state = state;
+        changed.then_some(entry.callback)
+    });
+    
+    if let Some(callback) = callback {
+        tracing::debug!("Window state changed to {:?}", state);
+        (*callback)(state);
+    }
+}
+
+/// Get the ShowWindow command that puts a window in a state
+fn show_command(state: WindowState) -> SHOW_WINDOW_CMD {
+    match state {
+        WindowState::Normal => SW_SHOWNORMAL,
+        WindowState::Maximized => SW_SHOWMAXIMIZED,
+        WindowState::Minimized => SW_SHOWMINIMIZED,
+    }
+}
+
 /// Convert WindowFlags to Win32 WINDOW_STYLE
 fn window_flags_to_style(flags: WindowFlags) -> WINDOW_STYLE {
     let mut style = WS_OVERLAPPEDWINDOW;
@@ -312,7 +1548,166 @@ fn window_flags_to_style(flags: WindowFlags) -> WINDOW_STYLE {
     }
     if !flags.contains(WindowFlags::TITLED) {
         style = WS_POPUP | WS_BORDER;
+        
+        // Borderless windows keep a (custom-drawn) resize frame if resizable
+        if flags.contains(WindowFlags::RESIZABLE) {
+            style = WS_POPUP | WS_THICKFRAME;
+        }
     }
     
     style
 }
+
+/// Convert WindowFlags to Win32 WINDOW_EX_STYLE
+fn window_flags_to_ex_style(flags: WindowFlags) -> WINDOW_EX_STYLE {
+    let mut ex_style = WINDOW_EX_STYLE(0);
+    
+    if flags.contains(WindowFlags::ALWAYS_ON_TOP) {
+        ex_style |= WS_EX_TOPMOST;
+    }
+    if flags.contains(WindowFlags::TOOL_WINDOW) {
+        ex_style |= WS_EX_TOOLWINDOW;
+    }
+    
+    ex_style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_standard_style() {
+        assert_eq!(window_flags_to_style(WindowFlags::standard()), WS_OVERLAPPEDWINDOW);
+        assert_eq!(window_flags_to_ex_style(WindowFlags::standard()), WINDOW_EX_STYLE(0));
+    }
+    
+    #[test]
+    fn test_dialog_style() {
+        let style = window_flags_to_style(WindowFlags::dialog());
+        assert!(style.contains(WS_CAPTION | WS_SYSMENU));
+        assert!(!style.contains(WS_THICKFRAME));
+        assert!(!style.contains(WS_MINIMIZEBOX));
+        assert!(!style.contains(WS_MAXIMIZEBOX));
+    }
+    
+    #[test]
+    fn test_borderless_style() {
+        assert_eq!(window_flags_to_style(WindowFlags::borderless()), WS_POPUP | WS_BORDER);
+    }
+    
+    #[test]
+    fn test_color_to_colorref() {
+        assert_eq!(color_to_colorref(Color::rgb(0x11, 0x22, 0x33)), COLORREF(0x0033_2211));
+    }
+    
+    #[test]
+    fn test_resizable_borderless_style() {
+        let flags = WindowFlags::borderless() | WindowFlags::RESIZABLE;
+        assert_eq!(window_flags_to_style(flags), WS_POPUP | WS_THICKFRAME);
+    }
+    
+    #[test]
+    fn test_tool_window_style() {
+        let flags = WindowFlags::tool_window();
+        let style = window_flags_to_style(flags);
+        assert!(style.contains(WS_CAPTION | WS_SYSMENU | WS_THICKFRAME));
+        assert!(!style.contains(WS_MINIMIZEBOX));
+        assert_eq!(window_flags_to_ex_style(flags), WS_EX_TOOLWINDOW);
+    }
+    
+    #[test]
+    fn test_always_on_top_ex_style() {
+        let flags = WindowFlags::tool_window() | WindowFlags::ALWAYS_ON_TOP;
+        assert_eq!(window_flags_to_ex_style(flags), WS_EX_TOOLWINDOW | WS_EX_TOPMOST);
+    }
+    
+    #[test]
+    fn test_opacity_to_alpha() {
+        assert_eq!(opacity_to_alpha(0.0), 0);
+        assert_eq!(opacity_to_alpha(0.5), 128);
+        assert_eq!(opacity_to_alpha(1.0), 255);
+    }
+    
+    #[test]
+    fn test_premultiply() {
+        // Opaque pixels are unchanged and transparent ones become black
+        assert_eq!(premultiply(0xFF11_2233), 0xFF11_2233);
+        assert_eq!(premultiply(0x00FF_FFFF), 0x0000_0000);
+        
+        // Each channel is scaled by the alpha on its own
+        assert_eq!(premultiply(0x80FF_0000), 0x8080_0000);
+        assert_eq!(premultiply(0x8000_FF00), 0x8000_8000);
+        assert_eq!(premultiply(0x8000_00FF), 0x8000_0080);
+        assert_eq!(premultiply(0x40FF_8040), 0x4040_2010);
+    }
+    
+    #[test]
+    fn test_panicking_button_callback_is_caught() {
+        // No real window is needed: WM_COMMAND only looks up the control HWND
+        let control = 0x1234;
+        let mut on_click: Box<dyn FnMut()> = Box::new(|| panic!("button callback failed"));
+        register_callback(control, on_click.as_mut() as *mut dyn FnMut());
+        
+        let result = unsafe { window_proc(HWND(0), WM_COMMAND, WPARAM(0), LPARAM(control)) };
+        assert_eq!(result, LRESULT(0));
+        
+        unregister_callback(control);
+    }
+    
+    #[test]
+    fn test_command_dispatches_by_control_id() {
+        use crate::control_ids::{assign_control_id, release_control_id};
+        use std::cell::Cell;
+        use std::rc::Rc;
+        
+        const BN_CLICKED: u32 = 0;
+        const BN_SETFOCUS: u32 = 6;
+        
+        // The control HWND only has to be non-null; dispatch goes through the ID
+        let widget = WidgetId::new();
+        let control_id = assign_control_id(0x5678, widget).unwrap();
+        let clicked = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&clicked);
+        let mut on_click: Box<dyn FnMut()> = Box::new(move || counter.set(counter.get() + 1));
+        register_widget_callback(widget, Some(BN_CLICKED), on_click.as_mut() as *mut dyn FnMut());
+        
+        let command = |code: u32| WPARAM(((code as usize) << 16) | control_id as usize);
+        unsafe {
+            window_proc(HWND(0), WM_COMMAND, command(BN_CLICKED), LPARAM(0x5678));
+            window_proc(HWND(0), WM_COMMAND, command(BN_SETFOCUS), LPARAM(0x5678));
+        }
+        assert_eq!(clicked.get(), 1);
+        
+        unregister_widget_callback(widget);
+        release_control_id(widget);
+        unsafe {
+            window_proc(HWND(0), WM_COMMAND, command(BN_CLICKED), LPARAM(0x5678));
+        }
+        assert_eq!(clicked.get(), 1);
+    }
+    
+    #[test]
+    fn test_button_screen_bounds() {
+        use crate::button::Win32Button;
+        use luma_core::{ButtonFlags, traits::ButtonBackend};
+        
+        let window = Win32Window::new("Screen bounds", 300, 200, WindowFlags::default()).unwrap();
+        let button = Win32Button::new(
+            window.raw_handle(),
+            "OK",
+            Point::new(10, 20),
+            Size::new(80, 25),
+            ButtonFlags::empty(),
+        ).unwrap();
+        
+        // The button sits at its client position offset by the client area's screen origin
+        let mut origin = POINT::default();
+        unsafe {
+            let _ = ClientToScreen(window.hwnd, &mut origin);
+        }
+        let bounds = screen_bounds(button.hwnd()).unwrap();
+        assert_eq!(bounds, Rect::new(origin.x + 10, origin.y + 20, 80, 25));
+        assert_eq!(bounds.bottom_left(), Point::new(origin.x + 10, origin.y + 45));
+    }
+}
@@ -0,0 +1,166 @@
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use luma_core::{Result, Error};
+use std::cell::RefCell;
+
+/// A child window move queued during a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingMove {
+    hwnd: isize,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+thread_local! {
+    /// Moves queued by `set_child_bounds`, or `None` when no batch is open
+    static BATCH: RefCell<Option<Vec<PendingMove>>> = const { RefCell::new(None) };
+}
+
+/// Applies the open batch when the outermost `with_deferred_positioning` returns
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let moves = BATCH.with(|batch| batch.borrow_mut().take()).unwrap_or_default();
+        apply_moves(&moves);
+    }
+}
+
+/// Run `f`, moving all child windows it positions in one batch
+/// 
+/// `set_child_bounds` calls made inside `f` are queued and applied together
+/// with `DeferWindowPos` when `f` returns, so siblings move in a single
+/// repaint instead of one at a time. Nested calls join the outer batch.
+pub fn with_deferred_positioning<R>(f: impl FnOnce() -> R) -> R {
+    let started = BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        if batch.is_some() {
+            false
+        } else {
+            *batch = Some(Vec::new());
+            true
+        }
+    });
+    let _guard = started.then_some(BatchGuard);
+    f()
+}
+
+/// Move and resize a child window, deferring it if a batch is open
+pub fn set_child_bounds(hwnd: HWND, x: i32, y: i32, width: i32, height: i32) -> Result<()> {
+    let queued = BATCH.with(|batch| match batch.borrow_mut().as_mut() {
+        Some(moves) => {
+            queue_move(moves, PendingMove { hwnd: hwnd.0, x, y, width, height });
+            true
+        }
+        None => false,
+    });
+    if queued {
+        return Ok(());
+    }
+    
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            HWND::default(),
+            x,
+            y,
+            width,
+            height,
+            SWP_NOZORDER | SWP_NOACTIVATE,
+        ).map_err(|e| Error::OperationFailed(format!("SetWindowPos failed: {}", e)))
+    }
+}
+
+/// Add a move to the batch, replacing any earlier move of the same window
+fn queue_move(moves: &mut Vec<PendingMove>, pending: PendingMove) {
+    match moves.iter_mut().find(|queued| queued.hwnd == pending.hwnd) {
+        Some(queued) => *queued = pending,
+        None => moves.push(pending),
+    }
+}
+
+/// Apply queued moves, one `DeferWindowPos` batch per parent window
+/// 
+/// Every window in a batch must share a parent, so nested containers get a
+/// batch each. If a batch cannot be built its moves fall back to
+/// `SetWindowPos`.
+fn apply_moves(moves: &[PendingMove]) {
+    let mut groups: Vec<(isize, Vec<PendingMove>)> = Vec::new();
+    for pending in moves {
+        let parent = unsafe { GetParent(HWND(pending.hwnd)) }.0;
+        match groups.iter_mut().find(|(group_parent, _)| *group_parent == parent) {
+            Some((_, group)) => group.push(*pending),
+            None => groups.push((parent, vec![*pending])),
+        }
+    }
+    
+    for (_, group) in groups {
+        if let Err(e) = defer_group(&group) {
+            tracing::warn!("DeferWindowPos batch failed, moving windows one at a time: {}", e);
+            for pending in &group {
+                unsafe {
+                    let _ = SetWindowPos(
+                        HWND(pending.hwnd),
+                        HWND::default(),
+                        pending.x,
+                        pending.y,
+                        pending.width,
+                        pending.height,
+                        SWP_NOZORDER | SWP_NOACTIVATE,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Move a group of sibling windows with one `DeferWindowPos` batch
+fn defer_group(group: &[PendingMove]) -> windows::core::Result<()> {
+    unsafe {
+        let mut hdwp = BeginDeferWindowPos(group.len() as i32)?;
+        for pending in group {
+            // On failure the system frees the batch, so it must not be ended
+            hdwp = DeferWindowPos(
+                hdwp,
+                HWND(pending.hwnd),
+                HWND::default(),
+                pending.x,
+                pending.y,
+                pending.width,
+                pending.height,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            )?;
+        }
+        EndDeferWindowPos(hdwp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_queue_move_keeps_latest_bounds() {
+        let mut moves = Vec::new();
+        queue_move(&mut moves, PendingMove { hwnd: 1, x: 0, y: 0, width: 10, height: 10 });
+        queue_move(&mut moves, PendingMove { hwnd: 2, x: 5, y: 5, width: 10, height: 10 });
+        queue_move(&mut moves, PendingMove { hwnd: 1, x: 20, y: 0, width: 30, height: 10 });
+        
+        assert_eq!(moves, [
+            PendingMove { hwnd: 1, x: 20, y: 0, width: 30, height: 10 },
+            PendingMove { hwnd: 2, x: 5, y: 5, width: 10, height: 10 },
+        ]);
+    }
+    
+    #[test]
+    fn test_nested_batches_join_outer() {
+        with_deferred_positioning(|| {
+            with_deferred_positioning(|| {});
+            // The inner call returning must not close the outer batch
+            assert!(BATCH.with(|batch| batch.borrow().is_some()));
+        });
+        assert!(BATCH.with(|batch| batch.borrow().is_none()));
+    }
+}
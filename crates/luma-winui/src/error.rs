@@ -1,6 +1,7 @@
 //! Error types for WinUI backend.
 
 use thiserror::Error;
+use windows::Win32::Foundation::E_INVALIDARG;
 
 /// Result type for WinUI operations.
 pub type Result<T> = std::result::Result<T, WinUIError>;
@@ -24,6 +25,10 @@ pub enum WinUIError {
     #[error("Failed to create widget: {0}")]
     WidgetCreation(String),
 
+    /// An argument was out of range or otherwise invalid.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     /// XAML parsing error (when xaml-support feature is enabled).
     #[cfg(feature = "xaml-support")]
     #[error("XAML error: {0}")]
@@ -46,3 +51,75 @@ impl WinUIError {
         }
     }
 }
+
+/// Map WinUI errors onto the backend-neutral core error, so `luma-winui`
+/// can implement the `luma-core` backend traits.
+///
+/// A missing or failed runtime becomes `NotInitialized`, invalid arguments
+/// (including COM calls failing with `E_INVALIDARG`) become
+/// `InvalidParameter`, and other COM failures become `Platform`.
+impl From<WinUIError> for luma_core::Error {
+    fn from(error: WinUIError) -> Self {
+        match error {
+            WinUIError::Windows(e) if e.code() == E_INVALIDARG => {
+                luma_core::Error::InvalidParameter(e.message().to_string())
+            }
+            WinUIError::Windows(e) => luma_core::Error::Platform(e.to_string()),
+            WinUIError::RuntimeInitialization(_) => luma_core::Error::NotInitialized,
+            WinUIError::WindowCreation(message) => luma_core::Error::WindowCreation(message),
+            WinUIError::WidgetCreation(message) => luma_core::Error::WidgetCreation(message),
+            WinUIError::InvalidArgument(message) => luma_core::Error::InvalidParameter(message),
+            #[cfg(feature = "xaml-support")]
+            WinUIError::Xaml(e) => luma_core::Error::OperationFailed(e.to_string()),
+            WinUIError::Core(e) => e,
+            WinUIError::Custom { message } => luma_core::Error::OperationFailed(message),
+        }
+    }
+}
+
+// The conversion links against the Windows error APIs, so only runs on Windows
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_missing_maps_to_not_initialized() {
+        let error: luma_core::Error = WinUIError::RuntimeInitialization("bootstrap failed".to_string()).into();
+        assert!(matches!(error, luma_core::Error::NotInitialized));
+    }
+
+    #[test]
+    fn test_invalid_argument_maps_to_invalid_parameter() {
+        let error: luma_core::Error = WinUIError::InvalidArgument("width".to_string()).into();
+        assert!(matches!(error, luma_core::Error::InvalidParameter(message) if message == "width"));
+    }
+
+    #[test]
+    fn test_creation_and_custom_errors_keep_their_messages() {
+        let error: luma_core::Error = WinUIError::WindowCreation("no window".to_string()).into();
+        assert!(matches!(error, luma_core::Error::WindowCreation(message) if message == "no window"));
+        
+        let error: luma_core::Error = WinUIError::WidgetCreation("no button".to_string()).into();
+        assert!(matches!(error, luma_core::Error::WidgetCreation(message) if message == "no button"));
+        
+        let error: luma_core::Error = WinUIError::custom("oops").into();
+        assert!(matches!(error, luma_core::Error::OperationFailed(message) if message == "oops"));
+    }
+
+    #[test]
+    fn test_core_errors_pass_through() {
+        let error: luma_core::Error = WinUIError::Core(luma_core::Error::LayoutError("loop".to_string())).into();
+        assert!(matches!(error, luma_core::Error::LayoutError(message) if message == "loop"));
+    }
+
+    #[test]
+    fn test_com_failures_map_by_hresult() {
+        use windows::Win32::Foundation::E_FAIL;
+        
+        let error: luma_core::Error = WinUIError::Windows(E_INVALIDARG.into()).into();
+        assert!(matches!(error, luma_core::Error::InvalidParameter(_)));
+        
+        let error: luma_core::Error = WinUIError::Windows(E_FAIL.into()).into();
+        assert!(matches!(error, luma_core::Error::Platform(_)));
+    }
+}
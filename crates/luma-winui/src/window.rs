@@ -2,6 +2,8 @@
 
 use crate::error::Result;
 use crate::runtime::WinUIRuntime;
+use luma_core::traits::WindowBackend;
+use luma_core::{Size, WindowFlags};
 
 /// WinUI window.
 pub struct WinUIWindow {
@@ -35,3 +37,55 @@ impl WinUIWindow {
         todo!("WinUIWindow::set_title not yet implemented")
     }
 }
+
+// Errors are converted to `luma_core::Error` so this backend can stand in for Win32
+impl WindowBackend for WinUIWindow {
+    fn new(title: &str, width: u32, height: u32, _flags: WindowFlags) -> luma_core::Result<Self> {
+        Ok(WinUIWindow::new(title, width, height)?)
+    }
+
+    fn set_title(&mut self, title: &str) -> luma_core::Result<()> {
+        Ok(WinUIWindow::set_title(self, title)?)
+    }
+
+    fn get_title(&self) -> luma_core::Result<String> {
+        Err(luma_core::Error::OperationFailed("WinUIWindow::get_title is not supported yet".into()))
+    }
+
+    fn set_size(&mut self, _width: u32, _height: u32) -> luma_core::Result<()> {
+        Err(luma_core::Error::OperationFailed("WinUIWindow::set_size is not supported yet".into()))
+    }
+
+    fn show(&mut self) -> luma_core::Result<()> {
+        Ok(WinUIWindow::show(self)?)
+    }
+
+    fn hide(&mut self) -> luma_core::Result<()> {
+        Ok(WinUIWindow::hide(self)?)
+    }
+
+    // There is no native window behind this type yet
+    fn raw_handle(&self) -> *mut std::ffi::c_void {
+        std::ptr::null_mut()
+    }
+
+    fn get_client_size(&self) -> luma_core::Result<Size> {
+        Err(luma_core::Error::OperationFailed("WinUIWindow::get_client_size is not supported yet".into()))
+    }
+}
+
+// Unlike the error conversions, these stubs never reach the Windows APIs, so
+// they are tested on every platform
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_operations_return_errors() {
+        let mut window = WinUIWindow {};
+        assert!(matches!(window.get_title(), Err(luma_core::Error::OperationFailed(_))));
+        assert!(matches!(window.set_size(800, 600), Err(luma_core::Error::OperationFailed(_))));
+        assert!(matches!(window.get_client_size(), Err(luma_core::Error::OperationFailed(_))));
+        assert!(window.raw_handle().is_null());
+    }
+}
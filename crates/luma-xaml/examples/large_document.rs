@@ -0,0 +1,69 @@
+//! Measure string interning on a generated 10,000-element document
+//!
+//! Parses the same document with and without `ParserSettings::intern_strings`
+//! and prints the time taken and the number of heap allocations the finished
+//! document keeps alive.
+
+use luma_xaml::parser::{XamlParser, ParserSettings};
+use luma_xaml::types::TypeRegistry;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::time::Instant;
+
+/// Counts live allocations so the cost of a parsed document can be seen
+struct CountingAlloc;
+
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(1, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+fn main() {
+    let xaml = generate(10_000);
+
+    for (label, settings) in [
+        ("plain", ParserSettings::new()),
+        ("interned", ParserSettings::new().intern_strings()),
+    ] {
+        let parser = XamlParser::new(TypeRegistry::new()).with_settings(settings);
+        let before = LIVE.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let doc = parser.parse_string(&xaml).expect("generated XAML should parse");
+        let elapsed = start.elapsed();
+        let live = LIVE.load(Ordering::Relaxed) - before;
+
+        println!(
+            "{:>8}: {} elements in {:?}, {} live allocations",
+            label,
+            doc.root.child_elements().count() + 1,
+            elapsed,
+            live
+        );
+    }
+}
+
+/// Build a panel holding `count - 1` alternating buttons and text blocks
+fn generate(count: usize) -> String {
+    let mut xaml = String::from(r#"<StackPanel xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation">"#);
+    for i in 1..count {
+        if i % 2 == 0 {
+            xaml.push_str(&format!(r#"<Button Content="Button {}"/>"#, i));
+        } else {
+            xaml.push_str(&format!(r#"<TextBlock Text="Line {}"/>"#, i));
+        }
+    }
+    xaml.push_str("</StackPanel>");
+    xaml
+}
@@ -1,24 +1,24 @@
 //! Property value converters - convert strings to typed values.
 
 use crate::error::{Result, XamlError};
+use crate::model::XamlValue;
+use crate::types::XamlTypeName;
+use std::collections::HashMap;
+
+pub use luma_core::Color;
 
 /// Convert a string to a Brush value.
 ///
 /// Supports:
 /// - Named colors: "Red", "Blue", "Transparent"
-/// - Hex colors: "#FF0000", "#AAFF0000"
+/// - Hex colors: "#F00", "#FF0000", "#AAFF0000" (see [`parse_color`])
 pub fn parse_brush(value: &str) -> Result<String> {
     let trimmed = value.trim();
     
     // Hex color
     if trimmed.starts_with('#') {
-        // Validate hex format
-        let hex = &trimmed[1..];
-        if hex.len() == 6 || hex.len() == 8 {
-            // Validate all characters are hex digits
-            if hex.chars().all(|c| c.is_ascii_hexdigit()) {
-                return Ok(trimmed.to_string());
-            }
+        if Color::from_hex(trimmed).is_some() {
+            return Ok(trimmed.to_string());
         }
         return Err(XamlError::InvalidAttributeValue {
             attribute: "Brush".to_string(),
@@ -39,6 +39,50 @@ pub fn parse_brush(value: &str) -> Result<String> {
     })
 }
 
+/// Parse a color value.
+///
+/// Supports hex colors in the forms `#RGB`, `#ARGB`, `#RRGGBB` and
+/// `#AARRGGBB`, with alpha first as in XAML, and the names known to
+/// [`Color::from_name`], such as `Red` or `Transparent`.
+pub fn parse_color(value: &str) -> Result<Color> {
+    let trimmed = value.trim();
+    let color = if trimmed.starts_with('#') {
+        Color::from_hex(trimmed)
+    } else {
+        Color::from_name(trimmed)
+    };
+    color.ok_or_else(|| XamlError::InvalidAttributeValue {
+        attribute: "Color".to_string(),
+        line: 0,
+        details: format!("Invalid color value: {}", trimmed),
+    })
+}
+
+/// Parse a boolean value.
+///
+/// Case-insensitively accepts:
+/// - "true" / "false"
+/// - "1" / "0"
+/// - "yes" / "no"
+/// - "on" / "off"
+///
+/// Returns `None` for anything else, so callers can fall back to treating
+/// the value as a string.
+pub fn parse_bool(value: &str) -> Option<bool> {
+    let trimmed = value.trim();
+    
+    const TRUE_SPELLINGS: [&str; 4] = ["true", "1", "yes", "on"];
+    const FALSE_SPELLINGS: [&str; 4] = ["false", "0", "no", "off"];
+    
+    if TRUE_SPELLINGS.iter().any(|s| trimmed.eq_ignore_ascii_case(s)) {
+        Some(true)
+    } else if FALSE_SPELLINGS.iter().any(|s| trimmed.eq_ignore_ascii_case(s)) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
 /// Represents a thickness value (left, top, right, bottom).
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Thickness {
@@ -422,16 +466,213 @@ pub fn parse_vertical_alignment(value: &str) -> Result<VerticalAlignment> {
     }
 }
 
+/// Stretch enum describing how content such as an image fills its area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stretch {
+    /// Keep the content's original size
+    None,
+    /// Scale each axis to fill the area, ignoring aspect ratio
+    Fill,
+    /// Scale to fit inside the area, preserving aspect ratio
+    Uniform,
+    /// Scale to cover the area, preserving aspect ratio and clipping
+    UniformToFill,
+}
+
+/// Parse a Stretch value.
+///
+/// Supports:
+/// - "None"
+/// - "Fill"
+/// - "Uniform"
+/// - "UniformToFill"
+pub fn parse_stretch(value: &str) -> Result<Stretch> {
+    match value.trim() {
+        "None" => Ok(Stretch::None),
+        "Fill" => Ok(Stretch::Fill),
+        "Uniform" => Ok(Stretch::Uniform),
+        "UniformToFill" => Ok(Stretch::UniformToFill),
+        _ => Err(XamlError::InvalidAttributeValue {
+            attribute: "Stretch".to_string(),
+            line: 0,
+            details: format!("Invalid stretch value: {}. Expected 'None', 'Fill', 'Uniform', or 'UniformToFill'", value),
+        }),
+    }
+}
+
+/// A function converting an attribute string into a typed value.
+pub type ValueConverter = fn(&str) -> Result<XamlValue>;
+
+/// Converters for attribute values, keyed by the declared type of the property.
+///
+/// When an attribute names a property whose type has a converter, the parser
+/// uses it instead of guessing between integer, float, boolean and string
+/// (if `ParserFlags::VALIDATE_TYPES` is set, as it is by default).
+/// A converter registered under a type name with an empty namespace applies
+/// to every type with that local name; the built-in converters are registered
+/// this way because dialects place types such as `Thickness` in different
+/// namespaces.
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::converters::ConverterRegistry;
+/// use luma_xaml::{XamlTypeName, XamlValue};
+///
+/// let mut converters = ConverterRegistry::new();
+/// converters.register(XamlTypeName::new("", "Percentage"), |value| {
+///     let number = value.trim().trim_end_matches('%').parse::<f64>()
+///         .map_err(|e| luma_xaml::XamlError::custom(e.to_string()))?;
+///     Ok(XamlValue::Float(number / 100.0))
+/// });
+///
+/// let convert = converters.get(&XamlTypeName::new("http://example", "Percentage")).unwrap();
+/// assert_eq!(convert("50%")?, XamlValue::Float(0.5));
+/// # Ok::<(), luma_xaml::XamlError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConverterRegistry {
+    converters: HashMap<XamlTypeName, ValueConverter>,
+}
+
+impl ConverterRegistry {
+    /// Create a registry holding the built-in converters.
+    ///
+    /// Primitive types (`String`, `Int32`, `Int64`, `Double`, `Single`,
+    /// `Boolean`) become typed values. Structured types (`Thickness`,
+    /// `CornerRadius`, `GridLength`, `Brush`, `Color`) and enumerations are validated
+    /// and kept as their trimmed text, for the matching `parse_*` function.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        
+        registry.register_builtin("String", |value| Ok(XamlValue::String(value.to_string())));
+        registry.register_builtin("Int32", convert_integer);
+        registry.register_builtin("Int64", convert_integer);
+        registry.register_builtin("Double", convert_float);
+        registry.register_builtin("Single", convert_float);
+        registry.register_builtin("Boolean", |value| {
+            parse_bool(value)
+                .map(XamlValue::Boolean)
+                .ok_or_else(|| invalid_value("Boolean", value))
+        });
+        
+        registry.register_builtin("Thickness", |value| validated(value, parse_thickness(value)));
+        registry.register_builtin("CornerRadius", |value| validated(value, parse_corner_radius(value)));
+        registry.register_builtin("GridLength", |value| validated(value, parse_grid_length(value)));
+        registry.register_builtin("Brush", |value| validated(value, parse_brush(value)));
+        registry.register_builtin("Color", |value| validated(value, parse_color(value)));
+        registry.register_builtin("Orientation", |value| validated(value, parse_orientation(value)));
+        registry.register_builtin("Visibility", |value| validated(value, parse_visibility(value)));
+        registry.register_builtin("HorizontalAlignment", |value| validated(value, parse_horizontal_alignment(value)));
+        registry.register_builtin("VerticalAlignment", |value| validated(value, parse_vertical_alignment(value)));
+        registry.register_builtin("Stretch", |value| validated(value, parse_stretch(value)));
+        
+        registry
+    }
+    
+    /// Create a registry with no converters, so every value is guessed.
+    pub fn empty() -> Self {
+        Self {
+            converters: HashMap::new(),
+        }
+    }
+    
+    /// Register a converter for a property type, replacing any existing one.
+    pub fn register(&mut self, type_name: XamlTypeName, converter: ValueConverter) {
+        self.converters.insert(type_name, converter);
+    }
+    
+    /// Find the converter for a property type.
+    ///
+    /// An exact match wins over a converter registered for the local name.
+    pub fn get(&self, type_name: &XamlTypeName) -> Option<ValueConverter> {
+        self.converters
+            .get(type_name)
+            .or_else(|| self.converters.get(&XamlTypeName::new("", type_name.name.as_str())))
+            .copied()
+    }
+    
+    fn register_builtin(&mut self, name: &str, converter: ValueConverter) {
+        self.register(XamlTypeName::new("", name), converter);
+    }
+}
+
+impl Default for ConverterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn convert_integer(value: &str) -> Result<XamlValue> {
+    value.trim()
+        .parse::<i64>()
+        .map(XamlValue::Integer)
+        .map_err(|_| invalid_value("Integer", value))
+}
+
+/// Convert a double, where `Auto` means an unset length (NaN), as in XAML.
+fn convert_float(value: &str) -> Result<XamlValue> {
+    let trimmed = value.trim();
+    if trimmed.eq_ignore_ascii_case("Auto") {
+        return Ok(XamlValue::Float(f64::NAN));
+    }
+    trimmed
+        .parse::<f64>()
+        .map(XamlValue::Float)
+        .map_err(|_| invalid_value("Double", value))
+}
+
+/// Keep a value as text once its parser has accepted it.
+fn validated<T>(value: &str, parsed: Result<T>) -> Result<XamlValue> {
+    parsed.map(|_| XamlValue::String(value.trim().to_string()))
+}
+
+fn invalid_value(type_name: &str, value: &str) -> XamlError {
+    XamlError::InvalidAttributeValue {
+        attribute: type_name.to_string(),
+        line: 0,
+        details: format!("Invalid {} value: {}", type_name, value),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_bool_spellings() {
+        for spelling in ["true", "True", "TRUE", "1", "yes", "Yes", "on", "ON", " true "] {
+            assert_eq!(parse_bool(spelling), Some(true), "{}", spelling);
+        }
+        for spelling in ["false", "False", "FALSE", "0", "no", "NO", "off", "Off"] {
+            assert_eq!(parse_bool(spelling), Some(false), "{}", spelling);
+        }
+    }
+
+    #[test]
+    fn test_parse_bool_rejects_other_values() {
+        for value in ["", "2", "truee", "enabled", "nope", "-1"] {
+            assert_eq!(parse_bool(value), None, "{}", value);
+        }
+    }
+
     #[test]
     fn test_parse_brush_hex() {
         assert_eq!(parse_brush("#FF0000").unwrap(), "#FF0000");
         assert_eq!(parse_brush("#AAFF0000").unwrap(), "#AAFF0000");
     }
 
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#FF0000").unwrap(), Color::RED);
+        assert_eq!(parse_color(" #80FF0000 ").unwrap(), Color::new(255, 0, 0, 128));
+        assert_eq!(parse_color("Transparent").unwrap(), Color::TRANSPARENT);
+        assert!(parse_color("#FF00").is_ok());
+        assert!(parse_color("#FF000").is_err());
+        assert!(parse_color("NotAColor").is_err());
+        assert!(parse_brush("#FF000").is_err());
+    }
+
     #[test]
     fn test_parse_brush_named() {
         assert_eq!(parse_brush("Red").unwrap(), "Red");
@@ -507,6 +748,15 @@ mod tests {
         assert!(parse_visibility("Invalid").is_err());
     }
 
+    #[test]
+    fn test_parse_stretch() {
+        assert_eq!(parse_stretch("None").unwrap(), Stretch::None);
+        assert_eq!(parse_stretch("Fill").unwrap(), Stretch::Fill);
+        assert_eq!(parse_stretch("Uniform").unwrap(), Stretch::Uniform);
+        assert_eq!(parse_stretch(" UniformToFill ").unwrap(), Stretch::UniformToFill);
+        assert!(parse_stretch("Zoom").is_err());
+    }
+
     #[test]
     fn test_parse_horizontal_alignment() {
         assert_eq!(parse_horizontal_alignment("Left").unwrap(), HorizontalAlignment::Left);
@@ -524,4 +774,33 @@ mod tests {
         assert_eq!(parse_vertical_alignment("Stretch").unwrap(), VerticalAlignment::Stretch);
         assert!(parse_vertical_alignment("Invalid").is_err());
     }
+
+    #[test]
+    fn test_converter_registry_builtins() {
+        let converters = ConverterRegistry::new();
+        let double = converters.get(&XamlTypeName::new("System", "Double")).unwrap();
+        assert_eq!(double("1.5").unwrap(), XamlValue::Float(1.5));
+        assert!(matches!(double("Auto").unwrap(), XamlValue::Float(f) if f.is_nan()));
+        assert!(double("wide").is_err());
+        
+        let string = converters.get(&XamlTypeName::new("System", "String")).unwrap();
+        assert_eq!(string("42").unwrap(), XamlValue::String("42".to_string()));
+        
+        let thickness = converters.get(&XamlTypeName::new("http://any", "Thickness")).unwrap();
+        assert_eq!(thickness(" 1,2 ").unwrap(), XamlValue::String("1,2".to_string()));
+        assert!(thickness("1,2,3").is_err());
+        
+        assert!(ConverterRegistry::empty().get(&XamlTypeName::new("System", "Double")).is_none());
+    }
+    
+    #[test]
+    fn test_converter_registry_exact_match_wins() {
+        let mut converters = ConverterRegistry::new();
+        converters.register(XamlTypeName::new("http://custom", "Thickness"), |_| Ok(XamlValue::Null));
+        
+        let custom = converters.get(&XamlTypeName::new("http://custom", "Thickness")).unwrap();
+        assert_eq!(custom("1").unwrap(), XamlValue::Null);
+        let builtin = converters.get(&XamlTypeName::new("http://other", "Thickness")).unwrap();
+        assert_eq!(builtin("1").unwrap(), XamlValue::String("1".to_string()));
+    }
 }
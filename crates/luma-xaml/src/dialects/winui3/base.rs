@@ -31,6 +31,35 @@ pub fn ui_element_type_def() -> BasicXamlType {
             XamlProperty::new("RenderTransform", object_type())
                 .dependency_property()
         )
+        // Events
+        .with_property(
+            XamlProperty::new("Tapped", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("KeyDown", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("KeyUp", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("PointerPressed", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("PointerReleased", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("GotFocus", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("LostFocus", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// FrameworkElement - base class for elements with layout.
@@ -87,6 +116,19 @@ pub fn framework_element_type_def() -> BasicXamlType {
             XamlProperty::new("DataContext", object_type())
                 .dependency_property()
         )
+        // Events
+        .with_property(
+            XamlProperty::new("Loaded", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("Unloaded", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("SizeChanged", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// Panel - base class for layout panels.
@@ -23,6 +23,10 @@ pub fn button_type() -> BasicXamlType {
             XamlProperty::new("CornerRadius", corner_radius_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("Click", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// TextBlock - displays text.
@@ -97,6 +101,10 @@ pub fn text_box_type() -> BasicXamlType {
             XamlProperty::new("MaxLength", int32_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("TextChanged", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// CheckBox - two or three-state checkbox.
@@ -111,6 +119,14 @@ pub fn check_box_type() -> BasicXamlType {
             XamlProperty::new("IsThreeState", boolean_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("Checked", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("Unchecked", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// RadioButton - mutually exclusive selection button.
@@ -125,6 +141,14 @@ pub fn radio_button_type() -> BasicXamlType {
             XamlProperty::new("GroupName", string_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("Checked", routed_event_handler_type())
+                .event()
+        )
+        .with_property(
+            XamlProperty::new("Unchecked", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// ToggleSwitch - on/off switch control.
@@ -147,6 +171,10 @@ pub fn toggle_switch_type() -> BasicXamlType {
             XamlProperty::new("OffContent", object_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("Toggled", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// Slider - value selection slider.
@@ -173,6 +201,10 @@ pub fn slider_type() -> BasicXamlType {
             XamlProperty::new("Orientation", orientation_type())
                 .dependency_property()
         )
+        .with_property(
+            XamlProperty::new("ValueChanged", routed_event_handler_type())
+                .event()
+        )
 }
 
 /// ProgressBar - progress indicator.
@@ -286,3 +318,20 @@ pub fn ellipse_type() -> BasicXamlType {
                 .dependency_property()
         )
 }
+
+/// DataTemplate - describes the visual tree of a data item.
+pub fn data_template_type() -> BasicXamlType {
+    BasicXamlType::new(XamlTypeName::new(WINUI3_NAMESPACE, "DataTemplate"))
+        .with_base_type(XamlTypeName::new(WINUI3_NAMESPACE, "DependencyObject"))
+        .as_namescope()
+}
+
+/// ControlTemplate - describes the visual tree of a control.
+pub fn control_template_type() -> BasicXamlType {
+    BasicXamlType::new(XamlTypeName::new(WINUI3_NAMESPACE, "ControlTemplate"))
+        .with_base_type(XamlTypeName::new(WINUI3_NAMESPACE, "DependencyObject"))
+        .with_property(
+            XamlProperty::new("TargetType", object_type())
+        )
+        .as_namescope()
+}
@@ -45,6 +45,8 @@ pub fn create_type_registry() -> TypeRegistry {
     registry.register_type(Box::new(controls::border_type()));
     registry.register_type(Box::new(controls::rectangle_type()));
     registry.register_type(Box::new(controls::ellipse_type()));
+    registry.register_type(Box::new(controls::data_template_type()));
+    registry.register_type(Box::new(controls::control_template_type()));
     
     // Register panels
     registry.register_type(Box::new(panels::stack_panel_type()));
@@ -59,7 +59,7 @@ pub fn row_definition_type() -> BasicXamlType {
     BasicXamlType::new(XamlTypeName::new(WINUI3_NAMESPACE, "RowDefinition"))
         .with_base_type(XamlTypeName::new(WINUI3_NAMESPACE, "DependencyObject"))
         .with_property(
-            XamlProperty::new("Height", object_type())
+            XamlProperty::new("Height", grid_length_type())
                 .dependency_property()
         )
         .with_property(
@@ -77,7 +77,7 @@ pub fn column_definition_type() -> BasicXamlType {
     BasicXamlType::new(XamlTypeName::new(WINUI3_NAMESPACE, "ColumnDefinition"))
         .with_base_type(XamlTypeName::new(WINUI3_NAMESPACE, "DependencyObject"))
         .with_property(
-            XamlProperty::new("Width", object_type())
+            XamlProperty::new("Width", grid_length_type())
                 .dependency_property()
         )
         .with_property(
@@ -42,6 +42,10 @@ pub fn corner_radius_type() -> XamlTypeName {
     XamlTypeName::new(WINUI3_NAMESPACE, "CornerRadius")
 }
 
+pub fn grid_length_type() -> XamlTypeName {
+    XamlTypeName::new(WINUI3_NAMESPACE, "GridLength")
+}
+
 pub fn font_family_type() -> XamlTypeName {
     XamlTypeName::new(WINUI3_NAMESPACE, "FontFamily")
 }
@@ -70,6 +74,10 @@ pub fn orientation_type() -> XamlTypeName {
     XamlTypeName::new(WINUI3_NAMESPACE, "Orientation")
 }
 
+pub fn routed_event_handler_type() -> XamlTypeName {
+    XamlTypeName::new(WINUI3_NAMESPACE, "RoutedEventHandler")
+}
+
 pub fn ui_element_type() -> XamlTypeName {
     XamlTypeName::new(WINUI3_NAMESPACE, "UIElement")
 }
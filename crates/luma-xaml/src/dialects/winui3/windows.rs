@@ -79,4 +79,5 @@ pub fn user_control_type() -> BasicXamlType {
                 .content_property()
         )
         .with_content_property("Content")
+        .as_namescope()
 }
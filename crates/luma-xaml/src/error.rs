@@ -80,6 +80,15 @@ pub enum XamlError {
         details: String,
     },
 
+    /// An x:Name was declared twice in the same namescope.
+    #[error("Duplicate x:Name '{name}' at line {line}")]
+    DuplicateName {
+        /// The duplicated name.
+        name: String,
+        /// Line where the second declaration occurred.
+        line: usize,
+    },
+
     /// Resource not found.
     #[error("Resource '{key}' not found (referenced at line {line})")]
     ResourceNotFound {
@@ -127,6 +136,7 @@ impl XamlError {
             | XamlError::TypeMismatch { line, .. }
             | XamlError::InvalidNamespace { line, .. }
             | XamlError::InvalidAttributeValue { line, .. }
+            | XamlError::DuplicateName { line, .. }
             | XamlError::ResourceNotFound { line, .. } => Some(*line),
             _ => None,
         }
@@ -9,7 +9,8 @@ bitflags! {
         /// Strict mode: unknown types cause errors.
         const STRICT_MODE = 0b00000001;
         
-        /// Validate that property types match expected types.
+        /// Convert attribute values to their property's declared type; in
+        /// strict mode a value that does not fit the type is an error.
         const VALIDATE_TYPES = 0b00000010;
         
         /// Allow unknown types (create placeholder types).
@@ -27,6 +28,22 @@ bitflags! {
         /// Parse and resolve resource references.
         const RESOLVE_RESOURCES = 0b01000000;
         
+        /// Apply XAML whitespace rules to text content: collapse runs of
+        /// whitespace to a single space and trim text next to elements.
+        /// Ignored when `PRESERVE_WHITESPACE` is set.
+        const NORMALIZE_WHITESPACE = 0b10000000;
+        
+        /// Keep built-in markup extensions ({x:Null}, {x:Type}) as raw
+        /// `MarkupExtension` values instead of resolving them.
+        const RAW_MARKUP_EXTENSIONS = 0b1_00000000;
+        
+        /// Reject duplicate x:Name values within a namescope.
+        const VALIDATE_NAMES = 0b10_00000000;
+        
+        /// Share one allocation between equal type names and namespace URIs,
+        /// which saves memory on large documents.
+        const INTERN_STRINGS = 0b100_00000000;
+        
         /// Default parser flags (strict, validate types, parse extensions, resolve resources).
         const DEFAULT = Self::STRICT_MODE.bits()
             | Self::VALIDATE_TYPES.bits()
@@ -38,6 +55,7 @@ bitflags! {
 bitflags! {
     /// Element-level flags for tracking element state and attributes.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct ElementFlags: u32 {
         /// Element has x:Name attribute.
         const HAS_NAME = 0b00000001;
@@ -62,6 +80,9 @@ bitflags! {
         
         /// Element uses content property syntax (implicit property).
         const USES_CONTENT_PROPERTY = 0b10000000;
+        
+        /// Element's type is not registered and was allowed as a placeholder.
+        const SYNTHETIC_TYPE = 0b1_00000000;
     }
 }
 
@@ -92,6 +113,9 @@ bitflags! {
         
         /// Property value is data-bound.
         const DATA_BOUND = 0b10000000;
+        
+        /// Property is an event; its attribute value names a handler method.
+        const EVENT = 0b1_00000000;
     }
 }
 
@@ -106,6 +130,7 @@ mod tests {
         assert!(flags.contains(ParserFlags::VALIDATE_TYPES));
         assert!(flags.contains(ParserFlags::PARSE_MARKUP_EXTENSIONS));
         assert!(!flags.contains(ParserFlags::ALLOW_UNKNOWN_TYPES));
+        assert!(!flags.contains(ParserFlags::VALIDATE_NAMES));
     }
 
     #[test]
@@ -40,14 +40,19 @@ pub mod converters;
 // Re-export commonly used types
 pub use error::{XamlError, Result, ErrorLocation};
 pub use flags::{ParserFlags, ElementFlags, PropertyFlags};
-pub use model::{XamlElement, XamlNode, XamlValue, XamlDocument};
-pub use types::{XamlTypeName, XamlType, XamlProperty, TypeRegistry};
+pub use model::{
+    XamlElement, XamlElementBuilder, XamlNode, XamlValue, XamlDocument, ElementPath, ElementIndex,
+    Style, Setter, StyleResources, apply_style, resolve_styles,
+    ControlTemplate, TemplateBinding, XamlChange, diff,
+};
+pub use types::{XamlTypeName, XamlType, XamlProperty, TypeRegistry, NamespaceMap};
 pub use markup::{MarkupExtension, StaticResourceExtension, BindingExtension, NullExtension, TypeExtension};
 pub use parser::{XamlParser, ParserSettings};
 pub use context::ServiceProvider;
 pub use converters::{
-    Thickness, CornerRadius, GridLength,
-    Orientation, Visibility, HorizontalAlignment, VerticalAlignment
+    ConverterRegistry, ValueConverter,
+    Color, Thickness, CornerRadius, GridLength,
+    Orientation, Visibility, HorizontalAlignment, VerticalAlignment, Stretch
 };
 
 /// Prelude module for convenient imports.
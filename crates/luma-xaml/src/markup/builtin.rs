@@ -80,8 +80,7 @@ impl MarkupExtension for TypeExtension {
     }
 
     fn provide_value(&self, _context: &ServiceProvider) -> Result<XamlValue> {
-        // Return the type name as a string for now
-        Ok(XamlValue::String(self.type_name.to_string()))
+        Ok(XamlValue::Type(self.type_name.clone()))
     }
 }
 
@@ -96,4 +95,14 @@ mod tests {
         let value = ext.provide_value(&context).unwrap();
         assert!(value.is_null());
     }
+
+    #[test]
+    fn test_type_extension() {
+        let ext = TypeExtension {
+            type_name: XamlTypeName::new("Test", "Button"),
+        };
+        let context = ServiceProvider::new();
+        let value = ext.provide_value(&context).unwrap();
+        assert_eq!(value.as_type(), Some(&XamlTypeName::new("Test", "Button")));
+    }
 }
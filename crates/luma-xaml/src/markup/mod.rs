@@ -6,4 +6,4 @@ pub mod parser;
 
 pub use extension::MarkupExtension;
 pub use builtin::{StaticResourceExtension, BindingExtension, NullExtension, TypeExtension};
-pub use parser::{parse_markup_extension, ParsedMarkupExtension};
+pub use parser::{parse_markup_extension, ParsedMarkupExtension, MarkupArgument};
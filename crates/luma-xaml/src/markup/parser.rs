@@ -22,18 +22,31 @@ pub enum MarkupToken {
     Eof,
 }
 
+/// Longest markup extension string (in characters) the parser accepts.
+pub const MAX_MARKUP_EXTENSION_LENGTH: usize = 64 * 1024;
+
+/// Deepest brace nesting the parser accepts before giving up.
+pub const MAX_MARKUP_EXTENSION_DEPTH: usize = 32;
+
 /// Tokenizes markup extension syntax.
 pub struct MarkupLexer {
     input: Vec<char>,
     position: usize,
+    line: usize,
 }
 
 impl MarkupLexer {
     /// Create a new lexer from input string.
     pub fn new(input: &str) -> Self {
+        Self::with_line(input, 0)
+    }
+
+    /// Create a lexer for input found on `line`, which is reported in errors.
+    pub fn with_line(input: &str, line: usize) -> Self {
         Self {
             input: input.chars().collect(),
             position: 0,
+            line,
         }
     }
 
@@ -41,11 +54,9 @@ impl MarkupLexer {
     pub fn next_token(&mut self) -> Result<MarkupToken> {
         self.skip_whitespace();
 
-        if self.position >= self.input.len() {
+        let Some(ch) = self.current_char() else {
             return Ok(MarkupToken::Eof);
-        }
-
-        let ch = self.current_char();
+        };
 
         match ch {
             '{' => {
@@ -67,7 +78,7 @@ impl MarkupLexer {
             '\'' | '"' => self.read_string(ch),
             _ if ch.is_alphabetic() || ch == '_' || ch == ':' => self.read_identifier(),
             _ => Err(XamlError::InvalidMarkupExtension {
-                line: 0,
+                line: self.line,
                 details: format!("Unexpected character: '{}'", ch),
             }),
         }
@@ -78,35 +89,32 @@ impl MarkupLexer {
         self.position += 1; // Skip opening quote
         let mut value = String::new();
 
-        while self.position < self.input.len() {
-            let ch = self.current_char();
+        while let Some(ch) = self.current_char() {
+            self.position += 1;
             
             if ch == quote {
-                self.position += 1; // Skip closing quote
                 return Ok(MarkupToken::String(value));
             }
             
-            if ch == '\\' && self.position + 1 < self.input.len() {
+            if ch == '\\' {
+                // A backslash must escape something; one at the very end cannot
+                let Some(escaped) = self.current_char() else {
+                    break;
+                };
                 self.position += 1;
-                let escaped = self.current_char();
                 value.push(match escaped {
                     'n' => '\n',
                     't' => '\t',
                     'r' => '\r',
-                    '\\' => '\\',
-                    '\'' => '\'',
-                    '"' => '"',
                     _ => escaped,
                 });
-                self.position += 1;
             } else {
                 value.push(ch);
-                self.position += 1;
             }
         }
 
         Err(XamlError::InvalidMarkupExtension {
-            line: 0,
+            line: self.line,
             details: "Unterminated string literal".to_string(),
         })
     }
@@ -115,9 +123,7 @@ impl MarkupLexer {
     fn read_identifier(&mut self) -> Result<MarkupToken> {
         let mut value = String::new();
 
-        while self.position < self.input.len() {
-            let ch = self.current_char();
-            
+        while let Some(ch) = self.current_char() {
             if ch.is_alphanumeric() || ch == '_' || ch == ':' || ch == '.' {
                 value.push(ch);
                 self.position += 1;
@@ -131,20 +137,20 @@ impl MarkupLexer {
 
     /// Skip whitespace characters.
     fn skip_whitespace(&mut self) {
-        while self.position < self.input.len() && self.current_char().is_whitespace() {
+        while self.current_char().is_some_and(char::is_whitespace) {
             self.position += 1;
         }
     }
 
-    /// Get current character.
-    fn current_char(&self) -> char {
-        self.input[self.position]
+    /// Get current character, or `None` at the end of input.
+    fn current_char(&self) -> Option<char> {
+        self.input.get(self.position).copied()
     }
 
     /// Peek at remaining input for debugging.
     #[allow(dead_code)]
     fn remaining(&self) -> String {
-        self.input[self.position..].iter().collect()
+        self.input.get(self.position..).unwrap_or_default().iter().collect()
     }
 }
 
@@ -155,33 +161,67 @@ pub struct ParsedMarkupExtension {
     pub name: String,
     
     /// Positional argument (the first argument without a name)
-    pub positional_arg: Option<String>,
+    pub positional_arg: Option<MarkupArgument>,
     
     /// Named arguments
-    pub arguments: HashMap<String, String>,
+    pub arguments: HashMap<String, MarkupArgument>,
+}
+
+/// The value of a markup extension argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkupArgument {
+    /// Plain text, from an identifier or a string literal
+    Text(String),
+    /// A nested extension, as in `Source={StaticResource Key}`
+    Extension(Box<ParsedMarkupExtension>),
+}
+
+impl MarkupArgument {
+    /// Get the text of a plain argument.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            MarkupArgument::Text(text) => Some(text),
+            MarkupArgument::Extension(_) => None,
+        }
+    }
 }
 
 /// Parse a markup extension string.
-pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
-    let mut lexer = MarkupLexer::new(input);
+///
+/// Argument values may themselves be markup extensions, up to
+/// [`MAX_MARKUP_EXTENSION_DEPTH`] levels deep.
+///
+/// `line` is the line of the attribute the string came from and is reported
+/// in any `InvalidMarkupExtension` error (use 0 if unknown).
+pub fn parse_markup_extension(input: &str, line: usize) -> Result<ParsedMarkupExtension> {
+    check_limits(input, line)?;
+    
+    let mut lexer = MarkupLexer::with_line(input, line);
     
     // Expect opening brace
     match lexer.next_token()? {
         MarkupToken::OpenBrace => {}
         _ => {
             return Err(XamlError::InvalidMarkupExtension {
-                line: 0,
+                line,
                 details: "Markup extension must start with '{'".to_string(),
             });
         }
     }
     
+    parse_extension_body(&mut lexer, line)
+}
+
+/// Parse an extension after its opening brace, up to and including the closing brace.
+///
+/// Nested extensions recurse; `check_limits` has already bounded the depth.
+fn parse_extension_body(lexer: &mut MarkupLexer, line: usize) -> Result<ParsedMarkupExtension> {
     // Read extension name
     let name = match lexer.next_token()? {
         MarkupToken::Identifier(name) => name,
         _ => {
             return Err(XamlError::InvalidMarkupExtension {
-                line: 0,
+                line,
                 details: "Expected extension name".to_string(),
             });
         }
@@ -198,7 +238,7 @@ pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
             MarkupToken::CloseBrace => break,
             MarkupToken::Eof => {
                 return Err(XamlError::InvalidMarkupExtension {
-                    line: 0,
+                    line,
                     details: "Unexpected end of markup extension".to_string(),
                 });
             }
@@ -210,11 +250,12 @@ pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
                     MarkupToken::Equals => {
                         // Named argument
                         let value = match lexer.next_token()? {
-                            MarkupToken::String(s) => s,
-                            MarkupToken::Identifier(s) => s,
+                            MarkupToken::String(s) => MarkupArgument::Text(s),
+                            MarkupToken::Identifier(s) => MarkupArgument::Text(s),
+                            MarkupToken::OpenBrace => MarkupArgument::Extension(Box::new(parse_extension_body(lexer, line)?)),
                             _ => {
                                 return Err(XamlError::InvalidMarkupExtension {
-                                    line: 0,
+                                    line,
                                     details: "Expected value after '='".to_string(),
                                 });
                             }
@@ -223,30 +264,16 @@ pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
                     }
                     MarkupToken::Comma => {
                         // Positional argument followed by comma
-                        if positional_arg.is_none() {
-                            positional_arg = Some(id);
-                        } else {
-                            return Err(XamlError::InvalidMarkupExtension {
-                                line: 0,
-                                details: "Multiple positional arguments not supported".to_string(),
-                            });
-                        }
+                        set_positional(&mut positional_arg, MarkupArgument::Text(id), line)?;
                     }
                     MarkupToken::CloseBrace => {
                         // Positional argument at end
-                        if positional_arg.is_none() {
-                            positional_arg = Some(id);
-                        } else {
-                            return Err(XamlError::InvalidMarkupExtension {
-                                line: 0,
-                                details: "Multiple positional arguments not supported".to_string(),
-                            });
-                        }
+                        set_positional(&mut positional_arg, MarkupArgument::Text(id), line)?;
                         break;
                     }
                     _ => {
                         return Err(XamlError::InvalidMarkupExtension {
-                            line: 0,
+                            line,
                             details: "Unexpected token after identifier".to_string(),
                         });
                     }
@@ -254,18 +281,16 @@ pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
             }
             MarkupToken::String(s) => {
                 // Positional string argument
-                if positional_arg.is_none() {
-                    positional_arg = Some(s);
-                } else {
-                    return Err(XamlError::InvalidMarkupExtension {
-                        line: 0,
-                        details: "Multiple positional arguments not supported".to_string(),
-                    });
-                }
+                set_positional(&mut positional_arg, MarkupArgument::Text(s), line)?;
+            }
+            MarkupToken::OpenBrace => {
+                // Positional nested extension
+                let nested = parse_extension_body(lexer, line)?;
+                set_positional(&mut positional_arg, MarkupArgument::Extension(Box::new(nested)), line)?;
             }
             _ => {
                 return Err(XamlError::InvalidMarkupExtension {
-                    line: 0,
+                    line,
                     details: "Unexpected token in markup extension".to_string(),
                 });
             }
@@ -279,45 +304,104 @@ pub fn parse_markup_extension(input: &str) -> Result<ParsedMarkupExtension> {
     })
 }
 
+/// Store the positional argument, failing if there already is one.
+fn set_positional(positional_arg: &mut Option<MarkupArgument>, value: MarkupArgument, line: usize) -> Result<()> {
+    if positional_arg.is_some() {
+        return Err(XamlError::InvalidMarkupExtension {
+            line,
+            details: "Multiple positional arguments not supported".to_string(),
+        });
+    }
+    *positional_arg = Some(value);
+    Ok(())
+}
+
+/// Reject input that is too long or too deeply nested before tokenizing it.
+///
+/// Nesting is measured on braces outside string literals, so pathological
+/// input fails fast with an error instead of doing unbounded work.
+fn check_limits(input: &str, line: usize) -> Result<()> {
+    let too_long = input.chars().nth(MAX_MARKUP_EXTENSION_LENGTH).is_some();
+    if too_long {
+        return Err(XamlError::InvalidMarkupExtension {
+            line,
+            details: format!(
+                "Markup extension longer than {} characters",
+                MAX_MARKUP_EXTENSION_LENGTH
+            ),
+        });
+    }
+    
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = input.chars();
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), _) if ch == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, '{') => {
+                depth += 1;
+                if depth > MAX_MARKUP_EXTENSION_DEPTH {
+                    return Err(XamlError::InvalidMarkupExtension {
+                        line,
+                        details: format!(
+                            "Markup extension nested deeper than {} levels",
+                            MAX_MARKUP_EXTENSION_DEPTH
+                        ),
+                    });
+                }
+            }
+            (None, '}') => depth = depth.saturating_sub(1),
+            (None, _) => {}
+        }
+    }
+    
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_simple_extension() {
-        let parsed = parse_markup_extension("{StaticResource MyBrush}").unwrap();
+        let parsed = parse_markup_extension("{StaticResource MyBrush}", 0).unwrap();
         assert_eq!(parsed.name, "StaticResource");
-        assert_eq!(parsed.positional_arg, Some("MyBrush".to_string()));
+        assert_eq!(parsed.positional_arg.as_ref().and_then(MarkupArgument::as_text), Some("MyBrush"));
         assert!(parsed.arguments.is_empty());
     }
 
     #[test]
     fn test_extension_with_named_args() {
-        let parsed = parse_markup_extension("{Binding Path=Name, Mode=TwoWay}").unwrap();
+        let parsed = parse_markup_extension("{Binding Path=Name, Mode=TwoWay}", 0).unwrap();
         assert_eq!(parsed.name, "Binding");
         assert_eq!(parsed.positional_arg, None);
-        assert_eq!(parsed.arguments.get("Path"), Some(&"Name".to_string()));
-        assert_eq!(parsed.arguments.get("Mode"), Some(&"TwoWay".to_string()));
+        assert_eq!(parsed.arguments.get("Path").and_then(MarkupArgument::as_text), Some("Name"));
+        assert_eq!(parsed.arguments.get("Mode").and_then(MarkupArgument::as_text), Some("TwoWay"));
     }
 
     #[test]
     fn test_extension_with_positional_and_named() {
-        let parsed = parse_markup_extension("{Binding Name, Mode=TwoWay}").unwrap();
+        let parsed = parse_markup_extension("{Binding Name, Mode=TwoWay}", 0).unwrap();
         assert_eq!(parsed.name, "Binding");
-        assert_eq!(parsed.positional_arg, Some("Name".to_string()));
-        assert_eq!(parsed.arguments.get("Mode"), Some(&"TwoWay".to_string()));
+        assert_eq!(parsed.positional_arg.as_ref().and_then(MarkupArgument::as_text), Some("Name"));
+        assert_eq!(parsed.arguments.get("Mode").and_then(MarkupArgument::as_text), Some("TwoWay"));
     }
 
     #[test]
     fn test_extension_with_string_literal() {
-        let parsed = parse_markup_extension("{StaticResource 'My Brush'}").unwrap();
+        let parsed = parse_markup_extension("{StaticResource 'My Brush'}", 0).unwrap();
         assert_eq!(parsed.name, "StaticResource");
-        assert_eq!(parsed.positional_arg, Some("My Brush".to_string()));
+        assert_eq!(parsed.positional_arg.as_ref().and_then(MarkupArgument::as_text), Some("My Brush"));
     }
 
     #[test]
     fn test_null_extension() {
-        let parsed = parse_markup_extension("{x:Null}").unwrap();
+        let parsed = parse_markup_extension("{x:Null}", 0).unwrap();
         assert_eq!(parsed.name, "x:Null");
         assert_eq!(parsed.positional_arg, None);
         assert!(parsed.arguments.is_empty());
@@ -325,19 +409,118 @@ mod tests {
 
     #[test]
     fn test_type_extension() {
-        let parsed = parse_markup_extension("{x:Type local:MyType}").unwrap();
+        let parsed = parse_markup_extension("{x:Type local:MyType}", 0).unwrap();
         assert_eq!(parsed.name, "x:Type");
-        assert_eq!(parsed.positional_arg, Some("local:MyType".to_string()));
+        assert_eq!(parsed.positional_arg.as_ref().and_then(MarkupArgument::as_text), Some("local:MyType"));
     }
 
     #[test]
     fn test_complex_binding() {
         let parsed = parse_markup_extension(
-            "{Binding Path=User.Name, Mode=TwoWay, UpdateSourceTrigger=PropertyChanged}"
+            "{Binding Path=User.Name, Mode=TwoWay, UpdateSourceTrigger=PropertyChanged}",
+            0,
         ).unwrap();
         assert_eq!(parsed.name, "Binding");
-        assert_eq!(parsed.arguments.get("Path"), Some(&"User.Name".to_string()));
-        assert_eq!(parsed.arguments.get("Mode"), Some(&"TwoWay".to_string()));
-        assert_eq!(parsed.arguments.get("UpdateSourceTrigger"), Some(&"PropertyChanged".to_string()));
+        assert_eq!(parsed.arguments.get("Path").and_then(MarkupArgument::as_text), Some("User.Name"));
+        assert_eq!(parsed.arguments.get("Mode").and_then(MarkupArgument::as_text), Some("TwoWay"));
+        assert_eq!(parsed.arguments.get("UpdateSourceTrigger").and_then(MarkupArgument::as_text), Some("PropertyChanged"));
+    }
+    
+    #[test]
+    fn test_malformed_input_errors() {
+        let inputs = [
+            "{",
+            "{Binding",
+            "{Binding Path=",
+            "{Binding Path='unterminated",
+            "{Binding Path='ends in backslash\\",
+            "{Binding \\",
+            "}",
+            "",
+            "{{{{}}}}",
+            "{Binding Source={StaticResource Key}",
+            "{Binding Source={}}",
+        ];
+        
+        for input in inputs {
+            assert!(parse_markup_extension(input, 0).is_err(), "{:?} should fail", input);
+        }
+    }
+    
+    #[test]
+    fn test_nested_extensions() {
+        let parsed = parse_markup_extension("{Binding Path=Name, Source={StaticResource Key}}", 0).unwrap();
+        assert_eq!(parsed.arguments.get("Path").and_then(MarkupArgument::as_text), Some("Name"));
+        let Some(MarkupArgument::Extension(source)) = parsed.arguments.get("Source") else {
+            panic!("Source should be a nested extension");
+        };
+        assert_eq!(source.name, "StaticResource");
+        assert_eq!(source.positional_arg.as_ref().and_then(MarkupArgument::as_text), Some("Key"));
+        
+        // A nested extension can also be the positional argument
+        let parsed = parse_markup_extension("{Binding {StaticResource Key}, Mode=OneWay}", 0).unwrap();
+        assert!(matches!(&parsed.positional_arg, Some(MarkupArgument::Extension(inner)) if inner.name == "StaticResource"));
+        assert_eq!(parsed.arguments.get("Mode").and_then(MarkupArgument::as_text), Some("OneWay"));
+    }
+    
+    #[test]
+    fn test_nesting_depth_limit() {
+        let nested = |depth: usize| format!("{}{}", "{Ext Value=".repeat(depth - 1) + "{Ext", "}".repeat(depth));
+        
+        let mut parsed = parse_markup_extension(&nested(MAX_MARKUP_EXTENSION_DEPTH), 0).unwrap();
+        let mut depth = 1;
+        while let Some(MarkupArgument::Extension(inner)) = parsed.arguments.remove("Value") {
+            parsed = *inner;
+            depth += 1;
+        }
+        assert_eq!(depth, MAX_MARKUP_EXTENSION_DEPTH);
+        
+        let err = parse_markup_extension(&nested(MAX_MARKUP_EXTENSION_DEPTH + 1), 3).unwrap_err();
+        assert_eq!(err.line(), Some(3));
+    }
+    
+    #[test]
+    fn test_limits() {
+        let deep = format!("{}{}", "{".repeat(MAX_MARKUP_EXTENSION_DEPTH + 1), "}".repeat(MAX_MARKUP_EXTENSION_DEPTH + 1));
+        let err = parse_markup_extension(&deep, 7).unwrap_err();
+        assert_eq!(err.line(), Some(7));
+        
+        let long = format!("{{Binding Path={}}}", "a".repeat(MAX_MARKUP_EXTENSION_LENGTH));
+        assert!(parse_markup_extension(&long, 0).is_err());
+        
+        // Braces inside string literals do not count as nesting
+        let quoted = format!("{{Binding Path='{}'}}", "{".repeat(MAX_MARKUP_EXTENSION_DEPTH + 1));
+        assert!(parse_markup_extension(&quoted, 0).is_ok());
+    }
+    
+    #[test]
+    fn test_random_input_never_panics() {
+        // Small xorshift generator so the test is deterministic without extra dependencies
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        
+        let alphabet: Vec<char> = "{}=,'\"\\ x:Path.éé_9\t\n\0".chars().collect();
+        for _ in 0..5000 {
+            let len = (next() % 24) as usize;
+            let mut input = String::new();
+            if next() % 2 == 0 {
+                input.push('{');
+            }
+            for _ in 0..len {
+                input.push(alphabet[(next() % alphabet.len() as u64) as usize]);
+            }
+            
+            // Only the absence of a panic matters here
+            let _ = parse_markup_extension(&input, 0);
+            
+            // Arbitrary bytes, decoded lossily like a broken document would be
+            let bytes: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+            let _ = parse_markup_extension(&String::from_utf8_lossy(&bytes), 0);
+        }
     }
 }
@@ -0,0 +1,197 @@
+//! Differences between two versions of a document, for incremental updates.
+
+use crate::model::{ElementPath, OrderedMap, XamlDocument, XamlElement, XamlNode, XamlValue};
+
+/// One difference between two documents, found by [`diff`].
+///
+/// Paths are child element indices from the root (see [`ElementPath`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum XamlChange {
+    /// An element was inserted; `path` is its position in the new document.
+    ElementAdded {
+        /// The position of the new element.
+        path: ElementPath,
+        /// The new element, with its children.
+        element: XamlElement,
+    },
+    
+    /// An element was removed; `path` is its position in the old document.
+    ElementRemoved {
+        /// The position of the removed element.
+        path: ElementPath,
+    },
+    
+    /// An element changed its type, name, key, events, namespaces or text,
+    /// so it must be rebuilt.
+    ElementReplaced {
+        /// The position of the element.
+        path: ElementPath,
+        /// The new element, with its children.
+        element: XamlElement,
+    },
+    
+    /// An attribute was added or given a new value.
+    AttributeChanged {
+        /// The position of the element.
+        path: ElementPath,
+        /// The attribute name; `Owner.Name` for an attached property.
+        name: String,
+        /// The previous value, or `None` if the attribute is new.
+        old: Option<XamlValue>,
+        /// The new value.
+        new: XamlValue,
+    },
+    
+    /// An attribute was removed.
+    AttributeRemoved {
+        /// The position of the element.
+        path: ElementPath,
+        /// The attribute name.
+        name: String,
+    },
+    
+    /// A property element was added or given a new value.
+    PropertyChanged {
+        /// The position of the element.
+        path: ElementPath,
+        /// The property name.
+        name: String,
+        /// The previous value, or `None` if the property is new.
+        old: Option<XamlValue>,
+        /// The new value.
+        new: XamlValue,
+    },
+    
+    /// A property element was removed.
+    PropertyRemoved {
+        /// The position of the element.
+        path: ElementPath,
+        /// The property name.
+        name: String,
+    },
+}
+
+impl XamlChange {
+    /// Get the position of the element the change is for.
+    pub fn path(&self) -> &ElementPath {
+        match self {
+            Self::ElementAdded { path, .. }
+            | Self::ElementRemoved { path }
+            | Self::ElementReplaced { path, .. }
+            | Self::AttributeChanged { path, .. }
+            | Self::AttributeRemoved { path, .. }
+            | Self::PropertyChanged { path, .. }
+            | Self::PropertyRemoved { path, .. } => path,
+        }
+    }
+}
+
+/// Find the changes that turn the root element of `old` into that of `new`.
+///
+/// Child elements are matched by position, so inserting an element before its
+/// siblings shows up as changes to each of them. Changes to an element come
+/// before changes to its children. Removed children are listed last first and
+/// before added ones, so the changes can be applied in order without paths
+/// going stale. Document resources and the line numbers of elements in the
+/// tree are not compared, but property values are compared whole, elements
+/// and all.
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::{XamlParser, TypeRegistry, XamlChange, diff};
+///
+/// let parser = XamlParser::new(TypeRegistry::new());
+/// let old = parser.parse_string(r#"<Button xmlns="http://test" Content="OK"/>"#)?;
+/// let new = parser.parse_string(r#"<Button xmlns="http://test" Content="Cancel"/>"#)?;
+///
+/// let changes = diff(&old, &new);
+/// assert!(matches!(&changes[..], [XamlChange::AttributeChanged { name, .. }] if name == "Content"));
+/// # Ok::<(), luma_xaml::XamlError>(())
+/// ```
+pub fn diff(old: &XamlDocument, new: &XamlDocument) -> Vec<XamlChange> {
+    let mut changes = Vec::new();
+    diff_element(&old.root, &new.root, ElementPath::root(), &mut changes);
+    changes
+}
+
+fn diff_element(old: &XamlElement, new: &XamlElement, path: ElementPath, changes: &mut Vec<XamlChange>) {
+    if needs_rebuild(old, new) {
+        changes.push(XamlChange::ElementReplaced {
+            path,
+            element: new.clone(),
+        });
+        return;
+    }
+    
+    diff_members(&old.attributes, &new.attributes, &path, changes, |path, name, old, new| match new {
+        Some(new) => XamlChange::AttributeChanged { path, name, old, new },
+        None => XamlChange::AttributeRemoved { path, name },
+    });
+    diff_members(&old.attached_by_name(), &new.attached_by_name(), &path, changes, |path, name, old, new| match new {
+        Some(new) => XamlChange::AttributeChanged { path, name, old, new },
+        None => XamlChange::AttributeRemoved { path, name },
+    });
+    diff_members(&old.properties, &new.properties, &path, changes, |path, name, old, new| match new {
+        Some(new) => XamlChange::PropertyChanged { path, name, old, new },
+        None => XamlChange::PropertyRemoved { path, name },
+    });
+    
+    let old_children: Vec<_> = old.child_elements().collect();
+    let new_children: Vec<_> = new.child_elements().collect();
+    let common = old_children.len().min(new_children.len());
+    for (i, (old_child, new_child)) in old_children.iter().zip(&new_children).enumerate() {
+        diff_element(old_child, new_child, path.child(i), changes);
+    }
+    for i in (common..old_children.len()).rev() {
+        changes.push(XamlChange::ElementRemoved { path: path.child(i) });
+    }
+    for (i, child) in new_children.iter().enumerate().skip(common) {
+        changes.push(XamlChange::ElementAdded {
+            path: path.child(i),
+            element: (*child).clone(),
+        });
+    }
+}
+
+/// Check if an element changed in a way that attribute updates cannot express.
+fn needs_rebuild(old: &XamlElement, new: &XamlElement) -> bool {
+    old.type_name != new.type_name
+        || old.name != new.name
+        || old.key != new.key
+        || old.events != new.events
+        || old.namespaces != new.namespaces
+        || texts(old).ne(texts(new))
+}
+
+/// Iterate over the text children of an element.
+fn texts(element: &XamlElement) -> impl Iterator<Item = &str> {
+    element.children.iter().filter_map(|child| match child {
+        XamlNode::Text(text) | XamlNode::CData(text) => Some(text.as_str()),
+        XamlNode::Element(_) => None,
+    })
+}
+
+/// Record the attributes or property elements that were set or removed.
+///
+/// `change` builds a change from the path, name, old value and new value
+/// (`None` when removed).
+fn diff_members(
+    old: &OrderedMap<XamlValue>,
+    new: &OrderedMap<XamlValue>,
+    path: &ElementPath,
+    changes: &mut Vec<XamlChange>,
+    change: impl Fn(ElementPath, String, Option<XamlValue>, Option<XamlValue>) -> XamlChange,
+) {
+    for (name, value) in new.iter() {
+        let previous = old.get(name);
+        if previous != Some(value) {
+            changes.push(change(path.clone(), name.clone(), previous.cloned(), Some(value.clone())));
+        }
+    }
+    for (name, value) in old.iter() {
+        if !new.contains_key(name) {
+            changes.push(change(path.clone(), name.clone(), Some(value.clone()), None));
+        }
+    }
+}
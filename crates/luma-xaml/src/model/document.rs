@@ -2,10 +2,13 @@
 
 use crate::model::XamlElement;
 use crate::model::XamlValue;
+use crate::model::{ElementIndex, ElementPath};
+use crate::types::NamespaceMap;
 use std::collections::HashMap;
 
 /// A parsed XAML document.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XamlDocument {
     /// The root element of the document.
     pub root: XamlElement,
@@ -37,6 +40,42 @@ impl XamlDocument {
     pub fn has_resource(&self, key: &str) -> bool {
         self.resources.contains_key(key)
     }
+
+    /// Get the namespace scope of the root element: the prefixes declared on it.
+    ///
+    /// Elements only record their own declarations, so for a nested element
+    /// enter each of its ancestors with [`NamespaceMap::enter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, TypeRegistry, XamlTypeName};
+    ///
+    /// let parser = XamlParser::new(TypeRegistry::new());
+    /// let doc = parser.parse_string(r#"<Window xmlns="http://test" xmlns:local="urn:local"/>"#)?;
+    ///
+    /// let type_name = XamlTypeName::from_qualified("local:Gauge", &doc.namespaces())?;
+    /// assert_eq!(type_name.namespace, "urn:local");
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn namespaces(&self) -> NamespaceMap {
+        NamespaceMap::new().enter(&self.root)
+    }
+
+    /// Resolve a prefix declared on the root element to its namespace URI.
+    pub fn resolve_prefix(&self, prefix: &str) -> Option<&str> {
+        self.root.resolve_namespace(prefix)
+    }
+
+    /// Index the parent of every element, so the tree can be walked upward.
+    pub fn build_index(&self) -> ElementIndex {
+        ElementIndex::build(&self.root)
+    }
+
+    /// Get the element at a path from the root element.
+    pub fn element_at(&self, path: &ElementPath) -> Option<&XamlElement> {
+        path.resolve(&self.root)
+    }
 }
 
 #[cfg(test)]
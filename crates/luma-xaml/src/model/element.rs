@@ -3,9 +3,13 @@
 use std::collections::HashMap;
 use crate::types::XamlTypeName;
 use crate::flags::ElementFlags;
+use crate::model::OrderedMap;
+use crate::error::{Result, XamlError};
+use crate::converters::parse_bool;
 
 /// A value in XAML (property value, attribute, collection element, etc.).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XamlValue {
     /// A string value.
     String(String),
@@ -35,6 +39,9 @@ pub enum XamlValue {
     
     /// A collection of values.
     Collection(Vec<XamlValue>),
+    
+    /// A type reference (e.g., {x:Type Button}).
+    Type(XamlTypeName),
 }
 
 impl XamlValue {
@@ -54,6 +61,111 @@ impl XamlValue {
         }
     }
 
+    /// Try to extract a number as `f64`.
+    ///
+    /// Accepts `Integer`, `Float`, and strings that parse as a number, so
+    /// `Width="100"` and `Height="50.5"` can be read the same way.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            XamlValue::Integer(i) => Some(*i as f64),
+            XamlValue::Float(f) => Some(*f),
+            XamlValue::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Try to extract a non-negative integer that fits in a `u32`.
+    ///
+    /// Accepts `Integer`, whole-number `Float`, and numeric strings.
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            XamlValue::Integer(i) => u32::try_from(*i).ok(),
+            XamlValue::Float(f) if f.fract() == 0.0 && *f >= 0.0 && *f <= u32::MAX as f64 => {
+                Some(*f as u32)
+            }
+            XamlValue::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Convert this value toward a declared property type.
+    ///
+    /// Numeric, boolean, and string primitives (`Double`, `Single`, `Int32`,
+    /// `Int64`, `UInt32`, `Boolean`, `String`) are converted by local name;
+    /// any other target type, as well as `Null` and markup extensions
+    /// (which are evaluated later), is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlTypeName, XamlValue};
+    ///
+    /// let double = XamlTypeName::new("System", "Double");
+    /// let value = XamlValue::Integer(100).coerce_to(&double)?;
+    /// assert_eq!(value.as_f64(), Some(100.0));
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn coerce_to(&self, target: &XamlTypeName) -> Result<XamlValue> {
+        if matches!(self, XamlValue::Null | XamlValue::MarkupExtension { .. }) {
+            return Ok(self.clone());
+        }
+        
+        let coerced = match target.name.as_str() {
+            "Double" | "Single" => self.as_f64().map(XamlValue::Float),
+            "Int32" => self.as_whole_number()
+                .filter(|i| i32::try_from(*i).is_ok())
+                .map(XamlValue::Integer),
+            "Int64" => self.as_whole_number().map(XamlValue::Integer),
+            "UInt32" => self.as_u32().map(|u| XamlValue::Integer(u as i64)),
+            "Boolean" => match self {
+                XamlValue::Boolean(b) => Some(XamlValue::Boolean(*b)),
+                XamlValue::Integer(0) => Some(XamlValue::Boolean(false)),
+                XamlValue::Integer(1) => Some(XamlValue::Boolean(true)),
+                XamlValue::String(s) => parse_bool(s).map(XamlValue::Boolean),
+                _ => None,
+            },
+            "String" => match self {
+                XamlValue::String(s) => Some(XamlValue::String(s.clone())),
+                XamlValue::Integer(i) => Some(XamlValue::String(i.to_string())),
+                XamlValue::Float(f) => Some(XamlValue::String(f.to_string())),
+                XamlValue::Boolean(b) => Some(XamlValue::String(if *b { "True" } else { "False" }.to_string())),
+                _ => None,
+            },
+            _ => Some(self.clone()),
+        };
+        
+        coerced.ok_or_else(|| XamlError::TypeMismatch {
+            expected: target.full_name(),
+            actual: self.kind_name().to_string(),
+            line: 0,
+        })
+    }
+
+    /// Integer value of `Integer`, whole-number `Float`, or integer string.
+    fn as_whole_number(&self) -> Option<i64> {
+        match self {
+            XamlValue::Integer(i) => Some(*i),
+            XamlValue::Float(f) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => Some(*f as i64),
+            XamlValue::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Short description of the value's variant, for error messages.
+    fn kind_name(&self) -> &'static str {
+        match self {
+            XamlValue::String(_) => "String",
+            XamlValue::Integer(_) => "Integer",
+            XamlValue::Float(_) => "Float",
+            XamlValue::Boolean(_) => "Boolean",
+            XamlValue::Null => "Null",
+            XamlValue::Element(_) => "Element",
+            XamlValue::MarkupExtension { .. } => "MarkupExtension",
+            XamlValue::Collection(_) => "Collection",
+            XamlValue::Type(_) => "Type",
+        }
+    }
+
     /// Try to extract a boolean value.
     pub fn as_bool(&self) -> Option<bool> {
         match self {
@@ -75,6 +187,14 @@ impl XamlValue {
         }
     }
 
+    /// Try to extract a type reference.
+    pub fn as_type(&self) -> Option<&XamlTypeName> {
+        match self {
+            XamlValue::Type(t) => Some(t),
+            _ => None,
+        }
+    }
+
     /// Try to extract a collection.
     pub fn as_collection(&self) -> Option<&[XamlValue]> {
         match self {
@@ -85,13 +205,18 @@ impl XamlValue {
 }
 
 /// A node in the XAML tree (element, text, or markup extension).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XamlNode {
     /// An element node (e.g., <Button>).
     Element(XamlElement),
     
     /// A text node (string content).
     Text(String),
+    
+    /// Text from a `<![CDATA[...]]>` section, kept apart so it is written
+    /// back as CDATA.
+    CData(String),
 }
 
 impl XamlNode {
@@ -103,26 +228,35 @@ impl XamlNode {
         }
     }
 
-    /// Try to extract text.
+    /// Try to extract text, whether plain or from a CDATA section.
     pub fn as_text(&self) -> Option<&str> {
         match self {
-            XamlNode::Text(t) => Some(t),
+            XamlNode::Text(t) | XamlNode::CData(t) => Some(t),
             _ => None,
         }
     }
 }
 
 /// Represents a XAML element (e.g., <Button Content="Click Me"/>).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XamlElement {
     /// The type of this element (e.g., Button).
     pub type_name: XamlTypeName,
     
-    /// Attributes set directly on the element (e.g., Content="Click Me").
-    pub attributes: HashMap<String, XamlValue>,
+    /// Attributes set directly on the element (e.g., Content="Click Me"), in document order.
+    pub attributes: OrderedMap<XamlValue>,
+    
+    /// Properties set via property element syntax (e.g., <Button.Content>), in document order.
+    pub properties: OrderedMap<XamlValue>,
     
-    /// Properties set via property element syntax (e.g., <Button.Content>).
-    pub properties: HashMap<String, XamlValue>,
+    /// Event handler names keyed by event (e.g., Click="OnClick").
+    pub events: HashMap<String, String>,
+    
+    /// Attached properties set by attribute (e.g., Grid.Row="1"), keyed by
+    /// owner type and property name. The owner keeps any namespace prefix.
+    #[cfg_attr(feature = "serde", serde(with = "attached_entries"))]
+    pub attached_properties: HashMap<(String, String), XamlValue>,
     
     /// Child nodes (content).
     pub children: Vec<XamlNode>,
@@ -138,6 +272,9 @@ pub struct XamlElement {
     
     /// Element flags tracking various states.
     pub flags: ElementFlags,
+    
+    /// Line (1-based) on which the element's start tag begins, or 0 if it was not parsed.
+    pub line: usize,
 }
 
 impl XamlElement {
@@ -145,13 +282,48 @@ impl XamlElement {
     pub fn new(type_name: XamlTypeName) -> Self {
         Self {
             type_name,
-            attributes: HashMap::new(),
-            properties: HashMap::new(),
+            attributes: OrderedMap::new(),
+            properties: OrderedMap::new(),
+            events: HashMap::new(),
+            attached_properties: HashMap::new(),
             children: Vec::new(),
             namespaces: HashMap::new(),
             name: None,
             key: None,
             flags: ElementFlags::empty(),
+            line: 0,
+        }
+    }
+
+    /// Start building an element of the given type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlElement, XamlNode, XamlTypeName, XamlValue};
+    ///
+    /// let panel = XamlElement::builder(XamlTypeName::new("http://test", "StackPanel"))
+    ///     .name("Root")
+    ///     .child(XamlNode::Element(
+    ///         XamlElement::builder(XamlTypeName::new("http://test", "Button"))
+    ///             .attr("Content", XamlValue::String("OK".to_string()))
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(panel.name.as_deref(), Some("Root"));
+    /// assert_eq!(panel.child_elements().count(), 1);
+    /// ```
+    pub fn builder(type_name: XamlTypeName) -> XamlElementBuilder {
+        XamlElementBuilder {
+            element: XamlElement::new(type_name),
+        }
+    }
+
+    /// Start building a copy of this element, to override some of its values.
+    pub fn to_builder(&self) -> XamlElementBuilder {
+        XamlElementBuilder {
+            element: self.clone(),
         }
     }
 
@@ -165,6 +337,81 @@ impl XamlElement {
         self.attributes.get(name)
     }
 
+    /// Set an attached property, such as `Grid.Row`, by owner type and name.
+    pub fn set_attached_property(&mut self, owner: impl Into<String>, name: impl Into<String>, value: XamlValue) {
+        self.attached_properties.insert((owner.into(), name.into()), value);
+    }
+
+    /// Remove an attached property, returning its value if it was set.
+    pub fn remove_attached_property(&mut self, owner: &str, name: &str) -> Option<XamlValue> {
+        self.attached_properties.remove(&(owner.to_string(), name.to_string()))
+    }
+
+    /// Get an attached property set on this element, such as `Grid.Row`.
+    ///
+    /// The owner matches with or without a namespace prefix, so `"Grid"`
+    /// also finds `local:Grid.Row`. Falls back to a bare `Name` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, TypeRegistry};
+    ///
+    /// let parser = XamlParser::new(TypeRegistry::new());
+    /// let doc = parser.parse_string(r#"<Button xmlns="http://test" Grid.Row="1"/>"#)?;
+    /// assert_eq!(doc.root.attached_property("Grid", "Row").and_then(|v| v.as_integer()), Some(1));
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn attached_property(&self, owner: &str, name: &str) -> Option<&XamlValue> {
+        self.attached_properties.get(&(owner.to_string(), name.to_string()))
+            .or_else(|| {
+                self.attached_properties
+                    .iter()
+                    .find(|((key_owner, key_name), _)| {
+                        key_name == name
+                            && key_owner.split_once(':').is_some_and(|(_, local)| local == owner)
+                    })
+                    .map(|(_, value)| value)
+            })
+            .or_else(|| self.attributes.get(name))
+    }
+
+    /// Get the attached properties keyed by `Owner.Name`, sorted by that key.
+    pub(crate) fn attached_by_name(&self) -> OrderedMap<XamlValue> {
+        let mut attached: Vec<_> = self.attached_properties.iter().collect();
+        attached.sort_by(|a, b| a.0.cmp(b.0));
+        let mut map = OrderedMap::new();
+        for ((owner, name), value) in attached {
+            map.insert(format!("{}.{}", owner, name), value.clone());
+        }
+        map
+    }
+
+    /// Get the `Grid.Row` of this element, or 0 if it is unset or not a number.
+    pub fn grid_row(&self) -> i32 {
+        self.grid_index("Row")
+    }
+
+    /// Get the `Grid.Column` of this element, or 0 if it is unset or not a number.
+    pub fn grid_column(&self) -> i32 {
+        self.grid_index("Column")
+    }
+
+    /// Read a `Grid` attached property that holds an index.
+    fn grid_index(&self, name: &str) -> i32 {
+        self.attached_property("Grid", name)
+            .and_then(|value| match value {
+                XamlValue::String(s) => s.trim().parse().ok(),
+                other => other.as_integer().and_then(|i| i32::try_from(i).ok()),
+            })
+            .unwrap_or(0)
+    }
+
+    /// Remove an attribute, returning its value if it was set.
+    pub fn remove_attribute(&mut self, name: &str) -> Option<XamlValue> {
+        self.attributes.remove(name)
+    }
+
     /// Set a property value.
     pub fn set_property(&mut self, name: impl Into<String>, value: XamlValue) {
         self.properties.insert(name.into(), value);
@@ -175,12 +422,51 @@ impl XamlElement {
         self.properties.get(name)
     }
 
+    /// Set the handler name for an event.
+    pub fn set_event_handler(&mut self, event: impl Into<String>, handler: impl Into<String>) {
+        self.events.insert(event.into(), handler.into());
+    }
+
+    /// Get the handler name for an event.
+    pub fn get_event_handler(&self, event: &str) -> Option<&str> {
+        self.events.get(event).map(|s| s.as_str())
+    }
+
+    /// Remove a property, returning its value if it was set.
+    pub fn remove_property(&mut self, name: &str) -> Option<XamlValue> {
+        self.properties.remove(name)
+    }
+
     /// Add a child node.
     pub fn add_child(&mut self, child: XamlNode) {
         self.children.push(child);
         self.flags.insert(ElementFlags::HAS_CHILDREN);
     }
 
+    /// Remove the child node at `index`, or return `None` if it is out of range.
+    ///
+    /// Removing the last child clears `HAS_CHILDREN`.
+    pub fn remove_child(&mut self, index: usize) -> Option<XamlNode> {
+        if index >= self.children.len() {
+            return None;
+        }
+        
+        let removed = self.children.remove(index);
+        if self.children.is_empty() {
+            self.flags.remove(ElementFlags::HAS_CHILDREN);
+        }
+        Some(removed)
+    }
+
+    /// Replace the child node at `index`, returning the old node.
+    ///
+    /// Returns `None` and leaves the children unchanged if `index` is out of range.
+    pub fn replace_child(&mut self, index: usize, child: XamlNode) -> Option<XamlNode> {
+        self.children
+            .get_mut(index)
+            .map(|slot| std::mem::replace(slot, child))
+    }
+
     /// Check if this element has any children.
     pub fn has_children(&self) -> bool {
         self.flags.contains(ElementFlags::HAS_CHILDREN)
@@ -239,6 +525,99 @@ impl XamlElement {
     }
 }
 
+/// Fluent builder for [`XamlElement`], for trees constructed in code.
+///
+/// Each method goes through the matching `XamlElement` setter, so element
+/// flags such as `HAS_NAME` and `HAS_CHILDREN` are set as they would be by
+/// the parser.
+#[derive(Debug, Clone)]
+pub struct XamlElementBuilder {
+    element: XamlElement,
+}
+
+impl XamlElementBuilder {
+    /// Set an attribute value.
+    pub fn attr(mut self, name: impl Into<String>, value: XamlValue) -> Self {
+        self.element.set_attribute(name, value);
+        self
+    }
+
+    /// Set an attached property, such as `Grid.Row`.
+    pub fn attached(mut self, owner: impl Into<String>, name: impl Into<String>, value: XamlValue) -> Self {
+        self.element.set_attached_property(owner, name, value);
+        self
+    }
+
+    /// Set a property value, as if by property element syntax.
+    pub fn property(mut self, name: impl Into<String>, value: XamlValue) -> Self {
+        self.element.set_property(name, value);
+        self
+    }
+
+    /// Set the handler name for an event.
+    pub fn event(mut self, event: impl Into<String>, handler: impl Into<String>) -> Self {
+        self.element.set_event_handler(event, handler);
+        self
+    }
+
+    /// Set the x:Name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.element.set_name(name);
+        self
+    }
+
+    /// Set the x:Key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.element.set_key(key);
+        self
+    }
+
+    /// Declare a namespace prefix; an empty prefix sets the default namespace.
+    pub fn namespace(mut self, prefix: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.element.declare_namespace(prefix, uri);
+        self
+    }
+
+    /// Append a child node.
+    pub fn child(mut self, child: XamlNode) -> Self {
+        self.element.add_child(child);
+        self
+    }
+
+    /// Set the source line, for trees that mirror a parsed document.
+    pub fn line(mut self, line: usize) -> Self {
+        self.element.line = line;
+        self
+    }
+
+    /// Finish building the element.
+    pub fn build(self) -> XamlElement {
+        self.element
+    }
+}
+
+/// Attached properties as a list of entries, since JSON keys must be strings.
+#[cfg(feature = "serde")]
+mod attached_entries {
+    use std::collections::HashMap;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use super::XamlValue;
+    
+    pub fn serialize<S: Serializer>(
+        map: &HashMap<(String, String), XamlValue>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(map)
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<HashMap<(String, String), XamlValue>, D::Error> {
+        let entries = Vec::<((String, String), XamlValue)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,6 +651,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_event_handlers() {
+        let type_name = XamlTypeName::new("Test", "Button");
+        let mut element = XamlElement::new(type_name);
+        
+        element.set_event_handler("Click", "OnClick");
+        
+        assert_eq!(element.get_event_handler("Click"), Some("OnClick"));
+        assert_eq!(element.get_event_handler("Loaded"), None);
+        assert!(element.attributes.is_empty());
+    }
+
     #[test]
     fn test_children() {
         let type_name = XamlTypeName::new("Test", "StackPanel");
@@ -287,4 +678,128 @@ mod tests {
         assert_eq!(element.text_content(), "Hello");
         assert_eq!(element.child_elements().count(), 1);
     }
+
+    #[test]
+    fn test_remove_attributes_and_properties() {
+        let type_name = XamlTypeName::new("Test", "Button");
+        let mut element = XamlElement::new(type_name);
+        
+        element.set_attribute("Content", XamlValue::String("OK".to_string()));
+        element.set_attribute("Width", XamlValue::Integer(100));
+        element.set_property("Tag", XamlValue::Null);
+        
+        assert_eq!(element.remove_attribute("Content"), Some(XamlValue::String("OK".to_string())));
+        assert_eq!(element.remove_attribute("Content"), None);
+        assert_eq!(element.attributes.keys().collect::<Vec<_>>(), ["Width"]);
+        
+        assert_eq!(element.remove_property("Tag"), Some(XamlValue::Null));
+        assert!(element.properties.is_empty());
+    }
+
+    #[test]
+    fn test_remove_last_child_clears_flag() {
+        let type_name = XamlTypeName::new("Test", "StackPanel");
+        let mut element = XamlElement::new(type_name);
+        
+        element.add_child(XamlNode::Text("one".to_string()));
+        element.add_child(XamlNode::Text("two".to_string()));
+        
+        assert_eq!(element.remove_child(5), None);
+        assert_eq!(element.remove_child(0), Some(XamlNode::Text("one".to_string())));
+        assert!(element.has_children());
+        
+        assert_eq!(element.remove_child(0), Some(XamlNode::Text("two".to_string())));
+        assert!(!element.has_children());
+        assert!(!element.has_flag(ElementFlags::HAS_CHILDREN));
+        
+        element.add_child(XamlNode::Text("three".to_string()));
+        assert!(element.has_children());
+    }
+
+    #[test]
+    fn test_replace_child() {
+        let type_name = XamlTypeName::new("Test", "StackPanel");
+        let mut element = XamlElement::new(type_name);
+        
+        element.add_child(XamlNode::Text("old".to_string()));
+        
+        let button = XamlElement::new(XamlTypeName::new("Test", "Button"));
+        let old = element.replace_child(0, XamlNode::Element(button));
+        
+        assert_eq!(old, Some(XamlNode::Text("old".to_string())));
+        assert_eq!(element.child_elements().count(), 1);
+        assert!(element.has_children());
+        assert_eq!(element.replace_child(1, XamlNode::Text("x".to_string())), None);
+        assert_eq!(element.children.len(), 1);
+    }
+
+    #[test]
+    fn test_builder_overrides() {
+        let original = XamlElement::builder(XamlTypeName::new("Test", "Button"))
+            .attr("Content", XamlValue::String("OK".to_string()))
+            .attr("Width", XamlValue::Integer(100))
+            .build();
+        
+        let copy = original
+            .to_builder()
+            .attr("Content", XamlValue::String("Cancel".to_string()))
+            .name("CancelButton")
+            .build();
+        
+        assert_eq!(original.get_attribute("Content").and_then(|v| v.as_string()), Some("OK"));
+        assert_eq!(copy.get_attribute("Content").and_then(|v| v.as_string()), Some("Cancel"));
+        assert_eq!(copy.get_attribute("Width"), original.get_attribute("Width"));
+        assert!(copy.has_flag(ElementFlags::HAS_NAME));
+        assert!(!original.has_flag(ElementFlags::HAS_NAME));
+    }
+
+    #[test]
+    fn test_numeric_accessors() {
+        assert_eq!(XamlValue::Integer(100).as_f64(), Some(100.0));
+        assert_eq!(XamlValue::Float(50.5).as_f64(), Some(50.5));
+        assert_eq!(XamlValue::String(" 12.5 ".to_string()).as_f64(), Some(12.5));
+        assert_eq!(XamlValue::Boolean(true).as_f64(), None);
+        
+        assert_eq!(XamlValue::Integer(42).as_u32(), Some(42));
+        assert_eq!(XamlValue::Integer(-1).as_u32(), None);
+        assert_eq!(XamlValue::Float(8.0).as_u32(), Some(8));
+        assert_eq!(XamlValue::Float(8.5).as_u32(), None);
+        assert_eq!(XamlValue::String("7".to_string()).as_u32(), Some(7));
+    }
+
+    #[test]
+    fn test_coerce_int_float() {
+        let double = XamlTypeName::new("System", "Double");
+        let int32 = XamlTypeName::new("System", "Int32");
+        
+        let value = XamlValue::Integer(100).coerce_to(&double).unwrap();
+        assert!(matches!(value, XamlValue::Float(f) if f == 100.0));
+        
+        let value = XamlValue::Float(3.0).coerce_to(&int32).unwrap();
+        assert_eq!(value.as_integer(), Some(3));
+        
+        assert!(matches!(
+            XamlValue::Float(3.5).coerce_to(&int32),
+            Err(XamlError::TypeMismatch { .. })
+        ));
+        assert!(XamlValue::Integer(i64::MAX).coerce_to(&int32).is_err());
+    }
+
+    #[test]
+    fn test_coerce_other_types() {
+        let boolean = XamlTypeName::new("System", "Boolean");
+        let string = XamlTypeName::new("System", "String");
+        let brush = XamlTypeName::new("Microsoft.UI.Xaml.Media", "Brush");
+        
+        let value = XamlValue::String("True".to_string()).coerce_to(&boolean).unwrap();
+        assert_eq!(value.as_bool(), Some(true));
+        
+        let value = XamlValue::Integer(5).coerce_to(&string).unwrap();
+        assert_eq!(value.as_string(), Some("5"));
+        
+        // Non-primitive targets and null pass through unchanged
+        let value = XamlValue::String("Red".to_string()).coerce_to(&brush).unwrap();
+        assert_eq!(value.as_string(), Some("Red"));
+        assert!(XamlValue::Null.coerce_to(&boolean).unwrap().is_null());
+    }
 }
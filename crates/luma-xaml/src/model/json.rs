@@ -0,0 +1,159 @@
+//! JSON export of the XAML object model (requires the `serde` feature).
+//!
+//! The JSON mirrors the tree: elements become objects with their type,
+//! namespace, attributes, attached properties, event handlers, properties,
+//! and children. Scalar values map to JSON scalars, while elements, markup
+//! extensions, collections, and type references are objects tagged with a
+//! `"kind"` field so they can be told apart from each other and from plain
+//! strings.
+
+use serde_json::{json, Map, Value};
+use crate::model::{OrderedMap, XamlDocument, XamlElement, XamlNode, XamlValue};
+
+impl XamlDocument {
+    /// Convert the document to a JSON value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, TypeRegistry};
+    ///
+    /// let parser = XamlParser::new(TypeRegistry::new());
+    /// let doc = parser.parse_string(r#"<Button xmlns="http://test" Content="OK"/>"#)?;
+    /// let json = doc.to_json();
+    /// assert_eq!(json["root"]["type"], "Button");
+    /// assert_eq!(json["root"]["attributes"]["Content"], "OK");
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let resources: Map<String, Value> = self.resources
+            .iter()
+            .map(|(key, value)| (key.clone(), value.to_json()))
+            .collect();
+        
+        json!({
+            "root": self.root.to_json(),
+            "resources": resources,
+        })
+    }
+}
+
+impl XamlElement {
+    /// Convert the element and its subtree to a JSON value.
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("kind".to_string(), json!("element"));
+        object.insert("type".to_string(), json!(self.type_name.name));
+        object.insert("namespace".to_string(), json!(self.type_name.namespace));
+        if let Some(name) = &self.name {
+            object.insert("name".to_string(), json!(name));
+        }
+        if let Some(key) = &self.key {
+            object.insert("key".to_string(), json!(key));
+        }
+        object.insert("attributes".to_string(), map_to_json(&self.attributes));
+        object.insert("properties".to_string(), map_to_json(&self.properties));
+        if !self.attached_properties.is_empty() {
+            object.insert("attached".to_string(), map_to_json(&self.attached_by_name()));
+        }
+        if !self.events.is_empty() {
+            let events: Map<String, Value> = self.events
+                .iter()
+                .map(|(event, handler)| (event.clone(), json!(handler)))
+                .collect();
+            object.insert("events".to_string(), Value::Object(events));
+        }
+        object.insert(
+            "children".to_string(),
+            Value::Array(self.children.iter().map(XamlNode::to_json).collect()),
+        );
+        Value::Object(object)
+    }
+}
+
+impl XamlNode {
+    /// Convert the node to a JSON value; text nodes become JSON strings.
+    pub fn to_json(&self) -> Value {
+        match self {
+            XamlNode::Element(element) => element.to_json(),
+            XamlNode::Text(text) | XamlNode::CData(text) => json!(text),
+        }
+    }
+}
+
+impl XamlValue {
+    /// Convert the value to a JSON value.
+    pub fn to_json(&self) -> Value {
+        match self {
+            XamlValue::String(s) => json!(s),
+            XamlValue::Integer(i) => json!(i),
+            XamlValue::Float(f) => json!(f),
+            XamlValue::Boolean(b) => json!(b),
+            XamlValue::Null => Value::Null,
+            XamlValue::Element(element) => element.to_json(),
+            XamlValue::MarkupExtension { extension_name, arguments } => {
+                // Sort arguments so the output is stable
+                let mut names: Vec<_> = arguments.keys().collect();
+                names.sort();
+                let arguments: Map<String, Value> = names
+                    .into_iter()
+                    .map(|name| (name.clone(), arguments[name].to_json()))
+                    .collect();
+                
+                json!({
+                    "kind": "markupExtension",
+                    "extension": extension_name,
+                    "arguments": arguments,
+                })
+            }
+            XamlValue::Collection(items) => json!({
+                "kind": "collection",
+                "items": items.iter().map(XamlValue::to_json).collect::<Vec<_>>(),
+            }),
+            XamlValue::Type(type_name) => json!({
+                "kind": "type",
+                "type": type_name.name,
+                "namespace": type_name.namespace,
+            }),
+        }
+    }
+}
+
+/// Convert an ordered attribute or property map to a JSON object, keeping order.
+fn map_to_json(map: &OrderedMap<XamlValue>) -> Value {
+    Value::Object(
+        map.iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::XamlTypeName;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_value_kinds_are_distinct() {
+        let mut arguments = HashMap::new();
+        arguments.insert("Path".to_string(), XamlValue::String("Name".to_string()));
+        let extension = XamlValue::MarkupExtension {
+            extension_name: "Binding".to_string(),
+            arguments,
+        };
+        let collection = XamlValue::Collection(vec![XamlValue::Integer(1), XamlValue::Null]);
+        
+        assert_eq!(extension.to_json(), json!({
+            "kind": "markupExtension",
+            "extension": "Binding",
+            "arguments": { "Path": "Name" },
+        }));
+        assert_eq!(collection.to_json(), json!({ "kind": "collection", "items": [1, null] }));
+        assert_eq!(
+            XamlValue::Type(XamlTypeName::new("System", "String")).to_json()["kind"],
+            "type"
+        );
+        assert_eq!(XamlValue::String("{Binding}".to_string()).to_json(), json!("{Binding}"));
+    }
+}
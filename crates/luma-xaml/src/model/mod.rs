@@ -2,6 +2,20 @@
 
 pub mod element;
 pub mod document;
+pub mod diff;
+pub mod ordered_map;
+pub mod path;
+pub mod style;
+pub mod template;
+mod validate;
+pub mod writer;
+#[cfg(feature = "serde")]
+pub mod json;
 
-pub use element::{XamlElement, XamlNode, XamlValue};
+pub use element::{XamlElement, XamlElementBuilder, XamlNode, XamlValue};
 pub use document::XamlDocument;
+pub use diff::{diff, XamlChange};
+pub use ordered_map::OrderedMap;
+pub use path::{ElementPath, ElementIndex};
+pub use style::{Style, Setter, StyleResources, apply_style, resolve_styles};
+pub use template::{ControlTemplate, TemplateBinding};
@@ -0,0 +1,210 @@
+//! Insertion-ordered string-keyed map for element attributes and properties.
+
+use std::borrow::Borrow;
+
+/// A map from names to values that iterates in insertion order.
+///
+/// XAML elements rarely carry more than a handful of attributes, so entries
+/// are kept in a `Vec` and looked up linearly. Re-inserting an existing key
+/// replaces its value but keeps its original position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderedMap<V> {
+    entries: Vec<(String, V)>,
+}
+
+impl<V> OrderedMap<V> {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Insert a value, returning the previous value for the key, if any.
+    pub fn insert(&mut self, key: impl Into<String>, value: V) -> Option<V> {
+        let key = key.into();
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Get the value for a key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        String: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+
+    /// Get a mutable reference to the value for a key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        String: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(move |index| &mut self.entries[index].1)
+    }
+
+    /// Check if the map contains a key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        String: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Remove a key, keeping the order of the remaining entries.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        String: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(|index| self.entries.remove(index).1)
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over `(key, value)` pairs in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Iterate over `(key, value)` pairs in insertion order, with mutable values.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut V)> {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Iterate over keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Iterate over values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        String: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+}
+
+impl<V> Default for OrderedMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Into<String>, V> FromIterator<(K, V)> for OrderedMap<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+// Serialized as a map so the entries keep their order in the output
+#[cfg(feature = "serde")]
+impl<V: serde::Serialize> serde::Serialize for OrderedMap<V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: serde::Deserialize<'de>> serde::Deserialize<'de> for OrderedMap<V> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedMapVisitor<V>(std::marker::PhantomData<V>);
+        
+        impl<'de, V: serde::Deserialize<'de>> serde::de::Visitor<'de> for OrderedMapVisitor<V> {
+            type Value = OrderedMap<V>;
+            
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map")
+            }
+            
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = OrderedMap::new();
+                while let Some((key, value)) = access.next_entry::<String, V>()? {
+                    map.insert(key, value);
+                }
+                Ok(map)
+            }
+        }
+        
+        deserializer.deserialize_map(OrderedMapVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<V> IntoIterator for OrderedMap<V> {
+    type Item = (String, V);
+    type IntoIter = std::vec::IntoIter<(String, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a OrderedMap<V> {
+    type Item = (&'a String, &'a V);
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, (String, V)>, fn(&'a (String, V)) -> (&'a String, &'a V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_order() {
+        let mut map = OrderedMap::new();
+        map.insert("Width", 1);
+        map.insert("Height", 2);
+        map.insert("Margin", 3);
+        
+        let keys: Vec<_> = map.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, ["Width", "Height", "Margin"]);
+    }
+
+    #[test]
+    fn test_replace_keeps_position() {
+        let mut map = OrderedMap::new();
+        map.insert("A", 1);
+        map.insert("B", 2);
+        
+        assert_eq!(map.insert("A", 10), Some(1));
+        assert_eq!(map.iter().next(), Some((&"A".to_string(), &10)));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_preserves_order() {
+        let mut map: OrderedMap<i32> = [("A", 1), ("B", 2), ("C", 3)].into_iter().collect();
+        
+        assert_eq!(map.remove("B"), Some(2));
+        assert_eq!(map.remove("B"), None);
+        let keys: Vec<_> = map.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, ["A", "C"]);
+    }
+}
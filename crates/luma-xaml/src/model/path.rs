@@ -0,0 +1,133 @@
+//! Element paths - stable positions in an element tree, for navigating upward.
+
+use std::collections::HashMap;
+use std::fmt;
+use crate::model::XamlElement;
+
+/// The position of an element in a tree, as child element indices from the root.
+///
+/// The root's path is empty; `[1, 0]` is the first child element of the
+/// root's second child element. Text and other non-element children are
+/// not counted. A path stays valid as long as the tree is not restructured.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementPath(Vec<usize>);
+
+impl ElementPath {
+    /// Get the path of the root element.
+    pub fn root() -> Self {
+        Self::default()
+    }
+    
+    /// Create a path from child element indices.
+    pub fn new(indices: Vec<usize>) -> Self {
+        Self(indices)
+    }
+    
+    /// Get the child element indices.
+    pub fn indices(&self) -> &[usize] {
+        &self.0
+    }
+    
+    /// Check if this is the root's path.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+    
+    /// Get the path of a child element of the element at this path.
+    pub fn child(&self, index: usize) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(index);
+        Self(indices)
+    }
+    
+    /// Get the path of the parent, or `None` for the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.0.split_last()?;
+        Some(Self(parent.to_vec()))
+    }
+    
+    /// Get the element at this path under `root`.
+    pub fn resolve<'a>(&self, root: &'a XamlElement) -> Option<&'a XamlElement> {
+        self.0
+            .iter()
+            .try_fold(root, |element, index| element.child_elements().nth(*index))
+    }
+}
+
+impl fmt::Display for ElementPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "/")?;
+        for (i, index) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{}", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// The parent of every element in a tree, built by
+/// [`XamlDocument::build_index`](crate::XamlDocument::build_index).
+///
+/// The tree has no parent pointers, so this lets tools walk upward from an
+/// element without the tree owning cycles. The index is a snapshot: rebuild
+/// it after the tree changes.
+#[derive(Debug, Clone, Default)]
+pub struct ElementIndex {
+    /// Every path in document order.
+    paths: Vec<ElementPath>,
+    
+    /// The parent of every path except the root.
+    parents: HashMap<ElementPath, ElementPath>,
+}
+
+impl ElementIndex {
+    /// Index the elements under `root`.
+    pub fn build(root: &XamlElement) -> Self {
+        let mut index = Self::default();
+        index.visit(root, ElementPath::root());
+        index
+    }
+    
+    fn visit(&mut self, element: &XamlElement, path: ElementPath) {
+        self.paths.push(path.clone());
+        for (i, child) in element.child_elements().enumerate() {
+            let child_path = path.child(i);
+            self.parents.insert(child_path.clone(), path.clone());
+            self.visit(child, child_path);
+        }
+    }
+    
+    /// Get the path of an element's parent, or `None` for the root or a path
+    /// that is not in the tree.
+    pub fn parent_of(&self, path: &ElementPath) -> Option<&ElementPath> {
+        self.parents.get(path)
+    }
+    
+    /// Iterate over an element's ancestors, from its parent up to the root.
+    pub fn ancestors<'a>(&'a self, path: &ElementPath) -> impl Iterator<Item = &'a ElementPath> + 'a {
+        std::iter::successors(self.parent_of(path), move |path| self.parent_of(path))
+    }
+    
+    /// Check if a path is in the tree.
+    pub fn contains(&self, path: &ElementPath) -> bool {
+        (path.is_root() && !self.paths.is_empty()) || self.parents.contains_key(path)
+    }
+    
+    /// Iterate over the paths of every element, in document order.
+    pub fn paths(&self) -> impl Iterator<Item = &ElementPath> {
+        self.paths.iter()
+    }
+    
+    /// Get the number of elements.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+    
+    /// Check if no elements are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
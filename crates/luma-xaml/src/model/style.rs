@@ -0,0 +1,334 @@
+//! Styles - named sets of property values shared between elements.
+
+use std::collections::HashMap;
+use crate::error::{Result, XamlError};
+use crate::model::{XamlElement, XamlNode, XamlValue};
+
+/// A property value set by a [`Style`], from a `<Setter Property="..." Value="..."/>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Setter {
+    /// The name of the property to set, such as `Background`.
+    pub property: String,
+    
+    /// The value to give the property.
+    pub value: XamlValue,
+}
+
+/// A `<Style>` element: a list of setters, optionally limited to one type.
+///
+/// Styles are usually declared in a resource dictionary and applied to
+/// elements with [`apply_style`].
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::{XamlParser, TypeRegistry};
+/// use luma_xaml::{Style, apply_style};
+///
+/// let parser = XamlParser::new(TypeRegistry::new());
+/// let style = parser.parse_string(r#"
+///     <Style xmlns="http://test" TargetType="Button">
+///         <Setter Property="Width" Value="80"/>
+///     </Style>
+/// "#)?.root;
+/// let style = Style::from_element(&style)?;
+///
+/// let mut button = parser.parse_string(r#"<Button xmlns="http://test"/>"#)?.root;
+/// apply_style(&mut button, &style);
+/// assert_eq!(button.get_attribute("Width").and_then(|v| v.as_integer()), Some(80));
+/// # Ok::<(), luma_xaml::XamlError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// The `x:Key` the style is declared with, if any.
+    pub key: Option<String>,
+    
+    /// The `TargetType` the style is meant for, as written (such as `Button`
+    /// or `local:MyButton`), or the type name of an `{x:Type}` target.
+    pub target_type: Option<String>,
+    
+    /// The setters, in document order.
+    pub setters: Vec<Setter>,
+}
+
+impl Style {
+    /// Build a style from a parsed `<Style>` element.
+    ///
+    /// A setter's value may be given as a `Value` attribute, as a
+    /// `<Setter.Value>` property element, or as the setter's child element.
+    /// Fails if the element is not a `Style`, if it contains an element other
+    /// than a `Setter`, or if a setter has no `Property` or no `Value`.
+    pub fn from_element(element: &XamlElement) -> Result<Self> {
+        if element.type_name.name != "Style" {
+            return Err(XamlError::TypeMismatch {
+                expected: "Style".to_string(),
+                actual: element.type_name.name.to_string(),
+                line: element.line,
+            });
+        }
+        
+        let setters = element
+            .child_elements()
+            .map(Setter::from_element)
+            .collect::<Result<Vec<_>>>()?;
+        
+        Ok(Self {
+            key: element.key.clone(),
+            target_type: match element.get_attribute("TargetType") {
+                Some(XamlValue::Type(type_name)) => Some(type_name.name.to_string()),
+                Some(value) => value.as_string().map(String::from),
+                None => None,
+            },
+            setters,
+        })
+    }
+}
+
+impl Setter {
+    /// Build a setter from a parsed `<Setter>` element.
+    fn from_element(element: &XamlElement) -> Result<Self> {
+        if element.type_name.name != "Setter" {
+            return Err(XamlError::TypeMismatch {
+                expected: "Setter".to_string(),
+                actual: element.type_name.name.to_string(),
+                line: element.line,
+            });
+        }
+        
+        let missing = |attribute: &str| XamlError::InvalidAttributeValue {
+            attribute: attribute.to_string(),
+            line: element.line,
+            details: format!("Setter requires a {}", attribute),
+        };
+        
+        let property = element
+            .get_attribute("Property")
+            .and_then(|value| value.as_string())
+            .ok_or_else(|| missing("Property"))?;
+        let value = match element.get_attribute("Value").or_else(|| element.get_property("Value")) {
+            Some(value) => value.clone(),
+            // Value is the content property, so it may also be the setter's only child
+            None => match element.child_elements().next() {
+                Some(child) => XamlValue::Element(Box::new(child.clone())),
+                None => return Err(missing("Value")),
+            },
+        };
+        
+        Ok(Self {
+            property: property.to_string(),
+            value,
+        })
+    }
+}
+
+/// Apply a style's setters to an element as attributes.
+///
+/// Properties the element already sets, as an attribute or a property
+/// element, keep their own value, as local values take precedence over
+/// styles in XAML. The style's target type is not checked.
+pub fn apply_style(element: &mut XamlElement, style: &Style) {
+    for setter in &style.setters {
+        // Attached properties such as Grid.Row are kept apart, as the parser does
+        if let Some((owner, name)) = setter.property.rsplit_once('.') {
+            if element.attached_property(owner, name).is_none() {
+                element.set_attached_property(owner, name, setter.value.clone());
+            }
+            continue;
+        }
+        
+        let is_set = element.get_attribute(&setter.property).is_some()
+            || element.get_property(&setter.property).is_some();
+        if !is_set {
+            element.set_attribute(setter.property.as_str(), setter.value.clone());
+        }
+    }
+}
+
+/// Styles declared in `Resources` property elements, by key and by target type.
+///
+/// A style with an `x:Key` is applied only where it is referenced; one
+/// without is the implicit style for every element of its `TargetType`.
+#[derive(Debug, Clone, Default)]
+pub struct StyleResources {
+    keyed: HashMap<String, Style>,
+    implicit: HashMap<String, Style>,
+}
+
+impl StyleResources {
+    /// Create an empty set of styles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Collect the styles in an element's `Resources` property element.
+    ///
+    /// Styles may sit directly in `Resources` or inside a
+    /// `<ResourceDictionary>`; other resources are skipped. Fails if a style
+    /// is malformed, or has neither an `x:Key` nor a `TargetType`.
+    pub fn from_element(element: &XamlElement) -> Result<Self> {
+        let mut resources = Self::new();
+        if let Some(value) = element.get_property("Resources") {
+            resources.collect(value)?;
+        }
+        Ok(resources)
+    }
+    
+    /// Add the styles in a resource value.
+    fn collect(&mut self, value: &XamlValue) -> Result<()> {
+        match value {
+            XamlValue::Element(element) if element.type_name.name == "ResourceDictionary" => {
+                for child in element.child_elements() {
+                    self.collect_element(child)?;
+                }
+            }
+            XamlValue::Element(element) => self.collect_element(element)?,
+            XamlValue::Collection(items) => {
+                for item in items {
+                    self.collect(item)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+    
+    fn collect_element(&mut self, element: &XamlElement) -> Result<()> {
+        if element.type_name.name != "Style" {
+            return Ok(());
+        }
+        let style = Style::from_element(element)?;
+        if style.key.is_none() && style.target_type.is_none() {
+            return Err(XamlError::InvalidAttributeValue {
+                attribute: "TargetType".to_string(),
+                line: element.line,
+                details: "A Style without an x:Key needs a TargetType".to_string(),
+            });
+        }
+        self.insert(style);
+        Ok(())
+    }
+    
+    /// Add a style, replacing any with the same key (or, for a keyless style, target type).
+    ///
+    /// Implicit styles are keyed by the target type's name without its
+    /// namespace prefix. A style with neither a key nor a target type can
+    /// never be applied, so it is dropped.
+    pub fn insert(&mut self, style: Style) {
+        if let Some(key) = &style.key {
+            self.keyed.insert(key.clone(), style);
+        } else if let Some(target_type) = &style.target_type {
+            let name = local_name(target_type).to_string();
+            self.implicit.insert(name, style);
+        }
+    }
+    
+    /// Get the style with an `x:Key`.
+    pub fn get(&self, key: &str) -> Option<&Style> {
+        self.keyed.get(key)
+    }
+    
+    /// Get the implicit style for elements of a type, by its name (such as `Button`).
+    pub fn implicit(&self, type_name: &str) -> Option<&Style> {
+        self.implicit.get(local_name(type_name))
+    }
+    
+    /// Check if there are no styles.
+    pub fn is_empty(&self) -> bool {
+        self.keyed.is_empty() && self.implicit.is_empty()
+    }
+}
+
+/// Strip the namespace prefix from a type name such as `local:MyButton`.
+fn local_name(type_name: &str) -> &str {
+    type_name.rsplit(':').next().unwrap_or(type_name)
+}
+
+/// Apply styles to an element and everything beneath it.
+///
+/// Each element gets the style it names explicitly, with a
+/// `Style="{StaticResource Key}"` attribute or a `<Button.Style>` property
+/// element, or failing that the implicit style for its type. Styles are
+/// looked up in the `Resources` of the element and then of its ancestors,
+/// so nearer declarations win. Styles are applied with [`apply_style`], so
+/// values set on an element are kept.
+///
+/// Fails if a style is malformed or a referenced key is not declared.
+pub fn resolve_styles(element: &mut XamlElement) -> Result<()> {
+    resolve(element, &mut Vec::new())
+}
+
+fn resolve(element: &mut XamlElement, scopes: &mut Vec<StyleResources>) -> Result<()> {
+    scopes.push(StyleResources::from_element(element)?);
+    
+    let result = style_for(element, scopes).and_then(|style| {
+        if let Some(style) = style {
+            apply_style(element, &style);
+        }
+        resolve_nested(element, scopes)
+    });
+    
+    scopes.pop();
+    result
+}
+
+/// Resolve the elements in an element's children and property values.
+fn resolve_nested(element: &mut XamlElement, scopes: &mut Vec<StyleResources>) -> Result<()> {
+    for (name, value) in element.properties.iter_mut() {
+        // Styles and other resources are not themselves styled
+        if name != "Resources" && name != "Style" {
+            resolve_value(value, scopes)?;
+        }
+    }
+    for child in element.children.iter_mut() {
+        if let XamlNode::Element(child) = child {
+            resolve(child, scopes)?;
+        }
+    }
+    Ok(())
+}
+
+fn resolve_value(value: &mut XamlValue, scopes: &mut Vec<StyleResources>) -> Result<()> {
+    match value {
+        XamlValue::Element(element) => resolve(element, scopes),
+        XamlValue::Collection(items) => items.iter_mut().try_for_each(|item| resolve_value(item, scopes)),
+        _ => Ok(()),
+    }
+}
+
+/// Find the style for an element: its explicit style if it has one, else the implicit one.
+fn style_for(element: &XamlElement, scopes: &[StyleResources]) -> Result<Option<Style>> {
+    if let Some(XamlValue::Element(style)) = element.get_property("Style") {
+        return Style::from_element(style).map(Some);
+    }
+    
+    match element.get_attribute("Style") {
+        Some(XamlValue::MarkupExtension { extension_name, arguments })
+            if extension_name == "StaticResource" || extension_name == "DynamicResource" =>
+        {
+            let key = arguments
+                .get("_positional")
+                .or_else(|| arguments.get("ResourceKey"))
+                .and_then(|key| key.as_string())
+                .unwrap_or_default();
+            scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(key))
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| XamlError::ResourceNotFound {
+                    key: key.to_string(),
+                    line: element.line,
+                })
+        }
+        // Any other explicit style replaces the implicit one, but cannot be looked up here
+        Some(_) => Ok(None),
+        None => Ok(scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.implicit(&element.type_name.name))
+            .cloned()),
+    }
+}
@@ -0,0 +1,203 @@
+//! Control templates - the visual tree that replaces a control's default look.
+
+use crate::error::{Result, XamlError};
+use crate::model::{XamlElement, XamlNode, XamlValue};
+
+/// A `{TemplateBinding Property}` in a template's visual tree.
+///
+/// Links a value in the template to a property of the control the template
+/// is applied to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemplateBinding {
+    /// The position of the bound element under the template root, as child
+    /// element indices (empty for the root itself).
+    pub element_path: Vec<usize>,
+    
+    /// The attribute or property element on that element that is bound.
+    pub target: String,
+    
+    /// The property of the templated control that provides the value.
+    pub property: String,
+}
+
+/// A `<ControlTemplate>`: a visual tree and the `TargetType` it is for.
+///
+/// The `{TemplateBinding}` values in the tree are collected when the
+/// template is built, so they can be resolved against a control with
+/// [`ControlTemplate::apply`].
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::{XamlParser, TypeRegistry, ControlTemplate};
+///
+/// let parser = XamlParser::new(TypeRegistry::new());
+/// let template = parser.parse_string(r#"
+///     <ControlTemplate xmlns="http://test" TargetType="Button">
+///         <Border Background="{TemplateBinding Background}"/>
+///     </ControlTemplate>
+/// "#)?.root;
+/// let template = ControlTemplate::from_element(&template)?;
+///
+/// let button = parser.parse_string(r#"<Button xmlns="http://test" Background="Red"/>"#)?.root;
+/// let visual = template.apply(&button);
+/// assert_eq!(visual.get_attribute("Background").and_then(|v| v.as_string()), Some("Red"));
+/// # Ok::<(), luma_xaml::XamlError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlTemplate {
+    /// The `TargetType` the template is meant for, as written.
+    pub target_type: Option<String>,
+    
+    /// The root of the visual tree.
+    pub root: XamlElement,
+    
+    /// The template bindings in the visual tree, in document order.
+    pub bindings: Vec<TemplateBinding>,
+}
+
+impl ControlTemplate {
+    /// Build a template from a parsed `<ControlTemplate>` element.
+    ///
+    /// Fails if the element is not a `ControlTemplate`, does not contain
+    /// exactly one root element, or has a `{TemplateBinding}` without a
+    /// property name.
+    pub fn from_element(element: &XamlElement) -> Result<Self> {
+        if element.type_name.name != "ControlTemplate" {
+            return Err(XamlError::TypeMismatch {
+                expected: "ControlTemplate".to_string(),
+                actual: element.type_name.name.to_string(),
+                line: element.line,
+            });
+        }
+        
+        let mut roots = element.child_elements();
+        let root = match (roots.next(), roots.next()) {
+            (Some(root), None) => root.clone(),
+            _ => {
+                return Err(XamlError::InvalidAttributeValue {
+                    attribute: "Content".to_string(),
+                    line: element.line,
+                    details: "A ControlTemplate needs exactly one root element".to_string(),
+                });
+            }
+        };
+        
+        let mut bindings = Vec::new();
+        collect_bindings(&root, &mut Vec::new(), &mut bindings)?;
+        
+        Ok(Self {
+            target_type: element.get_attribute("TargetType").and_then(|value| match value {
+                XamlValue::Type(type_name) => Some(type_name.name.to_string()),
+                value => value.as_string().map(String::from),
+            }),
+            root,
+            bindings,
+        })
+    }
+    
+    /// Get the element a binding is on.
+    pub fn element(&self, binding: &TemplateBinding) -> Option<&XamlElement> {
+        binding
+            .element_path
+            .iter()
+            .try_fold(&self.root, |element, index| element.child_elements().nth(*index))
+    }
+    
+    /// Get the value a binding takes from a control: its attribute or
+    /// property element for the bound property, if it sets one.
+    pub fn resolve<'a>(&self, binding: &TemplateBinding, control: &'a XamlElement) -> Option<&'a XamlValue> {
+        control
+            .get_attribute(&binding.property)
+            .or_else(|| control.get_property(&binding.property))
+    }
+    
+    /// Create the visual tree for a control.
+    ///
+    /// Each template binding is replaced with the control's value for its
+    /// property. Bindings to properties the control does not set are removed,
+    /// so the element falls back to its default.
+    pub fn apply(&self, control: &XamlElement) -> XamlElement {
+        let mut root = self.root.clone();
+        for binding in &self.bindings {
+            let value = self.resolve(binding, control).cloned();
+            let Some(element) = element_mut(&mut root, &binding.element_path) else {
+                continue;
+            };
+            let in_attributes = element.attributes.contains_key(&binding.target);
+            match (value, in_attributes) {
+                (Some(value), true) => element.set_attribute(binding.target.as_str(), value),
+                (Some(value), false) => element.set_property(binding.target.as_str(), value),
+                (None, true) => {
+                    element.remove_attribute(&binding.target);
+                }
+                (None, false) => {
+                    element.remove_property(&binding.target);
+                }
+            }
+        }
+        root
+    }
+}
+
+/// Get the property name of a `{TemplateBinding}` value, or `None` for any other value.
+fn template_binding(value: &XamlValue, line: usize) -> Option<Result<String>> {
+    let XamlValue::MarkupExtension { extension_name, arguments } = value else {
+        return None;
+    };
+    if extension_name != "TemplateBinding" {
+        return None;
+    }
+    
+    let property = arguments
+        .get("_positional")
+        .or_else(|| arguments.get("Property"))
+        .and_then(|property| property.as_string())
+        .map(|property| property.trim().to_string())
+        .filter(|property| !property.is_empty())
+        .ok_or_else(|| XamlError::InvalidMarkupExtension {
+            line,
+            details: "TemplateBinding requires a property name".to_string(),
+        });
+    Some(property)
+}
+
+/// Record the template bindings of an element and its child elements.
+fn collect_bindings(
+    element: &XamlElement,
+    path: &mut Vec<usize>,
+    bindings: &mut Vec<TemplateBinding>,
+) -> Result<()> {
+    for (target, value) in element.attributes.iter().chain(element.properties.iter()) {
+        if let Some(property) = template_binding(value, element.line) {
+            bindings.push(TemplateBinding {
+                element_path: path.clone(),
+                target: target.clone(),
+                property: property?,
+            });
+        }
+    }
+    
+    for (index, child) in element.child_elements().enumerate() {
+        path.push(index);
+        collect_bindings(child, path, bindings)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// Get the element at a path of child element indices.
+fn element_mut<'a>(root: &'a mut XamlElement, path: &[usize]) -> Option<&'a mut XamlElement> {
+    path.iter().try_fold(root, |element, index| {
+        element
+            .children
+            .iter_mut()
+            .filter_map(|child| match child {
+                XamlNode::Element(child) => Some(child),
+                _ => None,
+            })
+            .nth(*index)
+    })
+}
@@ -0,0 +1,194 @@
+//! Validation of a parsed document against a type registry.
+//!
+//! Parsing stops at the first error; validation walks the whole tree and
+//! collects every problem it finds, so tooling can report them in one pass.
+
+use crate::converters::ConverterRegistry;
+use crate::error::XamlError;
+use crate::model::{XamlDocument, XamlElement, XamlValue};
+use crate::types::{NamespaceMap, TypeRegistry, XamlProperty, XamlTypeName};
+
+impl XamlDocument {
+    /// Check the document against a type registry, returning every error found.
+    ///
+    /// Reports elements whose type is not registered, attributes and
+    /// property elements that are not declared on the element's type (or,
+    /// for attached properties such as `Grid.Row`, on the owner type), and
+    /// values that the built-in converter for the property's declared type
+    /// rejects. Prefixed attributes such as `x:Uid` are not checked.
+    ///
+    /// Errors are reported at the line of the element they occur on. An
+    /// empty result means the document is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, ParserSettings, TypeRegistry};
+    ///
+    /// let parser = XamlParser::new(TypeRegistry::new()).with_settings(ParserSettings::new().lenient());
+    /// let doc = parser.parse_string(r#"<Button xmlns="http://test"/>"#)?;
+    ///
+    /// let errors = doc.validate(&TypeRegistry::new());
+    /// assert_eq!(errors.len(), 1);
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn validate(&self, registry: &TypeRegistry) -> Vec<XamlError> {
+        let mut validator = Validator {
+            registry,
+            converters: ConverterRegistry::new(),
+            errors: Vec::new(),
+        };
+        
+        validator.element(&self.root, &NamespaceMap::new());
+        for value in self.resources.values() {
+            validator.value(value, &NamespaceMap::new());
+        }
+        
+        validator.errors
+    }
+}
+
+/// Walks a document, collecting errors.
+struct Validator<'a> {
+    registry: &'a TypeRegistry,
+    converters: ConverterRegistry,
+    errors: Vec<XamlError>,
+}
+
+impl<'a> Validator<'a> {
+    /// Validate an element and everything beneath it.
+    fn element(&mut self, element: &XamlElement, outer_namespaces: &NamespaceMap) {
+        let namespaces = outer_namespaces.enter(element);
+        
+        // Members of an unknown type cannot be checked, so one error covers the element
+        if self.registry.lookup_type(&element.type_name).is_some() {
+            self.members(element, &namespaces);
+        } else {
+            self.errors.push(XamlError::UnknownType {
+                type_name: element.type_name.full_name(),
+                line: element.line,
+            });
+        }
+        
+        for (_, value) in element.attributes.iter().chain(element.properties.iter()) {
+            self.value(value, &namespaces);
+        }
+        for value in element.attached_properties.values() {
+            self.value(value, &namespaces);
+        }
+        for child in element.child_elements() {
+            self.element(child, &namespaces);
+        }
+    }
+    
+    /// Check the attributes and property elements of an element of a known type.
+    fn members(&mut self, element: &XamlElement, namespaces: &NamespaceMap) {
+        for (name, value) in element.attributes.iter() {
+            if name.contains(':') {
+                continue;
+            }
+            if let Some(property) = self.property(element, name, namespaces) {
+                self.check_value(element, name, property, value);
+            }
+        }
+        
+        for (name, value) in element.properties.iter() {
+            if let Some(property) = self.property(element, name, namespaces) {
+                self.check_value(element, name, property, value);
+            }
+        }
+        
+        for (name, value) in element.attached_by_name().iter() {
+            if name.contains(':') {
+                continue;
+            }
+            if let Some(property) = self.property(element, name, namespaces) {
+                self.check_value(element, name, property, value);
+            }
+        }
+        
+        for event in element.events.keys() {
+            self.property(element, event, namespaces);
+        }
+    }
+    
+    /// Find a property by its attribute name, recording an error if it is not declared.
+    fn property(
+        &mut self,
+        element: &XamlElement,
+        name: &str,
+        namespaces: &NamespaceMap,
+    ) -> Option<&'a XamlProperty> {
+        let (owner, property_name) = match name.rsplit_once('.') {
+            Some((owner, property_name)) => {
+                let owner = XamlTypeName::from_qualified(owner, namespaces)
+                    .unwrap_or_else(|_| XamlTypeName::new("", owner));
+                if self.registry.lookup_type(&owner).is_none() {
+                    self.errors.push(XamlError::UnknownType {
+                        type_name: owner.full_name(),
+                        line: element.line,
+                    });
+                    return None;
+                }
+                (owner, property_name)
+            }
+            None => (element.type_name.clone(), name),
+        };
+        
+        let property = self.registry
+            .get_all_properties(&owner)
+            .into_iter()
+            .find(|property| property.name == property_name);
+        if property.is_none() {
+            self.errors.push(XamlError::UnknownProperty {
+                type_name: owner.full_name(),
+                property: property_name.to_string(),
+                line: element.line,
+            });
+        }
+        property
+    }
+    
+    /// Check that a scalar value fits the declared type of its property.
+    fn check_value(&mut self, element: &XamlElement, name: &str, property: &XamlProperty, value: &XamlValue) {
+        let Some(convert) = self.converters.get(&property.type_name) else {
+            return;
+        };
+        let text = match value {
+            XamlValue::String(s) => s.strip_prefix("{}").unwrap_or(s).to_string(),
+            XamlValue::Integer(i) => i.to_string(),
+            XamlValue::Float(f) => f.to_string(),
+            XamlValue::Boolean(b) => b.to_string(),
+            _ => return,
+        };
+        
+        if let Err(e) = convert(&text) {
+            self.errors.push(match e {
+                XamlError::InvalidAttributeValue { details, .. } => XamlError::InvalidAttributeValue {
+                    attribute: name.to_string(),
+                    line: element.line,
+                    details,
+                },
+                other => other,
+            });
+        }
+    }
+    
+    /// Validate any elements nested inside a value.
+    fn value(&mut self, value: &XamlValue, namespaces: &NamespaceMap) {
+        match value {
+            XamlValue::Element(element) => self.element(element, namespaces),
+            XamlValue::Collection(items) => {
+                for item in items {
+                    self.value(item, namespaces);
+                }
+            }
+            XamlValue::MarkupExtension { arguments, .. } => {
+                for argument in arguments.values() {
+                    self.value(argument, namespaces);
+                }
+            }
+            _ => {}
+        }
+    }
+}
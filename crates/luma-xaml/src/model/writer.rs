@@ -0,0 +1,232 @@
+//! XAML export of the object model.
+//!
+//! The writer produces compact XAML with no added whitespace: namespace
+//! declarations come first, then `x:Name`, `x:Key`, attributes, attached
+//! properties and event handlers, followed by property elements and children.
+//! Text and attribute values are XML-escaped, and text that came from a CDATA
+//! section is written back as CDATA.
+
+use quick_xml::escape::{escape, partial_escape};
+use crate::model::{XamlDocument, XamlElement, XamlNode, XamlValue};
+use crate::types::{NamespaceMap, XamlTypeName};
+
+impl XamlDocument {
+    /// Write the document back out as XAML.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, TypeRegistry};
+    ///
+    /// let parser = XamlParser::new(TypeRegistry::new());
+    /// let xaml = r#"<TextBlock xmlns="http://test">Fish &amp; Chips</TextBlock>"#;
+    /// let doc = parser.parse_string(xaml)?;
+    /// assert_eq!(doc.to_xaml(), xaml);
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn to_xaml(&self) -> String {
+        self.root.to_xaml()
+    }
+}
+
+impl XamlElement {
+    /// Write the element and its content as XAML.
+    ///
+    /// Namespace prefixes are resolved against the declarations on this
+    /// element and its descendants only.
+    pub fn to_xaml(&self) -> String {
+        let mut out = String::new();
+        write_element(self, &NamespaceMap::new(), &mut out);
+        out
+    }
+}
+
+/// Append an element, resolving prefixes in the scope of its parent.
+fn write_element(element: &XamlElement, outer: &NamespaceMap, out: &mut String) {
+    let namespaces = outer.enter(element);
+    let tag = qualified_name(&element.type_name, &namespaces);
+    
+    out.push('<');
+    out.push_str(&tag);
+    
+    // HashMap order isn't stable, so declarations and handlers are sorted
+    let mut declarations: Vec<_> = element.namespaces.iter().collect();
+    declarations.sort();
+    for (prefix, uri) in declarations {
+        let attr_name = if prefix.is_empty() { "xmlns".to_string() } else { format!("xmlns:{}", prefix) };
+        write_attribute(&attr_name, uri, out);
+    }
+    if let Some(name) = &element.name {
+        write_attribute("x:Name", name, out);
+    }
+    if let Some(key) = &element.key {
+        write_attribute("x:Key", key, out);
+    }
+    for (attr_name, value) in element.attributes.iter() {
+        write_attribute(attr_name, &attribute_text(value, &namespaces), out);
+    }
+    for (attr_name, value) in element.attached_by_name().iter() {
+        write_attribute(attr_name, &attribute_text(value, &namespaces), out);
+    }
+    let mut events: Vec<_> = element.events.iter().collect();
+    events.sort();
+    for (event, handler) in events {
+        write_attribute(event, handler, out);
+    }
+    
+    if element.properties.is_empty() && element.children.is_empty() {
+        out.push_str("/>");
+        return;
+    }
+    out.push('>');
+    
+    for (property, value) in element.properties.iter() {
+        out.push_str(&format!("<{}.{}>", tag, property));
+        write_content(value, &namespaces, out);
+        out.push_str(&format!("</{}.{}>", tag, property));
+    }
+    for child in &element.children {
+        match child {
+            XamlNode::Element(child) => write_element(child, &namespaces, out),
+            XamlNode::Text(text) => out.push_str(&partial_escape(text.as_str())),
+            XamlNode::CData(text) => write_cdata(text, out),
+        }
+    }
+    
+    out.push_str(&format!("</{}>", tag));
+}
+
+/// Append ` name="value"`, escaping the value.
+fn write_attribute(name: &str, value: &str, out: &mut String) {
+    out.push_str(&format!(" {}=\"{}\"", name, escape(value)));
+}
+
+/// Append the content of a property element.
+fn write_content(value: &XamlValue, namespaces: &NamespaceMap, out: &mut String) {
+    match value {
+        XamlValue::Element(element) => write_element(element, namespaces, out),
+        XamlValue::Collection(items) => {
+            for item in items {
+                write_content(item, namespaces, out);
+            }
+        }
+        // An empty property element parses as null
+        XamlValue::Null => {}
+        other => out.push_str(&partial_escape(attribute_text(other, namespaces).as_str())),
+    }
+}
+
+/// Append a CDATA section.
+///
+/// A `]]>` inside the text would end the section early, so it is split
+/// across two sections.
+fn write_cdata(text: &str, out: &mut String) {
+    out.push_str("<![CDATA[");
+    out.push_str(&text.replace("]]>", "]]]]><![CDATA[>"));
+    out.push_str("]]>");
+}
+
+/// Get the unescaped attribute form of a value.
+///
+/// Strings that would read as a markup extension get the `{}` escape; the
+/// parser keeps that escape in the value, so one already there is left alone.
+fn attribute_text(value: &XamlValue, namespaces: &NamespaceMap) -> String {
+    match value {
+        XamlValue::String(s) if s.starts_with('{') && !s.starts_with("{}") => format!("{{}}{}", s),
+        XamlValue::String(s) => s.clone(),
+        XamlValue::Integer(i) => i.to_string(),
+        XamlValue::Float(f) => f.to_string(),
+        XamlValue::Boolean(b) => if *b { "True" } else { "False" }.to_string(),
+        XamlValue::Null => "{x:Null}".to_string(),
+        XamlValue::Type(type_name) => format!("{{x:Type {}}}", qualified_name(type_name, namespaces)),
+        XamlValue::MarkupExtension { extension_name, arguments } => {
+            let mut parts = Vec::new();
+            if let Some(positional) = arguments.get("_positional") {
+                parts.push(attribute_text(positional, namespaces));
+            }
+            let mut named: Vec<_> = arguments.iter().filter(|(key, _)| *key != "_positional").collect();
+            named.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, value) in named {
+                parts.push(format!("{}={}", key, attribute_text(value, namespaces)));
+            }
+            
+            if parts.is_empty() {
+                format!("{{{}}}", extension_name)
+            } else {
+                format!("{{{} {}}}", extension_name, parts.join(", "))
+            }
+        }
+        // Only property elements hold these; there is no attribute form
+        XamlValue::Element(_) | XamlValue::Collection(_) => String::new(),
+    }
+}
+
+/// Get the `prefix:Name` form of a type name.
+///
+/// The default namespace is preferred; a namespace with no declared prefix
+/// leaves the name bare.
+fn qualified_name(type_name: &XamlTypeName, namespaces: &NamespaceMap) -> String {
+    if namespaces.default_namespace() == type_name.namespace {
+        return type_name.name.to_string();
+    }
+    
+    let mut prefixes: Vec<_> = namespaces
+        .iter()
+        .filter(|(prefix, uri)| !prefix.is_empty() && *uri == type_name.namespace)
+        .map(|(prefix, _)| prefix)
+        .collect();
+    prefixes.sort();
+    match prefixes.first() {
+        Some(prefix) => format!("{}:{}", prefix, type_name.name),
+        None => type_name.name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{XamlParser, TypeRegistry};
+    
+    fn round_trip(xaml: &str) -> String {
+        XamlParser::new(TypeRegistry::new()).parse_string(xaml).unwrap().to_xaml()
+    }
+    
+    #[test]
+    fn test_escaped_text_round_trips() {
+        let xaml = concat!(
+            r#"<StackPanel xmlns="http://test" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml">"#,
+            r#"<TextBlock x:Name="Title" Text="&quot;A&quot; &amp; &lt;B&gt;">1 &lt; 2 &amp;&amp; 3 &gt; 2</TextBlock>"#,
+            r#"<TextBlock><![CDATA[if (a < b && c) { }]]></TextBlock>"#,
+            r#"</StackPanel>"#,
+        );
+        
+        assert_eq!(round_trip(xaml), xaml);
+    }
+    
+    #[test]
+    fn test_cdata_stays_cdata() {
+        let doc = XamlParser::new(TypeRegistry::new())
+            .parse_string(r#"<TextBlock xmlns="http://test"><![CDATA[a & b]]></TextBlock>"#)
+            .unwrap();
+        
+        assert_eq!(doc.root.children, [crate::XamlNode::CData("a & b".to_string())]);
+        assert_eq!(doc.root.text_content(), "a & b");
+    }
+    
+    #[test]
+    fn test_cdata_end_marker_is_split() {
+        let mut out = String::new();
+        super::write_cdata("a]]>b", &mut out);
+        assert_eq!(out, "<![CDATA[a]]]]><![CDATA[>b]]>");
+    }
+    
+    #[test]
+    fn test_values_and_property_elements() {
+        let xaml = concat!(
+            r#"<Button xmlns="http://test" Width="100" IsEnabled="False" Content="{}{literal}" Tag="{Binding Name, Mode=TwoWay}">"#,
+            r#"<Button.Flyout><Flyout/></Button.Flyout>"#,
+            r#"</Button>"#,
+        );
+        
+        assert_eq!(round_trip(xaml), xaml);
+    }
+}
@@ -1,9 +1,11 @@
 //! XAML parser - parses XAML files and strings into object models.
 
 use crate::model::XamlDocument;
-use crate::types::TypeRegistry;
+use crate::types::{TypeRegistry, NamespaceMap, StringInterner};
 use crate::flags::ParserFlags;
 use crate::error::{Result, XamlError};
+use crate::converters::ConverterRegistry;
+use crate::markup::MarkupArgument;
 use std::path::Path;
 
 /// Settings for the XAML parser.
@@ -11,17 +13,31 @@ use std::path::Path;
 pub struct ParserSettings {
     /// Parser behavior flags.
     pub flags: ParserFlags,
+    
+    /// Deepest allowed element nesting; the root element is at depth 1.
+    pub max_depth: usize,
+    
+    /// Most elements allowed in one document.
+    pub max_elements: usize,
 }
 
 impl Default for ParserSettings {
     fn default() -> Self {
         Self {
             flags: ParserFlags::DEFAULT,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_elements: Self::DEFAULT_MAX_ELEMENTS,
         }
     }
 }
 
 impl ParserSettings {
+    /// Default for [`max_depth`](Self::max_depth).
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+    
+    /// Default for [`max_elements`](Self::max_elements).
+    pub const DEFAULT_MAX_ELEMENTS: usize = 1_000_000;
+    
     /// Create new parser settings with default flags.
     pub fn new() -> Self {
         Self::default()
@@ -29,7 +45,7 @@ impl ParserSettings {
 
     /// Create parser settings with custom flags.
     pub fn with_flags(flags: ParserFlags) -> Self {
-        Self { flags }
+        Self { flags, ..Self::default() }
     }
 
     /// Enable strict mode (unknown types cause errors).
@@ -57,11 +73,54 @@ impl ParserSettings {
         self
     }
 
+    /// Keep {x:Null} and {x:Type} as raw markup extensions (for tooling).
+    pub fn raw_markup_extensions(mut self) -> Self {
+        self.flags.insert(ParserFlags::RAW_MARKUP_EXTENSIONS);
+        self
+    }
+
+    /// Normalize whitespace in text content following XAML rules.
+    pub fn normalize_whitespace(mut self) -> Self {
+        self.flags.insert(ParserFlags::NORMALIZE_WHITESPACE);
+        self
+    }
+
+    /// Reject duplicate x:Name values within a namescope.
+    pub fn validate_names(mut self) -> Self {
+        self.flags.insert(ParserFlags::VALIDATE_NAMES);
+        self
+    }
+
+    /// Intern element type names and namespace URIs.
+    ///
+    /// Every element of the same type then shares its name and namespace
+    /// strings instead of allocating its own copies. The parsed document is
+    /// the same either way; this only saves memory on large documents.
+    pub fn intern_strings(mut self) -> Self {
+        self.flags.insert(ParserFlags::INTERN_STRINGS);
+        self
+    }
+
     /// Enable namespace validation.
     pub fn validate_namespaces(mut self) -> Self {
         self.flags.insert(ParserFlags::VALIDATE_NAMESPACES);
         self
     }
+
+    /// Limit how deeply elements may nest.
+    ///
+    /// Elements are parsed recursively, so this bounds stack use on deeply
+    /// nested (or hostile) documents.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Limit the total number of elements in a document.
+    pub fn max_elements(mut self, max_elements: usize) -> Self {
+        self.max_elements = max_elements;
+        self
+    }
 }
 
 /// XAML parser that converts XAML text into an object model.
@@ -71,6 +130,9 @@ pub struct XamlParser {
     
     /// Parser settings.
     settings: ParserSettings,
+    
+    /// Converters for attribute values, by declared property type.
+    converters: ConverterRegistry,
 }
 
 impl XamlParser {
@@ -79,6 +141,7 @@ impl XamlParser {
         Self {
             registry,
             settings: ParserSettings::default(),
+            converters: ConverterRegistry::new(),
         }
     }
 
@@ -88,15 +151,65 @@ impl XamlParser {
         self
     }
 
+    /// Set the converters applied to attribute values.
+    ///
+    /// The parser starts with [`ConverterRegistry::new`], which holds the
+    /// built-in converters; pass an extended registry to convert custom
+    /// property types. Converters only run with `ParserFlags::VALIDATE_TYPES`.
+    pub fn with_converters(mut self, converters: ConverterRegistry) -> Self {
+        self.converters = converters;
+        self
+    }
+
     /// Parse a XAML file.
     pub fn parse_file(&self, path: &Path) -> Result<XamlDocument> {
-        let content = std::fs::read_to_string(path)?;
-        self.parse_string(&content)
+        let file = std::fs::File::open(path)?;
+        self.parse_reader(std::io::BufReader::new(file))
     }
 
     /// Parse a XAML string.
     pub fn parse_string(&self, xaml: &str) -> Result<XamlDocument> {
-        let mut reader = crate::reader::XamlReader::from_str(xaml);
+        self.parse_reader(xaml.as_bytes())
+    }
+
+    /// Parse XAML from any buffered reader, such as a file or network stream.
+    ///
+    /// The input is read incrementally rather than loaded into memory first.
+    /// A leading byte-order mark is honored: a UTF-8 BOM is skipped, and
+    /// UTF-16 (LE or BE) input, as saved by Visual Studio, is decoded up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{XamlParser, TypeRegistry};
+    /// use std::io::Cursor;
+    ///
+    /// let input = Cursor::new(br#"<Button xmlns="http://test" Content="OK"/>"#.to_vec());
+    /// let doc = XamlParser::new(TypeRegistry::new()).parse_reader(input)?;
+    /// assert_eq!(doc.root.type_name.name, "Button");
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn parse_reader<R: std::io::BufRead>(&self, mut reader: R) -> Result<XamlDocument> {
+        use std::io::Read;
+        
+        let (prefix, len) = read_bom_prefix(&mut reader)?;
+        let prefix = &prefix[..len];
+        match detect_bom(prefix) {
+            // The prefix is exactly the UTF-8 mark
+            Some(Bom::Utf8) => self.parse_events(reader),
+            Some(Bom::Utf16 { big_endian }) => {
+                let mut bytes = prefix[2..].to_vec();
+                reader.read_to_end(&mut bytes)?;
+                let text = decode_utf16(&bytes, big_endian)?;
+                self.parse_events(text.as_bytes())
+            }
+            None => self.parse_events(prefix.chain(reader)),
+        }
+    }
+    
+    /// Parse UTF-8 XAML from a reader positioned after any byte-order mark.
+    fn parse_events<R: std::io::BufRead>(&self, reader: R) -> Result<XamlDocument> {
+        let mut reader = crate::reader::XamlReader::new(quick_xml::Reader::from_reader(reader));
         let mut context = ParseContext::new(&self.registry, &self.settings);
         
         // Skip any leading whitespace or comments
@@ -107,9 +220,9 @@ impl XamlParser {
                     // Skip whitespace
                     continue;
                 }
-                crate::reader::XamlEvent::StartElement { name, attributes, is_empty } => {
+                crate::reader::XamlEvent::StartElement { name, attributes, attribute_lines, is_empty, line } => {
                     // Found the root element - parse it directly
-                    let root = self.parse_root_element(name, attributes, is_empty, &mut reader, &mut context)?;
+                    let root = self.parse_element(name, attributes.into_iter().zip(attribute_lines).collect(), is_empty, line, &mut reader, &mut context)?;
                     
                     // Create the document
                     let mut doc = XamlDocument::new(root);
@@ -131,12 +244,16 @@ impl XamlParser {
         }
     }
     
-    /// Parse the root element with known start event data.
-    fn parse_root_element<R: std::io::BufRead>(
+    /// Parse an element whose start tag has already been read.
+    ///
+    /// `attributes` pairs each attribute with the line it appears on, and
+    /// `line` is the line of the start tag itself.
+    fn parse_element<R: std::io::BufRead>(
         &self,
         element_name: String,
-        attributes: Vec<(String, String)>,
+        attributes: Vec<((String, String), usize)>,
         is_empty: bool,
+        line: usize,
         reader: &mut crate::reader::XamlReader<R>,
         context: &mut ParseContext<'_>,
     ) -> Result<crate::model::XamlElement> {
@@ -144,274 +261,59 @@ impl XamlParser {
         use crate::model::{XamlElement, XamlNode};
         use crate::types::XamlTypeName;
         
-        // Parse the element name (handle namespaces)
-        let (prefix, local_name) = parse_qualified_name(&element_name);
-        
-        // Create the type name - initially without namespace resolution
-        let type_name = XamlTypeName::new("", local_name);
-        
-        // Create the element
-        let mut element = XamlElement::new(type_name.clone());
-        
-        // Process attributes FIRST to get namespace declarations
-        for (attr_name, attr_value) in attributes {
-            self.process_attribute(&mut element, &attr_name, &attr_value, context)?;
+        // Refuse to recurse further before growing the stack
+        context.depth += 1;
+        context.element_count += 1;
+        if context.depth > context.settings.max_depth {
+            return Err(XamlError::custom("maximum nesting depth exceeded"));
         }
-        
-        // NOW resolve the namespace for this element
-        let namespace = if let Some(prefix) = prefix {
-            context.resolve_namespace(prefix)?
-        } else {
-            context.default_namespace.clone()
-        };
-        
-        // Update the type name with resolved namespace
-        element.type_name = XamlTypeName::new(namespace, local_name);
-        
-        // If not empty, parse children
-        if !is_empty {
-            loop {
-                let event = reader.read_event()?;
-                
-                match event {
-                    XamlEvent::EndElement { name } => {
-                        if name != element_name {
-                            return Err(XamlError::XmlError {
-                                line: 0,
-                                col: 0,
-                                message: format!("Mismatched tags: expected {}, got {}", element_name, name),
-                            });
-                        }
-                        break;
-                    }
-                    
-                    XamlEvent::StartElement { name, attributes, is_empty } => {
-                        if name.contains('.') {
-                            self.parse_property_element(&mut element, &name, reader, context)?;
-                        } else {
-                            let child = self.parse_child_element(name, attributes, is_empty, reader, context)?;
-                            element.add_child(XamlNode::Element(child));
-                        }
-                    }
-                    
-                    XamlEvent::Text(text) => {
-                        if self.has_flag(ParserFlags::PRESERVE_WHITESPACE) || !text.trim().is_empty() {
-                            element.add_child(XamlNode::Text(text));
-                        }
-                    }
-                    
-                    XamlEvent::Eof => {
-                        return Err(XamlError::custom(format!("Unexpected EOF while parsing element {}", element_name)));
-                    }
-                }
-            }
+        if context.element_count > context.settings.max_elements {
+            return Err(XamlError::custom("maximum element count exceeded"));
         }
         
-        Ok(element)
-    }
-    
-    /// Parse a single element from the reader.
-    #[allow(dead_code)]
-    fn parse_element<R: std::io::BufRead>(
-        &self,
-        reader: &mut crate::reader::XamlReader<R>,
-        context: &mut ParseContext<'_>,
-    ) -> Result<crate::model::XamlElement> {
-        use crate::reader::XamlEvent;
-        use crate::model::{XamlElement, XamlNode};
-        use crate::types::XamlTypeName;
-        
-        // Read the start element event
-        let (element_name, attributes, is_empty) = match reader.read_event()? {
-            XamlEvent::StartElement { name, attributes, is_empty } => {
-                (name, attributes, is_empty)
-            }
-            XamlEvent::Eof => {
-                return Err(XamlError::custom("Unexpected end of file"));
-            }
-            XamlEvent::EndElement { name } => {
-                return Err(XamlError::custom(format!("Unexpected end element: {}", name)));
-            }
-            XamlEvent::Text(text) => {
-                return Err(XamlError::custom(format!("Unexpected text: {}", text)));
-            }
-        };
+        context.open_elements.push(element_name.clone());
         
         // Parse the element name (handle namespaces)
-        let (prefix, local_name) = parse_qualified_name(&element_name);
-        
-        // Resolve namespace if prefix exists
-        let namespace = if let Some(prefix) = prefix {
-            context.resolve_namespace(prefix)?
-        } else {
-            context.default_namespace.clone()
-        };
-        
-        // Create the type name
-        let type_name = XamlTypeName::new(namespace, local_name);
+        let (_, local_name) = XamlTypeName::split_qualified(&element_name);
         
-        // Create the element
-        let mut element = XamlElement::new(type_name.clone());
+        // Create the element with temporary type name
+        let mut element = XamlElement::new(XamlTypeName::new("", local_name));
+        element.line = line;
         
-        // Process attributes
-        for (attr_name, attr_value) in attributes {
-            self.process_attribute(&mut element, &attr_name, &attr_value, context)?;
-        }
+        // Remember the enclosing xml:space state; attributes may change it
+        let outer_preserve_space = context.preserve_space;
         
-        // If not empty, parse children
-        if !is_empty {
-            loop {
-                let event = reader.read_event()?;
-                
-                match event {
-                    XamlEvent::EndElement { name } => {
-                        // Verify this is the correct end tag
-                        if name != element_name {
-                            return Err(XamlError::XmlError {
-                                line: 0,
-                                col: 0,
-                                message: format!("Mismatched tags: expected {}, got {}", element_name, name),
-                            });
-                        }
-                        break;
-                    }
-                    
-                    XamlEvent::StartElement { name, .. } => {
-                        // Check if this is a property element (e.g., <Button.Content>)
-                        if name.contains('.') {
-                            self.parse_property_element(&mut element, &name, reader, context)?;
-                        } else {
-                            // Regular child element - need to "put back" this event
-                            // For now, we'll re-read by creating a new reader for this element
-                            // This is a simplification; a proper implementation would buffer events
-                            let child = self.parse_element_from_event(name, reader, context)?;
-                            element.add_child(XamlNode::Element(child));
-                        }
-                    }
-                    
-                    XamlEvent::Text(text) => {
-                        // Add text content if not just whitespace (unless preserving whitespace)
-                        if self.has_flag(ParserFlags::PRESERVE_WHITESPACE) || !text.trim().is_empty() {
-                            element.add_child(XamlNode::Text(text));
-                        }
-                    }
-                    
-                    XamlEvent::Eof => {
-                        return Err(XamlError::custom(format!("Unexpected EOF while parsing element {}", element_name)));
-                    }
-                }
+        // Process directives FIRST to get any new namespace declarations
+        let mut properties = Vec::new();
+        for ((attr_name, attr_value), attr_line) in attributes {
+            context.current_line = attr_line;
+            if !self.process_directive(&mut element, &attr_name, &attr_value, context)? {
+                properties.push((attr_name, attr_value, attr_line));
             }
         }
+        context.current_line = line;
         
-        Ok(element)
-    }
-    
-    /// Parse an element when we already have the start event information.
-    #[allow(dead_code)]
-    fn parse_element_from_event<R: std::io::BufRead>(
-        &self,
-        element_name: String,
-        reader: &mut crate::reader::XamlReader<R>,
-        context: &mut ParseContext<'_>,
-    ) -> Result<crate::model::XamlElement> {
-        use crate::reader::XamlEvent;
-        use crate::model::{XamlElement, XamlNode};
-        use crate::types::XamlTypeName;
-        
-        // We need to peek to get attributes - for now, assume they're already read
-        // This is a helper that handles the case where we've already seen the start tag
-        
-        // Parse the element name (handle namespaces)
-        let (prefix, local_name) = parse_qualified_name(&element_name);
-        
-        // Resolve namespace if prefix exists
-        let namespace = if let Some(prefix) = prefix {
-            context.resolve_namespace(prefix)?
-        } else {
-            context.default_namespace.clone()
-        };
-        
-        // Create the type name
-        let type_name = XamlTypeName::new(namespace, local_name);
-        
-        // Create the element
-        let mut element = XamlElement::new(type_name.clone());
-        
-        // Parse children until we hit the end tag
-        loop {
-            let event = reader.read_event()?;
-            
-            match event {
-                XamlEvent::EndElement { name } => {
-                    if name != element_name {
-                        return Err(XamlError::XmlError {
-                            line: 0,
-                            col: 0,
-                            message: format!("Mismatched tags: expected {}, got {}", element_name, name),
-                        });
-                    }
-                    break;
-                }
-                
-                XamlEvent::StartElement { name, attributes, is_empty } => {
-                    // Check if this is a property element
-                    if name.contains('.') {
-                        self.parse_property_element(&mut element, &name, reader, context)?;
-                    } else {
-                        // Create child element with attributes
-                        let child = self.parse_child_element(name, attributes, is_empty, reader, context)?;
-                        element.add_child(XamlNode::Element(child));
-                    }
-                }
-                
-                XamlEvent::Text(text) => {
-                    if self.has_flag(ParserFlags::PRESERVE_WHITESPACE) || !text.trim().is_empty() {
-                        element.add_child(XamlNode::Text(text));
-                    }
-                }
-                
-                XamlEvent::Eof => {
-                    return Err(XamlError::custom(format!("Unexpected EOF while parsing element {}", element_name)));
-                }
-            }
+        // NOW resolve the namespace
+        element.type_name = XamlTypeName::from_qualified(&element_name, &context.namespaces)?;
+        if let Some(interner) = &mut context.interner {
+            element.type_name = intern_type_name(interner, &element.type_name);
+        }
+        if self.has_flag(ParserFlags::ALLOW_UNKNOWN_TYPES) && self.registry.lookup_type(&element.type_name).is_none() {
+            element.set_flag(crate::flags::ElementFlags::SYNTHETIC_TYPE);
         }
         
-        Ok(element)
-    }
-    
-    /// Parse a child element with known attributes.
-    fn parse_child_element<R: std::io::BufRead>(
-        &self,
-        element_name: String,
-        attributes: Vec<(String, String)>,
-        is_empty: bool,
-        reader: &mut crate::reader::XamlReader<R>,
-        context: &mut ParseContext<'_>,
-    ) -> Result<crate::model::XamlElement> {
-        use crate::reader::XamlEvent;
-        use crate::model::{XamlElement, XamlNode};
-        use crate::types::XamlTypeName;
-        
-        // Parse the element name (handle namespaces)
-        let (prefix, local_name) = parse_qualified_name(&element_name);
-        
-        // Create the element with temporary type name
-        let mut element = XamlElement::new(XamlTypeName::new("", local_name));
-        
-        // Process attributes FIRST to get any new namespace declarations
-        for (attr_name, attr_value) in attributes {
+        // Property values are converted by declared type, so need the resolved type
+        for (attr_name, attr_value, attr_line) in properties {
+            context.current_line = attr_line;
             self.process_attribute(&mut element, &attr_name, &attr_value, context)?;
         }
+        context.current_line = line;
         
-        // NOW resolve the namespace
-        let namespace = if let Some(prefix) = prefix {
-            context.resolve_namespace(prefix)?
-        } else {
-            context.default_namespace.clone()
-        };
-        
-        // Update the type name with resolved namespace
-        element.type_name = XamlTypeName::new(namespace, local_name);
+        // Templates and user controls get their own namescope; names inside may repeat names outside
+        let is_namescope = self.registry.lookup_type(&element.type_name).is_some_and(|t| t.is_namescope());
+        if is_namescope {
+            context.name_scopes.push(std::collections::HashSet::new());
+        }
         
         // If not empty, parse children
         if !is_empty {
@@ -422,135 +324,226 @@ impl XamlParser {
                     XamlEvent::EndElement { name } => {
                         if name != element_name {
                             return Err(XamlError::XmlError {
-                                line: 0,
+                                line: reader.position().line,
                                 col: 0,
-                                message: format!("Mismatched tags: expected {}, got {}", element_name, name),
+                                message: format!(
+                                    "Mismatched tags: expected {}, got {} {}",
+                                    element_name, name, context.enclosing_path()
+                                ),
                             });
                         }
                         break;
                     }
                     
-                    XamlEvent::StartElement { name, attributes, is_empty } => {
+                    XamlEvent::StartElement { name, attributes, attribute_lines, is_empty, line } => {
                         if name.contains('.') {
                             self.parse_property_element(&mut element, &name, reader, context)?;
                         } else {
-                            let child = self.parse_child_element(name, attributes, is_empty, reader, context)?;
+                            let child = self.parse_element(name, attributes.into_iter().zip(attribute_lines).collect(), is_empty, line, reader, context)?;
                             element.add_child(XamlNode::Element(child));
                         }
                     }
                     
                     XamlEvent::Text(text) => {
-                        if self.has_flag(ParserFlags::PRESERVE_WHITESPACE) || !text.trim().is_empty() {
+                        if let Some(text) = self.process_text(text, context) {
                             element.add_child(XamlNode::Text(text));
                         }
                     }
                     
+                    // CDATA is deliberate content, so whitespace rules don't apply
+                    XamlEvent::CData(text) => {
+                        element.add_child(XamlNode::CData(text));
+                    }
+                    
                     XamlEvent::Eof => {
-                        return Err(XamlError::custom(format!("Unexpected EOF while parsing element {}", element_name)));
+                        return Err(XamlError::custom(format!(
+                            "Unexpected EOF while parsing element {} {}",
+                            element_name, context.enclosing_path()
+                        )));
                     }
                 }
             }
         }
         
+        // Leave the xml:space scope and namescope of this element
+        context.preserve_space = outer_preserve_space;
+        if is_namescope {
+            context.name_scopes.pop();
+        }
+        context.depth -= 1;
+        context.open_elements.pop();
+        
         Ok(element)
     }
     
-    /// Process an attribute on an element.
-    fn process_attribute(
+    /// Process a namespace declaration or directive attribute on an element.
+    ///
+    /// Returns `false` for property attributes, which are left to
+    /// [`process_attribute`](Self::process_attribute).
+    fn process_directive(
         &self,
         element: &mut crate::model::XamlElement,
         attr_name: &str,
         attr_value: &str,
         context: &mut ParseContext<'_>,
-    ) -> Result<()> {
+    ) -> Result<bool> {
         // Handle xmlns declarations
         if attr_name == "xmlns" {
-            context.default_namespace = attr_value.to_string();
+            context.declare_namespace("", attr_value);
             element.declare_namespace("", attr_value);
-            return Ok(());
+            return Ok(true);
         }
         
         if attr_name.starts_with("xmlns:") {
             let prefix = &attr_name[6..];
             context.declare_namespace(prefix, attr_value);
             element.declare_namespace(prefix, attr_value);
-            return Ok(());
+            return Ok(true);
+        }
+        
+        // Handle xml:space, which applies to this element and its descendants
+        if attr_name == "xml:space" {
+            context.preserve_space = attr_value == "preserve";
+            return Ok(true);
         }
         
         // Handle x:Name
         if attr_name == "x:Name" || attr_name == "Name" {
+            context.register_name(attr_value, self.has_flag(ParserFlags::VALIDATE_NAMES))?;
             element.set_name(attr_value);
-            return Ok(());
+            return Ok(true);
         }
         
         // Handle x:Key
         if attr_name == "x:Key" {
             element.set_key(attr_value);
+            return Ok(true);
+        }
+        
+        Ok(false)
+    }
+    
+    /// Process a property attribute on an element whose type is resolved.
+    ///
+    /// Attributes naming a declared event, such as `Click="OnClick"`, record
+    /// the handler name in the element's events rather than its attributes.
+    /// Attached properties such as `Grid.Row` go in its attached properties,
+    /// keyed `("Grid", "Row")`.
+    ///
+    /// With `VALIDATE_TYPES`, a property whose declared type has a converter
+    /// always gets a value of that type, so `Width="100"` on a `Double`
+    /// property is a `Float` rather than an `Integer`. Other values are guessed.
+    fn process_attribute(
+        &self,
+        element: &mut crate::model::XamlElement,
+        attr_name: &str,
+        attr_value: &str,
+        context: &ParseContext<'_>,
+    ) -> Result<()> {
+        let property = self.declared_property(&element.type_name, attr_name, context);
+        if property.is_some_and(|property| property.is_event()) {
+            element.set_event_handler(attr_name, attr_value.trim());
             return Ok(());
         }
         
-        // Parse the value
-        let value = self.parse_attribute_value(attr_value, context)?;
+        let is_markup_extension = attr_value.starts_with('{') && !attr_value.starts_with("{}");
         
-        // Set the attribute
-        element.set_attribute(attr_name, value);
+        // Long extensions are often split across lines; "{{" is a literal string
+        let normalized;
+        let attr_value = if is_markup_extension && !attr_value.starts_with("{{") {
+            normalized = normalize_markup_whitespace(attr_value);
+            normalized.as_str()
+        } else {
+            attr_value
+        };
+        let converter = if self.has_flag(ParserFlags::VALIDATE_TYPES) {
+            property.and_then(|property| self.converters.get(&property.type_name))
+        } else {
+            None
+        };
         
+        let value = match converter {
+            Some(convert) if !is_markup_extension => {
+                let text = attr_value.strip_prefix("{}").unwrap_or(attr_value);
+                match convert(text) {
+                    Ok(value) => value,
+                    Err(e) if self.has_flag(ParserFlags::STRICT_MODE) => {
+                        return Err(match e {
+                            XamlError::InvalidAttributeValue { details, .. } => XamlError::InvalidAttributeValue {
+                                attribute: attr_name.to_string(),
+                                line: context.current_line,
+                                details,
+                            },
+                            other => other,
+                        });
+                    }
+                    // In lenient mode an unconvertible value is guessed like an untyped one
+                    Err(_) => self.parse_attribute_value(attr_value, context)?,
+                }
+            }
+            _ => self.parse_attribute_value(attr_value, context)?,
+        };
+        
+        match attr_name.rsplit_once('.') {
+            Some((owner, name)) => element.set_attached_property(owner, name, value),
+            None => element.set_attribute(attr_name, value),
+        }
         Ok(())
     }
     
+    /// Find the registered metadata for a property set by attribute.
+    ///
+    /// Attached properties such as `Grid.Row` are looked up on the type named
+    /// before the dot; other properties on the element's own type and its bases.
+    fn declared_property(
+        &self,
+        element_type: &crate::types::XamlTypeName,
+        attr_name: &str,
+        context: &ParseContext<'_>,
+    ) -> Option<&crate::types::XamlProperty> {
+        let (owner, property_name) = match attr_name.rsplit_once('.') {
+            Some((owner, property_name)) => (
+                crate::types::XamlTypeName::from_qualified(owner, &context.namespaces).ok()?,
+                property_name,
+            ),
+            None => (element_type.clone(), attr_name),
+        };
+        
+        self.registry
+            .get_all_properties(&owner)
+            .into_iter()
+            .find(|property| property.name == property_name)
+    }
+    
     /// Parse an attribute value (may contain markup extensions).
     fn parse_attribute_value(
         &self,
         value: &str,
-        _context: &ParseContext<'_>,
+        context: &ParseContext<'_>,
     ) -> Result<crate::model::XamlValue> {
         use crate::model::XamlValue;
         
         // Check if this is a markup extension
-        if value.starts_with('{') && value.ends_with('}') {
+        // (an unterminated "{Binding" is still an extension, just a malformed one)
+        if value.starts_with('{') && !value.starts_with("{}") {
             // Special case: {{ escapes to {
             if value.starts_with("{{") {
                 return Ok(XamlValue::String(value[1..].to_string()));
             }
             
             // Parse the markup extension
-            match crate::markup::parse_markup_extension(value) {
-                Ok(parsed) => {
-                    // Store as MarkupExtension value with parsed data
-                    let mut arguments = std::collections::HashMap::new();
-                    
-                    // Add positional argument if present
-                    if let Some(pos_arg) = parsed.positional_arg {
-                        arguments.insert("_positional".to_string(), XamlValue::String(pos_arg));
-                    }
-                    
-                    // Add named arguments
-                    for (key, val) in parsed.arguments {
-                        arguments.insert(key, XamlValue::String(val));
-                    }
-                    
-                    return Ok(XamlValue::MarkupExtension {
-                        extension_name: parsed.name,
-                        arguments,
-                    });
-                }
+            match crate::markup::parse_markup_extension(value, context.current_line) {
+                Ok(parsed) => return self.markup_extension_value(parsed, context),
+                Err(e) if self.has_flag(ParserFlags::STRICT_MODE) => return Err(e),
                 Err(_) => {
-                    // If parsing fails, treat as string
+                    // If parsing fails in lenient mode, treat as string
                     return Ok(XamlValue::String(value.to_string()));
                 }
             }
         }
         
         // Try to parse as various types
-        // Boolean
-        if value == "true" || value == "True" {
-            return Ok(XamlValue::Boolean(true));
-        }
-        if value == "false" || value == "False" {
-            return Ok(XamlValue::Boolean(false));
-        }
-        
-        // Integer
+        // Integer (checked before booleans so "1"/"0" stay numbers)
         if let Ok(i) = value.parse::<i64>() {
             return Ok(XamlValue::Integer(i));
         }
@@ -560,10 +553,104 @@ impl XamlParser {
             return Ok(XamlValue::Float(f));
         }
         
+        // Boolean
+        if let Some(b) = crate::converters::parse_bool(value) {
+            return Ok(XamlValue::Boolean(b));
+        }
+        
         // Default to string
         Ok(XamlValue::String(value.to_string()))
     }
     
+    /// Convert a parsed markup extension, and any extensions nested in its
+    /// arguments, resolving `x:Null` and `x:Type`.
+    fn markup_extension_value(
+        &self,
+        parsed: crate::markup::ParsedMarkupExtension,
+        context: &ParseContext<'_>,
+    ) -> Result<crate::model::XamlValue> {
+        use crate::model::XamlValue;
+        
+        if self.resolves_builtin(&parsed.name, "Null") {
+            return Ok(XamlValue::Null);
+        }
+        if self.resolves_builtin(&parsed.name, "Type") {
+            let type_arg = parsed.positional_arg
+                .as_ref()
+                .or_else(|| parsed.arguments.get("TypeName"))
+                .and_then(MarkupArgument::as_text)
+                .ok_or_else(|| XamlError::InvalidMarkupExtension {
+                    line: context.current_line,
+                    details: "x:Type requires a type name".to_string(),
+                })?;
+            return self.resolve_type_reference(type_arg.trim(), context);
+        }
+        
+        // Store as MarkupExtension value with parsed data
+        let mut arguments = std::collections::HashMap::new();
+        
+        // Add positional argument if present
+        if let Some(pos_arg) = parsed.positional_arg {
+            arguments.insert("_positional".to_string(), self.markup_argument_value(pos_arg, context)?);
+        }
+        
+        // Add named arguments
+        for (key, val) in parsed.arguments {
+            arguments.insert(key, self.markup_argument_value(val, context)?);
+        }
+        
+        Ok(XamlValue::MarkupExtension {
+            extension_name: parsed.name,
+            arguments,
+        })
+    }
+    
+    /// Convert one markup extension argument to a value.
+    fn markup_argument_value(&self, argument: MarkupArgument, context: &ParseContext<'_>) -> Result<crate::model::XamlValue> {
+        match argument {
+            MarkupArgument::Text(text) => Ok(crate::model::XamlValue::String(text)),
+            MarkupArgument::Extension(nested) => self.markup_extension_value(*nested, context),
+        }
+    }
+    
+    /// Check if a markup extension is the built-in `x:<name>` (or bare `<name>`)
+    /// and should be resolved rather than kept raw.
+    fn resolves_builtin(&self, extension_name: &str, builtin: &str) -> bool {
+        if self.has_flag(ParserFlags::RAW_MARKUP_EXTENSIONS) {
+            return false;
+        }
+        
+        extension_name == builtin || extension_name.strip_prefix("x:") == Some(builtin)
+    }
+
+    /// Resolve a `prefix:Type` reference (from {x:Type}) to a type in the registry.
+    ///
+    /// Unknown types are an error in strict mode; otherwise the name is kept
+    /// with its XML namespace.
+    fn resolve_type_reference(
+        &self,
+        qualified_name: &str,
+        context: &ParseContext<'_>,
+    ) -> Result<crate::model::XamlValue> {
+        use crate::model::XamlValue;
+        use crate::types::XamlTypeName;
+        
+        let type_name = XamlTypeName::from_qualified(qualified_name, &context.namespaces)?;
+        
+        if let Some(xaml_type) = self.registry.lookup_type(&type_name) {
+            return Ok(XamlValue::Type(xaml_type.name().clone()));
+        }
+        
+        if self.has_flag(ParserFlags::STRICT_MODE) && !self.has_flag(ParserFlags::ALLOW_UNKNOWN_TYPES) {
+            return Err(XamlError::UnknownType {
+                type_name: type_name.full_name(),
+                line: context.current_line,
+            });
+        }
+        
+        Ok(XamlValue::Type(type_name))
+    }
+
     /// Parse a property element (e.g., <Button.Content>).
     fn parse_property_element<R: std::io::BufRead>(
         &self,
@@ -582,9 +669,10 @@ impl XamlParser {
         }
         
         let property_local_name = parts[1];
+        context.open_elements.push(property_name.to_string());
         
         // Read the property content
-        let mut property_value: Option<XamlValue> = None;
+        let mut values: Vec<XamlValue> = Vec::new();
         let mut text_content = String::new();
         
         loop {
@@ -594,44 +682,72 @@ impl XamlParser {
                 XamlEvent::EndElement { name } => {
                     if name != property_name {
                         return Err(XamlError::XmlError {
-                            line: 0,
+                            line: reader.position().line,
                             col: 0,
-                            message: format!("Mismatched property element tags: expected {}, got {}", property_name, name),
+                            message: format!(
+                                "Mismatched property element tags: expected {}, got {} {}",
+                                property_name, name, context.enclosing_path()
+                            ),
                         });
                     }
                     break;
                 }
                 
-                XamlEvent::StartElement { name, attributes, is_empty } => {
-                    // Parse the child element as the property value
-                    let child = self.parse_child_element(name, attributes, is_empty, reader, context)?;
-                    property_value = Some(XamlValue::Element(Box::new(child)));
+                XamlEvent::StartElement { name, attributes, attribute_lines, is_empty, line } => {
+                    // Parse the child element as (one item of) the property value
+                    let child = self.parse_element(name, attributes.into_iter().zip(attribute_lines).collect(), is_empty, line, reader, context)?;
+                    values.push(XamlValue::Element(Box::new(child)));
                 }
                 
-                XamlEvent::Text(text) => {
+                XamlEvent::Text(text) | XamlEvent::CData(text) => {
                     text_content.push_str(&text);
                 }
                 
                 XamlEvent::Eof => {
-                    return Err(XamlError::custom(format!("Unexpected EOF while parsing property element {}", property_name)));
+                    return Err(XamlError::custom(format!(
+                        "Unexpected EOF while parsing property element {} {}",
+                        property_name, context.enclosing_path()
+                    )));
                 }
             }
         }
         
-        // Set the property value
-        let final_value = if let Some(val) = property_value {
+        // Set the property value; several child elements form a collection
+        let final_value = if values.len() > 1 {
+            XamlValue::Collection(values)
+        } else if let Some(val) = values.pop() {
             val
-        } else if !text_content.trim().is_empty() {
-            XamlValue::String(text_content)
+        } else if let Some(text) = self.process_text(text_content, context) {
+            XamlValue::String(text)
         } else {
             XamlValue::Null
         };
         
         element.set_property(property_local_name, final_value);
+        context.open_elements.pop();
         
         Ok(())
     }
 
+    /// Apply the whitespace mode to a text node.
+    ///
+    /// Returns `None` if the node should be dropped.
+    fn process_text(&self, text: String, context: &ParseContext<'_>) -> Option<String> {
+        if self.has_flag(ParserFlags::PRESERVE_WHITESPACE) || context.preserve_space {
+            return Some(text);
+        }
+        
+        if text.trim().is_empty() {
+            return None;
+        }
+        
+        if self.has_flag(ParserFlags::NORMALIZE_WHITESPACE) {
+            return Some(normalize_whitespace(&text));
+        }
+        
+        Some(text)
+    }
+
     /// Get a reference to the type registry.
     pub fn type_registry(&self) -> &TypeRegistry {
         &self.registry
@@ -657,14 +773,32 @@ struct ParseContext<'a> {
     /// Parser settings reference.
     settings: &'a ParserSettings,
     
-    /// Default namespace (from xmlns attribute).
-    default_namespace: String,
-    
-    /// Namespace prefix mappings.
-    namespace_map: std::collections::HashMap<String, String>,
+    /// Namespace prefix mappings (the empty prefix is the default namespace).
+    namespaces: NamespaceMap,
     
     /// Resources collected during parsing.
     resources: std::collections::HashMap<String, crate::model::XamlValue>,
+    
+    /// Whitespace is preserved in the current subtree (`xml:space="preserve"`).
+    preserve_space: bool,
+    
+    /// Names declared in each enclosing namescope (innermost last).
+    name_scopes: Vec<std::collections::HashSet<String>>,
+    
+    /// Line of the start tag or attribute currently being processed.
+    current_line: usize,
+    
+    /// Nesting depth of the element being parsed (the root is 1).
+    depth: usize,
+    
+    /// Number of elements started so far.
+    element_count: usize,
+    
+    /// Names of the elements and property elements currently open (innermost last).
+    open_elements: Vec<String>,
+    
+    /// Pool for type names, with `ParserFlags::INTERN_STRINGS`.
+    interner: Option<StringInterner>,
 }
 
 impl<'a> ParseContext<'a> {
@@ -673,38 +807,150 @@ impl<'a> ParseContext<'a> {
         Self {
             registry,
             settings,
-            default_namespace: String::new(),
-            namespace_map: std::collections::HashMap::new(),
+            namespaces: NamespaceMap::new(),
             resources: std::collections::HashMap::new(),
+            preserve_space: false,
+            name_scopes: vec![std::collections::HashSet::new()],
+            current_line: 0,
+            depth: 0,
+            element_count: 0,
+            open_elements: Vec::new(),
+            interner: settings.flags
+                .contains(ParserFlags::INTERN_STRINGS)
+                .then(StringInterner::default),
+        }
+    }
+    
+    /// Record an x:Name in the current namescope, failing on duplicates if `validate` is set.
+    fn register_name(&mut self, name: &str, validate: bool) -> Result<()> {
+        let scope = self.name_scopes.last_mut().expect("root namescope is never popped");
+        if !scope.insert(name.to_string()) && validate {
+            return Err(XamlError::DuplicateName {
+                name: name.to_string(),
+                line: self.current_line,
+            });
         }
+        Ok(())
+    }
+    
+    /// Describe the open elements for error messages, e.g. "while inside Window > Grid".
+    fn enclosing_path(&self) -> String {
+        format!("while inside {}", self.open_elements.join(" > "))
     }
     
     /// Declare a namespace prefix mapping.
     fn declare_namespace(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
-        self.namespace_map.insert(prefix.into(), uri.into());
+        self.namespaces.declare(prefix, uri);
     }
-    
-    /// Resolve a namespace prefix to its URI.
-    fn resolve_namespace(&self, prefix: &str) -> Result<String> {
-        self.namespace_map
-            .get(prefix)
-            .cloned()
-            .ok_or_else(|| XamlError::InvalidNamespace {
-                line: 0,
-                details: format!("Undefined namespace prefix: {}", prefix),
-            })
+}
+
+/// Replace the strings of a type name with pooled copies.
+fn intern_type_name(interner: &mut StringInterner, type_name: &crate::types::XamlTypeName) -> crate::types::XamlTypeName {
+    crate::types::XamlTypeName::with_type_args(
+        interner.intern(&type_name.namespace),
+        interner.intern(&type_name.name),
+        type_name.type_args.iter().map(|arg| intern_type_name(interner, arg)).collect(),
+    )
+}
+
+/// A byte-order mark at the start of the input.
+enum Bom {
+    Utf8,
+    Utf16 { big_endian: bool },
+}
+
+/// Read the first three bytes of input, or all of it if shorter.
+///
+/// Three bytes hold any byte-order mark. A reader may hand out fewer bytes
+/// per read, so this keeps reading until it has them or the input ends.
+fn read_bom_prefix<R: std::io::BufRead>(reader: &mut R) -> Result<([u8; 3], usize)> {
+    let mut prefix = [0; 3];
+    let mut len = 0;
+    while len < prefix.len() {
+        match reader.read(&mut prefix[len..]) {
+            Ok(0) => break,
+            Ok(read) => len += read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok((prefix, len))
+}
+
+/// Detect a byte-order mark at the start of `bytes`.
+fn detect_bom(bytes: &[u8]) -> Option<Bom> {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, ..] => Some(Bom::Utf8),
+        [0xFF, 0xFE, ..] => Some(Bom::Utf16 { big_endian: false }),
+        [0xFE, 0xFF, ..] => Some(Bom::Utf16 { big_endian: true }),
+        _ => None,
+    }
+}
+
+/// Decode UTF-16 bytes (without their BOM) into a string.
+fn decode_utf16(bytes: &[u8], big_endian: bool) -> Result<String> {
+    if bytes.len() % 2 != 0 {
+        return Err(XamlError::custom("UTF-16 input has an odd number of bytes"));
     }
+    
+    let units = bytes.chunks_exact(2).map(|pair| {
+        if big_endian {
+            u16::from_be_bytes([pair[0], pair[1]])
+        } else {
+            u16::from_le_bytes([pair[0], pair[1]])
+        }
+    });
+    
+    char::decode_utf16(units)
+        .collect::<std::result::Result<String, _>>()
+        .map_err(|e| XamlError::custom(format!("Invalid UTF-16 input: {}", e)))
+}
+
+/// Collapse runs of XML whitespace (space, tab, CR, LF) to a single space and trim the ends.
+///
+/// Other Unicode spaces, such as the no-break space, are significant in XAML
+/// and left untouched.
+fn normalize_whitespace(text: &str) -> String {
+    text.split([' ', '\t', '\r', '\n'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-/// Parse a qualified name into (prefix, local_name).
-fn parse_qualified_name(name: &str) -> (Option<&str>, &str) {
-    if let Some(colon_pos) = name.find(':') {
-        let prefix = &name[..colon_pos];
-        let local = &name[colon_pos + 1..];
-        (Some(prefix), local)
-    } else {
-        (None, name)
+/// Collapse runs of XML whitespace in a markup extension to a single space.
+///
+/// Quoted strings inside the extension are literal and kept as written.
+fn normalize_markup_whitespace(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    let mut quote = None;
+    let mut in_whitespace = false;
+    
+    for ch in value.trim_matches([' ', '\t', '\r', '\n']).chars() {
+        match quote {
+            Some(open) => {
+                if ch == open {
+                    quote = None;
+                }
+                normalized.push(ch);
+            }
+            None if matches!(ch, ' ' | '\t' | '\r' | '\n') => {
+                if !in_whitespace {
+                    normalized.push(' ');
+                }
+                in_whitespace = true;
+                continue;
+            }
+            None => {
+                if ch == '\'' || ch == '"' {
+                    quote = Some(ch);
+                }
+                normalized.push(ch);
+            }
+        }
+        in_whitespace = false;
     }
+    
+    normalized
 }
 
 #[cfg(test)]
@@ -732,6 +978,25 @@ mod tests {
         assert!(settings.flags.contains(ParserFlags::VALIDATE_NAMESPACES));
     }
 
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(normalize_whitespace("  Hello \n\t  world  "), "Hello world");
+        assert_eq!(normalize_whitespace("one"), "one");
+        assert_eq!(normalize_whitespace("a\u{00A0}\u{00A0}b"), "a\u{00A0}\u{00A0}b");
+    }
+
+    #[test]
+    fn test_normalize_markup_whitespace() {
+        assert_eq!(
+            normalize_markup_whitespace("{Binding Path=X,\n\t    Mode=TwoWay}"),
+            "{Binding Path=X, Mode=TwoWay}"
+        );
+        assert_eq!(
+            normalize_markup_whitespace("{Binding StringFormat='a\n  b'}"),
+            "{Binding StringFormat='a\n  b'}"
+        );
+    }
+
     #[test]
     fn test_parser_creation() {
         let registry = TypeRegistry::new();
@@ -1,6 +1,6 @@
 //! XML reader wrapper for XAML parsing.
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use crate::error::{Result, XamlError, ErrorLocation};
 use std::io::BufRead;
@@ -13,7 +13,11 @@ pub struct XamlReader<R: BufRead> {
 
 impl<R: BufRead> XamlReader<R> {
     /// Create a new XAML reader from a BufRead source.
-    pub fn new(reader: Reader<R>) -> Self {
+    ///
+    /// End tag names are not checked here; the parser checks them itself so
+    /// it can report which elements enclose a mismatched tag.
+    pub fn new(mut reader: Reader<R>) -> Self {
+        reader.check_end_names(false);
         Self {
             reader,
             position: ErrorLocation::new(1, 0),
@@ -28,55 +32,20 @@ impl<R: BufRead> XamlReader<R> {
     /// Read the next event from the XML stream.
     pub fn read_event(&mut self) -> Result<XamlEvent> {
         let mut buf = Vec::new();
+        let line = self.position.line;
         
-        match self.reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                let name = std::str::from_utf8(e.name().as_ref())
-                    .map_err(|e| XamlError::Utf8(e))?
-                    .to_string();
-                
-                let mut attributes = Vec::new();
-                for attr in e.attributes() {
-                    let attr = attr.map_err(|e| XamlError::QuickXml(e.into()))?;
-                    let key = std::str::from_utf8(attr.key.as_ref())
-                        .map_err(|e| XamlError::Utf8(e))?
-                        .to_string();
-                    let value = attr.unescape_value()
-                        .map_err(|e| XamlError::QuickXml(e))?
-                        .to_string();
-                    attributes.push((key, value));
-                }
-                
-                Ok(XamlEvent::StartElement {
-                    name,
-                    attributes,
-                    is_empty: false,
-                })
-            }
+        let event = self.reader.read_event_into(&mut buf);
+        
+        // Every byte outside the markup delimiters belongs to some event, so
+        // counting newlines in each event's raw content tracks the line
+        if let Ok(ref event) = event {
+            self.position.line += count_newlines(event);
+        }
+        
+        match event {
+            Ok(Event::Start(e)) => read_start_element(&e, false, line),
             
-            Ok(Event::Empty(e)) => {
-                let name = std::str::from_utf8(e.name().as_ref())
-                    .map_err(|e| XamlError::Utf8(e))?
-                    .to_string();
-                
-                let mut attributes = Vec::new();
-                for attr in e.attributes() {
-                    let attr = attr.map_err(|e| XamlError::QuickXml(e.into()))?;
-                    let key = std::str::from_utf8(attr.key.as_ref())
-                        .map_err(|e| XamlError::Utf8(e))?
-                        .to_string();
-                    let value = attr.unescape_value()
-                        .map_err(|e| XamlError::QuickXml(e))?
-                        .to_string();
-                    attributes.push((key, value));
-                }
-                
-                Ok(XamlEvent::StartElement {
-                    name,
-                    attributes,
-                    is_empty: true,
-                })
-            }
+            Ok(Event::Empty(e)) => read_start_element(&e, true, line),
             
             Ok(Event::End(e)) => {
                 let name = std::str::from_utf8(e.name().as_ref())
@@ -96,7 +65,7 @@ impl<R: BufRead> XamlReader<R> {
                 let text = std::str::from_utf8(&e)
                     .map_err(|e| XamlError::Utf8(e))?
                     .to_string();
-                Ok(XamlEvent::Text(text))
+                Ok(XamlEvent::CData(text))
             }
             
             Ok(Event::Comment(_)) => {
@@ -160,6 +129,57 @@ impl<'a> XamlReader<&'a [u8]> {
     }
 }
 
+/// Convert a start (or self-closing) tag into a `StartElement` event.
+///
+/// `line` is the line on which the tag begins; the line of each attribute is
+/// found by counting newlines in the raw tag up to the attribute's key.
+fn read_start_element(e: &BytesStart<'_>, is_empty: bool, line: usize) -> Result<XamlEvent> {
+    let name = std::str::from_utf8(e.name().as_ref())
+        .map_err(|e| XamlError::Utf8(e))?
+        .to_string();
+    
+    let raw: &[u8] = e;
+    let mut attributes = Vec::new();
+    let mut attribute_lines = Vec::new();
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| XamlError::QuickXml(e.into()))?;
+        let key_bytes = attr.key.as_ref();
+        let offset = (key_bytes.as_ptr() as usize).saturating_sub(raw.as_ptr() as usize);
+        let preceding = raw.get(..offset).unwrap_or(raw);
+        attribute_lines.push(line + preceding.iter().filter(|&&b| b == b'\n').count());
+        
+        let key = std::str::from_utf8(key_bytes)
+            .map_err(|e| XamlError::Utf8(e))?
+            .to_string();
+        let value = attr.unescape_value()
+            .map_err(|e| XamlError::QuickXml(e))?
+            .to_string();
+        attributes.push((key, value));
+    }
+    
+    Ok(XamlEvent::StartElement {
+        name,
+        attributes,
+        attribute_lines,
+        is_empty,
+        line,
+    })
+}
+
+/// Count the newlines in the raw content of an event.
+fn count_newlines(event: &Event<'_>) -> usize {
+    let raw: &[u8] = match event {
+        Event::Start(e) | Event::Empty(e) => e,
+        Event::End(e) => e,
+        Event::Text(e) | Event::Comment(e) | Event::DocType(e) => e,
+        Event::CData(e) => e,
+        Event::Decl(e) => e,
+        Event::PI(e) => e,
+        Event::Eof => &[],
+    };
+    raw.iter().filter(|&&b| b == b'\n').count()
+}
+
 /// Events emitted by the XAML reader.
 #[derive(Debug, Clone, PartialEq)]
 pub enum XamlEvent {
@@ -169,8 +189,12 @@ pub enum XamlEvent {
         name: String,
         /// Attributes as (name, value) pairs.
         attributes: Vec<(String, String)>,
+        /// Line (1-based) of each attribute, parallel to `attributes`.
+        attribute_lines: Vec<usize>,
         /// Whether this is a self-closing element.
         is_empty: bool,
+        /// Line (1-based) on which the start tag begins.
+        line: usize,
     },
     
     /// End of an element.
@@ -179,9 +203,12 @@ pub enum XamlEvent {
         name: String,
     },
     
-    /// Text content.
+    /// Text content, with entities unescaped.
     Text(String),
     
+    /// Content of a CDATA section, taken literally.
+    CData(String),
+    
     /// End of file.
     Eof,
 }
@@ -197,7 +224,7 @@ mod tests {
         
         let event = reader.read_event().unwrap();
         match event {
-            XamlEvent::StartElement { name, attributes, is_empty } => {
+            XamlEvent::StartElement { name, attributes, is_empty, .. } => {
                 assert_eq!(name, "Button");
                 assert_eq!(attributes.len(), 1);
                 assert_eq!(attributes[0].0, "Content");
@@ -283,4 +310,38 @@ mod tests {
             _ => panic!("Expected StartElement"),
         }
     }
+
+    #[test]
+    fn test_line_tracking() {
+        let xaml = "<Window>\n  <!-- one\n  two -->\n  <Button\n    Content=\"OK\"/>\n  <Label/>\n</Window>";
+        let mut reader = XamlReader::from_str(xaml);
+        
+        let mut lines = Vec::new();
+        loop {
+            match reader.read_event().unwrap() {
+                XamlEvent::StartElement { name, line, .. } => lines.push((name, line)),
+                XamlEvent::Eof => break,
+                _ => {}
+            }
+        }
+        
+        assert_eq!(lines, [
+            ("Window".to_string(), 1),
+            ("Button".to_string(), 4),
+            ("Label".to_string(), 6),
+        ]);
+    }
+    
+    #[test]
+    fn test_attribute_line_tracking() {
+        let xaml = "<Button\n  Width=\"10\" Height=\"20\"\n  Content=\"OK\"/>";
+        let mut reader = XamlReader::from_str(xaml);
+        
+        match reader.read_event().unwrap() {
+            XamlEvent::StartElement { attribute_lines, .. } => {
+                assert_eq!(attribute_lines, [2, 2, 3]);
+            }
+            _ => panic!("Expected StartElement"),
+        }
+    }
 }
@@ -4,8 +4,13 @@ pub mod type_name;
 pub mod xaml_type;
 pub mod property;
 pub mod registry;
+pub mod namespace_map;
+pub mod shared_str;
 
 pub use type_name::XamlTypeName;
 pub use xaml_type::{XamlType, BasicXamlType};
 pub use property::XamlProperty;
-pub use registry::TypeRegistry;
+pub use registry::{TypeRegistry, PlaceholderProvider};
+pub use namespace_map::NamespaceMap;
+pub use shared_str::SharedStr;
+pub(crate) use shared_str::StringInterner;
@@ -0,0 +1,103 @@
+//! Namespace prefix mappings used to resolve qualified XAML names.
+
+use crate::model::XamlElement;
+use std::collections::HashMap;
+
+/// Maps XML namespace prefixes to namespace URIs.
+///
+/// The empty prefix holds the default namespace (declared with a bare
+/// `xmlns` attribute).
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::types::NamespaceMap;
+///
+/// let mut namespaces = NamespaceMap::new();
+/// namespaces.declare("", "http://schemas.microsoft.com/winfx/2006/xaml/presentation");
+/// namespaces.declare("x", "http://schemas.microsoft.com/winfx/2006/xaml");
+///
+/// assert_eq!(namespaces.resolve("x"), Some("http://schemas.microsoft.com/winfx/2006/xaml"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamespaceMap {
+    /// Map from prefix to namespace URI.
+    prefixes: HashMap<String, String>,
+}
+
+impl NamespaceMap {
+    /// Create an empty namespace map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a prefix mapping. An empty prefix sets the default namespace.
+    pub fn declare(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
+        self.prefixes.insert(prefix.into(), uri.into());
+    }
+
+    /// Resolve a prefix to its namespace URI.
+    pub fn resolve(&self, prefix: &str) -> Option<&str> {
+        self.prefixes.get(prefix).map(|s| s.as_str())
+    }
+
+    /// Get the default namespace URI, or an empty string if none is declared.
+    pub fn default_namespace(&self) -> &str {
+        self.resolve("").unwrap_or("")
+    }
+
+    /// Check if a prefix is declared.
+    pub fn contains_prefix(&self, prefix: &str) -> bool {
+        self.prefixes.contains_key(prefix)
+    }
+
+    /// Iterate over all `(prefix, uri)` mappings.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.prefixes.iter().map(|(p, u)| (p.as_str(), u.as_str()))
+    }
+
+    /// Get the number of declared prefixes (including the default namespace).
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    /// Check if no prefixes are declared.
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Get the scope inside an element: this map plus the element's own
+    /// declarations, which override any outer mapping for the same prefix.
+    pub fn enter(&self, element: &XamlElement) -> Self {
+        let mut namespaces = self.clone();
+        for (prefix, uri) in &element.namespaces {
+            namespaces.declare(prefix.as_str(), uri.as_str());
+        }
+        namespaces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_namespace() {
+        let mut namespaces = NamespaceMap::new();
+        assert_eq!(namespaces.default_namespace(), "");
+
+        namespaces.declare("", "urn:default");
+        assert_eq!(namespaces.default_namespace(), "urn:default");
+        assert!(namespaces.contains_prefix(""));
+    }
+
+    #[test]
+    fn test_redeclare_prefix() {
+        let mut namespaces = NamespaceMap::new();
+        namespaces.declare("local", "urn:first");
+        namespaces.declare("local", "urn:second");
+
+        assert_eq!(namespaces.resolve("local"), Some("urn:second"));
+        assert_eq!(namespaces.len(), 1);
+    }
+}
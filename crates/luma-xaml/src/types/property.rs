@@ -56,6 +56,12 @@ impl XamlProperty {
         self
     }
 
+    /// Mark this as an event.
+    pub fn event(mut self) -> Self {
+        self.flags.insert(PropertyFlags::EVENT);
+        self
+    }
+
     /// Check if this is an attached property.
     pub fn is_attached(&self) -> bool {
         self.flags.contains(PropertyFlags::ATTACHED)
@@ -81,6 +87,11 @@ impl XamlProperty {
         self.flags.contains(PropertyFlags::CONTENT_PROPERTY)
     }
 
+    /// Check if this is an event.
+    pub fn is_event(&self) -> bool {
+        self.flags.contains(PropertyFlags::EVENT)
+    }
+
     /// Check if a specific flag is set.
     pub fn has_flag(&self, flag: PropertyFlags) -> bool {
         self.flags.contains(flag)
@@ -123,4 +134,13 @@ mod tests {
         assert!(property.is_readonly());
         assert!(property.is_content_property());
     }
+
+    #[test]
+    fn test_event_property() {
+        let type_name = XamlTypeName::new("System", "Object");
+        let property = XamlProperty::new("Click", type_name).event();
+        
+        assert!(property.is_event());
+        assert!(!property.is_dependency_property());
+    }
 }
@@ -1,8 +1,11 @@
 //! Type registry for managing XAML types and namespace mappings.
 
-use crate::types::{XamlType, XamlTypeName};
+use crate::types::{BasicXamlType, XamlType, XamlTypeName};
 use std::collections::HashMap;
 
+/// Supplies metadata for a type name that is known but not registered.
+pub type PlaceholderProvider = Box<dyn Fn(&XamlTypeName) -> Option<BasicXamlType>>;
+
 /// Registry of XAML types and namespace mappings.
 pub struct TypeRegistry {
     /// Map from full type name to type metadata.
@@ -13,6 +16,9 @@ pub struct TypeRegistry {
     
     /// Map from namespace URI to prefix.
     reverse_namespaces: HashMap<String, String>,
+    
+    /// Metadata for unregistered types, consulted by `placeholder_for`.
+    placeholder_provider: Option<PlaceholderProvider>,
 }
 
 impl TypeRegistry {
@@ -22,6 +28,7 @@ impl TypeRegistry {
             types: HashMap::new(),
             namespaces: HashMap::new(),
             reverse_namespaces: HashMap::new(),
+            placeholder_provider: None,
         }
     }
 
@@ -36,6 +43,43 @@ impl TypeRegistry {
         self.types.get(&name.full_name()).map(|b| b.as_ref())
     }
 
+    /// Set the provider that supplies metadata to [`placeholder_for`](Self::placeholder_for).
+    ///
+    /// The provider is asked about each name; returning `None` leaves the
+    /// placeholder without properties.
+    pub fn set_placeholder_provider(
+        &mut self,
+        provider: impl Fn(&XamlTypeName) -> Option<BasicXamlType> + 'static,
+    ) {
+        self.placeholder_provider = Some(Box::new(provider));
+    }
+
+    /// Make a stand-in type for a name that is not registered.
+    ///
+    /// The type comes from the placeholder provider if one is set and knows
+    /// the name, and is otherwise empty. Either way it is marked synthetic,
+    /// so it can be told apart from registered types. Elements parsed with
+    /// `ParserFlags::ALLOW_UNKNOWN_TYPES` whose type is not registered carry
+    /// `ElementFlags::SYNTHETIC_TYPE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::{TypeRegistry, XamlType, XamlTypeName};
+    ///
+    /// let registry = TypeRegistry::new();
+    /// let gadget = registry.placeholder_for(&XamlTypeName::new("using:App", "Gadget"));
+    /// assert!(gadget.is_synthetic());
+    /// assert!(gadget.properties().is_empty());
+    /// ```
+    pub fn placeholder_for(&self, name: &XamlTypeName) -> BasicXamlType {
+        self.placeholder_provider
+            .as_ref()
+            .and_then(|provider| provider(name))
+            .unwrap_or_else(|| BasicXamlType::new(name.clone()))
+            .as_synthetic()
+    }
+
     /// Register a namespace mapping.
     pub fn register_namespace(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
         let prefix = prefix.into();
@@ -116,4 +160,25 @@ mod tests {
         assert_eq!(registry.resolve_namespace("test"), Some("http://test.com/xaml"));
         assert_eq!(registry.get_prefix("http://test.com/xaml"), Some("test"));
     }
+
+    #[test]
+    fn test_placeholder_provider() {
+        let mut registry = TypeRegistry::new();
+        registry.set_placeholder_provider(|name| {
+            (name.name == "Gauge").then(|| {
+                BasicXamlType::new(name.clone()).with_property(crate::types::XamlProperty::new(
+                    "Value",
+                    XamlTypeName::new("System", "Double"),
+                ))
+            })
+        });
+        
+        let gauge = registry.placeholder_for(&XamlTypeName::new("using:App", "Gauge"));
+        assert!(gauge.is_synthetic());
+        assert_eq!(gauge.properties().len(), 1);
+        
+        let other = registry.placeholder_for(&XamlTypeName::new("using:App", "Dial"));
+        assert!(other.is_synthetic());
+        assert!(other.properties().is_empty());
+    }
 }
@@ -0,0 +1,180 @@
+//! Shared strings for names that repeat throughout a document.
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// An immutable string that is cheap to clone.
+///
+/// Type names and namespace URIs are stored this way so that, when the
+/// parser interns strings, every element of the same type points at the
+/// same allocation. It derefs to `str` and compares equal to string slices
+/// and `String`s, so it can mostly be used like one.
+///
+/// # Examples
+///
+/// ```
+/// use luma_xaml::types::SharedStr;
+///
+/// let name = SharedStr::from("Button");
+/// assert_eq!(name, "Button");
+/// assert!(name.starts_with("But"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct SharedStr(Arc<str>);
+
+impl SharedStr {
+    /// Get the string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check if two shared strings use the same allocation.
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        Arc::ptr_eq(&a.0, &b.0)
+    }
+}
+
+impl Deref for SharedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SharedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for SharedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for SharedStr {
+    fn from(s: &str) -> Self {
+        Self(Arc::from(s))
+    }
+}
+
+impl From<String> for SharedStr {
+    fn from(s: String) -> Self {
+        Self(Arc::from(s))
+    }
+}
+
+impl From<&String> for SharedStr {
+    fn from(s: &String) -> Self {
+        Self(Arc::from(s.as_str()))
+    }
+}
+
+impl From<SharedStr> for String {
+    fn from(s: SharedStr) -> Self {
+        s.0.to_string()
+    }
+}
+
+impl PartialEq<str> for SharedStr {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for SharedStr {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for SharedStr {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl PartialEq<SharedStr> for str {
+    fn eq(&self, other: &SharedStr) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<SharedStr> for &str {
+    fn eq(&self, other: &SharedStr) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl PartialEq<SharedStr> for String {
+    fn eq(&self, other: &SharedStr) -> bool {
+        **self == *other.0
+    }
+}
+
+impl fmt::Debug for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SharedStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SharedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Pool that hands out one [`SharedStr`] per distinct string.
+#[derive(Debug, Default)]
+pub(crate) struct StringInterner {
+    strings: HashSet<SharedStr>,
+}
+
+impl StringInterner {
+    /// Get the pooled copy of `s`, adding it if it is new.
+    pub(crate) fn intern(&mut self, s: &str) -> SharedStr {
+        if let Some(shared) = self.strings.get(s) {
+            return shared.clone();
+        }
+        let shared = SharedStr::from(s);
+        self.strings.insert(shared.clone());
+        shared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interner_shares_allocations() {
+        let mut interner = StringInterner::default();
+        let a = interner.intern("Button");
+        let b = interner.intern(&String::from("Button"));
+        let c = interner.intern("Grid");
+
+        assert!(SharedStr::ptr_eq(&a, &b));
+        assert!(!SharedStr::ptr_eq(&a, &c));
+        assert!(!SharedStr::ptr_eq(&a, &SharedStr::from("Button")));
+        assert_eq!(a, SharedStr::from("Button"));
+    }
+}
@@ -1,5 +1,7 @@
 //! XAML type name representation with namespace and generic support.
 
+use crate::error::{Result, XamlError};
+use crate::types::{NamespaceMap, SharedStr};
 use std::fmt;
 
 /// Represents a XAML type name with namespace and optional type arguments.
@@ -17,18 +19,19 @@ use std::fmt;
 /// let list = XamlTypeName::with_type_args("System.Collections.Generic", "List", vec![string_type]);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XamlTypeName {
     /// The namespace (e.g., "Microsoft.UI.Xaml.Controls")
-    pub namespace: String,
+    pub namespace: SharedStr,
     /// The type name (e.g., "Button")
-    pub name: String,
+    pub name: SharedStr,
     /// Generic type arguments (if any)
     pub type_args: Vec<XamlTypeName>,
 }
 
 impl XamlTypeName {
     /// Create a new XAML type name without generic arguments.
-    pub fn new(namespace: impl Into<String>, name: impl Into<String>) -> Self {
+    pub fn new(namespace: impl Into<SharedStr>, name: impl Into<SharedStr>) -> Self {
         Self {
             namespace: namespace.into(),
             name: name.into(),
@@ -38,8 +41,8 @@ impl XamlTypeName {
 
     /// Create a new XAML type name with generic type arguments.
     pub fn with_type_args(
-        namespace: impl Into<String>,
-        name: impl Into<String>,
+        namespace: impl Into<SharedStr>,
+        name: impl Into<SharedStr>,
         type_args: Vec<XamlTypeName>,
     ) -> Self {
         Self {
@@ -49,10 +52,64 @@ impl XamlTypeName {
         }
     }
 
+    /// Create a type name from a `prefix:Local` (or bare `Local`) XML name.
+    ///
+    /// The prefix is resolved against `namespaces`; a bare name uses the
+    /// default namespace. Fails if the prefix is not declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use luma_xaml::XamlTypeName;
+    /// use luma_xaml::types::NamespaceMap;
+    ///
+    /// let mut namespaces = NamespaceMap::new();
+    /// namespaces.declare("local", "using:MyApp");
+    ///
+    /// let name = XamlTypeName::from_qualified("local:MainPage", &namespaces)?;
+    /// assert_eq!(name.namespace, "using:MyApp");
+    /// assert_eq!(name.name, "MainPage");
+    /// # Ok::<(), luma_xaml::XamlError>(())
+    /// ```
+    pub fn from_qualified(qualified: &str, namespaces: &NamespaceMap) -> Result<Self> {
+        let (prefix, local_name) = Self::split_qualified(qualified);
+        
+        let namespace = match prefix {
+            Some(prefix) => namespaces.resolve(prefix).ok_or_else(|| XamlError::InvalidNamespace {
+                line: 0,
+                details: format!("Undefined namespace prefix: {}", prefix),
+            })?,
+            None => namespaces.default_namespace(),
+        };
+        
+        Ok(Self::new(namespace, local_name))
+    }
+
+    /// Split a qualified XML name into `(prefix, local_name)`.
+    pub fn split_qualified(qualified: &str) -> (Option<&str>, &str) {
+        match qualified.split_once(':') {
+            Some((prefix, local)) => (Some(prefix), local),
+            None => (None, qualified),
+        }
+    }
+
+    /// Check if the local name matches, ignoring namespaces.
+    ///
+    /// Any `prefix:` on `name` is ignored, so `"local:Button"` and
+    /// `"Button"` both match a type named `Button`.
+    pub fn matches_local(&self, name: &str) -> bool {
+        self.name == Self::split_qualified(name).1
+    }
+
+    /// Like [`matches_local`](Self::matches_local), but ASCII case-insensitive.
+    pub fn matches_local_ignore_case(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(Self::split_qualified(name).1)
+    }
+
     /// Get the fully qualified name (namespace.name).
     pub fn full_name(&self) -> String {
         if self.namespace.is_empty() {
-            self.name.clone()
+            self.name.to_string()
         } else {
             format!("{}.{}", self.namespace, self.name)
         }
@@ -130,4 +187,45 @@ mod tests {
             "System.Collections.Generic.Dictionary<System.String, System.Int32>"
         );
     }
+
+    #[test]
+    fn test_from_qualified_prefixed() {
+        let mut namespaces = NamespaceMap::new();
+        namespaces.declare("", "urn:default");
+        namespaces.declare("local", "using:MyApp");
+        
+        let name = XamlTypeName::from_qualified("local:MainPage", &namespaces).unwrap();
+        assert_eq!(name, XamlTypeName::new("using:MyApp", "MainPage"));
+    }
+
+    #[test]
+    fn test_from_qualified_default_namespace() {
+        let mut namespaces = NamespaceMap::new();
+        namespaces.declare("", "urn:default");
+        
+        let name = XamlTypeName::from_qualified("Button", &namespaces).unwrap();
+        assert_eq!(name, XamlTypeName::new("urn:default", "Button"));
+        
+        // No default namespace declared
+        let name = XamlTypeName::from_qualified("Button", &NamespaceMap::new()).unwrap();
+        assert_eq!(name.namespace, "");
+    }
+
+    #[test]
+    fn test_from_qualified_undefined_prefix() {
+        let result = XamlTypeName::from_qualified("missing:Button", &NamespaceMap::new());
+        assert!(matches!(result, Err(XamlError::InvalidNamespace { .. })));
+    }
+
+    #[test]
+    fn test_matches_local() {
+        let name = XamlTypeName::new("urn:default", "Button");
+        
+        assert!(name.matches_local("Button"));
+        assert!(name.matches_local("local:Button"));
+        assert!(!name.matches_local("button"));
+        assert!(name.matches_local_ignore_case("button"));
+        assert!(name.matches_local_ignore_case("x:BUTTON"));
+        assert!(!name.matches_local_ignore_case("TextBox"));
+    }
 }
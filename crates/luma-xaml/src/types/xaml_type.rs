@@ -29,6 +29,18 @@ pub trait XamlType {
     fn is_abstract(&self) -> bool {
         false
     }
+    
+    /// Check if this type starts its own x:Name namescope, like templates
+    /// and user controls do.
+    fn is_namescope(&self) -> bool {
+        false
+    }
+    
+    /// Check if this type was guessed for an unregistered name rather than
+    /// registered; see [`TypeRegistry::placeholder_for`](crate::TypeRegistry::placeholder_for).
+    fn is_synthetic(&self) -> bool {
+        false
+    }
 }
 
 /// A basic implementation of XamlType for custom types.
@@ -51,6 +63,12 @@ pub struct BasicXamlType {
     
     /// Whether this type is abstract.
     pub is_abstract: bool,
+    
+    /// Whether this type is a placeholder for an unregistered name.
+    pub is_synthetic: bool,
+    
+    /// Whether this type starts its own x:Name namescope.
+    pub is_namescope: bool,
 }
 
 impl BasicXamlType {
@@ -63,6 +81,8 @@ impl BasicXamlType {
             is_collection: false,
             content_property: None,
             is_abstract: false,
+            is_synthetic: false,
+            is_namescope: false,
         }
     }
 
@@ -95,6 +115,18 @@ impl BasicXamlType {
         self.is_abstract = true;
         self
     }
+
+    /// Mark this type as a placeholder for an unregistered name.
+    pub fn as_synthetic(mut self) -> Self {
+        self.is_synthetic = true;
+        self
+    }
+
+    /// Mark this type as starting its own x:Name namescope.
+    pub fn as_namescope(mut self) -> Self {
+        self.is_namescope = true;
+        self
+    }
 }
 
 impl XamlType for BasicXamlType {
@@ -121,6 +153,14 @@ impl XamlType for BasicXamlType {
     fn is_abstract(&self) -> bool {
         self.is_abstract
     }
+
+    fn is_synthetic(&self) -> bool {
+        self.is_synthetic
+    }
+
+    fn is_namescope(&self) -> bool {
+        self.is_namescope
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
 //! Integration tests for markup extension parsing.
 
-use luma_xaml::parser::XamlParser;
+use luma_xaml::parser::{XamlParser, ParserSettings};
 use luma_xaml::types::TypeRegistry;
 use luma_xaml::model::XamlValue;
+use luma_xaml::XamlError;
 
 #[test]
 fn test_parse_static_resource() {
@@ -74,15 +75,56 @@ fn test_parse_binding_with_mode() {
     }
 }
 
+#[test]
+fn test_parse_binding_across_lines() {
+    let xaml = "<TextBox xmlns=\"http://test\"\n    Text=\"{Binding Path=Name,\n             Mode=TwoWay}\n\"/>";
+    
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(xaml).expect("Should parse");
+    
+    let text = doc.root.get_attribute("Text").expect("Should have Text");
+    match text {
+        XamlValue::MarkupExtension { extension_name, arguments } => {
+            assert_eq!(extension_name, "Binding");
+            assert_eq!(
+                arguments.get("Path").and_then(|v| v.as_string()),
+                Some("Name")
+            );
+            assert_eq!(
+                arguments.get("Mode").and_then(|v| v.as_string()),
+                Some("TwoWay")
+            );
+        }
+        _ => panic!("Expected MarkupExtension, got {:?}", text),
+    }
+}
+
 #[test]
 fn test_parse_x_null() {
-    let xaml = r#"<Button xmlns="http://test" xmlns:x="http://xaml" Content="{x:Null}"/>"#;
+    let xaml = r#"<Button xmlns="http://test" xmlns:x="http://xaml" Content="{x:Null}" Tag="{Null}"/>"#;
     
     let registry = TypeRegistry::new();
     let parser = XamlParser::new(registry);
     
     let doc = parser.parse_string(xaml).expect("Should parse");
     
+    let content = doc.root.get_attribute("Content").expect("Should have Content");
+    assert!(matches!(content, XamlValue::Null));
+    assert!(content.is_null());
+    assert!(doc.root.get_attribute("Tag").unwrap().is_null());
+}
+
+#[test]
+fn test_raw_markup_extensions_flag() {
+    let xaml = r#"<Button xmlns="http://test" xmlns:x="http://xaml" Content="{x:Null}" Tag="{x:Type Button}"/>"#;
+    
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry).with_settings(ParserSettings::new().raw_markup_extensions());
+    
+    let doc = parser.parse_string(xaml).expect("Should parse");
+    
     let content = doc.root.get_attribute("Content").expect("Should have Content");
     match content {
         XamlValue::MarkupExtension { extension_name, .. } => {
@@ -90,30 +132,74 @@ fn test_parse_x_null() {
         }
         _ => panic!("Expected MarkupExtension, got {:?}", content),
     }
+    
+    let tag = doc.root.get_attribute("Tag").expect("Should have Tag");
+    match tag {
+        XamlValue::MarkupExtension { extension_name, arguments } => {
+            assert_eq!(extension_name, "x:Type");
+            assert_eq!(
+                arguments.get("_positional").and_then(|v| v.as_string()),
+                Some("Button")
+            );
+        }
+        _ => panic!("Expected MarkupExtension, got {:?}", tag),
+    }
 }
 
 #[test]
 fn test_parse_x_type() {
     let xaml = r#"<ContentControl xmlns="http://test" xmlns:x="http://xaml" Content="{x:Type Button}"/>"#;
     
+    // Unknown types are kept as-is when not in strict mode
     let registry = TypeRegistry::new();
-    let parser = XamlParser::new(registry);
+    let parser = XamlParser::new(registry).with_settings(ParserSettings::new().lenient());
     
     let doc = parser.parse_string(xaml).expect("Should parse");
     
     let content = doc.root.get_attribute("Content").expect("Should have Content");
     match content {
-        XamlValue::MarkupExtension { extension_name, arguments } => {
-            assert_eq!(extension_name, "x:Type");
-            assert_eq!(
-                arguments.get("_positional").and_then(|v| v.as_string()),
-                Some("Button")
-            );
+        XamlValue::Type(type_name) => {
+            assert_eq!(type_name.namespace, "http://test");
+            assert_eq!(type_name.name, "Button");
         }
-        _ => panic!("Expected MarkupExtension, got {:?}", content),
+        _ => panic!("Expected Type, got {:?}", content),
     }
 }
 
+#[test]
+fn test_x_type_resolves_against_winui_registry() {
+    use luma_xaml::dialects::winui3::create_type_registry;
+    
+    let xaml = r#"
+        <Style xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation"
+               xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml"
+               TargetType="{x:Type Button}"/>
+    "#;
+    
+    let parser = XamlParser::new(create_type_registry());
+    let doc = parser.parse_string(xaml).expect("Should parse");
+    
+    let target = doc.root.get_attribute("TargetType").and_then(|v| v.as_type()).expect("Should be a type");
+    assert_eq!(target.name, "Button");
+    assert_eq!(target.namespace, "http://schemas.microsoft.com/winfx/2006/xaml/presentation");
+}
+
+#[test]
+fn test_x_type_unknown_type_fails_in_strict_mode() {
+    use luma_xaml::dialects::winui3::create_type_registry;
+    
+    let xaml = r#"
+        <Style xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation"
+               xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml"
+               TargetType="{x:Type NoSuchControl}"/>
+    "#;
+    
+    let parser = XamlParser::new(create_type_registry());
+    let result = parser.parse_string(xaml);
+    
+    assert!(matches!(result, Err(XamlError::UnknownType { ref type_name, line: 4 }) if type_name.ends_with("NoSuchControl")));
+}
+
 #[test]
 fn test_parse_escaped_brace() {
     let xaml = r#"<TextBlock xmlns="http://test" Text="{{Not a markup extension}}"/>"#;
@@ -232,3 +318,61 @@ fn test_parse_with_winui3_types() {
     let text = text_block.get_attribute("Text");
     assert!(matches!(text, Some(XamlValue::MarkupExtension { .. })));
 }
+
+#[test]
+fn test_malformed_extension_reports_attribute_line() {
+    let xaml = r#"<StackPanel xmlns="http://test">
+    <TextBlock
+        Width="100"
+        Text="{Binding Path=Name" />
+</StackPanel>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    match parser.parse_string(xaml) {
+        Err(error @ XamlError::InvalidMarkupExtension { .. }) => {
+            assert_eq!(error.line(), Some(4));
+        }
+        other => panic!("Expected InvalidMarkupExtension error, got {:?}", other),
+    }
+    
+    // Lenient mode keeps the malformed value as a string
+    let parser = XamlParser::new(TypeRegistry::new()).with_settings(ParserSettings::new().lenient());
+    let doc = parser.parse_string(xaml).expect("Lenient mode accepts malformed extensions");
+    let text_block = doc.root.child_elements().next().unwrap();
+    assert_eq!(
+        text_block.get_attribute("Text").and_then(|v| v.as_string()),
+        Some("{Binding Path=Name")
+    );
+}
+
+#[test]
+fn test_parse_nested_extension() {
+    let xaml = r#"<TextBlock xmlns="http://test" Text="{Binding Source={StaticResource Key}, FallbackValue={x:Null}}"/>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let doc = parser.parse_string(xaml).expect("Nested extensions parse in strict mode");
+    
+    let text = doc.root.get_attribute("Text").expect("Should have Text");
+    match text {
+        XamlValue::MarkupExtension { extension_name, arguments } => {
+            assert_eq!(extension_name, "Binding");
+            match arguments.get("Source") {
+                Some(XamlValue::MarkupExtension { extension_name, arguments }) => {
+                    assert_eq!(extension_name, "StaticResource");
+                    assert_eq!(
+                        arguments.get("_positional").and_then(|v| v.as_string()),
+                        Some("Key")
+                    );
+                }
+                other => panic!("Expected nested MarkupExtension, got {:?}", other),
+            }
+            // Built-in extensions are resolved when nested too
+            assert_eq!(arguments.get("FallbackValue"), Some(&XamlValue::Null));
+        }
+        _ => panic!("Expected MarkupExtension, got {:?}", text),
+    }
+    
+    // The nested extension is written back in place
+    let xaml = r#"<TextBlock xmlns="http://test" Text="{Binding Source={StaticResource Key}}"/>"#;
+    assert_eq!(parser.parse_string(xaml).unwrap().to_xaml(), xaml);
+}
@@ -1,7 +1,9 @@
 //! Integration tests for XAML parser.
 
 use luma_xaml::parser::{XamlParser, ParserSettings};
-use luma_xaml::types::TypeRegistry;
+use luma_xaml::types::{BasicXamlType, TypeRegistry};
+use luma_xaml::{XamlError, XamlValue, Style, Setter, apply_style, resolve_styles,
+    ControlTemplate, TemplateBinding, XamlTypeName, ElementPath, XamlChange, diff};
 
 #[test]
 fn test_parse_simple_element() {
@@ -162,6 +164,24 @@ fn test_parse_boolean_values() {
     );
 }
 
+#[test]
+fn test_parse_boolean_spellings() {
+    let xaml = r#"<CheckBox xmlns="http://test" IsChecked="TRUE" IsThreeState="Off" IsTabStop="yes" TabIndex="1" Content="Maybe"/>"#;
+    
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    
+    assert_eq!(doc.root.get_attribute("IsChecked").and_then(|v| v.as_bool()), Some(true));
+    assert_eq!(doc.root.get_attribute("IsThreeState").and_then(|v| v.as_bool()), Some(false));
+    assert_eq!(doc.root.get_attribute("IsTabStop").and_then(|v| v.as_bool()), Some(true));
+    
+    // Numbers stay numbers, other words stay strings
+    assert_eq!(doc.root.get_attribute("TabIndex").and_then(|v| v.as_integer()), Some(1));
+    assert_eq!(doc.root.get_attribute("Content").and_then(|v| v.as_string()), Some("Maybe"));
+}
+
 #[test]
 fn test_parse_numeric_values() {
     let xaml = r#"<Rectangle xmlns="http://test" Width="100" Height="50.5"/>"#;
@@ -176,10 +196,15 @@ fn test_parse_numeric_values() {
         Some(100)
     );
     
-    // Height is parsed as float
-    let height = doc.root.get_attribute("Height");
-    assert!(height.is_some());
-    // Note: 50.5 will be parsed as float, not integer
+    // Height is parsed as float, but both read the same way via as_f64
+    assert_eq!(
+        doc.root.get_attribute("Width").and_then(|v| v.as_f64()),
+        Some(100.0)
+    );
+    assert_eq!(
+        doc.root.get_attribute("Height").and_then(|v| v.as_f64()),
+        Some(50.5)
+    );
 }
 
 #[test]
@@ -270,9 +295,7 @@ fn test_x_key_attribute() {
     assert!(brush.has_flag(luma_xaml::flags::ElementFlags::HAS_KEY));
 }
 
-#[test]
-fn test_complex_nested_structure() {
-    let xaml = r#"
+const COMPLEX_NESTED_XAML: &str = r#"
         <Window xmlns="http://test" xmlns:x="http://xaml" x:Name="MainWindow">
             <Window.Resources>
                 <Style x:Key="ButtonStyle">
@@ -292,11 +315,13 @@ fn test_complex_nested_structure() {
             </Grid>
         </Window>
     "#;
-    
+
+#[test]
+fn test_complex_nested_structure() {
     let registry = TypeRegistry::new();
     let parser = XamlParser::new(registry);
     
-    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    let doc = parser.parse_string(COMPLEX_NESTED_XAML).expect("Failed to parse XAML");
     
     // Check root
     assert_eq!(doc.root.type_name.name, "Window");
@@ -312,3 +337,666 @@ fn test_complex_nested_structure() {
     // Grid should have RowDefinitions property
     assert!(grid.get_property("RowDefinitions").is_some());
 }
+
+#[test]
+fn test_grid_attached_properties() {
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(COMPLEX_NESTED_XAML).expect("Failed to parse XAML");
+    let grid = doc.root.child_elements().next().unwrap();
+    let rows: Vec<i32> = grid.child_elements().map(|child| child.grid_row()).collect();
+    assert_eq!(rows, [0, 1]);
+    
+    let panel = grid.child_elements().nth(1).unwrap();
+    assert_eq!(panel.attached_property("Grid", "Row"), Some(&XamlValue::Integer(1)));
+    assert_eq!(panel.attached_property("Grid", "Column"), None);
+    assert_eq!(panel.grid_column(), 0);
+    
+    let xaml = r#"<Button xmlns="http://test" xmlns:local="using:App" local:Grid.Column="2"/>"#;
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    assert_eq!(doc.root.grid_column(), 2);
+}
+
+#[test]
+fn test_attached_properties_are_keyed_by_owner() {
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(COMPLEX_NESTED_XAML).expect("Failed to parse XAML");
+    let header = doc.root.child_elements().next().unwrap().child_elements().next().unwrap();
+    assert_eq!(
+        header.attached_properties.get(&("Grid".to_string(), "Row".to_string())),
+        Some(&XamlValue::Integer(0))
+    );
+    assert_eq!(header.get_attribute("Grid.Row"), None);
+    assert_eq!(header.attributes.keys().collect::<Vec<_>>(), ["Text"]);
+    
+    let xaml = r#"<Button xmlns="http://test" xmlns:local="using:App" local:Grid.Column="2"/>"#;
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    assert!(doc.root.attached_properties.contains_key(&("local:Grid".to_string(), "Column".to_string())));
+    assert_eq!(doc.root.to_xaml(), xaml);
+}
+
+#[test]
+fn test_apply_style_from_resources() {
+    let parser = XamlParser::new(TypeRegistry::new());
+    let doc = parser.parse_string(COMPLEX_NESTED_XAML).expect("Failed to parse XAML");
+    
+    let resource = doc.root.get_property("Resources").and_then(|v| v.as_element()).unwrap();
+    let style = Style::from_element(resource).expect("Failed to build style");
+    assert_eq!(style.key.as_deref(), Some("ButtonStyle"));
+    assert_eq!(style.target_type, None);
+    assert_eq!(style.setters, [Setter {
+        property: "Background".to_string(),
+        value: XamlValue::String("Blue".to_string()),
+    }]);
+    
+    let mut button = parser
+        .parse_string(r#"<Button xmlns="http://test" Content="OK"/>"#)
+        .expect("Failed to parse XAML")
+        .root;
+    apply_style(&mut button, &style);
+    assert_eq!(button.get_attribute("Background").and_then(|v| v.as_string()), Some("Blue"));
+    assert_eq!(button.get_attribute("Content").and_then(|v| v.as_string()), Some("OK"));
+    
+    // A value set on the element wins over the style
+    let mut red = parser
+        .parse_string(r#"<Button xmlns="http://test" Background="Red"/>"#)
+        .expect("Failed to parse XAML")
+        .root;
+    apply_style(&mut red, &style);
+    assert_eq!(red.get_attribute("Background").and_then(|v| v.as_string()), Some("Red"));
+    
+    // Anything but a Style is rejected
+    assert!(Style::from_element(&button).is_err());
+}
+
+#[test]
+fn test_implicit_style_applies_by_target_type() {
+    let xaml = r#"
+        <Window xmlns="http://test" xmlns:x="http://xaml">
+            <Window.Resources>
+                <Style TargetType="Button">
+                    <Setter Property="Background" Value="Blue"/>
+                </Style>
+                <Style x:Key="Danger" TargetType="Button">
+                    <Setter Property="Foreground" Value="White"/>
+                </Style>
+            </Window.Resources>
+            <StackPanel>
+                <Button Content="Plain"/>
+                <Button Content="Own" Background="Green"/>
+                <Button Content="Explicit" Style="{StaticResource Danger}"/>
+                <TextBlock Text="Not a button"/>
+            </StackPanel>
+        </Window>
+    "#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let mut doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    resolve_styles(&mut doc.root).expect("Failed to resolve styles");
+    
+    let panel = doc.root.child_elements().next().unwrap();
+    let value = |index: usize, name: &str| {
+        panel.child_elements().nth(index).unwrap().get_attribute(name).and_then(|v| v.as_string())
+    };
+    assert_eq!(value(0, "Background"), Some("Blue"));
+    assert_eq!(value(1, "Background"), Some("Green"));
+    
+    // An explicit style replaces the implicit one
+    assert_eq!(value(2, "Background"), None);
+    assert_eq!(value(2, "Foreground"), Some("White"));
+    assert_eq!(value(3, "Background"), None);
+    
+    // The styles themselves are left alone
+    let styles = doc.root.get_property("Resources").and_then(|v| v.as_collection()).unwrap();
+    assert_eq!(styles.len(), 2);
+    
+    // Referencing an undeclared style fails
+    let mut doc = parser
+        .parse_string(r#"<Button xmlns="http://test" Style="{StaticResource Missing}"/>"#)
+        .expect("Failed to parse XAML");
+    assert!(matches!(resolve_styles(&mut doc.root), Err(XamlError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_control_template_links_template_bindings() {
+    let xaml = r#"
+        <Style xmlns="http://test" TargetType="Button">
+            <Setter Property="Template">
+                <ControlTemplate TargetType="Button">
+                    <Border Background="{TemplateBinding Background}" CornerRadius="4">
+                        <ContentPresenter Content="{TemplateBinding Content}"/>
+                    </Border>
+                </ControlTemplate>
+            </Setter>
+        </Style>
+    "#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let style = Style::from_element(&parser.parse_string(xaml).expect("Failed to parse XAML").root)
+        .expect("Failed to build style");
+    assert_eq!(style.setters[0].property, "Template");
+    let template = style.setters[0].value.as_element().expect("Template should be an element");
+    let template = ControlTemplate::from_element(template).expect("Failed to build template");
+    
+    assert_eq!(template.target_type.as_deref(), Some("Button"));
+    assert_eq!(template.root.type_name.name, "Border");
+    assert_eq!(template.bindings, [
+        TemplateBinding {
+            element_path: vec![],
+            target: "Background".to_string(),
+            property: "Background".to_string(),
+        },
+        TemplateBinding {
+            element_path: vec![0],
+            target: "Content".to_string(),
+            property: "Content".to_string(),
+        },
+    ]);
+    assert_eq!(template.element(&template.bindings[1]).unwrap().type_name.name, "ContentPresenter");
+    
+    // The binding resolves to the templated button's own Background
+    let button = parser
+        .parse_string(r#"<Button xmlns="http://test" Background="Red"/>"#)
+        .expect("Failed to parse XAML")
+        .root;
+    assert_eq!(template.resolve(&template.bindings[0], &button), Some(&XamlValue::String("Red".to_string())));
+    
+    let visual = template.apply(&button);
+    assert_eq!(visual.get_attribute("Background").and_then(|v| v.as_string()), Some("Red"));
+    assert_eq!(visual.get_attribute("CornerRadius").and_then(|v| v.as_integer()), Some(4));
+    let presenter = visual.child_elements().next().unwrap();
+    assert!(presenter.get_attribute("Content").is_none());
+}
+
+#[test]
+fn test_attribute_and_property_order_preserved() {
+    let xaml = r#"
+        <Button xmlns="http://test" Width="100" Content="OK" Height="30" Margin="4" IsEnabled="True">
+            <Button.ToolTip>Tip</Button.ToolTip>
+            <Button.Flyout>Menu</Button.Flyout>
+        </Button>
+    "#;
+    
+    let registry = TypeRegistry::new();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    
+    let attributes: Vec<&str> = doc.root.attributes.keys().map(|k| k.as_str()).collect();
+    assert_eq!(attributes, ["Width", "Content", "Height", "Margin", "IsEnabled"]);
+    
+    let properties: Vec<&str> = doc.root.properties.keys().map(|k| k.as_str()).collect();
+    assert_eq!(properties, ["ToolTip", "Flyout"]);
+}
+
+#[test]
+fn test_whitespace_modes() {
+    let xaml = "<TextBlock xmlns=\"http://test\">  Hello \n\t  <Run/>   big   world  </TextBlock>";
+    
+    let texts = |settings: ParserSettings| -> Vec<String> {
+        let parser = XamlParser::new(TypeRegistry::new()).with_settings(settings);
+        let doc = parser.parse_string(xaml).expect("Failed to parse");
+        doc.root.children.iter().filter_map(|n| n.as_text()).map(String::from).collect()
+    };
+    
+    // Default: whitespace-only nodes dropped, other text kept verbatim
+    assert_eq!(texts(ParserSettings::new()), ["  Hello \n\t  ", "   big   world  "]);
+    
+    // Preserve: text kept exactly
+    assert_eq!(
+        texts(ParserSettings::new().preserve_whitespace().normalize_whitespace()),
+        ["  Hello \n\t  ", "   big   world  "]
+    );
+    
+    // Normalize: runs collapsed, text trimmed next to elements
+    assert_eq!(texts(ParserSettings::new().normalize_whitespace()), ["Hello", "big world"]);
+}
+
+#[test]
+fn test_xml_space_preserve_subtree() {
+    let xaml = r#"
+        <StackPanel xmlns="http://test">
+            <TextBlock>  trimmed   text  </TextBlock>
+            <Border xml:space="preserve">
+                <TextBlock>  kept   text  </TextBlock>
+            </Border>
+            <TextBlock>  after   scope  </TextBlock>
+        </StackPanel>
+    "#;
+    
+    let settings = ParserSettings::new().normalize_whitespace();
+    let parser = XamlParser::new(TypeRegistry::new()).with_settings(settings);
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    
+    let children: Vec<_> = doc.root.child_elements().collect();
+    assert_eq!(children[0].text_content(), "trimmed text");
+    
+    let preserved = children[1].child_elements().next().unwrap();
+    assert_eq!(preserved.text_content(), "  kept   text  ");
+    assert!(children[1].get_attribute("xml:space").is_none());
+    
+    // The preserve state ends with the Border element
+    assert_eq!(children[2].text_content(), "after scope");
+}
+
+#[test]
+fn test_duplicate_name_detected() {
+    let xaml = r#"<StackPanel xmlns="http://test" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml">
+    <Button x:Name="Foo" />
+    <TextBlock x:Name="Foo" />
+</StackPanel>"#;
+    
+    // Duplicates are only rejected when asked for
+    let parser = XamlParser::new(TypeRegistry::new());
+    assert!(parser.parse_string(xaml).is_ok());
+    
+    let settings = ParserSettings::new().validate_names();
+    let parser = XamlParser::new(TypeRegistry::new()).with_settings(settings);
+    match parser.parse_string(xaml) {
+        Err(XamlError::DuplicateName { name, line }) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(line, 3);
+        }
+        other => panic!("Expected DuplicateName error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_names_scoped_to_templates() {
+    let xaml = r#"<StackPanel xmlns="http://test" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml">
+    <TextBlock x:Name="Title" />
+    <ListView x:Name="Items">
+        <ListView.ItemTemplate>
+            <DataTemplate>
+                <TextBlock x:Name="Title" />
+            </DataTemplate>
+        </ListView.ItemTemplate>
+    </ListView>
+</StackPanel>"#;
+    
+    let mut registry = TypeRegistry::new();
+    registry.register_type(Box::new(BasicXamlType::new(XamlTypeName::new("http://test", "DataTemplate")).as_namescope()));
+    let settings = ParserSettings::new().validate_names();
+    let parser = XamlParser::new(registry).with_settings(settings.clone());
+    let doc = parser.parse_string(xaml).expect("Names inside a template have their own scope");
+    assert_eq!(doc.root.child_elements().count(), 2);
+    
+    // Only registered namescope types start a scope, whatever they are called
+    let parser = XamlParser::new(TypeRegistry::new()).with_settings(settings);
+    assert!(matches!(parser.parse_string(xaml), Err(XamlError::DuplicateName { .. })));
+}
+
+#[test]
+fn test_winui3_user_control_is_namescope() {
+    let xaml = r#"<StackPanel xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml">
+    <TextBlock x:Name="Title" />
+    <UserControl>
+        <TextBlock x:Name="Title" />
+    </UserControl>
+</StackPanel>"#;
+    
+    let settings = ParserSettings::new().validate_names();
+    let parser = XamlParser::new(luma_xaml::dialects::winui3::create_type_registry()).with_settings(settings);
+    assert!(parser.parse_string(xaml).is_ok());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_document_to_json() {
+    let xaml = r#"<StackPanel xmlns="http://test" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml" x:Name="Root">
+    <Button Content="{Binding Title}" Width="100"/>
+    <TextBlock>Hello</TextBlock>
+</StackPanel>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let doc = parser.parse_string(xaml).expect("Failed to parse XAML");
+    let json = doc.to_json();
+    
+    let root = &json["root"];
+    assert_eq!(root["kind"], "element");
+    assert_eq!(root["type"], "StackPanel");
+    assert_eq!(root["namespace"], "http://test");
+    assert_eq!(root["name"], "Root");
+    
+    let button = &root["children"][0];
+    assert_eq!(button["type"], "Button");
+    assert_eq!(button["attributes"]["Width"], 100);
+    assert_eq!(button["attributes"]["Content"], serde_json::json!({
+        "kind": "markupExtension",
+        "extension": "Binding",
+        "arguments": { "_positional": "Title" },
+    }));
+    
+    // Attribute order follows the document
+    let keys: Vec<_> = button["attributes"].as_object().unwrap().keys().collect();
+    assert_eq!(keys, ["Content", "Width"]);
+    
+    let text_block = &root["children"][1];
+    assert_eq!(text_block["children"][0], "Hello");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let parser = XamlParser::new(TypeRegistry::new());
+    let mut doc = parser.parse_string(COMPLEX_NESTED_XAML).expect("Failed to parse XAML");
+    doc.root.set_attribute("Title", luma_xaml::XamlValue::MarkupExtension {
+        extension_name: "Binding".to_string(),
+        arguments: [("Path".to_string(), luma_xaml::XamlValue::String("Title".to_string()))].into(),
+    });
+    
+    let json = serde_json::to_string(&doc).expect("Failed to serialize");
+    let restored: luma_xaml::XamlDocument = serde_json::from_str(&json).expect("Failed to deserialize");
+    
+    assert_eq!(restored, doc);
+}
+
+#[test]
+fn test_parse_reader() {
+    let xaml = r#"<StackPanel xmlns="http://test">
+    <Button Content="OK"/>
+</StackPanel>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let from_reader = parser
+        .parse_reader(std::io::Cursor::new(xaml.as_bytes().to_vec()))
+        .expect("Failed to parse from reader");
+    let from_string = parser.parse_string(xaml).expect("Failed to parse XAML");
+    
+    assert_eq!(from_reader.root.type_name.name, "StackPanel");
+    assert_eq!(from_reader.root.child_elements().count(), 1);
+    assert_eq!(from_reader, from_string);
+}
+
+#[test]
+fn test_parse_file_with_byte_order_marks() {
+    let xaml = r#"<StackPanel xmlns="http://test">
+    <TextBlock Text="Grüße"/>
+</StackPanel>"#;
+    
+    let dir = std::env::temp_dir();
+    let utf8_path = dir.join(format!("luma_xaml_bom_utf8_{}.xaml", std::process::id()));
+    let utf16_path = dir.join(format!("luma_xaml_bom_utf16_{}.xaml", std::process::id()));
+    
+    let mut utf8_bytes = vec![0xEF, 0xBB, 0xBF];
+    utf8_bytes.extend_from_slice(xaml.as_bytes());
+    std::fs::write(&utf8_path, utf8_bytes).unwrap();
+    
+    let mut utf16_bytes = vec![0xFF, 0xFE];
+    utf16_bytes.extend(xaml.encode_utf16().flat_map(u16::to_le_bytes));
+    std::fs::write(&utf16_path, utf16_bytes).unwrap();
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let from_utf8 = parser.parse_file(&utf8_path);
+    let from_utf16 = parser.parse_file(&utf16_path);
+    std::fs::remove_file(&utf8_path).ok();
+    std::fs::remove_file(&utf16_path).ok();
+    
+    let from_utf8 = from_utf8.expect("Failed to parse UTF-8 file with BOM");
+    let from_utf16 = from_utf16.expect("Failed to parse UTF-16LE file with BOM");
+    
+    assert_eq!(from_utf8.root.type_name.name, "StackPanel");
+    assert_eq!(from_utf16, from_utf8);
+    assert_eq!(from_utf16, parser.parse_string(xaml).unwrap());
+}
+
+/// Reader that hands out one byte per read, like a slow stream.
+struct OneByteReader<'a>(&'a [u8]);
+
+impl std::io::Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.0.len().min(buf.len()).min(1);
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+impl std::io::BufRead for OneByteReader<'_> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(&self.0[..self.0.len().min(1)])
+    }
+    
+    fn consume(&mut self, amt: usize) {
+        self.0 = &self.0[amt..];
+    }
+}
+
+#[test]
+fn test_byte_order_marks_in_one_byte_chunks() {
+    let xaml = r#"<StackPanel xmlns="http://test"><TextBlock Text="Grüße"/></StackPanel>"#;
+    let parser = XamlParser::new(TypeRegistry::new());
+    let expected = parser.parse_string(xaml).unwrap();
+    
+    let mut utf8_bytes = vec![0xEF, 0xBB, 0xBF];
+    utf8_bytes.extend_from_slice(xaml.as_bytes());
+    let mut utf16le_bytes = vec![0xFF, 0xFE];
+    utf16le_bytes.extend(xaml.encode_utf16().flat_map(u16::to_le_bytes));
+    let mut utf16be_bytes = vec![0xFE, 0xFF];
+    utf16be_bytes.extend(xaml.encode_utf16().flat_map(u16::to_be_bytes));
+    
+    for bytes in [xaml.as_bytes(), &utf8_bytes, &utf16le_bytes, &utf16be_bytes] {
+        let doc = parser.parse_reader(OneByteReader(bytes)).expect("Failed to parse chunked input");
+        assert_eq!(doc, expected);
+    }
+}
+
+#[test]
+fn test_builder_matches_parsed_tree() {
+    use luma_xaml::{XamlElement, XamlNode, XamlTypeName, XamlValue};
+    
+    let xaml = r#"<StackPanel xmlns="http://test" xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml" x:Name="Root"><Button Content="OK" Width="100"/><TextBlock x:Key="Label">Hello</TextBlock></StackPanel>"#;
+    let parsed = XamlParser::new(TypeRegistry::new()).parse_string(xaml).unwrap();
+    
+    let built = XamlElement::builder(XamlTypeName::new("http://test", "StackPanel"))
+        .namespace("", "http://test")
+        .namespace("x", "http://schemas.microsoft.com/winfx/2006/xaml")
+        .name("Root")
+        .line(1)
+        .child(XamlNode::Element(
+            XamlElement::builder(XamlTypeName::new("http://test", "Button"))
+                .attr("Content", XamlValue::String("OK".to_string()))
+                .attr("Width", XamlValue::Integer(100))
+                .line(1)
+                .build(),
+        ))
+        .child(XamlNode::Element(
+            XamlElement::builder(XamlTypeName::new("http://test", "TextBlock"))
+                .key("Label")
+                .child(XamlNode::Text("Hello".to_string()))
+                .line(1)
+                .build(),
+        ))
+        .build();
+    
+    assert_eq!(parsed.root, built);
+}
+
+#[test]
+fn test_deep_nesting_is_an_error() {
+    let depth = 10_000;
+    let xaml = format!(
+        "<StackPanel xmlns=\"http://test\">{}{}</StackPanel>",
+        "<StackPanel>".repeat(depth - 1),
+        "</StackPanel>".repeat(depth - 1),
+    );
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    match parser.parse_string(&xaml) {
+        Err(XamlError::Custom { message }) => assert_eq!(message, "maximum nesting depth exceeded"),
+        other => panic!("Expected a nesting depth error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_nesting_and_element_limits() {
+    let nested = r#"<A xmlns="http://test"><B><C/></B></A>"#;
+    let wide = r#"<A xmlns="http://test"><B/><B/><B/></A>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new())
+        .with_settings(ParserSettings::new().max_depth(3).max_elements(4));
+    assert!(parser.parse_string(nested).is_ok());
+    assert!(parser.parse_string(wide).is_ok());
+    
+    let parser = XamlParser::new(TypeRegistry::new())
+        .with_settings(ParserSettings::new().max_depth(2));
+    assert!(parser.parse_string(nested).is_err());
+    
+    let parser = XamlParser::new(TypeRegistry::new())
+        .with_settings(ParserSettings::new().max_elements(3));
+    match parser.parse_string(wide) {
+        Err(XamlError::Custom { message }) => assert_eq!(message, "maximum element count exceeded"),
+        other => panic!("Expected an element count error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_mismatched_tag_reports_enclosing_elements() {
+    let xaml = r#"<Window xmlns="http://test">
+    <Grid>
+        <StackPanel>
+            <Button>
+        </StackPanel>
+    </Grid>
+</Window>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let message = parser.parse_string(xaml).unwrap_err().to_string();
+    
+    assert!(message.contains("expected Button, got StackPanel"), "{}", message);
+    assert!(message.contains("while inside Window > Grid > StackPanel > Button"), "{}", message);
+}
+
+#[test]
+fn test_unexpected_eof_reports_enclosing_elements() {
+    let xaml = r#"<Window xmlns="http://test"><Grid><Grid.Resources>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let message = parser.parse_string(xaml).unwrap_err().to_string();
+    
+    assert!(message.contains("while inside Window > Grid > Grid.Resources"), "{}", message);
+}
+
+#[test]
+fn test_document_resolves_root_prefixes() {
+    let xaml = r#"<Window xmlns="http://test" xmlns:local="urn:local">
+    <Grid xmlns:inner="urn:inner"/>
+</Window>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let doc = parser.parse_string(xaml).unwrap();
+    
+    assert_eq!(doc.resolve_prefix("local"), Some("urn:local"));
+    assert_eq!(doc.resolve_prefix(""), Some("http://test"));
+    assert_eq!(doc.resolve_prefix("inner"), None);
+    
+    let namespaces = doc.namespaces();
+    assert_eq!(namespaces.resolve("local"), Some("urn:local"));
+    let type_name = XamlTypeName::from_qualified("local:Gauge", &namespaces).unwrap();
+    assert_eq!(type_name.namespace, "urn:local");
+    
+    // Entering a child adds its declarations to the root scope
+    let grid = doc.root.child_elements().next().unwrap();
+    let inner = namespaces.enter(grid);
+    assert_eq!(inner.resolve("inner"), Some("urn:inner"));
+    assert_eq!(inner.resolve("local"), Some("urn:local"));
+}
+
+#[test]
+fn test_index_walks_up_to_ancestor() {
+    let xaml = r#"<Window xmlns="http://test">
+    <Grid>
+        <StackPanel>
+            <Border>
+                <TextBlock Text="Deep"/>
+            </Border>
+        </StackPanel>
+    </Grid>
+</Window>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let doc = parser.parse_string(xaml).unwrap();
+    let index = doc.build_index();
+    assert_eq!(index.len(), 5);
+    
+    let text_block = index
+        .paths()
+        .find(|path| doc.element_at(path).unwrap().type_name.name == "TextBlock")
+        .unwrap();
+    assert_eq!(text_block, &ElementPath::new(vec![0, 0, 0, 0]));
+    assert_eq!(index.parent_of(text_block), Some(&ElementPath::new(vec![0, 0, 0])));
+    
+    let grid = index
+        .ancestors(text_block)
+        .find(|path| doc.element_at(path).unwrap().type_name.name == "Grid")
+        .unwrap();
+    assert_eq!(grid, &ElementPath::new(vec![0]));
+    assert_eq!(index.ancestors(text_block).count(), 4);
+    assert_eq!(index.ancestors(text_block).last(), Some(&ElementPath::root()));
+    assert_eq!(index.parent_of(&ElementPath::root()), None);
+}
+
+#[test]
+fn test_diff_reports_changed_attribute_and_added_child() {
+    let old = r#"<Window xmlns="http://test" Title="Old">
+    <StackPanel>
+        <Button Content="OK"/>
+    </StackPanel>
+</Window>"#;
+    let new = r#"<Window xmlns="http://test" Title="New">
+    <StackPanel>
+        <Button Content="OK"/>
+        <Button Content="Cancel"/>
+    </StackPanel>
+</Window>"#;
+    
+    let parser = XamlParser::new(TypeRegistry::new());
+    let old = parser.parse_string(old).unwrap();
+    let new = parser.parse_string(new).unwrap();
+    let changes = diff(&old, &new);
+    
+    assert_eq!(changes.len(), 2, "{:?}", changes);
+    match &changes[0] {
+        XamlChange::AttributeChanged { path, name, old, new } => {
+            assert!(path.is_root());
+            assert_eq!(name, "Title");
+            assert_eq!(old.as_ref().and_then(|v| v.as_string()), Some("Old"));
+            assert_eq!(new.as_string(), Some("New"));
+        }
+        other => panic!("Expected an attribute change, got {:?}", other),
+    }
+    match &changes[1] {
+        XamlChange::ElementAdded { path, element } => {
+            assert_eq!(path, &ElementPath::new(vec![0, 1]));
+            assert_eq!(element.get_attribute("Content").and_then(|v| v.as_string()), Some("Cancel"));
+        }
+        other => panic!("Expected an added element, got {:?}", other),
+    }
+    
+    assert!(diff(&old, &old).is_empty());
+}
+
+#[test]
+fn test_interned_parse_matches_plain_parse() {
+    let mut xaml = String::from(r#"<StackPanel xmlns="http://test" xmlns:local="using:App">"#);
+    for i in 0..1000 {
+        xaml.push_str(&format!(r#"<Button Content="{}"/><local:Gauge Value="{}"/>"#, i, i));
+    }
+    xaml.push_str("</StackPanel>");
+    
+    let plain = XamlParser::new(TypeRegistry::new()).parse_string(&xaml).unwrap();
+    let interned = XamlParser::new(TypeRegistry::new())
+        .with_settings(ParserSettings::new().intern_strings())
+        .parse_string(&xaml)
+        .unwrap();
+    assert_eq!(interned, plain);
+    
+    let children: Vec<_> = interned.root.child_elements().collect();
+    assert!(luma_xaml::types::SharedStr::ptr_eq(&children[0].type_name.name, &children[2].type_name.name));
+    assert!(luma_xaml::types::SharedStr::ptr_eq(&children[0].type_name.namespace, &interned.root.type_name.namespace));
+    let children: Vec<_> = plain.root.child_elements().collect();
+    assert!(!luma_xaml::types::SharedStr::ptr_eq(&children[0].type_name.name, &children[2].type_name.name));
+}
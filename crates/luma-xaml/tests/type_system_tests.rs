@@ -252,6 +252,91 @@ fn test_parse_window() {
     );
 }
 
+#[cfg(feature = "winui3")]
+#[test]
+fn test_parse_event_handlers() {
+    let xaml = r#"
+        <StackPanel xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" Loaded="OnLoaded">
+            <Button Click="OnClick" Content="OK"/>
+            <CheckBox Checked="OnChecked" Unchecked="OnUnchecked"/>
+        </StackPanel>
+    "#;
+    
+    let registry = create_type_registry();
+    let parser = XamlParser::new(registry);
+    
+    let doc = parser.parse_string(xaml).expect("Should parse event handlers");
+    
+    // Inherited from FrameworkElement
+    assert_eq!(doc.root.get_event_handler("Loaded"), Some("OnLoaded"));
+    
+    let children: Vec<_> = doc.root.child_elements().collect();
+    assert_eq!(children[0].get_event_handler("Click"), Some("OnClick"));
+    assert!(children[0].get_attribute("Click").is_none());
+    assert_eq!(
+        children[0].get_attribute("Content").and_then(|v| v.as_string()),
+        Some("OK")
+    );
+    assert_eq!(children[1].get_event_handler("Checked"), Some("OnChecked"));
+    assert_eq!(children[1].get_event_handler("Unchecked"), Some("OnUnchecked"));
+    assert!(children[1].attributes.is_empty());
+}
+
+#[cfg(feature = "winui3")]
+#[test]
+fn test_validate_reports_all_errors() {
+    use luma_xaml::parser::ParserSettings;
+    use luma_xaml::XamlError;
+    
+    let xaml = r#"<StackPanel xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation">
+    <Buton Content="Typo"/>
+    <Button Frobnicate="1" Content="OK"/>
+    <Slider Value="lots"/>
+    <TextBlock Grid.Row="1" Text="Fine"/>
+</StackPanel>"#;
+    
+    let registry = create_type_registry();
+    let parser = XamlParser::new(create_type_registry())
+        .with_settings(ParserSettings::new().lenient());
+    
+    // Strict parsing still stops at the first bad value
+    assert!(XamlParser::new(create_type_registry()).parse_string(xaml).is_err());
+    
+    let doc = parser.parse_string(xaml).expect("Lenient parsing should succeed");
+    let errors = doc.validate(&registry);
+    
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+    assert!(matches!(
+        &errors[0],
+        XamlError::UnknownType { type_name, line: 2 } if type_name.ends_with("Buton")
+    ));
+    assert!(matches!(
+        &errors[1],
+        XamlError::UnknownProperty { property, line: 3, .. } if property == "Frobnicate"
+    ));
+    assert!(matches!(
+        &errors[2],
+        XamlError::InvalidAttributeValue { attribute, line: 4, .. } if attribute == "Value"
+    ));
+}
+
+#[cfg(feature = "winui3")]
+#[test]
+fn test_validate_accepts_valid_document() {
+    let xaml = r#"
+        <Grid xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation"
+              xmlns:x="http://schemas.microsoft.com/winfx/2006/xaml">
+            <Button x:Name="Ok" Grid.Row="1" Width="100" Click="OnClick" Content="OK"/>
+        </Grid>
+    "#;
+    
+    let registry = create_type_registry();
+    let parser = XamlParser::new(create_type_registry());
+    
+    let doc = parser.parse_string(xaml).expect("Should parse Grid");
+    assert!(doc.validate(&registry).is_empty());
+}
+
 #[cfg(feature = "winui3")]
 #[test]
 fn test_type_inheritance() {
@@ -300,6 +385,35 @@ fn test_abstract_types() {
     assert!(!button.is_abstract());
 }
 
+#[cfg(feature = "winui3")]
+#[test]
+fn test_unknown_element_is_synthetic_in_lenient_mode() {
+    use luma_xaml::parser::ParserSettings;
+    use luma_xaml::flags::ElementFlags;
+    use luma_xaml::XamlType;
+    
+    let xaml = r#"<StackPanel xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation">
+    <Gadget/>
+    <Button/>
+</StackPanel>"#;
+    
+    let registry = create_type_registry();
+    let parser = XamlParser::new(create_type_registry())
+        .with_settings(ParserSettings::new().lenient());
+    let doc = parser.parse_string(xaml).expect("Lenient parsing should succeed");
+    
+    let children: Vec<_> = doc.root.child_elements().collect();
+    assert!(children[0].has_flag(ElementFlags::SYNTHETIC_TYPE));
+    assert!(!children[1].has_flag(ElementFlags::SYNTHETIC_TYPE));
+    assert!(!doc.root.has_flag(ElementFlags::SYNTHETIC_TYPE));
+    
+    assert!(registry.lookup_type(&children[0].type_name).is_none());
+    let gadget = registry.placeholder_for(&children[0].type_name);
+    assert!(gadget.is_synthetic());
+    assert_eq!(gadget.name(), &children[0].type_name);
+    assert!(!registry.lookup_type(&children[1].type_name).unwrap().is_synthetic());
+}
+
 #[cfg(not(feature = "winui3"))]
 #[test]
 fn test_placeholder() {
@@ -12,6 +12,7 @@ fn test_parse_xaml_with_thickness_uniform() {
     let parser = XamlParser::new(registry).with_settings(ParserSettings::default());
     let doc = parser.parse_string(xaml).unwrap();
     
+    // Margin is a Thickness, so a bare number is not guessed to be an integer
     let margin = doc.root.attributes.get("Margin").unwrap();
     match margin {
         XamlValue::String(val) => {
@@ -21,12 +22,7 @@ fn test_parse_xaml_with_thickness_uniform() {
             assert_eq!(thickness.right, 10.0);
             assert_eq!(thickness.bottom, 10.0);
         }
-        XamlValue::Integer(i) => {
-            // Parser may parse simple numbers as integers
-            let thickness = parse_thickness(&i.to_string()).unwrap();
-            assert_eq!(thickness.left, 10.0);
-        }
-        _ => panic!("Expected string or integer value for Margin"),
+        _ => panic!("Expected string value for Margin"),
     }
 }
 
@@ -121,11 +117,7 @@ fn test_parse_xaml_with_corner_radius_uniform() {
             assert_eq!(cr.bottom_right, 5.0);
             assert_eq!(cr.bottom_left, 5.0);
         }
-        XamlValue::Integer(i) => {
-            let cr = parse_corner_radius(&i.to_string()).unwrap();
-            assert_eq!(cr.top_left, 5.0);
-        }
-        _ => panic!("Expected string or integer value for CornerRadius"),
+        _ => panic!("Expected string value for CornerRadius"),
     }
 }
 
@@ -211,11 +203,7 @@ fn test_parse_xaml_with_grid_length_absolute() {
             let grid_length = parse_grid_length(val).unwrap();
             assert_eq!(grid_length, GridLength::Absolute(100.0));
         }
-        XamlValue::Integer(i) => {
-            let grid_length = parse_grid_length(&i.to_string()).unwrap();
-            assert_eq!(grid_length, GridLength::Absolute(100.0));
-        }
-        _ => panic!("Expected string or integer value for Width"),
+        _ => panic!("Expected string value for Width"),
     }
 }
 
@@ -249,3 +237,111 @@ fn test_parse_complex_xaml_with_multiple_converters() {
         assert_eq!(thickness.top, 5.0);
     }
 }
+
+#[test]
+fn test_custom_percentage_converter() {
+    use luma_xaml::{ConverterRegistry, ParserFlags, TypeRegistry, XamlError, XamlProperty, XamlTypeName};
+    use luma_xaml::types::BasicXamlType;
+    
+    const NS: &str = "http://example.com/gauges";
+    let percentage = XamlTypeName::new(NS, "Percentage");
+    
+    let mut registry = TypeRegistry::new();
+    registry.register_type(Box::new(
+        BasicXamlType::new(XamlTypeName::new(NS, "Gauge"))
+            .with_property(XamlProperty::new("Level", percentage.clone()))
+    ));
+    
+    let mut converters = ConverterRegistry::new();
+    converters.register(percentage, |value| {
+        let number = value.trim().strip_suffix('%').unwrap_or(value.trim());
+        number.parse::<f64>()
+            .map(|n| XamlValue::Float(n / 100.0))
+            .map_err(|_| XamlError::InvalidAttributeValue {
+                attribute: "Percentage".to_string(),
+                line: 0,
+                details: format!("Invalid percentage: {}", value),
+            })
+    });
+    let parser = XamlParser::new(registry).with_converters(converters);
+    
+    // Undeclared properties are still guessed
+    let doc = parser.parse_string(r#"<Gauge xmlns="http://example.com/gauges" Level="75%" Label="50%" />"#).unwrap();
+    assert_eq!(doc.root.attributes.get("Level"), Some(&XamlValue::Float(0.75)));
+    assert_eq!(doc.root.attributes.get("Label"), Some(&XamlValue::String("50%".to_string())));
+    
+    // Strict mode reports the attribute and line of a value the converter rejects
+    let err = parser.parse_string("<Gauge xmlns=\"http://example.com/gauges\"\n  Level=\"lots\" />").unwrap_err();
+    match err {
+        XamlError::InvalidAttributeValue { attribute, line, .. } => {
+            assert_eq!(attribute, "Level");
+            assert_eq!(line, 2);
+        }
+        other => panic!("Expected InvalidAttributeValue, got {:?}", other),
+    }
+    
+    // Without VALIDATE_TYPES the converter never runs, so nothing is rejected
+    let unchecked = parser.with_settings(ParserSettings::with_flags(ParserFlags::DEFAULT - ParserFlags::VALIDATE_TYPES));
+    let doc = unchecked.parse_string(r#"<Gauge xmlns="http://example.com/gauges" Level="lots" />"#).unwrap();
+    assert_eq!(doc.root.attributes.get("Level"), Some(&XamlValue::String("lots".to_string())));
+}
+
+#[test]
+fn test_declared_type_overrides_guessing() {
+    // FrameworkElement declares Width as a Double and Margin as a Thickness
+    let xaml = r#"<Button xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" Width="100" Margin="10" />"#;
+    
+    let parser = XamlParser::new(winui3::create_type_registry());
+    let doc = parser.parse_string(xaml).unwrap();
+    assert_eq!(doc.root.attributes.get("Width"), Some(&XamlValue::Float(100.0)));
+    assert_eq!(doc.root.attributes.get("Margin"), Some(&XamlValue::String("10".to_string())));
+    
+    let guessing = XamlParser::new(winui3::create_type_registry())
+        .with_converters(luma_xaml::ConverterRegistry::empty());
+    let doc = guessing.parse_string(xaml).unwrap();
+    assert_eq!(doc.root.attributes.get("Width"), Some(&XamlValue::Integer(100)));
+}
+
+#[test]
+fn test_rectangle_width_is_always_float() {
+    let parser = XamlParser::new(winui3::create_type_registry());
+    
+    for (width, expected) in [("100", 100.0), ("12.5", 12.5), (" 0 ", 0.0), ("-3", -3.0)] {
+        let xaml = format!(
+            r#"<Rectangle xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" Width="{}" Height="{}" />"#,
+            width, width
+        );
+        let doc = parser.parse_string(&xaml).unwrap();
+        assert_eq!(doc.root.attributes.get("Width"), Some(&XamlValue::Float(expected)), "Width={:?}", width);
+        assert_eq!(doc.root.attributes.get("Height"), Some(&XamlValue::Float(expected)), "Height={:?}", width);
+    }
+}
+
+#[test]
+fn test_rectangle_width_rejects_non_numbers() {
+    let xaml = r#"<Rectangle xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" Width="wide" />"#;
+    
+    let strict = XamlParser::new(winui3::create_type_registry());
+    assert!(matches!(
+        strict.parse_string(xaml),
+        Err(luma_xaml::XamlError::InvalidAttributeValue { ref attribute, .. }) if attribute == "Width"
+    ));
+    
+    // Lenient parsing keeps the value, guessed as usual
+    let lenient = XamlParser::new(winui3::create_type_registry())
+        .with_settings(ParserSettings::default().lenient());
+    let doc = lenient.parse_string(xaml).unwrap();
+    assert_eq!(doc.root.attributes.get("Width"), Some(&XamlValue::String("wide".to_string())));
+}
+
+#[test]
+fn test_values_are_guessed_without_validate_types() {
+    use luma_xaml::ParserFlags;
+    
+    let xaml = r#"<Rectangle xmlns="http://schemas.microsoft.com/winfx/2006/xaml/presentation" Width="100" />"#;
+    let settings = ParserSettings::with_flags(ParserFlags::DEFAULT - ParserFlags::VALIDATE_TYPES);
+    let parser = XamlParser::new(winui3::create_type_registry()).with_settings(settings);
+    
+    let doc = parser.parse_string(xaml).unwrap();
+    assert_eq!(doc.root.attributes.get("Width"), Some(&XamlValue::Integer(100)));
+}
@@ -0,0 +1,70 @@
+// Accelerators Demo - Ctrl+S saves, Alt+letter activates mnemonic controls
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const CMD_SAVE: u16 = 1;
+const CMD_CLEAR: u16 = 2;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Accelerators Demo - Luma")
+        .size(420, 220)
+        .build()?;
+    
+    let _name_label = Label::builder()
+        .text("&Name:")
+        .position(20, 20)
+        .size(60, 20)
+        .build(&window)?;
+    
+    let input = Rc::new(RefCell::new(
+        TextInput::builder()
+            .position(90, 18)
+            .size(290, 24)
+            .build(&window)?
+    ));
+    
+    let status = Rc::new(RefCell::new(
+        Label::builder()
+            .text("Press Ctrl+S to save, Alt+N to focus the name field")
+            .position(20, 130)
+            .size(360, 20)
+            .build(&window)?
+    ));
+    
+    let save = {
+        let input = Rc::clone(&input);
+        let status = Rc::clone(&status);
+        move || {
+            let text = input.borrow().get_text().unwrap_or_default();
+            let message = format!("Saved \"{}\"", text);
+            if let Err(e) = status.borrow_mut().set_text(&message) {
+                eprintln!("Failed to update status: {}", e);
+            }
+        }
+    };
+    
+    // The button and Ctrl+S run the same action
+    let _save_button = Button::builder()
+        .label("&Save")
+        .position(20, 70)
+        .size(100, 30)
+        .on_click(save.clone())
+        .build(&window)?;
+    
+    app.register_accelerator(Accelerator::ctrl(Key::Char('S'), CMD_SAVE), save)?;
+    
+    let input_for_clear = Rc::clone(&input);
+    app.register_accelerator(
+        Accelerator::new(Key::Char('L'), Modifiers::CONTROL | Modifiers::SHIFT, CMD_CLEAR),
+        move || {
+            let _ = input_for_clear.borrow_mut().set_text("");
+        },
+    )?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,29 @@
+// Always-on-top Example
+// A small tool window that stays pinned above other windows
+
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    // Tool windows have a small caption and no taskbar button
+    let mut window = Window::builder()
+        .title("Pinned")
+        .size(220, 120)
+        .flags(WindowFlags::tool_window())
+        .always_on_top(true)
+        .build()?;
+    
+    let _label = Label::builder()
+        .text("I stay on top of other windows")
+        .position(10, 10)
+        .size(200, 20)
+        .build(&window)?;
+    
+    window.show()?;
+    
+    // The topmost state can also be toggled at runtime
+    window.set_always_on_top(true)?;
+    
+    app.run()
+}
@@ -0,0 +1,46 @@
+// Backdrop Example
+// Switch between Mica, Mica Alt, Acrylic and no backdrop at runtime
+// (requires Windows 11 22H2 or later)
+
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Backdrop Demo")
+            .size(420, 200)
+            .build()?,
+    ));
+    
+    let choices = [
+        ("None", Backdrop::None),
+        ("Mica", Backdrop::Mica),
+        ("Mica Alt", Backdrop::MicaAlt),
+        ("Acrylic", Backdrop::Acrylic),
+    ];
+    
+    // One button per backdrop; there is no combo box widget yet
+    let mut buttons = Vec::new();
+    for (i, (name, backdrop)) in choices.into_iter().enumerate() {
+        let target = Rc::clone(&window);
+        let button = Button::builder()
+            .label(name)
+            .position(20 + i as i32 * 95, 20)
+            .size(85, 30)
+            .on_click(move || {
+                if let Err(e) = target.borrow_mut().set_backdrop(backdrop) {
+                    eprintln!("Could not apply {} backdrop: {}", name, e);
+                }
+            })
+            .build(&*window.borrow())?;
+        buttons.push(button);
+    }
+    
+    window.borrow_mut().set_backdrop(Backdrop::Mica).ok();
+    window.borrow_mut().show()?;
+    app.run()
+}
@@ -0,0 +1,37 @@
+// Background Color Example
+// A window and a panel painted with their own colors instead of the system
+// window color; labels take the background of the window or panel they are in
+
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Background Color")
+        .size(400, 250)
+        .build()?;
+    window.set_background(Color::rgb(230, 240, 255))?;
+    
+    let _label = Label::builder()
+        .text("On the window's background")
+        .position(20, 20)
+        .size(360, 20)
+        .build(&window)?;
+    
+    let mut panel = Panel::builder()
+        .position(20, 60)
+        .size(360, 120)
+        .build(&window)?;
+    panel.set_background(Color::rgb(255, 244, 214))?;
+    
+    let panel_label = Label::builder()
+        .text("On the panel's background")
+        .position(10, 10)
+        .size(300, 20)
+        .build(&panel)?;
+    panel.add(panel_label);
+    
+    window.show()?;
+    app.run()
+}
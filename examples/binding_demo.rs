@@ -0,0 +1,80 @@
+// Binding Demo - a text input and a label bound to the same observable
+//
+// Typing in the input updates the shared value (TwoWay), which the label
+// follows (OneWay). Run with `--features binding`.
+use luma_gui::prelude::*;
+use luma_gui::binding::{bind, BindingExtension, DataContext};
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let name = Observable::new(String::from("World"));
+    let mut window = build_window(&name)?;
+    window.show()?;
+    
+    app.run()
+}
+
+/// Create a `{Binding}` to a value in the data context
+fn binding(path: &str, mode: Option<&str>) -> BindingExtension {
+    BindingExtension {
+        path: path.to_string(),
+        mode: mode.map(str::to_string),
+        source: None,
+    }
+}
+
+/// Create the window, binding its input and label to `name`
+fn build_window(name: &Observable<String>) -> Result<Window> {
+    let mut window = Window::builder()
+        .title("Binding Demo")
+        .size(360, 140)
+        .build()?;
+    
+    let mut context = DataContext::new();
+    context.insert("Name", name.clone());
+    
+    let mut layout = BoxLayout::vertical().with_gap(5);
+    
+    let mut input = TextInput::builder().build(&window)?;
+    bind(&mut input, "Text", &binding("Name", Some("TwoWay")), &context)?;
+    layout.add(
+        Box::new(input),
+        LayoutConstraints::default()
+            .preferred_height(24)
+            .padding(Padding::new(10, 10, 0, 10))
+            .expand_horizontal(true)
+    );
+    
+    let mut label = Label::builder().build(&window)?;
+    bind(&mut label, "Text", &binding("Name", None), &context)?;
+    layout.add(
+        Box::new(label),
+        LayoutConstraints::default()
+            .preferred_height(20)
+            .padding(Padding::symmetric(0, 10))
+            .expand_horizontal(true)
+    );
+    
+    window.set_layout(layout)?;
+    
+    Ok(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_label_follows_input() {
+        let name = Observable::new(String::from("World"));
+        let window = build_window(&name).unwrap();
+        assert_eq!(window.widget_tree().unwrap().texts(&WidgetKind::Label), ["World"]);
+        
+        // Setting the value in code updates both widgets
+        name.set("Luma".into());
+        let tree = window.widget_tree().unwrap();
+        assert_eq!(tree.texts(&WidgetKind::TextInput), ["Luma"]);
+        assert_eq!(tree.texts(&WidgetKind::Label), ["Luma"]);
+    }
+}
@@ -0,0 +1,36 @@
+// Borderless Window Example
+// A window without a system title bar that can be dragged by its top bar
+
+use luma_gui::prelude::*;
+
+const TITLE_BAR_HEIGHT: u32 = 32;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Borderless")
+        .size(400, 300)
+        .flags(WindowFlags::borderless() | WindowFlags::RESIZABLE)
+        .build()?;
+    
+    // Custom title bar: a caption label and a close button
+    let _caption = Label::builder()
+        .text("Drag me by this bar")
+        .position(10, 8)
+        .size(250, 20)
+        .build(&window)?;
+    
+    let _close = Button::builder()
+        .label("X")
+        .position(360, 2)
+        .size(36, 28)
+        .on_click(|| std::process::exit(0))
+        .build(&window)?;
+    
+    // Everything in the top bar (except the child controls) drags the window
+    window.set_drag_region(Rect::new(0, 0, 400, TITLE_BAR_HEIGHT));
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,49 @@
+// Click Counter Example
+//
+// A label updated after it is created: each click of the button rewrites the
+// counter label's text.
+use luma_gui::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Click Counter - Luma")
+        .size(300, 140)
+        .build()?;
+    
+    let counter = Rc::new(RefCell::new(
+        Label::builder()
+            .text(counter_text(0))
+            .position(20, 20)
+            .size(240, 20)
+            .build(&window)?
+    ));
+    
+    let clicks = Cell::new(0);
+    let for_click = Rc::clone(&counter);
+    let _button = Button::builder()
+        .label("&Click Me")
+        .position(20, 55)
+        .size(100, 28)
+        .on_click(move || {
+            clicks.set(clicks.get() + 1);
+            if let Err(e) = for_click.borrow_mut().set_text(&counter_text(clicks.get())) {
+                eprintln!("Failed to update the counter: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Describe the number of clicks
+fn counter_text(clicks: u32) -> String {
+    match clicks {
+        1 => "Clicked 1 time".to_string(),
+        n => format!("Clicked {} times", n),
+    }
+}
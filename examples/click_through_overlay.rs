@@ -0,0 +1,30 @@
+// Click-through Overlay Example
+//
+// A translucent label pinned above other windows that lets every click pass
+// through to whatever is underneath, like a heads-up display.
+
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Overlay")
+        .size(260, 60)
+        .flags(WindowFlags::tool_window())
+        .always_on_top(true)
+        .build()?;
+    
+    let _label = Label::builder()
+        .text("Clicks pass through this overlay")
+        .position(10, 10)
+        .size(240, 20)
+        .build(&window)?;
+    
+    // Click-through keeps the window opaque, so dim it explicitly
+    window.set_opacity(0.75)?;
+    window.set_click_through(true)?;
+    window.show()?;
+    
+    app.run()
+}
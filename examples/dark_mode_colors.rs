@@ -0,0 +1,60 @@
+// Dark Mode Colors Example
+// A dark window whose labels, text input and checkbox stay readable, using
+// per-widget text and background colors
+
+use luma_gui::prelude::*;
+
+/// Window background
+const BACKGROUND: Color = Color { r: 32, g: 32, b: 32, a: 255 };
+
+/// Background of the text input, a little lighter than the window
+const INPUT_BACKGROUND: Color = Color { r: 45, g: 45, b: 45, a: 255 };
+
+/// Text on the dark background
+const TEXT: Color = Color { r: 240, g: 240, b: 240, a: 255 };
+
+/// Secondary text
+const DIM_TEXT: Color = Color { r: 160, g: 160, b: 160, a: 255 };
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Dark Mode Colors")
+        .size(400, 220)
+        .build()?;
+    window.set_dark_mode(true);
+    window.set_background(BACKGROUND)?;
+    
+    let _title = Label::builder()
+        .text("Readable on a dark background")
+        .position(20, 20)
+        .size(360, 20)
+        .text_color(TEXT)
+        .build(&window)?;
+    
+    let _hint = Label::builder()
+        .text("Labels are drawn over the window's color")
+        .position(20, 45)
+        .size(360, 20)
+        .text_color(DIM_TEXT)
+        .build(&window)?;
+    
+    let _name = TextInput::builder()
+        .text("Type here")
+        .position(20, 80)
+        .size(360, 24)
+        .text_color(TEXT)
+        .background(INPUT_BACKGROUND)
+        .build(&window)?;
+    
+    let _remember = CheckBox::builder()
+        .label("Remember me")
+        .position(20, 120)
+        .size(200, 20)
+        .text_color(TEXT)
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,76 @@
+// Dropdown Popup - opens a list in a popup window at a text field's bottom-left corner
+use luma_gui::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+const FRUITS: [&str; 5] = ["Apple", "Banana", "Cherry", "Grape", "Orange"];
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Dropdown Popup - Luma")
+        .size(360, 200)
+        .build()?;
+    
+    let input = Rc::new(RefCell::new(
+        TextInput::builder()
+            .position(20, 20)
+            .size(200, 24)
+            .read_only(true)
+            .build(&window)?
+    ));
+    
+    let popup = Rc::new(RefCell::new(
+        Window::builder()
+            .flags(WindowFlags::borderless() | WindowFlags::TOOL_WINDOW | WindowFlags::ALWAYS_ON_TOP)
+            .size(224, 110)
+            .build()?
+    ));
+    let popup_open = Rc::new(Cell::new(false));
+    
+    let input_for_select = Rc::clone(&input);
+    let popup_for_select = Rc::clone(&popup);
+    let open_for_select = Rc::clone(&popup_open);
+    let _choices = ListBox::builder()
+        .items(FRUITS)
+        .position(0, 0)
+        .size(224, 110)
+        .on_select_single(move |index| {
+            if let Some(fruit) = index.and_then(|i| FRUITS.get(i)) {
+                let _ = input_for_select.borrow_mut().set_text(fruit);
+            }
+            let _ = popup_for_select.borrow_mut().hide();
+            open_for_select.set(false);
+        })
+        .build(&*popup.borrow())?;
+    
+    let _toggle = Button::builder()
+        .label("v")
+        .position(222, 20)
+        .size(24, 24)
+        .on_click(move || {
+            if popup_open.get() {
+                let _ = popup.borrow_mut().hide();
+                popup_open.set(false);
+                return;
+            }
+            
+            // Screen coordinates, so the popup lands under the field wherever the window is
+            let anchor = match input.borrow().get_screen_bounds() {
+                Ok(bounds) => bounds.bottom_left(),
+                Err(e) => {
+                    eprintln!("Could not locate the text field: {}", e);
+                    return;
+                }
+            };
+            let mut popup = popup.borrow_mut();
+            if popup.set_position(anchor.x, anchor.y).and_then(|_| popup.show()).is_ok() {
+                popup_open.set(true);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,115 @@
+// Dynamic Rows Example
+//
+// Widgets added to and removed from a window while it is showing. "Add Row"
+// creates a label and appends it to the window's layout; "Remove Row" takes
+// the newest one away again.
+use luma_gui::prelude::*;
+use luma_gui::WidgetId;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The window and the IDs of the rows added to it, newest last
+struct Rows {
+    window: RefCell<Window>,
+    ids: RefCell<Vec<WidgetId>>,
+}
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let rows = build_window()?;
+    rows.window.borrow_mut().show()?;
+    
+    app.run()
+}
+
+/// Create the window with the Add Row and Remove Row buttons
+fn build_window() -> Result<Rc<Rows>> {
+    let window = Window::builder()
+        .title("Dynamic Rows - Luma")
+        .size(320, 400)
+        .build()?;
+    let rows = Rc::new(Rows {
+        window: RefCell::new(window),
+        ids: RefCell::new(Vec::new()),
+    });
+    
+    // The buttons live in the layout too, so their callbacks only hold a weak
+    // reference to avoid a cycle through the window
+    let for_add = Rc::downgrade(&rows);
+    let add = Button::builder()
+        .label("&Add Row")
+        .on_click(move || {
+            if let Some(rows) = for_add.upgrade() {
+                if let Err(e) = add_row(&rows) {
+                    eprintln!("Could not add a row: {}", e);
+                }
+            }
+        })
+        .build(&*rows.window.borrow())?;
+    let for_remove = Rc::downgrade(&rows);
+    let remove = Button::builder()
+        .label("&Remove Row")
+        .on_click(move || {
+            if let Some(rows) = for_remove.upgrade() {
+                if let Err(e) = remove_row(&rows) {
+                    eprintln!("Could not remove a row: {}", e);
+                }
+            }
+        })
+        .build(&*rows.window.borrow())?;
+    
+    let mut window = rows.window.borrow_mut();
+    window.add_widget(add, row_constraints())?;
+    window.add_widget(remove, row_constraints())?;
+    drop(window);
+    
+    Ok(rows)
+}
+
+/// Append a numbered label below the existing rows
+fn add_row(rows: &Rows) -> Result<()> {
+    let number = rows.ids.borrow().len() + 1;
+    let label = Label::builder()
+        .text(format!("Row {}", number))
+        .build(&*rows.window.borrow())?;
+    rows.ids.borrow_mut().push(label.id());
+    rows.window.borrow_mut().add_widget(label, row_constraints())
+}
+
+/// Remove the newest row, if there is one
+fn remove_row(rows: &Rows) -> Result<()> {
+    let Some(id) = rows.ids.borrow_mut().pop() else {
+        return Ok(());
+    };
+    rows.window.borrow_mut().remove_widget(id)
+}
+
+/// Constraints shared by the buttons and rows
+fn row_constraints() -> LayoutConstraints {
+    LayoutConstraints::default()
+        .preferred_height(28)
+        .padding(Padding::new(5, 10, 5, 10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_rows_come_and_go() {
+        let rows = build_window().unwrap();
+        rows.window.borrow_mut().show().unwrap();
+        let labels = |rows: &Rows| rows.window.borrow().widget_tree().unwrap().find_all(&WidgetKind::Label).len();
+        
+        add_row(&rows).unwrap();
+        add_row(&rows).unwrap();
+        assert_eq!(labels(&rows), 2);
+        
+        remove_row(&rows).unwrap();
+        assert_eq!(labels(&rows), 1);
+        remove_row(&rows).unwrap();
+        remove_row(&rows).unwrap();
+        assert_eq!(labels(&rows), 0);
+    }
+}
@@ -0,0 +1,77 @@
+// Edit Commands Example
+//
+// Two text inputs sharing window-wide Edit commands. Luma has no menus yet, so
+// the commands are accelerators, as the Edit menu's shortcuts would be: each
+// finds the input with the keyboard focus and undoes, cuts, copies or pastes
+// there. Ctrl+Y redoes by undoing the undo.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const CMD_UNDO: u16 = 1;
+const CMD_REDO: u16 = 2;
+const CMD_CUT: u16 = 3;
+const CMD_COPY: u16 = 4;
+const CMD_PASTE: u16 = 5;
+
+type Inputs = Rc<Vec<RefCell<TextInput>>>;
+type EditAction = fn(&mut TextInput) -> Result<()>;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Edit Commands - Luma")
+        .size(420, 170)
+        .build()?;
+    
+    let inputs: Inputs = Rc::new(vec![
+        RefCell::new(
+            TextInput::builder()
+                .text("Select some text, then press Ctrl+X")
+                .position(20, 20)
+                .size(360, 24)
+                .build(&window)?
+        ),
+        RefCell::new(
+            TextInput::builder()
+                .text("and Ctrl+V to paste it here")
+                .position(20, 60)
+                .size(360, 24)
+                .build(&window)?
+        ),
+    ]);
+    
+    let _help = Label::builder()
+        .text("Ctrl+Z undo, Ctrl+Y redo, Ctrl+X cut, Ctrl+C copy, Ctrl+V paste")
+        .position(20, 100)
+        .size(380, 20)
+        .build(&window)?;
+    
+    let commands: [(char, u16, EditAction); 5] = [
+        ('Z', CMD_UNDO, TextInput::undo),
+        ('Y', CMD_REDO, TextInput::undo),
+        ('X', CMD_CUT, TextInput::cut),
+        ('C', CMD_COPY, |input| input.copy()),
+        ('V', CMD_PASTE, TextInput::paste),
+    ];
+    for (key, command_id, action) in commands {
+        let inputs = Rc::clone(&inputs);
+        app.register_accelerator(Accelerator::ctrl(Key::Char(key), command_id), move || {
+            with_focused(&inputs, action);
+        })?;
+    }
+    
+    window.show()?;
+    app.run()
+}
+
+/// Run an Edit command on the input with the keyboard focus, if any
+fn with_focused(inputs: &Inputs, action: EditAction) {
+    let Some(input) = inputs.iter().find(|input| input.borrow().has_focus()) else {
+        return;
+    };
+    if let Err(e) = action(&mut input.borrow_mut()) {
+        eprintln!("Edit command failed: {}", e);
+    }
+}
@@ -0,0 +1,44 @@
+// File Drop Demo - drag files from Explorer onto the window to list them
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("File Drop Demo - Luma")
+        .size(450, 350)
+        .accept_dropped_files(true)
+        .build()?;
+    
+    let _hint = Label::builder()
+        .text("Drop files anywhere on this window:")
+        .position(20, 15)
+        .size(400, 20)
+        .build(&window)?;
+    
+    let files = Rc::new(RefCell::new(
+        ListBox::builder()
+            .position(20, 40)
+            .size(400, 250)
+            .build(&window)?
+    ));
+    
+    let files_for_drop = Rc::clone(&files);
+    window.on_files_dropped(move |paths| {
+        let mut files = files_for_drop.borrow_mut();
+        for path in paths {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string());
+            if let Err(e) = files.add_item(&name) {
+                eprintln!("Failed to add {}: {}", name, e);
+            }
+        }
+    });
+    
+    window.show()?;
+    app.run()
+}
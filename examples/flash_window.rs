@@ -0,0 +1,58 @@
+// Flash Window Example
+//
+// "Start Task" runs a simulated three-second task; switch to another app while
+// it runs. When the task completes in the background the window flashes its
+// title bar and taskbar button until you come back. Luma can't yet hand
+// results from a worker thread back to the UI, so the task blocks the window
+// while it runs.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Flash Window - Luma")
+            .size(360, 140)
+            .build()?,
+    ));
+    
+    let status = Rc::new(RefCell::new(
+        Label::builder()
+            .text("Start the task, then switch to another app")
+            .position(20, 20)
+            .size(320, 20)
+            .build(&*window.borrow())?
+    ));
+    
+    let window_for_task = Rc::clone(&window);
+    let status_for_task = Rc::clone(&status);
+    let _start = Button::builder()
+        .label("&Start Task")
+        .position(20, 56)
+        .size(120, 28)
+        .on_click(move || {
+            if let Err(e) = run_task(&mut window_for_task.borrow_mut(), &mut status_for_task.borrow_mut()) {
+                eprintln!("Task failed: {}", e);
+            }
+        })
+        .build(&*window.borrow())?;
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
+
+/// Run the simulated task, flashing the window if it finishes in the background
+fn run_task(window: &mut Window, status: &mut Label) -> Result<()> {
+    thread::sleep(Duration::from_secs(3));
+    status.set_text("Task complete")?;
+    
+    if !window.is_active() {
+        window.flash_until_focus()?;
+    }
+    Ok(())
+}
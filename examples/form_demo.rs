@@ -4,9 +4,17 @@ use luma_gui::prelude::*;
 fn main() -> Result<()> {
     let mut app = Application::new()?;
     
+    let mut window = build_form()?;
+    window.show()?;
+    
+    app.run()
+}
+
+/// Create the form window with all its fields laid out
+fn build_form() -> Result<Window> {
     let mut window = Window::builder()
         .title("Contact Form - BoxLayout Demo")
-        .size(420, 650)  // Increased from 400x500 to fit all content
+        .size(420, 720)  // Increased from 400x500 to fit all content
         .build()?;
     
     // Create a vertical layout with 5px gap between widgets
@@ -75,6 +83,30 @@ fn main() -> Result<()> {
             .expand_horizontal(true)
     );
     
+    // Birthdate field
+    let label_birthdate = Label::builder()
+        .text("Birthdate:")
+        .build(&window)?;
+    layout.add(
+        Box::new(label_birthdate),
+        LayoutConstraints::default()
+            .preferred_height(20)
+            .padding(Padding::symmetric(0, 10))
+    );
+    
+    let picker_birthdate = DatePicker::builder()
+        .date(Date::new(1990, 1, 1).expect("valid date"))
+        .format("dd MMMM yyyy")
+        .on_change(|date| println!("Birthdate set to {}", date))
+        .build(&window)?;
+    layout.add(
+        Box::new(picker_birthdate),
+        LayoutConstraints::default()
+            .preferred_height(24)
+            .preferred_width(200)
+            .padding(Padding::new(5, 10, 5, 10))
+    );
+    
     // Newsletter checkbox
     let checkbox_newsletter = CheckBox::builder()
         .label("Subscribe to newsletter")
@@ -170,7 +202,35 @@ fn main() -> Result<()> {
     // Apply the layout to the window
     window.set_layout(layout)?;
     
-    window.show()?;
-    
-    app.run()
+    Ok(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_form_widget_tree() {
+        let window = build_form().unwrap();
+        let tree = window.widget_tree().unwrap();
+        
+        assert_eq!(tree.kind, WidgetKind::Window);
+        assert_eq!(tree.text, "Contact Form - BoxLayout Demo");
+        assert_eq!(
+            tree.texts(&WidgetKind::Label),
+            ["Name:", "Email:", "Phone:", "Birthdate:", "Country:", "* All fields are required"]
+        );
+        assert_eq!(tree.find_all(&WidgetKind::TextInput).len(), 3);
+        assert_eq!(tree.find_all(&WidgetKind::DatePicker).len(), 1);
+        assert_eq!(tree.find_all(&WidgetKind::ListBox).len(), 1);
+        assert_eq!(
+            tree.texts(&WidgetKind::CheckBox),
+            ["Subscribe to newsletter", "I agree to the terms and conditions"]
+        );
+        assert_eq!(tree.texts(&WidgetKind::Button), ["Submit", "Cancel"]);
+        
+        // The layout stacks the fields from top to bottom
+        let tops: Vec<i32> = tree.children.iter().map(|child| child.bounds.y).collect();
+        assert!(tops.windows(2).all(|pair| pair[0] < pair[1]));
+    }
 }
@@ -0,0 +1,29 @@
+// Hyperlink Example - clickable links in a line of text, opened in the
+// default browser
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Hyperlink - Luma")
+        .size(400, 160)
+        .build()?;
+    
+    let _links = Hyperlink::builder()
+        .text(concat!(
+            r#"Visit the <a href="https://github.com/DaCodeChick/Luma">project page</a> "#,
+            r#"or read the <a href="https://www.rust-lang.org/policies/privacy">privacy policy</a>."#,
+        ))
+        .position(20, 20)
+        .size(360, 40)
+        .on_click(|url| {
+            if let Err(e) = luma_gui::shell::open(url) {
+                eprintln!("{}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,80 @@
+// ImageView Demo - displays a logo scaled to fit the window
+//
+// Pass the path of a .bmp file to show it; without one, a generated logo is used.
+use luma_gui::prelude::*;
+use std::path::PathBuf;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let path = match std::env::args_os().nth(1) {
+        Some(path) => PathBuf::from(path),
+        None => write_sample_logo()?,
+    };
+    
+    let mut window = Window::builder()
+        .title("ImageView Demo - Luma")
+        .size(480, 360)
+        .build()?;
+    
+    let logo = ImageView::builder()
+        .file(&path)
+        .stretch(Stretch::Uniform)
+        .build(&window)?;
+    
+    if let Some(size) = logo.image_size() {
+        println!("Loaded {} ({}x{})", path.display(), size.width, size.height);
+    }
+    
+    // The image view fills the window and rescales the logo on resize
+    let mut layout = BoxLayout::vertical();
+    layout.add(
+        Box::new(logo),
+        LayoutConstraints::default()
+            .expand_both(true)
+            .padding(Padding::all(10))
+    );
+    window.set_layout(layout)?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Write a 160x80 gradient bitmap to the temp directory
+fn write_sample_logo() -> Result<PathBuf> {
+    const WIDTH: u32 = 160;
+    const HEIGHT: u32 = 80;
+    
+    // 24-bit rows are padded to a multiple of 4 bytes
+    let row_size = (WIDTH * 3).div_ceil(4) * 4;
+    let pixel_bytes = row_size * HEIGHT;
+    
+    let mut bmp = Vec::with_capacity(54 + pixel_bytes as usize);
+    // BITMAPFILEHEADER
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&(54 + pixel_bytes).to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&54u32.to_le_bytes());
+    // BITMAPINFOHEADER
+    bmp.extend_from_slice(&40u32.to_le_bytes());
+    bmp.extend_from_slice(&(WIDTH as i32).to_le_bytes());
+    bmp.extend_from_slice(&(HEIGHT as i32).to_le_bytes());
+    bmp.extend_from_slice(&1u16.to_le_bytes());
+    bmp.extend_from_slice(&24u16.to_le_bytes());
+    bmp.extend_from_slice(&[0; 24]);
+    
+    // Rows are stored bottom-up as BGR
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let red = (x * 255 / WIDTH) as u8;
+            let blue = (y * 255 / HEIGHT) as u8;
+            bmp.extend_from_slice(&[blue, 96, red]);
+        }
+        bmp.resize(bmp.len() + (row_size - WIDTH * 3) as usize, 0);
+    }
+    
+    let path = std::env::temp_dir().join("luma_logo.bmp");
+    std::fs::write(&path, bmp)
+        .map_err(|e| Error::OperationFailed(format!("Failed to write sample logo: {}", e)))?;
+    Ok(path)
+}
@@ -0,0 +1,41 @@
+// Label Ellipsis Example
+// A narrow column of labels too short for their text, shortened in each of
+// the ellipsis modes; the path label keeps the file name visible
+
+use luma_gui::prelude::*;
+
+/// Text long enough to overflow the column
+const PATH: &str = r"C:\Users\Ada\Documents\Projects\Analytical Engine\Notes\translation-of-menabrea.txt";
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Label Ellipsis")
+        .size(260, 220)
+        .build()?;
+    
+    let mut layout = BoxLayout::vertical().with_gap(8);
+    let modes = [
+        EllipsisMode::None,
+        EllipsisMode::End,
+        EllipsisMode::Word,
+        EllipsisMode::Path,
+    ];
+    for mode in modes {
+        let label = Label::builder()
+            .text(PATH)
+            .ellipsize(mode)
+            .build(&window)?;
+        layout.add(
+            Box::new(label),
+            LayoutConstraints::default()
+                .preferred_height(20)
+                .padding(Padding::new(0, 10, 0, 10))
+        );
+    }
+    window.set_layout(layout)?;
+    
+    window.show()?;
+    app.run()
+}
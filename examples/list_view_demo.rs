@@ -0,0 +1,92 @@
+// ListView Demo - a table of contacts with column headers
+//
+// Clicking a column header sorts the rows by that column, and clicking it
+// again reverses the order. The Id column sorts as numbers, so 9 comes
+// before 12. Selecting a row prints its position.
+use luma_gui::prelude::*;
+use luma_gui::widgets::compare_numeric;
+
+/// Column titles and widths
+const COLUMNS: [(&str, u32); 4] = [("Id", 50), ("Name", 160), ("Email", 200), ("Country", 140)];
+
+/// The numeric column
+const ID_COLUMN: usize = 0;
+
+/// Id, name, email and country of each contact
+const CONTACTS: [[&str; 4]; 5] = [
+    ["7", "Ada Lovelace", "ada@example.com", "United Kingdom"],
+    ["12", "Grace Hopper", "grace@example.com", "United States"],
+    ["3", "Alan Turing", "alan@example.com", "United Kingdom"],
+    ["41", "Katherine Johnson", "katherine@example.com", "United States"],
+    ["9", "Linus Torvalds", "linus@example.com", "Finland"],
+];
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = build_window()?;
+    window.show()?;
+    
+    app.run()
+}
+
+/// Create the window with the contacts table
+fn build_window() -> Result<Window> {
+    let mut window = Window::builder()
+        .title("ListView Demo - Luma")
+        .size(610, 300)
+        .build()?;
+    
+    let mut layout = BoxLayout::vertical().with_gap(10);
+    
+    let title = Label::builder()
+        .text("Contacts - click a column header to sort")
+        .build(&window)?;
+    layout.add(
+        Box::new(title),
+        LayoutConstraints::default()
+            .preferred_height(20)
+            .padding(Padding::new(10, 10, 0, 10))
+            .expand_horizontal(true)
+    );
+    
+    let mut builder = ListView::builder();
+    for (title, width) in COLUMNS {
+        builder = builder.column(title, width);
+    }
+    for contact in CONTACTS {
+        builder = builder.row(contact);
+    }
+    let table = builder
+        .sortable(true)
+        .comparator(ID_COLUMN, compare_numeric)
+        .on_select(|index| match index {
+            Some(index) => println!("Selected row {}", index),
+            None => println!("No contact selected"),
+        })
+        .on_column_click(|column| println!("Sorted by {}", COLUMNS[column].0))
+        .build(&window)?;
+    layout.add(
+        Box::new(table),
+        LayoutConstraints::default()
+            .padding(Padding::new(0, 10, 10, 10))
+            .expand_horizontal(true)
+            .expand_vertical(true)
+    );
+    
+    window.set_layout(layout)?;
+    
+    Ok(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_contacts_table() {
+        let window = build_window().unwrap();
+        let tree = window.widget_tree().unwrap();
+        assert_eq!(tree.find_all(&WidgetKind::ListView).len(), 1);
+    }
+}
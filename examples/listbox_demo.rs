@@ -34,19 +34,21 @@ fn main() -> Result<()> {
             .padding(Padding::symmetric(0, 10))
     );
     
+    // Already in alphabetical order, so indices match the sorted listbox
+    let fruits = [
+        "Apple",
+        "Banana",
+        "Cherry",
+        "Date",
+        "Elderberry",
+        "Fig",
+        "Grape",
+        "Honeydew",
+        "Kiwi",
+        "Lemon",
+    ];
     let listbox_single = ListBox::builder()
-        .items(vec![
-            "Apple",
-            "Banana",
-            "Cherry",
-            "Date",
-            "Elderberry",
-            "Fig",
-            "Grape",
-            "Honeydew",
-            "Kiwi",
-            "Lemon",
-        ])
+        .items(fruits)
         .sorted(true)
         .on_select_single(|index| {
             match index {
@@ -54,6 +56,7 @@ fn main() -> Result<()> {
                 None => println!("Single-select: No selection"),
             }
         })
+        .on_activate(move |index| println!("Opening {}", fruits[index]))
         .build(&window)?;
     layout.add(
         Box::new(listbox_single),
@@ -64,7 +67,7 @@ fn main() -> Result<()> {
     );
     
     let hint1 = Label::builder()
-        .text("↑ Click to select an item")
+        .text("↑ Click to select an item, double-click to open it")
         .build(&window)?;
     layout.add(
         Box::new(hint1),
@@ -0,0 +1,69 @@
+// ListBox Select All Example
+//
+// The multi-select language list from the ListBox demo, with "Select All" and
+// "Clear" buttons that change the whole selection at once.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("ListBox Select All - Luma")
+        .size(320, 300)
+        .build()?;
+    
+    let languages = Rc::new(RefCell::new(
+        ListBox::builder()
+            .items(vec![
+                "C",
+                "C++",
+                "C#",
+                "Java",
+                "JavaScript",
+                "Python",
+                "Rust",
+                "Go",
+                "Swift",
+                "Kotlin",
+                "TypeScript",
+                "Ruby",
+            ])
+            .multi_select(true)
+            .sorted(true)
+            .on_select_multi(|indices| {
+                println!("Selected {} items: {:?}", indices.len(), indices);
+            })
+            .position(20, 20)
+            .size(260, 180)
+            .build(&window)?
+    ));
+    
+    let for_select_all = Rc::clone(&languages);
+    let _select_all = Button::builder()
+        .label("Select &All")
+        .position(20, 215)
+        .size(120, 28)
+        .on_click(move || {
+            if let Err(e) = for_select_all.borrow_mut().select_all() {
+                eprintln!("Failed to select all: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    let for_clear = Rc::clone(&languages);
+    let _clear = Button::builder()
+        .label("&Clear")
+        .position(160, 215)
+        .size(120, 28)
+        .on_click(move || {
+            if let Err(e) = for_clear.borrow_mut().deselect_all() {
+                eprintln!("Failed to clear selection: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
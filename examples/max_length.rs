@@ -0,0 +1,43 @@
+// Max Length Example
+//
+// A code field limited to 10 characters. The edit control itself rejects
+// anything typed or pasted past the limit, so the counter never goes above
+// 10/10 however much is entered.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const MAX_LENGTH: u32 = 10;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Max Length - Luma")
+        .size(360, 140)
+        .build()?;
+    
+    let counter = Rc::new(RefCell::new(
+        Label::builder()
+            .text(format!("0/{} characters", MAX_LENGTH))
+            .position(20, 60)
+            .size(300, 20)
+            .build(&window)?
+    ));
+    
+    let for_counter = Rc::clone(&counter);
+    let _code = TextInput::builder()
+        .max_length(MAX_LENGTH)
+        .on_text_changed(move |text| {
+            let message = format!("{}/{} characters", text.chars().count(), MAX_LENGTH);
+            if let Err(e) = for_counter.borrow_mut().set_text(&message) {
+                eprintln!("Failed to update counter: {}", e);
+            }
+        })
+        .position(20, 20)
+        .size(300, 24)
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,52 @@
+// NumberBox Demo - a quantity field that updates the order total
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const UNIT_PRICE: f64 = 4.25;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("NumberBox Demo - Luma")
+        .size(360, 200)
+        .build()?;
+    
+    let _item = Label::builder()
+        .text(format!("Widget, ${:.2} each", UNIT_PRICE))
+        .position(20, 20)
+        .size(300, 20)
+        .build(&window)?;
+    
+    let _quantity_label = Label::builder()
+        .text("&Quantity:")
+        .position(20, 55)
+        .size(80, 20)
+        .build(&window)?;
+    
+    let total = Rc::new(RefCell::new(
+        Label::builder()
+            .text(format!("Total: ${:.2}", UNIT_PRICE))
+            .position(20, 100)
+            .size(300, 20)
+            .build(&window)?
+    ));
+    
+    let total_for_change = Rc::clone(&total);
+    let _quantity = NumberBox::builder()
+        .value(1.0)
+        .range(1.0, 99.0)
+        .position(110, 52)
+        .size(80, 24)
+        .on_change(move |quantity| {
+            let text = format!("Total: ${:.2}", quantity * UNIT_PRICE);
+            if let Err(e) = total_for_change.borrow_mut().set_text(&text) {
+                eprintln!("Failed to update total: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,39 @@
+// Open With Shell Example - launching a website in the default browser and
+// a local folder in the file manager
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Open With Shell - Luma")
+        .size(320, 150)
+        .build()?;
+    
+    let _website = Button::builder()
+        .label("Open &Website")
+        .position(20, 20)
+        .size(270, 30)
+        .on_click(|| open_or_report("https://github.com/DaCodeChick/Luma"))
+        .build(&window)?;
+    
+    let _folder = Button::builder()
+        .label("Open &Current Folder")
+        .position(20, 60)
+        .size(270, 30)
+        .on_click(|| match std::env::current_dir() {
+            Ok(dir) => open_or_report(&dir.to_string_lossy()),
+            Err(e) => eprintln!("No current folder: {}", e),
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Open a target, printing the error if the shell could not
+fn open_or_report(target: &str) {
+    if let Err(e) = luma_gui::shell::open(target) {
+        eprintln!("{}", e);
+    }
+}
@@ -0,0 +1,65 @@
+// Panel Demo - rebuilds the contents of a panel when the listbox selection changes
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const CATEGORIES: [(&str, &[&str]); 3] = [
+    ("Fruits", &["Apple", "Banana", "Cherry"]),
+    ("Colors", &["Red", "Green", "Blue", "Yellow"]),
+    ("Animals", &["Cat", "Dog"]),
+];
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Panel Demo - Luma")
+        .size(500, 300)
+        .build()?;
+    
+    let panel = Rc::new(RefCell::new(
+        Panel::builder()
+            .position(230, 20)
+            .size(240, 220)
+            .build(&window)?
+    ));
+    
+    let panel_for_callback = Rc::clone(&panel);
+    let _categories = ListBox::builder()
+        .items(CATEGORIES.iter().map(|(name, _)| *name))
+        .position(20, 20)
+        .size(190, 220)
+        .on_select_single(move |index| {
+            let Some((name, items)) = index.and_then(|i| CATEGORIES.get(i)) else {
+                return;
+            };
+            
+            if let Err(e) = populate(&mut panel_for_callback.borrow_mut(), items) {
+                eprintln!("Failed to populate panel: {}", e);
+                return;
+            }
+            println!("{}: panel now has {} children", name, panel_for_callback.borrow().child_count());
+        })
+        .build(&window)?;
+    
+    populate(&mut panel.borrow_mut(), &["Select a category"])?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Replace the panel's children with one label per item
+fn populate(panel: &mut Panel, items: &[&str]) -> Result<()> {
+    panel.clear_children()?;
+    
+    for (row, item) in items.iter().enumerate() {
+        let label = Label::builder()
+            .text(*item)
+            .position(10, 10 + row as i32 * 25)
+            .size(200, 20)
+            .build(panel)?;
+        panel.add(label);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// Phone Field Example
+//
+// A phone number field that only accepts digits and dashes, typed or pasted,
+// and an age field built with the `numeric()` shortcut.
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Phone Field - Luma")
+        .size(360, 160)
+        .build()?;
+    
+    let _phone_label = Label::builder()
+        .text("Phone:")
+        .position(20, 22)
+        .size(60, 20)
+        .build(&window)?;
+    
+    let _phone = TextInput::builder()
+        .char_filter(|c| c.is_ascii_digit() || c == '-')
+        .on_text_changed(|text| println!("Phone: {}", text))
+        .position(90, 20)
+        .size(230, 24)
+        .build(&window)?;
+    
+    let _age_label = Label::builder()
+        .text("Age:")
+        .position(20, 62)
+        .size(60, 20)
+        .build(&window)?;
+    
+    let _age = TextInput::builder()
+        .numeric()
+        .position(90, 60)
+        .size(80, 24)
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
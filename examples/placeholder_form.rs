@@ -0,0 +1,64 @@
+// Placeholder Form Example
+//
+// The name and email fields of the form demo, with grey hints in place of
+// instructions. The name hint disappears when the field is focused, like a
+// XAML `TextBox.PlaceholderText`; the email hint stays until something is typed.
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Placeholder Form - Luma")
+        .size(360, 200)
+        .build()?;
+    
+    let mut layout = BoxLayout::vertical().with_gap(5);
+    
+    let label_name = Label::builder()
+        .text("Name:")
+        .build(&window)?;
+    layout.add(
+        Box::new(label_name),
+        LayoutConstraints::default()
+            .preferred_height(20)
+            .padding(Padding::new(10, 10, 0, 10))
+    );
+    
+    let input_name = TextInput::builder()
+        .placeholder("Your name here...")
+        .build(&window)?;
+    layout.add(
+        Box::new(input_name),
+        LayoutConstraints::default()
+            .preferred_height(24)
+            .padding(Padding::symmetric(0, 10))
+            .expand_horizontal(true)
+    );
+    
+    let label_email = Label::builder()
+        .text("Email:")
+        .build(&window)?;
+    layout.add(
+        Box::new(label_email),
+        LayoutConstraints::default()
+            .preferred_height(20)
+            .padding(Padding::new(5, 10, 0, 10))
+    );
+    
+    let input_email = TextInput::builder()
+        .placeholder("name@example.com")
+        .show_placeholder_when_focused(true)
+        .build(&window)?;
+    layout.add(
+        Box::new(input_email),
+        LayoutConstraints::default()
+            .preferred_height(24)
+            .padding(Padding::symmetric(0, 10))
+            .expand_horizontal(true)
+    );
+    
+    window.set_layout(layout)?;
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,56 @@
+// Progress Demo - a progress bar switching between determinate and
+// indeterminate states. "Step" advances the bar by a tenth; checking
+// "Working..." starts the marquee animation for work of unknown length, and
+// unchecking it shows the value again.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Progress Demo - Luma")
+        .size(360, 160)
+        .build()?;
+    
+    let bar = Rc::new(RefCell::new(
+        ProgressBar::builder()
+            .range(0, 100)
+            .position(20, 20)
+            .size(300, 20)
+            .build(&window)?
+    ));
+    
+    let bar_for_step = Rc::clone(&bar);
+    let _step = Button::builder()
+        .label("&Step")
+        .position(20, 60)
+        .size(100, 28)
+        .on_click(move || {
+            let mut bar = bar_for_step.borrow_mut();
+            // Start over once the bar is full
+            let next = match bar.value() {
+                Ok(value) if value < 100 => value + 10,
+                _ => 0,
+            };
+            if let Err(e) = bar.set_value(next) {
+                eprintln!("Failed to step progress: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    let bar_for_working = Rc::clone(&bar);
+    let _working = CheckBox::builder()
+        .label("&Working...")
+        .position(140, 64)
+        .on_checked_changed(move |checked| {
+            if let Err(e) = bar_for_working.borrow_mut().set_indeterminate(checked) {
+                eprintln!("Failed to change progress mode: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,27 @@
+// Quit Button Example
+//
+// Ending the application from a widget callback. The Quit button asks the
+// event loop to exit, so `run` returns and main goes on to clean up.
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Quit Button - Luma")
+        .size(260, 120)
+        .build()?;
+    
+    let _quit = Button::builder()
+        .label("&Quit")
+        .position(80, 30)
+        .size(100, 28)
+        .on_click(Application::request_quit)
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()?;
+    
+    println!("Event loop finished, exiting");
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Reflow Demo - rearranges 50 widgets at once, moving them in a single batch
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const CMD_TOGGLE_DIRECTION: u16 = 1;
+const CMD_TOGGLE_GAP: u16 = 2;
+const WIDGET_COUNT: usize = 50;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Reflow Demo - Ctrl+D direction, Ctrl+G gap")
+            .size(900, 700)
+            .build()?
+    ));
+    
+    let mut layout = BoxLayout::vertical().with_gap(2);
+    for index in 0..WIDGET_COUNT {
+        let label = Label::builder()
+            .text(format!("{}", index + 1))
+            .build(&*window.borrow())?;
+        layout.add(
+            Box::new(label),
+            LayoutConstraints::default()
+                .min_width(12)
+                .min_height(8)
+                .expand_both(true)
+        );
+    }
+    window.borrow_mut().set_layout(layout)?;
+    
+    let window_for_direction = Rc::clone(&window);
+    app.register_accelerator(Accelerator::ctrl(Key::Char('D'), CMD_TOGGLE_DIRECTION), move || {
+        reflow(&mut window_for_direction.borrow_mut(), |layout| {
+            let direction = match layout.direction() {
+                LayoutDirection::Vertical => LayoutDirection::Horizontal,
+                LayoutDirection::Horizontal => LayoutDirection::Vertical,
+            };
+            layout.set_direction(direction);
+        });
+    })?;
+    
+    let window_for_gap = Rc::clone(&window);
+    let mut wide_gap = false;
+    app.register_accelerator(Accelerator::ctrl(Key::Char('G'), CMD_TOGGLE_GAP), move || {
+        wide_gap = !wide_gap;
+        let gap = if wide_gap { 6 } else { 2 };
+        reflow(&mut window_for_gap.borrow_mut(), |layout| layout.set_gap(gap));
+    })?;
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
+
+/// Change the window's layout, then move every widget to its new place in one batch
+fn reflow(window: &mut Window, change: impl FnOnce(&mut BoxLayout)) {
+    if let Some(layout) = window.layout_mut() {
+        change(layout);
+    }
+    if let Err(e) = window.relayout() {
+        eprintln!("Relayout failed: {}", e);
+    }
+}
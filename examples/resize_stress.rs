@@ -0,0 +1,39 @@
+// Resize Stress - many laid-out widgets to exercise batched, throttled resizing
+//
+// Drag the window frame: children move together in one DeferWindowPos batch
+// and layout runs at most once per frame while dragging, so the rows resize
+// smoothly instead of flickering one control at a time.
+use luma_gui::prelude::*;
+
+const ROWS: usize = 40;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Resize Stress - Luma")
+        .size(420, 760)
+        .build()?;
+    
+    let mut layout = BoxLayout::vertical().with_gap(2);
+    
+    for row in 0..ROWS {
+        let constraints = LayoutConstraints::default()
+            .min_height(12)
+            .padding(Padding::symmetric(0, 10))
+            .expand_both(true);
+        
+        if row % 2 == 0 {
+            let button = Button::builder().label(format!("Button {}", row + 1)).build(&window)?;
+            layout.add(Box::new(button), constraints);
+        } else {
+            let label = Label::builder().text(format!("Label {}", row + 1)).build(&window)?;
+            layout.add(Box::new(label), constraints);
+        }
+    }
+    
+    window.set_layout(layout)?;
+    
+    window.show()?;
+    app.run()
+}
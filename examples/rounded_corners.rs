@@ -0,0 +1,27 @@
+// Rounded Corners Example
+// Windows 11 corner rounding and accent border/caption colors via DWM
+
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Rounded Corners")
+        .size(400, 250)
+        .build()?;
+    
+    let _label = Label::builder()
+        .text("Rounded corners with an accent border (Windows 11)")
+        .position(20, 20)
+        .size(360, 20)
+        .build(&window)?;
+    
+    // These have no effect on Windows 10 and earlier
+    window.set_corner_preference(CornerPreference::Round);
+    window.set_border_color(Color::rgb(0, 120, 215));
+    window.set_caption_color(Color::rgb(32, 32, 32));
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,70 @@
+// Scrolling Form - a form taller than its window, wrapped in a ScrollViewer
+use luma_gui::prelude::*;
+
+const FIELDS: [&str; 16] = [
+    "First name", "Last name", "Email", "Phone",
+    "Company", "Job title", "Street", "City",
+    "State", "Postal code", "Country", "Website",
+    "Username", "Referral code", "Department", "Notes",
+];
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Scrolling Form - Luma")
+        .size(400, 400)
+        .build()?;
+    
+    let mut scroll_viewer = ScrollViewer::builder()
+        .build(&window)?;
+    
+    // Children of the scrolled content use the scroll viewer as their parent
+    let mut content = BoxLayout::vertical().with_gap(5);
+    for field in FIELDS {
+        let label = Label::builder()
+            .text(format!("{}:", field))
+            .build(&scroll_viewer)?;
+        content.add(
+            Box::new(label),
+            LayoutConstraints::default()
+                .preferred_height(20)
+                .padding(Padding::new(5, 10, 0, 10))
+        );
+        
+        let input = TextInput::builder()
+            .build(&scroll_viewer)?;
+        content.add(
+            Box::new(input),
+            LayoutConstraints::default()
+                .preferred_height(24)
+                .padding(Padding::symmetric(0, 10))
+                .expand_horizontal(true)
+        );
+    }
+    
+    let submit = Button::builder()
+        .label("Submit")
+        .build(&scroll_viewer)?;
+    content.add(
+        Box::new(submit),
+        LayoutConstraints::default()
+            .preferred_height(30)
+            .preferred_width(100)
+            .padding(Padding::new(10, 10, 10, 10))
+    );
+    
+    scroll_viewer.set_content(content)?;
+    
+    // The scroll viewer fills the window; the form scrolls inside it
+    let mut layout = BoxLayout::vertical();
+    layout.add(
+        Box::new(scroll_viewer),
+        LayoutConstraints::default()
+            .expand_both(true)
+    );
+    window.set_layout(layout)?;
+    
+    window.show()?;
+    app.run()
+}
@@ -0,0 +1,42 @@
+// Splash Fade Example
+// A borderless splash window that fades in when shown
+
+use luma_gui::prelude::*;
+use std::time::Duration;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Splash")
+        .size(360, 180)
+        .flags(WindowFlags::borderless())
+        .always_on_top(true)
+        .build()?;
+    
+    let _title = Label::builder()
+        .text("Luma")
+        .position(20, 40)
+        .size(320, 24)
+        .build(&window)?;
+    
+    let _status = Label::builder()
+        .text("Loading, please wait...")
+        .position(20, 80)
+        .size(320, 20)
+        .build(&window)?;
+    
+    let _close = Button::builder()
+        .label("Close")
+        .position(250, 130)
+        .size(90, 30)
+        .on_click(|| std::process::exit(0))
+        .build(&window)?;
+    
+    // The whole window drags, since it has no title bar
+    window.set_drag_region(Rect::new(0, 0, 360, 180));
+    
+    // Starts fully transparent and reaches full opacity after 600ms
+    window.fade_in(Duration::from_millis(600))?;
+    app.run()
+}
@@ -0,0 +1,23 @@
+// Start Maximized Example - a window that opens maximized, and returns to a
+// centered 640x480 window when restored
+use luma_gui::prelude::*;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Start Maximized - Luma")
+        .size(640, 480)
+        .centered(true)
+        .start_state(WindowState::Maximized)
+        .build()?;
+    
+    let _hint = Label::builder()
+        .text("Restore the window to see it centered at 640x480.")
+        .position(20, 20)
+        .size(400, 20)
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
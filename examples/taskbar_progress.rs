@@ -0,0 +1,76 @@
+// Taskbar Progress Example
+//
+// The progress demo with its bar mirrored onto the window's taskbar button.
+// "Step" advances both by a tenth, clearing the taskbar once the bar starts
+// over; checking "Working..." makes both indeterminate.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Taskbar Progress - Luma")
+            .size(360, 160)
+            .build()?,
+    ));
+    
+    let bar = Rc::new(RefCell::new(
+        ProgressBar::builder()
+            .range(0, 100)
+            .position(20, 20)
+            .size(300, 20)
+            .build(&*window.borrow())?
+    ));
+    
+    let bar_for_step = Rc::clone(&bar);
+    let window_for_step = Rc::clone(&window);
+    let _step = Button::builder()
+        .label("&Step")
+        .position(20, 60)
+        .size(100, 28)
+        .on_click(move || {
+            let mut bar = bar_for_step.borrow_mut();
+            // Start over once the bar is full
+            let next = match bar.value() {
+                Ok(value) if value < 100 => value + 10,
+                _ => 0,
+            };
+            let progress = (next > 0).then_some((next as u64, 100));
+            let result = bar
+                .set_value(next)
+                .and_then(|_| window_for_step.borrow_mut().set_taskbar_progress(progress));
+            if let Err(e) = result {
+                eprintln!("Failed to step progress: {}", e);
+            }
+        })
+        .build(&*window.borrow())?;
+    
+    let bar_for_working = Rc::clone(&bar);
+    let window_for_working = Rc::clone(&window);
+    let _working = CheckBox::builder()
+        .label("&Working...")
+        .position(140, 64)
+        .on_checked_changed(move |checked| {
+            let mut bar = bar_for_working.borrow_mut();
+            let mut window = window_for_working.borrow_mut();
+            let result = bar.set_indeterminate(checked).and_then(|_| {
+                if checked {
+                    window.set_taskbar_state(TaskbarProgressState::Indeterminate)
+                } else {
+                    // Showing the value again leaves the marquee
+                    let value = bar.value()?;
+                    window.set_taskbar_progress((value > 0).then_some((value as u64, 100)))
+                }
+            });
+            if let Err(e) = result {
+                eprintln!("Failed to change progress mode: {}", e);
+            }
+        })
+        .build(&*window.borrow())?;
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
@@ -0,0 +1,58 @@
+// Text Selection Example
+//
+// A text input with a "Select All" button and an "Insert Time" button that
+// types the current time at the caret, replacing any selected text.
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Text Selection - Luma")
+        .size(420, 140)
+        .build()?;
+    
+    let input = Rc::new(RefCell::new(
+        TextInput::builder()
+            .text("Click in the text, then insert the time")
+            .position(20, 20)
+            .size(360, 24)
+            .build(&window)?
+    ));
+    
+    let for_select_all = Rc::clone(&input);
+    let _select_all = Button::builder()
+        .label("Select &All")
+        .position(20, 60)
+        .size(120, 28)
+        .on_click(move || {
+            if let Err(e) = for_select_all.borrow_mut().select_all() {
+                eprintln!("Failed to select all: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    let for_insert = Rc::clone(&input);
+    let _insert_time = Button::builder()
+        .label("Insert &Time")
+        .position(160, 60)
+        .size(120, 28)
+        .on_click(move || {
+            if let Err(e) = for_insert.borrow_mut().insert_text_at_caret(&timestamp()) {
+                eprintln!("Failed to insert the time: {}", e);
+            }
+        })
+        .build(&window)?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Format the current UTC time as HH:MM:SS
+fn timestamp() -> String {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    format!("{:02}:{:02}:{:02} UTC", seconds / 3600 % 24, seconds / 60 % 60, seconds % 60)
+}
@@ -0,0 +1,47 @@
+// Theme Change Example
+// Follows the system light/dark setting live, without restarting
+
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn theme_text(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Light => "System theme: Light",
+        Theme::Dark => "System theme: Dark",
+    }
+}
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    let theme = Application::system_theme();
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Theme Follower")
+            .size(400, 150)
+            .build()?,
+    ));
+    window.borrow_mut().set_dark_mode(theme == Theme::Dark);
+    
+    let label = Rc::new(RefCell::new(
+        Label::builder()
+            .text(theme_text(theme))
+            .position(20, 20)
+            .size(360, 20)
+            .build(&*window.borrow())?,
+    ));
+    
+    // Re-theme whenever the user flips the light/dark setting
+    let weak_window = Rc::downgrade(&window);
+    let theme_label = Rc::clone(&label);
+    window.borrow_mut().on_theme_changed(move |theme| {
+        if let Some(window) = weak_window.upgrade() {
+            window.borrow_mut().set_dark_mode(theme == Theme::Dark);
+        }
+        theme_label.borrow_mut().set_text(theme_text(theme)).ok();
+    });
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
@@ -0,0 +1,42 @@
+// Tray Icon Example
+// Hide the window to the notification area and restore it by clicking the icon
+
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Tray Icon Demo")
+            .size(360, 160)
+            .build()?,
+    ));
+    
+    let mut tray = TrayIcon::new(&window.borrow(), Icon::Application, "Tray Icon Demo")?;
+    let tray_window = Rc::clone(&window);
+    tray.on_click(move || {
+        tray_window.borrow_mut().show().ok();
+    });
+    let tray = Rc::new(RefCell::new(tray));
+    
+    let hide_window = Rc::clone(&window);
+    let hide_tray = Rc::clone(&tray);
+    let _minimize = Button::builder()
+        .label("Minimize to tray")
+        .position(20, 20)
+        .size(150, 30)
+        .on_click(move || {
+            hide_window.borrow_mut().hide().ok();
+            hide_tray
+                .borrow_mut()
+                .show_balloon("Still running", "Click the tray icon to restore the window")
+                .ok();
+        })
+        .build(&*window.borrow())?;
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
@@ -0,0 +1,64 @@
+// Widget Handles Example
+//
+// Widgets added to a layout with a handle stay reachable by their concrete
+// type. The task labels are kept in a Vec of handles; "Finish Task" marks the
+// next one done and updates the status label below them.
+use luma_gui::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+const TASKS: [&str; 3] = ["Write the report", "Review the figures", "Send it off"];
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let mut window = Window::builder()
+        .title("Widget Handles - Luma")
+        .size(320, 240)
+        .build()?;
+    
+    let mut tasks = Vec::new();
+    for task in TASKS {
+        let label = Label::builder().text(task).build(&window)?;
+        tasks.push(window.add_widget_with_handle(label, row_constraints())?);
+    }
+    let status = Label::builder()
+        .text(status_text(0))
+        .build(&window)?;
+    let status = window.add_widget_with_handle(status, row_constraints())?;
+    
+    let finished = Rc::new(Cell::new(0));
+    let finish = Button::builder()
+        .label("&Finish Task")
+        .on_click(move || {
+            let done = finished.get();
+            let Some(task) = tasks.get(done) else {
+                return;
+            };
+            finished.set(done + 1);
+            
+            let result = task.borrow_mut()
+                .set_text(&format!("{} (done)", TASKS[done]))
+                .and_then(|_| status.borrow_mut().set_text(&status_text(done + 1)));
+            if let Err(e) = result {
+                eprintln!("Could not update the labels: {}", e);
+            }
+        })
+        .build(&window)?;
+    window.add_widget(finish, row_constraints())?;
+    
+    window.show()?;
+    app.run()
+}
+
+/// Describe how many tasks are done
+fn status_text(done: usize) -> String {
+    format!("{} of {} tasks done", done, TASKS.len())
+}
+
+/// Constraints shared by every row
+fn row_constraints() -> LayoutConstraints {
+    LayoutConstraints::default()
+        .preferred_height(28)
+        .padding(Padding::new(5, 10, 5, 10))
+}
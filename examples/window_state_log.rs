@@ -0,0 +1,48 @@
+// Window State Log Example - prints each maximize, minimize and restore,
+// whether it comes from the title bar buttons or the buttons in the window
+use luma_gui::prelude::*;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+fn main() -> Result<()> {
+    let mut app = Application::new()?;
+    
+    let window = Rc::new(RefCell::new(
+        Window::builder()
+            .title("Window State Log - Luma")
+            .size(360, 140)
+            .on_state_changed(|state| println!("Window is now {:?}", state))
+            .build()?
+    ));
+    
+    let window_for_maximize = Rc::downgrade(&window);
+    let _maximize = Button::builder()
+        .label("Ma&ximize")
+        .position(20, 20)
+        .size(150, 30)
+        .on_click(move || set_state(&window_for_maximize, WindowState::Maximized))
+        .build(&*window.borrow())?;
+    
+    let window_for_minimize = Rc::downgrade(&window);
+    let _minimize = Button::builder()
+        .label("Mi&nimize")
+        .position(180, 20)
+        .size(150, 30)
+        .on_click(move || set_state(&window_for_minimize, WindowState::Minimized))
+        .build(&*window.borrow())?;
+    
+    window.borrow_mut().show()?;
+    app.run()
+}
+
+/// Change the window's state, if it still exists, and check the result
+fn set_state(window: &Weak<RefCell<Window>>, state: WindowState) {
+    let Some(window) = window.upgrade() else {
+        return;
+    };
+    let mut window = window.borrow_mut();
+    if let Err(e) = window.set_state(state) {
+        eprintln!("Failed to change window state: {}", e);
+    }
+    println!("Maximized: {}, minimized: {}", window.is_maximized(), window.is_minimized());
+}